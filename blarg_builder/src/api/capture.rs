@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use thiserror::Error;
 
 use crate::model::Nargs;
@@ -16,14 +17,60 @@ pub trait GenericCapturable<'a, T> {
     /// Declare that the parameter has been matched.
     fn matched(&mut self);
 
+    /// Declare the flag spelling with which the option has been matched (ex: `-v` or `--verbose`).
+    fn spelled(&mut self, _spelling: &str) {
+        // Do nothing.
+    }
+
     /// Capture a value into the generic type T for this parameter.
     fn capture(&mut self, token: &str) -> Result<(), InvalidCapture>;
 
     /// Get the `Nargs` for this implementation.
     fn nargs(&self) -> Nargs;
+
+    /// Get the additional short names for this implementation.
+    fn short_aliases(&self) -> Vec<char> {
+        Vec::default()
+    }
+
+    /// Whether this implementation captures the `--` token as a value (along with all subsequent tokens).
+    fn terminator(&self) -> bool {
+        false
+    }
+
+    /// Whether this implementation captures all the subsequent tokens as values, once matched (even those which look like options).
+    fn rest(&self) -> bool {
+        false
+    }
+
+    /// Whether this implementation prompts for its value, when matched without one.
+    fn prompt(&self) -> bool {
+        false
+    }
+
+    /// Whether this implementation warns when a value resembles an unexpanded glob (ex: `*.txt`).
+    fn warn_on_glob(&self) -> bool {
+        false
+    }
+
+    /// Get the number of consecutive values which are captured together, as a group.
+    fn group(&self) -> u8 {
+        1
+    }
+
+    /// Get the reason this implementation cannot be built (ex: a `Cycle` without any states), if any.
+    /// The reason is reported as a `ConfigError` when the parser is built.
+    fn misconfiguration(&self) -> Option<String> {
+        None
+    }
+
+    /// Whether this implementation may be matched repeatedly, declaring each occurrence via `matched`.
+    fn repeatable(&self) -> bool {
+        false
+    }
 }
 
-#[derive(Debug, Error)]
+#[derive(Debug, Error, PartialEq, Eq)]
 #[doc(hidden)]
 pub enum InvalidCapture {
     #[error("cannot convert '{token}' to {type_name}.")]
@@ -33,4 +80,47 @@ pub enum InvalidCapture {
     },
     #[error("cannot collect '{token}': {message}.")]
     InvalidAdd { token: String, message: String },
+    #[error("{message}")]
+    InvalidValue { token: String, message: String },
+    #[error("{} ({label}).", .error.to_string().trim_end_matches('.'))]
+    InvalidPosition {
+        label: String,
+        error: Box<InvalidCapture>,
+    },
+}
+
+/// The validation of a parameter's value token against the values of its previously declared parameters.
+pub(crate) type Validator<'a> = Box<dyn Fn(&str, &ValidationContext) -> Result<(), String> + 'a>;
+
+/// An immutable snapshot of the parsed value tokens, by parameter name.
+/// Used with [`Parameter::validate_with_context`](./struct.Parameter.html#method.validate_with_context).
+///
+/// The snapshot only contains the parameters declared before the parameter under validation.
+#[derive(Debug, Default)]
+pub struct ValidationContext {
+    values: HashMap<String, Vec<String>>,
+}
+
+impl ValidationContext {
+    pub(crate) fn insert(&mut self, name: impl Into<String>, values: Vec<String>) {
+        self.values.insert(name.into(), values);
+    }
+
+    /// Get the final value token of the parameter `name`, if it was parsed with any values.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values
+            .get(name)
+            .and_then(|values| values.last())
+            .map(String::as_str)
+    }
+
+    /// Get all the value tokens of the parameter `name`, if it was parsed.
+    pub fn values(&self, name: &str) -> Option<&[String]> {
+        self.values.get(name).map(Vec::as_slice)
+    }
+
+    /// Whether the parameter `name` was parsed.
+    pub fn contains(&self, name: &str) -> bool {
+        self.values.contains_key(name)
+    }
 }
@@ -1,13 +1,30 @@
-use std::collections::HashMap;
+use std::cell::Cell;
+use std::collections::{HashMap, HashSet};
 use std::marker::PhantomData;
+use std::rc::Rc;
 
-use crate::api::{Condition, Parameter, ParameterClass};
+use crate::api::{ChoiceKeys, Condition, Parameter, ParameterClass, Validator};
+use crate::matcher::Bound;
+use crate::model::{Messages, Nargs, Section};
 use crate::parser::{
-    ArgumentCapture, ArgumentParameter, ConfigError, ConsoleInterface, GeneralParser,
-    OptionCapture, UserInterface,
+    ArgumentCapture, ArgumentParameter, ConfigError, ConsoleInterface, GeneralParser, Handler,
+    OptionCapture, Preprocessor, QuietInterface, UserInterface,
 };
 use crate::parser::{OptionParameter, ParseUnit, Parser, Printer};
 
+/// The policy with which to handle unknown options (ex: `--moot` or `-m`).
+///
+/// Used with [`CommandLineParser::unknown_option_policy`].
+#[derive(Debug)]
+pub enum UnknownOptionPolicy<'a> {
+    /// Reject the unknown option with a parse error (default).
+    Error,
+    /// Skip over the unknown option, collecting its token (as is) into the sink.
+    Collect(&'a mut Vec<String>),
+    /// Skip over the unknown option.
+    Ignore,
+}
+
 /// The base command line parser.
 ///
 /// ### Example
@@ -23,11 +40,43 @@ use crate::parser::{OptionParameter, ParseUnit, Parser, Printer};
 pub struct CommandLineParser<'a> {
     program: String,
     about: Option<String>,
+    examples: Vec<(String, String)>,
     option_parameters: Vec<OptionParameter>,
     argument_parameters: Vec<ArgumentParameter>,
     option_captures: Vec<OptionCapture<'a>>,
     argument_captures: Vec<ArgumentCapture<'a>>,
+    contextual_parameters: Vec<(String, OptionParameter)>,
+    validators: Vec<(String, Option<Validator<'a>>)>,
+    initials: HashMap<String, String>,
+    deprecations: HashMap<String, String>,
+    value_names: HashMap<String, Vec<String>>,
+    prompts: HashSet<String>,
+    glob_warnings: HashSet<String>,
+    environment: Vec<(String, Option<String>)>,
+    env_prefix: Option<String>,
+    argument_terminator: Option<char>,
+    required_groups: Vec<Vec<String>>,
+    required_options: Vec<String>,
+    presets: Vec<(String, Vec<(String, String)>)>,
+    default_width: Option<usize>,
+    plain_output: bool,
+    messages: Option<Messages>,
+    usage_flag: bool,
+    version: Option<String>,
+    quiet: Option<Rc<Cell<bool>>>,
+    inline_defaults: bool,
+    no_meta: bool,
+    summary_maximum: Option<usize>,
+    section_order: Option<Vec<Section>>,
+    negative_numbers: bool,
+    options_before_positionals: bool,
     discriminator: Option<String>,
+    command_groups: Vec<(String, Vec<String>)>,
+    remainder: Option<&'a mut Vec<String>>,
+    trailing: bool,
+    unknown_option_policy: UnknownOptionPolicy<'a>,
+    preprocessor: Option<Preprocessor<'a>>,
+    deferred_error: Option<ConfigError>,
 }
 
 impl<'a> CommandLineParser<'a> {
@@ -47,11 +96,43 @@ impl<'a> CommandLineParser<'a> {
         Self {
             program: program.into(),
             about: None,
+            examples: Vec::default(),
             option_parameters: Vec::default(),
             argument_parameters: Vec::default(),
             option_captures: Vec::default(),
             argument_captures: Vec::default(),
+            contextual_parameters: Vec::default(),
+            validators: Vec::default(),
+            initials: HashMap::default(),
+            deprecations: HashMap::default(),
+            value_names: HashMap::default(),
+            prompts: HashSet::default(),
+            glob_warnings: HashSet::default(),
+            environment: Vec::default(),
+            env_prefix: None,
+            argument_terminator: None,
+            required_groups: Vec::default(),
+            required_options: Vec::default(),
+            presets: Vec::default(),
+            default_width: None,
+            plain_output: false,
+            messages: None,
+            usage_flag: false,
+            version: None,
+            quiet: None,
+            inline_defaults: false,
+            no_meta: false,
+            summary_maximum: None,
+            section_order: None,
+            negative_numbers: false,
+            options_before_positionals: false,
             discriminator: None,
+            command_groups: Vec::default(),
+            remainder: None,
+            trailing: false,
+            unknown_option_policy: UnknownOptionPolicy::Error,
+            preprocessor: None,
+            deferred_error: None,
         }
     }
 
@@ -78,874 +159,6768 @@ impl<'a> CommandLineParser<'a> {
         self
     }
 
-    /// Add an argument/option to the command line parser.
+    /// Document an example invocation of this command line parser, along with its `description`.
     ///
-    /// The order of argument parameters corresponds to their positional order during parsing.
-    /// The order of option parameters does not affect the command parser semantics.
+    /// The examples are listed in an examples section at the end of the help message, in declaration order.
     ///
     /// ### Example
     /// ```
     /// # use blarg_builder as blarg;
-    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    /// use blarg::CommandLineParser;
     ///
-    /// let mut a: u32 = 0;
-    /// let mut b: u32 = 0;
     /// let parser = CommandLineParser::new("program")
-    ///     .add(Parameter::argument(Scalar::new(&mut a), "a"))
-    ///     .add(Parameter::argument(Scalar::new(&mut b), "b"))
+    ///     .example("program --verbose", "Run the program verbosely.")
     ///     .build();
     ///
-    /// parser.parse_tokens(vec!["1", "2"].as_slice()).unwrap();
-    ///
-    /// assert_eq!(a, 1);
-    /// assert_eq!(b, 2);
+    /// parser.parse_tokens(vec![].as_slice()).unwrap();
     /// ```
-    pub fn add<T>(mut self, parameter: Parameter<'a, T>) -> Self {
-        let inner = parameter.consume();
-        match inner.class() {
-            ParameterClass::Opt => {
-                self.option_parameters.push(OptionParameter::from(&inner));
-                self.option_captures.push(OptionCapture::from(inner));
-            }
-            ParameterClass::Arg => {
-                self.argument_parameters
-                    .push(ArgumentParameter::from(&inner));
-                self.argument_captures.push(ArgumentCapture::from(inner));
-            }
-        }
-
+    pub fn example(mut self, command: impl Into<String>, description: impl Into<String>) -> Self {
+        self.examples.push((command.into(), description.into()));
         self
     }
 
-    /// Branch into a sub-command parser.
+    /// Set the total width used to render the help message when the terminal width cannot be detected (ex: piped output).
+    /// If repeated, only the final width will apply.
     ///
-    /// This changes the command line parser into a sub-command style command line parser.
-    /// Any parameters added before the branch apply to the root parser.
-    ///
-    /// Branching is always done with a special `Scalar` argument: [`Condition`].
+    /// Without a default width, the help message is rendered without an upper bound on its width.
+    /// A detected terminal width always takes precedence, followed by the `COLUMNS` environment variable.
     ///
     /// ### Example
     /// ```
     /// # use blarg_builder as blarg;
-    /// use blarg::{CommandLineParser, Parameter, Scalar, Condition};
+    /// use blarg::CommandLineParser;
     ///
-    /// let mut belongs_to_root: u32 = 0;
-    /// let mut sub_command: String = "".to_string();
-    /// let mut belongs_to_sub_command: u32 = 0;
     /// let parser = CommandLineParser::new("program")
-    ///     .add(Parameter::argument(Scalar::new(&mut belongs_to_root), "belongs_to_root"))
-    ///     .branch(Condition::new(Scalar::new(&mut sub_command), "sub_command"))
-    ///     .command("the-command".to_string(), |sub| {
-    ///         sub.add(Parameter::argument(Scalar::new(&mut belongs_to_sub_command), "belongs_to_sub_command"))
-    ///     })
+    ///     .default_width(80)
     ///     .build();
     ///
-    /// parser.parse_tokens(vec!["1", "the-command", "2"].as_slice()).unwrap();
-    ///
-    /// assert_eq!(belongs_to_root, 1);
-    /// assert_eq!(&sub_command, "the-command");
-    /// assert_eq!(belongs_to_sub_command, 2);
+    /// parser.parse_tokens(vec![].as_slice()).unwrap();
     /// ```
-    pub fn branch<T: std::str::FromStr + std::fmt::Display + PartialEq>(
-        mut self,
-        condition: Condition<'a, T>,
-    ) -> SubCommandParser<'a, T> {
-        let parameter = condition.consume();
-        if self.discriminator.replace(parameter.name()).is_some() {
-            unreachable!("internal error - cannot setup multiple discriminators");
-        }
+    pub fn default_width(mut self, width: usize) -> Self {
+        self.default_width.replace(width);
+        self
+    }
 
-        SubCommandParser::new(self.add(parameter))
+    /// Strip the control characters (ex: ANSI escape sequences) from all of the printed output.
+    ///
+    /// Without this, the control characters are only stripped from output which isn't to a terminal (ex: redirected to a file).
+    /// When branching, this also applies to each sub-command.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::CommandLineParser;
+    ///
+    /// let parser = CommandLineParser::new("program")
+    ///     .plain_output()
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec![].as_slice()).unwrap();
+    /// ```
+    pub fn plain_output(mut self) -> Self {
+        self.plain_output = true;
+        self
     }
 
-    fn build_with_interface(
-        self,
-        user_interface: Box<dyn UserInterface>,
-    ) -> Result<GeneralParser<'a>, ConfigError> {
-        let parser = Parser::new(
-            self.option_captures,
-            self.argument_captures,
-            self.discriminator,
-        )?;
-        let command = ParseUnit::new(
-            parser,
-            Printer::terminal(
-                self.program.clone(),
-                self.about,
-                self.option_parameters,
-                self.argument_parameters,
-            ),
-        );
-        Ok(GeneralParser::command(command, user_interface))
+    /// Render the sections of the help message in the `order` (ex: the options before the positional arguments).
+    /// Any sections omitted from the `order` follow in their default order: positional arguments, options, then examples.
+    /// The usage summary and about message always come first.
+    /// If repeated, only the final order will apply.
+    ///
+    /// When branching, the order also applies to each sub-command.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar, Section};
+    ///
+    /// let mut file: String = "".to_string();
+    /// let parser = CommandLineParser::new("program")
+    ///     .section_order(&[Section::Options, Section::Positionals])
+    ///     .add(Parameter::argument(Scalar::new(&mut file), "file"))
+    ///     .build();
+    ///
+    /// // Documents the options section before the positional arguments section.
+    /// assert_eq!(parser.parse_tokens(vec!["--help"].as_slice()), Err(0));
+    /// ```
+    pub fn section_order(mut self, order: &[Section]) -> Self {
+        self.section_order = Some(order.to_vec());
+        self
     }
 
-    /// Build the command line parser as a Result.
-    /// This finalizes the configuration and checks for errors (ex: a repeated parameter name).
-    pub fn build_parser(self) -> Result<GeneralParser<'a>, ConfigError> {
-        self.build_with_interface(Box::new(ConsoleInterface::default()))
+    /// Set the literal messages used to structure the help message (ex: the `usage:` prefix).
+    /// If repeated, only the final messages will apply.
+    ///
+    /// When branching, the messages also apply to each sub-command.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Messages};
+    ///
+    /// let parser = CommandLineParser::new("program")
+    ///     .messages(Messages::default().usage("Verwendung:"))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec![].as_slice()).unwrap();
+    /// ```
+    pub fn messages(mut self, messages: Messages) -> Self {
+        self.messages.replace(messages);
+        self
     }
 
-    /// Build the command line parser.
-    /// This finalizes the configuration and checks for errors (ex: a repeated parameter name).
-    /// If an error is encountered, exits with error code `1` (via [`std::process::exit`]).
-    pub fn build(self) -> GeneralParser<'a> {
-        match self.build_parser() {
-            Ok(gp) => gp,
-            Err(e) => {
-                eprintln!("{e}");
-                std::process::exit(1);
-            }
-        }
+    /// Add the `--usage` flag, which prints only the single line usage summary and exits.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::CommandLineParser;
+    ///
+    /// let parser = CommandLineParser::new("program")
+    ///     .add_usage_flag()
+    ///     .build();
+    ///
+    /// // Prints "usage: program [-h] [--usage]".
+    /// assert_eq!(parser.parse_tokens(vec!["--usage"].as_slice()), Err(0));
+    /// ```
+    pub fn add_usage_flag(mut self) -> Self {
+        self.usage_flag = true;
+        self
     }
-}
 
-/// The sub-command parser.
-pub struct SubCommandParser<'a, B: std::fmt::Display> {
-    root: CommandLineParser<'a>,
-    commands: HashMap<String, CommandLineParser<'a>>,
-    deferred_error: Option<ConfigError>,
-    _phantom: PhantomData<B>,
-}
+    /// Add the `--version` flag, which prints the program name followed by its `version` and exits.
+    ///
+    /// When branching, the flag also applies to each sub-command.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::CommandLineParser;
+    ///
+    /// let parser = CommandLineParser::new("program")
+    ///     .version(env!("CARGO_PKG_VERSION"))
+    ///     .build();
+    ///
+    /// // Prints "program X.Y.Z".
+    /// assert_eq!(parser.parse_tokens(vec!["--version"].as_slice()), Err(0));
+    /// ```
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
 
-impl<'a, B: std::str::FromStr + std::fmt::Display + PartialEq> SubCommandParser<'a, B> {
-    fn new(root: CommandLineParser<'a>) -> Self {
-        Self {
-            root,
-            commands: HashMap::default(),
-            deferred_error: None,
-            _phantom: PhantomData,
-        }
+    /// Add a `--quiet` flag to the command line parser, which suppresses warnings and notices (but not errors nor help messages).
+    ///
+    /// When branching, the flag also applies to each sub-command.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    ///
+    /// let mut value: u32 = 0;
+    /// let parser = CommandLineParser::new("program")
+    ///     .add_quiet_flag()
+    ///     .add(Parameter::argument(Scalar::new(&mut value), "value"))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["--quiet", "1"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(value, 1);
+    /// ```
+    pub fn add_quiet_flag(mut self) -> Self {
+        self.quiet = Some(Rc::new(Cell::new(false)));
+        self
     }
 
-    /// Setup a sub-command.
+    /// Rewrite the input tokens before they are matched (ex: to support a legacy flag).
+    /// If repeated, only the final preprocessor will apply.
     ///
-    /// Sub-commands may be added arbitrarily, as long as the correspond to the branching type `B`.
-    /// If repeated for the same `variant` of `B`, only the final version will be created on the parser.
-    /// The order of sub-commands does not affect the command parser semantics.
+    /// The `preprocessor` receives all of the input tokens (excluding the program name), and returns the tokens to parse.
+    /// When branching, the tokens are rewritten once, before parsing the command and its sub-command.
     ///
     /// ### Example
     /// ```
     /// # use blarg_builder as blarg;
-    /// use blarg::{CommandLineParser, Condition, Parameter, Scalar};
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
     ///
-    /// let mut value_a: u32 = 0;
-    /// let mut value_b: u32 = 0;
-    /// let mut sub_command: String = "".to_string();
+    /// let mut jobs: u32 = 1;
     /// let parser = CommandLineParser::new("program")
-    ///     .branch(Condition::new(Scalar::new(&mut sub_command), "sub_command"))
-    ///     .command("a".to_string(), |sub| sub.add(Parameter::argument(Scalar::new(&mut value_a), "value_a")))
-    ///     .command("b".to_string(), |sub| {
-    ///         sub.about("Description for the sub-command 'b'.")
-    ///             .add(Parameter::argument(Scalar::new(&mut value_b), "value_b"))
+    ///     .add(Parameter::option(Scalar::new(&mut jobs), "jobs", Some('j')))
+    ///     .preprocess_tokens(|tokens| {
+    ///         tokens
+    ///             .into_iter()
+    ///             .map(|token| match token.as_str() {
+    ///                 "--threads" => "--jobs".to_string(),
+    ///                 _ => token,
+    ///             })
+    ///             .collect()
     ///     })
     ///     .build();
     ///
-    /// parser.parse_tokens(vec!["a", "1"].as_slice()).unwrap();
+    /// parser.parse_tokens(vec!["--threads", "4"].as_slice()).unwrap();
     ///
-    /// assert_eq!(&sub_command, "a");
-    /// assert_eq!(value_a, 1);
-    /// assert_eq!(value_b, 0);
+    /// assert_eq!(jobs, 4);
     /// ```
-    pub fn command(
+    pub fn preprocess_tokens(
         mut self,
-        variant: B,
-        setup_fn: impl FnOnce(SubCommand<'a>) -> SubCommand<'a>,
+        preprocessor: impl Fn(Vec<String>) -> Vec<String> + 'a,
     ) -> Self {
-        let command_str = variant.to_string();
-
-        // Check if the variant does not respect the FromStr-inverts-Display invariant.
-        match B::from_str(&command_str) {
-            // This is where someone is trying to trick us!
-            // The from_str inverts to a valid `B`, however it is not this specific variant.
-            Ok(value) if value != variant => {
-                self.deferred_error.replace(ConfigError(format!(
-                    "parameter '{}' contains invalid sub-command '{command_str}': FromStr does not invert Display.",
-                    self.root.discriminator.as_ref().expect("internal error - root must have a discriminator"),
-                )));
-            }
-            // The from_str simply does not invert to a valid `B`.
-            Err(_) => {
-                self.deferred_error.replace(ConfigError(format!(
-                    "parameter '{}' contains invalid sub-command '{command_str}': FromStr does not invert Display.",
-                    self.root.discriminator.as_ref().expect("internal error - root must have a discriminator"),
-                )));
-            }
-            _ => {
-                // Do nothing.
-            }
-        }
-
-        let inner = CommandLineParser::new(command_str.clone());
-        let sub_command = setup_fn(SubCommand { inner });
-        self.commands.insert(command_str, sub_command.inner);
+        self.preprocessor = Some(Box::new(preprocessor));
         self
     }
 
-    fn build_with_interface(
-        self,
-        user_interface: Box<dyn UserInterface>,
-    ) -> Result<GeneralParser<'a>, ConfigError> {
-        if let Some(error) = self.deferred_error {
-            return Err(error);
-        }
-
-        let mut sub_commands = HashMap::default();
-
-        for (discriminee, cp) in self.commands.into_iter() {
-            let sub_parser = Parser::new(cp.option_captures, cp.argument_captures, None)?;
-            let sub_command = ParseUnit::new(
-                sub_parser,
-                Printer::terminal(
-                    format!(
-                        "{program} {sub_program}",
-                        program = self.root.program,
-                        sub_program = cp.program
-                    ),
-                    cp.about,
-                    cp.option_parameters,
-                    cp.argument_parameters,
-                ),
-            );
-            sub_commands.insert(discriminee, sub_command);
-        }
-
-        let parser = Parser::new(
-            self.root.option_captures,
-            self.root.argument_captures,
-            self.root.discriminator,
-        )?;
-        let command = ParseUnit::new(
-            parser,
-            Printer::terminal(
-                self.root.program.clone(),
-                self.root.about,
-                self.root.option_parameters,
-                self.root.argument_parameters,
-            ),
-        );
-        Ok(GeneralParser::sub_command(
-            // self.root.program,
-            command,
-            sub_commands,
-            user_interface,
-        ))
-    }
-
-    /// Build the sub-command based command line parser as a Result.
-    /// This finalizes the configuration and checks for errors (ex: a repeated parameter name).
-    pub fn build_parser(self) -> Result<GeneralParser<'a>, ConfigError> {
-        self.build_with_interface(Box::new(ConsoleInterface::default()))
+    /// Render the initial value of each parameter inline with its help message, as `(default: X)`.
+    ///
+    /// The initial value is taken from the `initial: X` meta message (as generated by the derive Api), which is otherwise rendered as a separate column.
+    /// When branching, this also applies to each sub-command.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    ///
+    /// let mut level: u32 = 3;
+    /// let parser = CommandLineParser::new("program")
+    ///     .inline_defaults()
+    ///     .add(
+    ///         Parameter::option(Scalar::new(&mut level), "level", None)
+    ///             .help("The level.")
+    ///             .meta(vec!["initial: 3"]),
+    ///     )
+    ///     .build();
+    ///
+    /// // Documents the option as "--level LEVEL   The level. (default: 3)".
+    /// assert_eq!(parser.parse_tokens(vec!["--help"].as_slice()), Err(0));
+    /// ```
+    pub fn inline_defaults(mut self) -> Self {
+        self.inline_defaults = true;
+        self
     }
 
-    /// Build the sub-command based command line parser.
-    /// This finalizes the configuration and checks for errors (ex: a repeated parameter name).
-    /// If an error is encountered, exits with error code `1` (via [`std::process::exit`]).
-    pub fn build(self) -> GeneralParser<'a> {
-        match self.build_parser() {
-            Ok(gp) => gp,
-            Err(e) => {
-                eprintln!("{e}");
-                std::process::exit(1);
-            }
-        }
+    /// Omit the meta messages of every parameter from the help message, for a terse layout of only the flags and their help.
+    ///
+    /// The meta messages are still configured (ex: the type and initial hints generated by the derive Api), but aren't rendered.
+    /// Combined with [`CommandLineParser::inline_defaults`], the initial value is still rendered inline with the help message.
+    /// When branching, this also applies to each sub-command.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    ///
+    /// let mut level: u32 = 3;
+    /// let parser = CommandLineParser::new("program")
+    ///     .no_meta()
+    ///     .add(
+    ///         Parameter::option(Scalar::new(&mut level), "level", None)
+    ///             .help("The level.")
+    ///             .meta(vec!["type: u32", "initial: 3"]),
+    ///     )
+    ///     .build();
+    ///
+    /// // Documents the option as "--level LEVEL   The level.".
+    /// assert_eq!(parser.parse_tokens(vec!["--help"].as_slice()), Err(0));
+    /// ```
+    pub fn no_meta(mut self) -> Self {
+        self.no_meta = true;
+        self
     }
-}
 
-/// A sub-command line parser.
-///
-/// Used with [`SubCommandParser::command`].
-pub struct SubCommand<'a> {
-    inner: CommandLineParser<'a>,
-}
+    /// Summarize the options as `[OPTIONS]` in the usage summary when there are more than `maximum` of them.
+    ///
+    /// Each option is still listed in the detailed section of the help message.
+    /// The built-in flags (ex: `-h`) are always shown in the usage summary.
+    /// When branching, this also applies to each sub-command.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Switch};
+    ///
+    /// let mut verbose: bool = false;
+    /// let mut silent: bool = false;
+    /// let parser = CommandLineParser::new("program")
+    ///     .summarize_options(1)
+    ///     .add(Parameter::option(Switch::new(&mut verbose, true), "verbose", None))
+    ///     .add(Parameter::option(Switch::new(&mut silent, true), "silent", None))
+    ///     .build();
+    ///
+    /// // Documents the usage summary as "usage: program [-h] [OPTIONS]".
+    /// assert_eq!(parser.parse_tokens(vec!["--help"].as_slice()), Err(0));
+    /// ```
+    pub fn summarize_options(mut self, maximum: usize) -> Self {
+        self.summary_maximum = Some(maximum);
+        self
+    }
 
-impl<'a> SubCommand<'a> {
-    /// *Available using 'unit_test' crate feature only.*</br></br>
-    /// Build a [`SubCommand`] for use in testing.
+    /// Match tokens which begin with a `-` followed by a digit (ex: `-5`, `-2.5`, `-30m`) as values, rather than as short options.
+    ///
+    /// This applies to the values of both options and arguments (ex: `program -5`).
+    /// A digit which is itself a short option continues to match that option.
+    /// When branching, this also applies to each sub-command.
     ///
     /// ### Example
     /// ```
     /// # use blarg_builder as blarg;
-    /// use blarg::{Parameter, Scalar, SubCommand};
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
     ///
-    /// // Function under test.
-    /// // We want to make sure the setup_fn is wired up correctly.
-    /// pub fn setup_fn<'a>(value: &'a mut u32) -> impl FnOnce(SubCommand<'a>) -> SubCommand<'a> {
-    ///     |sub| sub.add(Parameter::argument(Scalar::new(value), "value"))
-    /// }
+    /// let mut offset: i32 = 0;
+    /// let parser = CommandLineParser::new("program")
+    ///     .negative_numbers()
+    ///     .add(Parameter::option(Scalar::new(&mut offset), "offset", None))
+    ///     .build();
     ///
-    /// let mut x: u32 = 1;
-    /// let parser = setup_fn(&mut x)(SubCommand::test_dummy()).build_parser().unwrap();
-    /// parser.parse_tokens(vec!["2"].as_slice()).unwrap();
-    /// assert_eq!(x, 2);
+    /// parser.parse_tokens(vec!["--offset", "-5"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(offset, -5);
     /// ```
-    #[cfg(feature = "unit_test")]
-    pub fn test_dummy() -> Self {
-        SubCommand {
-            inner: CommandLineParser::new("test-dummy"),
-        }
+    pub fn negative_numbers(mut self) -> Self {
+        self.negative_numbers = true;
+        self
     }
 
-    /// *Available using 'unit_test' crate feature only.*</br></br>
-    /// Build a [`GeneralParser`] for testing.
-    /// See [`SubCommand::test_dummy`] for an example.
-    #[cfg(feature = "unit_test")]
-    pub fn build_parser(self) -> Result<GeneralParser<'a>, ConfigError> {
-        self.inner
-            .build_with_interface(Box::new(ConsoleInterface::default()))
+    /// Require that the options precede the positional arguments (POSIX style), so that the first positional argument ends option parsing.
+    ///
+    /// Any token after the first positional argument is matched as a positional argument, even when it resembles an option (ex: `--flag`).
+    /// When there's no positional argument to take the token, the parse fails.
+    /// When branching, this also applies to each sub-command.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{Collection, CommandLineParser, Nargs, Parameter, Switch};
+    ///
+    /// let mut verbose: bool = false;
+    /// let mut items: Vec<String> = Vec::default();
+    /// let parser = CommandLineParser::new("program")
+    ///     .options_before_positionals()
+    ///     .add(Parameter::option(Switch::new(&mut verbose, true), "verbose", Some('v')))
+    ///     .add(Parameter::argument(Collection::new(&mut items, Nargs::Any), "item"))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["-v", "a", "-v"].as_slice()).unwrap();
+    ///
+    /// assert!(verbose);
+    /// assert_eq!(items, vec!["a", "-v"]);
+    /// ```
+    pub fn options_before_positionals(mut self) -> Self {
+        self.options_before_positionals = true;
+        self
     }
 
-    /// Document the about message for this sub-command.
-    /// If repeated, only the final help message will apply.
+    /// Match the `sentinel` token as the explicit end of the current argument's values, advancing to the next argument (ex: `a b ; c d`).
+    /// If repeated, only the final sentinel will apply.
     ///
-    /// An about message documents the sub-command in full sentence/paragraph format.
-    /// We recommend allowing `blarg` to format this field (ex: it is not recommended to use line breaks `'\n'`).
+    /// This separates adjacent collection arguments without the need for an intervening option.
+    /// The sentinel itself is never captured, except after a `--` terminator (see [`Collection::split`](crate::Collection::split)).
+    /// When branching, this also applies to each sub-command.
     ///
-    /// See [`SubCommandParser::command`] for usage.
-    pub fn about(self, description: impl Into<String>) -> Self {
-        SubCommand {
-            inner: self.inner.about(description),
-        }
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{Collection, CommandLineParser, Nargs, Parameter};
+    ///
+    /// let mut sources: Vec<String> = Vec::default();
+    /// let mut targets: Vec<String> = Vec::default();
+    /// let parser = CommandLineParser::new("program")
+    ///     .argument_terminator(';')
+    ///     .add(Parameter::argument(Collection::new(&mut sources, Nargs::AtLeastOne), "source"))
+    ///     .add(Parameter::argument(Collection::new(&mut targets, Nargs::AtLeastOne), "target"))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["a", "b", ";", "c", "d"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(sources, vec!["a", "b"]);
+    /// assert_eq!(targets, vec!["c", "d"]);
+    /// ```
+    pub fn argument_terminator(mut self, sentinel: char) -> Self {
+        self.argument_terminator = Some(sentinel);
+        self
     }
 
-    /// Add an argument/option to the sub-command.
+    /// Fall back to the environment variable `{prefix}{NAME}` for each option which is not provided, where `NAME` is the option name uppercased, with dashes replaced by underscores.
+    /// If repeated, only the final prefix will apply.
+    ///
+    /// Only options which take values read from the environment, while [`Parameter::env`] overrides the derived variable name.
+    /// When branching, this also applies to each sub-command.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    ///
+    /// let mut output: String = "out.txt".to_string();
+    /// let parser = CommandLineParser::new("program")
+    ///     .env_prefix("PROGRAM_")
+    ///     .add(Parameter::option(Scalar::new(&mut output), "output", None))
+    ///     .build();
+    ///
+    /// // Captures the value of `PROGRAM_OUTPUT` into `output`, when set.
+    /// parser.parse_tokens(vec![].as_slice()).unwrap();
+    /// ```
+    pub fn env_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.env_prefix = Some(prefix.into());
+        self
+    }
+
+    /// Stop parsing at the first positional argument, and collect all the subsequent tokens into the `remainder` (as is).
+    ///
+    /// Useful for wrapper programs, where the tokens following the wrapped command are forwarded rather than interpreted.
+    /// The tokens before the first positional argument are parsed as usual.
+    /// Cannot be used in conjunction with [`CommandLineParser::branch`].
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar, Switch};
+    ///
+    /// let mut verbose: bool = false;
+    /// let mut command: String = "".to_string();
+    /// let mut forward: Vec<String> = Vec::default();
+    /// let parser = CommandLineParser::new("program")
+    ///     .stop_at_first_positional(&mut forward)
+    ///     .add(Parameter::option(Switch::new(&mut verbose, true), "verbose", Some('v')))
+    ///     .add(Parameter::argument(Scalar::new(&mut command), "command"))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["-v", "run", "--flag", "x"].as_slice()).unwrap();
+    ///
+    /// assert!(verbose);
+    /// assert_eq!(&command, "run");
+    /// assert_eq!(forward, vec!["--flag", "x"]);
+    /// ```
+    pub fn stop_at_first_positional(mut self, remainder: &'a mut Vec<String>) -> Self {
+        self.remainder.replace(remainder);
+        self
+    }
+
+    /// Stop parsing once the arguments are satisfied, leaving the first extra positional token and all the subsequent tokens (as is) for a downstream parser.
+    /// Without this, an extra positional token is a parse error.
+    ///
+    /// The remaining tokens are returned by [`GeneralParser::parse_partial`](./struct.GeneralParser.html#method.parse_partial).
+    /// Options are still matched as usual, up until the first extra positional token.
+    /// When branching, this also applies to each sub-command.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    ///
+    /// let mut source: String = "".to_string();
+    /// let parser = CommandLineParser::new("program")
+    ///     .allow_trailing()
+    ///     .add(Parameter::argument(Scalar::new(&mut source), "source"))
+    ///     .build();
+    ///
+    /// let remaining = parser.parse_partial(vec!["a", "b", "--c"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(&source, "a");
+    /// assert_eq!(remaining, vec!["b", "--c"]);
+    /// ```
+    pub fn allow_trailing(mut self) -> Self {
+        self.trailing = true;
+        self
+    }
+
+    /// Handle the unknown options (ex: `--moot` or `-m`) according to the `policy`.
+    ///
+    /// By default, an unknown option is rejected with a parse error ([`UnknownOptionPolicy::Error`]).
+    /// Otherwise, the unknown option token is skipped over, as if absent from the input.
+    /// Only the option token itself is skipped (ex: the `x` in `--moot x` is matched as usual).
+    /// Short options are skipped as a whole token when any of its characters is unknown (ex: `-vm`).
+    /// Tokens following a `--` terminator (see [`Collection::split`](crate::Collection::split)) are never considered options.
+    /// This only applies to this command, not to its sub-commands.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar, UnknownOptionPolicy};
+    ///
+    /// let mut value: u32 = 0;
+    /// let mut unknown: Vec<String> = Vec::default();
+    /// let parser = CommandLineParser::new("program")
+    ///     .unknown_option_policy(UnknownOptionPolicy::Collect(&mut unknown))
+    ///     .add(Parameter::argument(Scalar::new(&mut value), "value"))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["--moot", "1", "-m"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(value, 1);
+    /// assert_eq!(unknown, vec!["--moot", "-m"]);
+    /// ```
+    pub fn unknown_option_policy(mut self, policy: UnknownOptionPolicy<'a>) -> Self {
+        self.unknown_option_policy = policy;
+        self
+    }
+
+    /// Add an argument/option to the command line parser.
     ///
     /// The order of argument parameters corresponds to their positional order during parsing.
-    /// The order of option parameters does not affect the sub-command parser semantics.
+    /// The order of option parameters does not affect the command parser semantics.
     ///
-    /// See [`SubCommandParser::command`] for usage.
-    pub fn add<T>(self, parameter: Parameter<'a, T>) -> Self {
-        SubCommand {
-            inner: self.inner.add(parameter),
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    ///
+    /// let mut a: u32 = 0;
+    /// let mut b: u32 = 0;
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::argument(Scalar::new(&mut a), "a"))
+    ///     .add(Parameter::argument(Scalar::new(&mut b), "b"))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["1", "2"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(a, 1);
+    /// assert_eq!(b, 2);
+    /// ```
+    pub fn add<T>(mut self, parameter: Parameter<'a, T>) -> Self {
+        let mut inner = parameter.consume();
+        self.validators
+            .push((inner.name().to_string(), inner.take_validator()));
+
+        if let Some(initial) = inner.initial() {
+            self.initials
+                .insert(inner.name().to_string(), initial.clone());
         }
-    }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::api::{Collection, Parameter, Scalar, Switch};
-    use crate::model::Nargs;
-    use crate::parser::util::channel_interface;
-    use crate::prelude::Choices;
-    use crate::test::assert_contains;
-    use rstest::rstest;
+        if let Some(message) = inner.deprecated() {
+            self.deprecations
+                .insert(inner.name().to_string(), message.clone());
+        }
 
-    #[test]
-    fn empty_build() {
-        // Setup
-        let clp = CommandLineParser::new("program");
+        if let Some(reason) = inner.misconfiguration() {
+            self.deferred_error.replace(ConfigError(format!(
+                "parameter '{}' {reason}",
+                inner.name()
+            )));
+        }
 
-        // Execute
-        let parser = clp.build_parser().unwrap();
+        if inner.prompt() {
+            self.prompts.insert(inner.name().to_string());
+        }
 
-        // Verify
-        assert_eq!(parser.details(), ("program".to_string(), None));
-        parser.parse_tokens(empty::slice()).unwrap();
+        if inner.warn_on_glob() {
+            self.glob_warnings.insert(inner.name().to_string());
+        }
+
+        if !inner.value_names().is_empty() {
+            let count = inner.value_names().len();
+
+            if u8::try_from(count).map(Nargs::Precisely).ok() != Some(inner.nargs()) {
+                self.deferred_error.replace(ConfigError(format!(
+                    "parameter '{}' cannot name {count} values: it must take precisely {count} values.",
+                    inner.name()
+                )));
+            }
+
+            self.value_names
+                .insert(inner.name().to_string(), inner.value_names().to_vec());
+        }
+
+        if inner.placeholder().is_some()
+            && (inner.class() == ParameterClass::Arg || inner.nargs() != Nargs::Precisely(0))
+        {
+            self.deferred_error.replace(ConfigError(format!(
+                "parameter '{}' cannot have a placeholder: only switches may have a placeholder.",
+                inner.name()
+            )));
+        }
+
+        if inner.class() == ParameterClass::Arg && inner.order().is_some() {
+            self.deferred_error.replace(ConfigError(format!(
+                "parameter '{}' cannot have an order: only options may have an order.",
+                inner.name()
+            )));
+        }
+
+        if inner.class() == ParameterClass::Arg && inner.since().is_some() {
+            self.deferred_error.replace(ConfigError(format!(
+                "parameter '{}' cannot have a since version: only options may have a since version.",
+                inner.name()
+            )));
+        }
+
+        if inner.required() {
+            if inner.class() == ParameterClass::Arg {
+                self.deferred_error.replace(ConfigError(format!(
+                    "parameter '{}' cannot be required: only options may be required.",
+                    inner.name()
+                )));
+            } else {
+                self.required_options.push(inner.name().to_string());
+            }
+        }
+
+        // Only options which take values may read from the environment.
+        if inner.class() == ParameterClass::Opt && inner.nargs() != Nargs::Precisely(0) {
+            self.environment
+                .push((inner.name().to_string(), inner.env().cloned()));
+        } else if inner.env().is_some() {
+            self.deferred_error.replace(ConfigError(format!(
+                "parameter '{}' cannot read from the environment: only options which take values may read from the environment.",
+                inner.name()
+            )));
+        }
+
+        match inner.class() {
+            ParameterClass::Opt => {
+                if inner.group() > 1 {
+                    self.deferred_error.replace(ConfigError(format!(
+                        "parameter '{}' cannot use a repeatable group: only arguments may repeat a group.",
+                        inner.name()
+                    )));
+                }
+
+                match inner.contextual() {
+                    Some(trigger) => self
+                        .contextual_parameters
+                        .push((trigger.clone(), OptionParameter::from(&inner))),
+                    None => self.option_parameters.push(OptionParameter::from(&inner)),
+                }
+                self.option_captures.push(OptionCapture::from(inner));
+            }
+            ParameterClass::Arg => {
+                if inner.contextual().is_some() {
+                    self.deferred_error.replace(ConfigError(format!(
+                        "parameter '{}' cannot use contextual help: only options may be hidden.",
+                        inner.name()
+                    )));
+                }
+
+                self.argument_parameters
+                    .push(ArgumentParameter::from(&inner));
+                self.argument_captures.push(ArgumentCapture::from(inner));
+            }
+        }
+
+        self
     }
 
-    #[rstest]
-    #[case(vec![], false, vec![])]
-    #[case(vec!["1"], false, vec![1])]
-    #[case(vec!["01"], false, vec![1])]
-    #[case(vec!["1", "3", "2"], false, vec![1, 3, 2])]
-    #[case(vec!["--flag"], true, vec![])]
-    #[case(vec!["--flag", "1"], true, vec![1])]
-    #[case(vec!["--flag", "01"], true, vec![1])]
-    #[case(vec!["--flag", "1", "3", "2"], true, vec![1, 3, 2])]
-    fn build(
-        #[case] tokens: Vec<&str>,
-        #[case] expected_flag: bool,
-        #[case] expected_items: Vec<u32>,
-    ) {
-        // Setup
+    /// Require that at least one of the named options is provided.
+    ///
+    /// The requirement is checked after all the tokens have been matched.
+    /// If repeated, each group must be independently satisfied.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Optional, Parameter, Switch};
+    ///
+    /// let mut from_file: Option<String> = None;
+    /// let mut from_url: Option<String> = None;
+    /// let mut from_stdin: bool = false;
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(Optional::new(&mut from_file), "from-file", None))
+    ///     .add(Parameter::option(Optional::new(&mut from_url), "from-url", None))
+    ///     .add(Parameter::option(Switch::new(&mut from_stdin, true), "from-stdin", None))
+    ///     .required_group(&["from-file", "from-url", "from-stdin"])
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["--from-stdin"].as_slice()).unwrap();
+    ///
+    /// assert!(from_stdin);
+    /// ```
+    pub fn required_group(mut self, options: &[&str]) -> Self {
+        self.required_groups
+            .push(options.iter().map(|name| name.to_string()).collect());
+        self
+    }
+
+    /// Add a preset flag, which stands in for each of the mapped option values (ex: `--fast` for `--level 3`).
+    ///
+    /// The option values are provided as though they were matched from the command line, and converted as such.
+    /// Providing the preset flag alongside any of its mapped options is a parse error.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    ///
+    /// let mut level: u32 = 1;
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(Scalar::new(&mut level), "level", None))
+    ///     .preset_flag("fast", &[("level", "3")])
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["--fast"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(level, 3);
+    /// ```
+    pub fn preset_flag(mut self, name: impl Into<String>, options: &[(&str, &str)]) -> Self {
+        let name = name.into();
+        let help = options
+            .iter()
+            .map(|(option, value)| format!("--{option} {value}"))
+            .collect::<Vec<String>>()
+            .join(", ");
+        self.option_parameters.push(OptionParameter::new(
+            name.clone(),
+            None,
+            Nargs::Precisely(0),
+            Some(format!("Same as {help}.")),
+            None,
+            HashMap::default(),
+        ));
+        self.presets.push((
+            name,
+            options
+                .iter()
+                .map(|(option, value)| (option.to_string(), value.to_string()))
+                .collect(),
+        ));
+        self
+    }
+
+    /// Branch into a sub-command parser.
+    ///
+    /// This changes the command line parser into a sub-command style command line parser.
+    /// Any parameters added before the branch apply to the root parser.
+    ///
+    /// Branching is always done with a special `Scalar` argument: [`Condition`].
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar, Condition};
+    ///
+    /// let mut belongs_to_root: u32 = 0;
+    /// let mut sub_command: String = "".to_string();
+    /// let mut belongs_to_sub_command: u32 = 0;
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::argument(Scalar::new(&mut belongs_to_root), "belongs_to_root"))
+    ///     .branch(Condition::new(Scalar::new(&mut sub_command), "sub_command"))
+    ///     .command("the-command".to_string(), |sub| {
+    ///         sub.add(Parameter::argument(Scalar::new(&mut belongs_to_sub_command), "belongs_to_sub_command"))
+    ///     })
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["1", "the-command", "2"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(belongs_to_root, 1);
+    /// assert_eq!(&sub_command, "the-command");
+    /// assert_eq!(belongs_to_sub_command, 2);
+    /// ```
+    pub fn branch<T: std::str::FromStr + std::fmt::Display + PartialEq>(
+        mut self,
+        condition: Condition<'a, T>,
+    ) -> SubCommandParser<'a, T> {
+        let (parameter, choice_keys) = condition.consume();
+        if self.discriminator.replace(parameter.name()).is_some() {
+            unreachable!("internal error - cannot setup multiple discriminators");
+        }
+
+        SubCommandParser::new(self.add(parameter), choice_keys)
+    }
+
+    fn build_unit(self) -> Result<ParseUnit<'a>, ConfigError> {
+        if let Some(error) = self.deferred_error {
+            return Err(error);
+        }
+
+        for (trigger, _) in &self.contextual_parameters {
+            let known = self
+                .option_captures
+                .iter()
+                .map(|(config, _)| config.name())
+                .chain(
+                    self.argument_captures
+                        .iter()
+                        .map(|(config, _)| config.name()),
+                )
+                .any(|name| name == trigger);
+
+            if !known {
+                return Err(ConfigError(format!(
+                    "contextual help refers to unknown parameter '{trigger}'."
+                )));
+            }
+        }
+
+        for group in &self.required_groups {
+            if group.is_empty() {
+                return Err(ConfigError(
+                    "required group must contain at least one option.".to_string(),
+                ));
+            }
+
+            for name in group {
+                let known = self
+                    .option_captures
+                    .iter()
+                    .any(|(config, _)| config.name() == name);
+
+                if !known {
+                    return Err(ConfigError(format!(
+                        "required group refers to unknown option '{name}'."
+                    )));
+                }
+            }
+        }
+
+        for (name, options) in &self.presets {
+            for (option, _) in options {
+                let known = self
+                    .option_captures
+                    .iter()
+                    .any(|(config, _)| config.name() == option);
+
+                if !known {
+                    return Err(ConfigError(format!(
+                        "preset '{name}' refers to unknown option '{option}'."
+                    )));
+                }
+            }
+        }
+
+        if self.discriminator.is_some() && self.remainder.is_some() {
+            return Err(ConfigError(
+                "cannot stop at the first positional argument of a branching parser.".to_string(),
+            ));
+        }
+
+        // The root arguments precede the sub-command, so a greedy argument would consume it.
+        if let Some(discriminator) = &self.discriminator {
+            for (config, _) in &self.argument_captures {
+                if config.name() != discriminator && matches!(config.bound(), Bound::Lower(_)) {
+                    return Err(ConfigError(format!(
+                        "parameter '{}' cannot take an unbounded number of values in a branching parser: it would consume the sub-command.",
+                        config.name()
+                    )));
+                }
+            }
+        }
+
+        let discriminator = self.discriminator.clone();
+        let env_prefix = self.env_prefix;
+        let environment: Vec<(String, String)> = self
+            .environment
+            .into_iter()
+            .filter_map(|(name, variable)| {
+                // Derive the variable from the option name (ex: `--dry-run` -> `PREFIX_DRY_RUN`), unless explicitly named.
+                let variable = variable.or_else(|| {
+                    env_prefix.as_ref().map(|prefix| {
+                        format!("{prefix}{}", name.to_ascii_uppercase().replace("-", "_"))
+                    })
+                })?;
+                Some((name, variable))
+            })
+            .collect();
+        let mut parser = Parser::new(
+            self.option_captures,
+            self.argument_captures,
+            self.discriminator,
+        )?
+        .with_required_groups(self.required_groups)
+        .with_required_options(self.required_options)
+        .with_validators(self.validators)
+        .with_initials(self.initials)
+        .with_deprecations(self.deprecations)
+        .with_value_names(self.value_names)
+        .with_prompts(self.prompts)
+        .with_glob_warnings(self.glob_warnings)
+        .with_environment(environment.clone());
+
+        if self.usage_flag {
+            parser = parser.with_usage_flag()?;
+        }
+
+        if self.version.is_some() {
+            parser = parser.with_version_flag()?;
+        }
+
+        if self.negative_numbers {
+            parser = parser.with_negative_numbers();
+        }
+
+        if self.options_before_positionals {
+            parser = parser.with_options_before_positionals();
+        }
+
+        if let Some(sentinel) = self.argument_terminator {
+            parser = parser.with_argument_sentinel(sentinel);
+        }
+
+        if let Some(remainder) = self.remainder {
+            parser = parser.with_remainder(remainder);
+        }
+
+        if self.trailing {
+            parser = parser.with_trailing();
+        }
+
+        parser = parser.with_unknown_option_policy(self.unknown_option_policy);
+
+        if let Some(quiet) = &self.quiet {
+            parser = parser.with_quiet_flag(quiet.clone())?;
+        }
+
+        if !self.presets.is_empty() {
+            parser = parser.with_presets(self.presets)?;
+        }
+
+        let mut printer = Printer::terminal(
+            self.program,
+            self.about,
+            self.option_parameters,
+            self.argument_parameters,
+        )
+        .with_contextual(self.contextual_parameters)
+        .with_examples(self.examples)
+        .with_default_width(self.default_width)
+        .with_messages(self.messages.unwrap_or_default())
+        .with_usage_flag(self.usage_flag)
+        .with_version(self.version)
+        .with_quiet_flag(self.quiet.is_some())
+        .with_environment(&environment)
+        .with_inline_defaults(self.inline_defaults)
+        .with_no_meta(self.no_meta)
+        .with_summary_maximum(self.summary_maximum)
+        .with_section_order(self.section_order);
+
+        if let Some(discriminator) = discriminator {
+            printer = printer.with_choice_groups(discriminator, self.command_groups);
+        }
+
+        Ok(ParseUnit::new(parser, printer))
+    }
+
+    fn build_with_interface(
+        mut self,
+        user_interface: Box<dyn UserInterface>,
+    ) -> Result<GeneralParser<'a>, ConfigError> {
+        let user_interface = quiet_interface(user_interface, &self.quiet);
+        let preprocessor = self.preprocessor.take();
+        let command = self.build_unit()?;
+        Ok(GeneralParser::command(command, user_interface).with_preprocessor(preprocessor))
+    }
+
+    /// Build the command line parser as a Result.
+    /// This finalizes the configuration and checks for errors (ex: a repeated parameter name).
+    pub fn build_parser(self) -> Result<GeneralParser<'a>, ConfigError> {
+        let plain_output = self.plain_output;
+        self.build_with_interface(Box::new(ConsoleInterface::new(plain_output)))
+    }
+
+    /// Build the command line parser.
+    /// This finalizes the configuration and checks for errors (ex: a repeated parameter name).
+    /// If an error is encountered, exits with error code `1` (via [`std::process::exit`]).
+    pub fn build(self) -> GeneralParser<'a> {
+        match self.build_parser() {
+            Ok(gp) => gp,
+            Err(e) => {
+                eprintln!("{e}");
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+/// Drop the warnings from the `user_interface` once the `quiet` flag has been matched (when present).
+fn quiet_interface(
+    user_interface: Box<dyn UserInterface>,
+    quiet: &Option<Rc<Cell<bool>>>,
+) -> Box<dyn UserInterface> {
+    match quiet {
+        Some(quiet) => Box::new(QuietInterface::new(user_interface, quiet.clone())),
+        None => user_interface,
+    }
+}
+
+/// The sub-command parser.
+pub struct SubCommandParser<'a, B: std::fmt::Display> {
+    root: CommandLineParser<'a>,
+    commands: HashMap<String, CommandLineParser<'a>>,
+    aliases: HashMap<String, String>,
+    handlers: HashMap<String, Handler<'a>>,
+    choice_keys: ChoiceKeys,
+    help_on_unknown: bool,
+    deferred_error: Option<ConfigError>,
+    _phantom: PhantomData<B>,
+}
+
+impl<'a, B: std::str::FromStr + std::fmt::Display + PartialEq> SubCommandParser<'a, B> {
+    fn new(root: CommandLineParser<'a>, choice_keys: ChoiceKeys) -> Self {
+        Self {
+            root,
+            commands: HashMap::default(),
+            aliases: HashMap::default(),
+            handlers: HashMap::default(),
+            choice_keys,
+            help_on_unknown: false,
+            deferred_error: None,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Setup a sub-command.
+    ///
+    /// Sub-commands may be added arbitrarily, as long as the correspond to the branching type `B`.
+    /// If repeated for the same `variant` of `B`, only the final version will be created on the parser.
+    /// The order of sub-commands does not affect the command parser semantics.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Condition, Parameter, Scalar};
+    ///
+    /// let mut value_a: u32 = 0;
+    /// let mut value_b: u32 = 0;
+    /// let mut sub_command: String = "".to_string();
+    /// let parser = CommandLineParser::new("program")
+    ///     .branch(Condition::new(Scalar::new(&mut sub_command), "sub_command"))
+    ///     .command("a".to_string(), |sub| sub.add(Parameter::argument(Scalar::new(&mut value_a), "value_a")))
+    ///     .command("b".to_string(), |sub| {
+    ///         sub.about("Description for the sub-command 'b'.")
+    ///             .add(Parameter::argument(Scalar::new(&mut value_b), "value_b"))
+    ///     })
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["a", "1"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(&sub_command, "a");
+    /// assert_eq!(value_a, 1);
+    /// assert_eq!(value_b, 0);
+    /// ```
+    pub fn command(
+        mut self,
+        variant: B,
+        setup_fn: impl FnOnce(SubCommand<'a>) -> SubCommand<'a>,
+    ) -> Self {
+        let command_str = variant.to_string();
+
+        // Check if the variant does not respect the FromStr-inverts-Display invariant.
+        match B::from_str(&command_str) {
+            // This is where someone is trying to trick us!
+            // The from_str inverts to a valid `B`, however it is not this specific variant.
+            Ok(value) if value != variant => {
+                self.deferred_error.replace(ConfigError(format!(
+                    "parameter '{}' contains invalid sub-command '{command_str}': FromStr does not invert Display.",
+                    self.root.discriminator.as_ref().expect("internal error - root must have a discriminator"),
+                )));
+            }
+            // The from_str simply does not invert to a valid `B`.
+            Err(_) => {
+                self.deferred_error.replace(ConfigError(format!(
+                    "parameter '{}' contains invalid sub-command '{command_str}': FromStr does not invert Display.",
+                    self.root.discriminator.as_ref().expect("internal error - root must have a discriminator"),
+                )));
+            }
+            _ => {
+                // Do nothing.
+            }
+        }
+
+        // Check if the variant is presented (after its case conversion) the same as another variant.
+        if let Some(other) = self.choice_keys.collision(&command_str) {
+            self.deferred_error.replace(ConfigError(format!(
+                "parameter '{}' contains colliding sub-commands '{other}' and '{command_str}': both are presented as '{}'.",
+                self.root.discriminator.as_ref().expect("internal error - root must have a discriminator"),
+                self.choice_keys.key(&other),
+            )));
+        }
+
+        let command_key = self.choice_keys.key(&command_str);
+        let inner = CommandLineParser::new(command_key.clone());
+        let sub_command = setup_fn(SubCommand { inner });
+        self.handlers.remove(&command_key);
+        self.commands.insert(command_key, sub_command.inner);
+        self
+    }
+
+    /// Setup a sub-command, along with the `handler_fn` to dispatch to once it is selected.
+    ///
+    /// Behaves like [`SubCommandParser::command`], except that the `handler_fn` runs after a successful parse (of any of the `parse*` methods on [`GeneralParser`]) which selects this sub-command.
+    /// The `handler_fn` does not run when the parse fails, or when a help/usage/version message is requested.
+    /// If repeated for the same `variant` of `B`, only the final version (including its handler) will be created on the parser.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Condition, Parameter, Scalar};
+    /// use std::cell::Cell;
+    ///
+    /// let mut sub_command: String = "".to_string();
+    /// let mut value: u32 = 0;
+    /// let dispatched: Cell<Option<&str>> = Cell::new(None);
+    /// let parser = CommandLineParser::new("program")
+    ///     .branch(Condition::new(Scalar::new(&mut sub_command), "sub_command"))
+    ///     .command_with_handler(
+    ///         "a".to_string(),
+    ///         |sub| sub.add(Parameter::argument(Scalar::new(&mut value), "value")),
+    ///         || dispatched.set(Some("a")),
+    ///     )
+    ///     .command_with_handler("b".to_string(), |sub| sub, || dispatched.set(Some("b")))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["a", "1"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(dispatched.get(), Some("a"));
+    /// assert_eq!(value, 1);
+    /// ```
+    pub fn command_with_handler(
+        mut self,
+        variant: B,
+        setup_fn: impl FnOnce(SubCommand<'a>) -> SubCommand<'a>,
+        handler_fn: impl FnOnce() + 'a,
+    ) -> Self {
+        let command_key = self.choice_keys.key(&variant.to_string());
+        self = self.command(variant, setup_fn);
+        self.handlers.insert(command_key, Box::new(handler_fn));
+        self
+    }
+
+    /// Alias the sub-command `variant`, so that it may also be invoked via `alias`.
+    ///
+    /// The alias shares the entire sub-command, including its parameters and help message (which shows the canonical name).
+    /// The `variant` must be setup via [`SubCommandParser::command`], and the alias must not collide with any sub-command.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Condition, Parameter, Scalar, Switch};
+    ///
+    /// let mut sub_command: String = "".to_string();
+    /// let mut force: bool = false;
+    /// let parser = CommandLineParser::new("program")
+    ///     .branch(Condition::new(Scalar::new(&mut sub_command), "sub_command"))
+    ///     .command("checkout".to_string(), |sub| {
+    ///         sub.add(Parameter::option(Switch::new(&mut force, true), "force", None))
+    ///     })
+    ///     .alias("co", "checkout".to_string())
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["co", "--force"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(&sub_command, "checkout");
+    /// assert!(force);
+    /// ```
+    pub fn alias(mut self, alias: impl Into<String>, variant: B) -> Self {
+        let alias = alias.into();
+        let command_str = variant.to_string();
+        self.choice_keys.alias(&alias, &command_str);
+        self.aliases
+            .insert(alias, self.choice_keys.key(&command_str));
+        self
+    }
+
+    /// Group the sub-commands `variants` under the heading `name`, when listing the branch choices in the help message.
+    /// If repeated for the same `name`, the `variants` are added to the existing group.
+    ///
+    /// Groups are listed in the order they are setup, followed by any ungrouped sub-commands (see [`Messages::other_commands`]).
+    /// Each of the `variants` must be setup via [`SubCommandParser::command`], and may belong to only one group.
+    /// Command groups only affect the help message; they do not affect the command parser semantics.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{prelude::*, CommandLineParser, Condition, Scalar};
+    ///
+    /// let mut sub_command: String = "".to_string();
+    /// let parser = CommandLineParser::new("program")
+    ///     .branch(
+    ///         Condition::new(Scalar::new(&mut sub_command), "sub_command")
+    ///             .choice("push".to_string(), "Upload local changes.")
+    ///             .choice("pull".to_string(), "Download remote changes.")
+    ///             .choice("status".to_string(), "Show the working tree status."),
+    ///     )
+    ///     .command("push".to_string(), |sub| sub)
+    ///     .command("pull".to_string(), |sub| sub)
+    ///     .command("status".to_string(), |sub| sub)
+    ///     .command_group("remote commands", &["push".to_string(), "pull".to_string()])
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["status"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(&sub_command, "status");
+    /// ```
+    pub fn command_group(mut self, name: impl Into<String>, variants: &[B]) -> Self {
+        let name = name.into();
+        let keys = variants
+            .iter()
+            .map(|variant| self.choice_keys.key(&variant.to_string()));
+
+        match self
+            .root
+            .command_groups
+            .iter_mut()
+            .find(|(group, _)| group == &name)
+        {
+            Some((_, members)) => members.extend(keys),
+            None => {
+                let members = keys.collect();
+                self.root.command_groups.push((name, members));
+            }
+        }
+
+        self
+    }
+
+    /// Print the help message (listing the sub-commands) along with the error, when the sub-command is unknown.
+    /// By default, only the error is printed.
+    ///
+    /// The help message is printed on `stdout`, while the error message is printed on `stderr`.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Condition, Scalar};
+    ///
+    /// let mut sub_command: String = "".to_string();
+    /// let parser = CommandLineParser::new("program")
+    ///     .branch(Condition::new(Scalar::new(&mut sub_command), "sub_command"))
+    ///     .command("status".to_string(), |sub| sub)
+    ///     .help_on_unknown()
+    ///     .build();
+    ///
+    /// // Prints the help message, followed by the error "unknown sub-command 'wat'."
+    /// assert_eq!(parser.parse_tokens(vec!["wat"].as_slice()), Err(1));
+    /// ```
+    pub fn help_on_unknown(mut self) -> Self {
+        self.help_on_unknown = true;
+        self
+    }
+
+    fn build_with_interface(
+        mut self,
+        user_interface: Box<dyn UserInterface>,
+    ) -> Result<GeneralParser<'a>, ConfigError> {
+        if let Some(error) = self.deferred_error {
+            return Err(error);
+        }
+
+        let mut grouped = HashSet::new();
+
+        for (group, members) in &self.root.command_groups {
+            for key in members {
+                if !self.commands.contains_key(key) {
+                    return Err(ConfigError(format!(
+                        "command group '{group}' refers to unknown sub-command '{key}'."
+                    )));
+                }
+
+                if !grouped.insert(key) {
+                    return Err(ConfigError(format!(
+                        "sub-command '{key}' cannot belong to multiple command groups."
+                    )));
+                }
+            }
+        }
+
+        let mut sub_commands = HashMap::default();
+
+        for (alias, key) in &self.aliases {
+            if self.commands.contains_key(alias) {
+                return Err(ConfigError(format!(
+                    "sub-command alias '{alias}' collides with a sub-command."
+                )));
+            }
+
+            if !self.commands.contains_key(key) {
+                return Err(ConfigError(format!(
+                    "sub-command alias '{alias}' refers to unknown sub-command '{key}'."
+                )));
+            }
+        }
+
+        for (discriminee, mut cp) in self.commands.into_iter() {
+            cp.program = format!(
+                "{program} {sub_program}",
+                program = self.root.program,
+                sub_program = cp.program
+            );
+            cp.default_width = cp.default_width.or(self.root.default_width);
+            cp.messages = self.root.messages.clone();
+            cp.usage_flag |= self.root.usage_flag;
+            cp.version = cp.version.or(self.root.version.clone());
+            cp.quiet = cp.quiet.or(self.root.quiet.clone());
+            cp.inline_defaults |= self.root.inline_defaults;
+            cp.no_meta |= self.root.no_meta;
+            cp.summary_maximum = cp.summary_maximum.or(self.root.summary_maximum);
+            cp.section_order = cp.section_order.or(self.root.section_order.clone());
+            cp.negative_numbers |= self.root.negative_numbers;
+            cp.options_before_positionals |= self.root.options_before_positionals;
+            cp.trailing |= self.root.trailing;
+            cp.env_prefix = cp.env_prefix.or(self.root.env_prefix.clone());
+            cp.argument_terminator = cp.argument_terminator.or(self.root.argument_terminator);
+            sub_commands.insert(discriminee, cp.build_unit()?);
+        }
+
+        let user_interface = quiet_interface(user_interface, &self.root.quiet);
+        let preprocessor = self.root.preprocessor.take();
+        let command = self.root.build_unit()?;
+        Ok(GeneralParser::sub_command(
+            // self.root.program,
+            command,
+            sub_commands,
+            user_interface,
+        )
+        .with_aliases(self.aliases)
+        .with_handlers(self.handlers)
+        .with_preprocessor(preprocessor)
+        .with_help_on_unknown(self.help_on_unknown))
+    }
+
+    /// Build the sub-command based command line parser as a Result.
+    /// This finalizes the configuration and checks for errors (ex: a repeated parameter name).
+    pub fn build_parser(self) -> Result<GeneralParser<'a>, ConfigError> {
+        let plain_output = self.root.plain_output;
+        self.build_with_interface(Box::new(ConsoleInterface::new(plain_output)))
+    }
+
+    /// Build the sub-command based command line parser.
+    /// This finalizes the configuration and checks for errors (ex: a repeated parameter name).
+    /// If an error is encountered, exits with error code `1` (via [`std::process::exit`]).
+    pub fn build(self) -> GeneralParser<'a> {
+        match self.build_parser() {
+            Ok(gp) => gp,
+            Err(e) => {
+                eprintln!("{e}");
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+/// A sub-command line parser.
+///
+/// Used with [`SubCommandParser::command`].
+pub struct SubCommand<'a> {
+    inner: CommandLineParser<'a>,
+}
+
+impl<'a> SubCommand<'a> {
+    /// *Available using 'unit_test' crate feature only.*</br></br>
+    /// Build a [`SubCommand`] for use in testing.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{Parameter, Scalar, SubCommand};
+    ///
+    /// // Function under test.
+    /// // We want to make sure the setup_fn is wired up correctly.
+    /// pub fn setup_fn<'a>(value: &'a mut u32) -> impl FnOnce(SubCommand<'a>) -> SubCommand<'a> {
+    ///     |sub| sub.add(Parameter::argument(Scalar::new(value), "value"))
+    /// }
+    ///
+    /// let mut x: u32 = 1;
+    /// let parser = setup_fn(&mut x)(SubCommand::test_dummy()).build_parser().unwrap();
+    /// parser.parse_tokens(vec!["2"].as_slice()).unwrap();
+    /// assert_eq!(x, 2);
+    /// ```
+    #[cfg(feature = "unit_test")]
+    pub fn test_dummy() -> Self {
+        SubCommand {
+            inner: CommandLineParser::new("test-dummy"),
+        }
+    }
+
+    /// *Available using 'unit_test' crate feature only.*</br></br>
+    /// Build a [`GeneralParser`] for testing.
+    /// See [`SubCommand::test_dummy`] for an example.
+    #[cfg(feature = "unit_test")]
+    pub fn build_parser(self) -> Result<GeneralParser<'a>, ConfigError> {
+        self.inner
+            .build_with_interface(Box::new(ConsoleInterface::default()))
+    }
+
+    /// Document the about message for this sub-command.
+    /// If repeated, only the final help message will apply.
+    ///
+    /// An about message documents the sub-command in full sentence/paragraph format.
+    /// We recommend allowing `blarg` to format this field (ex: it is not recommended to use line breaks `'\n'`).
+    ///
+    /// See [`SubCommandParser::command`] for usage.
+    pub fn about(self, description: impl Into<String>) -> Self {
+        SubCommand {
+            inner: self.inner.about(description),
+        }
+    }
+
+    /// Document an example invocation of this sub-command, along with its `description`.
+    ///
+    /// See [`CommandLineParser::example`] for details.
+    pub fn example(self, command: impl Into<String>, description: impl Into<String>) -> Self {
+        SubCommand {
+            inner: self.inner.example(command, description),
+        }
+    }
+
+    /// Add an argument/option to the sub-command.
+    ///
+    /// The order of argument parameters corresponds to their positional order during parsing.
+    /// The order of option parameters does not affect the sub-command parser semantics.
+    ///
+    /// See [`SubCommandParser::command`] for usage.
+    pub fn add<T>(self, parameter: Parameter<'a, T>) -> Self {
+        SubCommand {
+            inner: self.inner.add(parameter),
+        }
+    }
+
+    /// Require that at least one of the named options is provided to the sub-command.
+    ///
+    /// See [`CommandLineParser::required_group`] for usage.
+    pub fn required_group(self, options: &[&str]) -> Self {
+        SubCommand {
+            inner: self.inner.required_group(options),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{Collection, Cycle, Lazy, Optional, Parameter, Scalar, Switch};
+    use crate::model::{Case, Nargs};
+    use crate::parser::util::channel_interface;
+    use crate::parser::{ConfigWarning, ErrorContext, ParseError, ParseOutcome};
+    use crate::prelude::Choices;
+    use crate::test::assert_contains;
+    use crate::InvalidCapture;
+    use rstest::rstest;
+    use std::cell::RefCell;
+    use std::collections::{BTreeSet, HashMap, VecDeque};
+    use std::path::PathBuf;
+
+    #[test]
+    fn empty_build() {
+        // Setup
+        let clp = CommandLineParser::new("program");
+
+        // Execute
+        let parser = clp.build_parser().unwrap();
+
+        // Verify
+        assert_eq!(parser.details(), ("program".to_string(), None));
+        parser.parse_tokens(empty::slice()).unwrap();
+    }
+
+    #[rstest]
+    #[case(vec![], false, vec![])]
+    #[case(vec!["1"], false, vec![1])]
+    #[case(vec!["01"], false, vec![1])]
+    #[case(vec!["1", "3", "2"], false, vec![1, 3, 2])]
+    #[case(vec!["--flag"], true, vec![])]
+    #[case(vec!["--flag", "1"], true, vec![1])]
+    #[case(vec!["--flag", "01"], true, vec![1])]
+    #[case(vec!["--flag", "1", "3", "2"], true, vec![1, 3, 2])]
+    fn build(
+        #[case] tokens: Vec<&str>,
+        #[case] expected_flag: bool,
+        #[case] expected_items: Vec<u32>,
+    ) {
+        // Setup
+        let mut flag: bool = false;
+        let mut items: Vec<u32> = Vec::default();
+        let clp = CommandLineParser::new("program")
+            .about("abc def")
+            .add(Parameter::option(
+                Switch::new(&mut flag, true),
+                "flag",
+                Some('f'),
+            ))
+            .add(Parameter::argument(
+                Collection::new(&mut items, Nargs::Any),
+                "item",
+            ));
+
+        // Execute
+        let parser = clp.build_parser().unwrap();
+
+        // Verify
+        assert_eq!(
+            parser.details(),
+            ("program".to_string(), Some("abc def".to_string()))
+        );
+
+        // We testing that build sets up the right parser.
+        // So the verification involves invoking the parser with the various permutations.
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+        assert_eq!(flag, expected_flag);
+        assert_eq!(items, expected_items);
+    }
+
+    #[rstest]
+    #[case(vec!["0"], false, 0, vec![], vec![])]
+    #[case(vec!["0", "1"], false, 0, vec![1], vec![])]
+    #[case(vec!["0", "1", "3", "2"], false, 0, vec![1, 3, 2], vec![])]
+    #[case(vec!["1"], false, 1, vec![], vec![])]
+    #[case(vec!["1", "1"], false, 1, vec![], vec![1])]
+    #[case(vec!["1", "1", "3", "2"], false, 1, vec![], vec![1, 3, 2])]
+    #[case(vec!["--flag", "0"], true, 0, vec![], vec![])]
+    #[case(vec!["--flag", "0", "1"], true, 0, vec![1], vec![])]
+    #[case(vec!["--flag", "0", "1", "3", "2"], true, 0, vec![1, 3, 2], vec![])]
+    #[case(vec!["--flag", "1"], true, 1, vec![], vec![])]
+    #[case(vec!["--flag", "1", "1"], true, 1, vec![], vec![1])]
+    #[case(vec!["--flag", "1", "1", "3", "2"], true, 1, vec![], vec![1, 3, 2])]
+    fn branch_build(
+        #[case] tokens: Vec<&str>,
+        #[case] expected_flag: bool,
+        #[case] expected_sub: u32,
+        #[case] expected_items_0: Vec<u32>,
+        #[case] expected_items_1: Vec<u32>,
+    ) {
+        // Setup
+        let mut flag: bool = false;
+        let mut sub: u32 = 0;
+        let mut items_0: Vec<u32> = Vec::default();
+        let mut items_1: Vec<u32> = Vec::default();
+        let clp = CommandLineParser::new("program");
+        let scp = clp
+            .add(Parameter::option(
+                Switch::new(&mut flag, true),
+                "flag",
+                Some('f'),
+            ))
+            .branch(Condition::new(Scalar::new(&mut sub), "sub"))
+            .command(0, |sub| {
+                sub.add(Parameter::argument(
+                    Collection::new(&mut items_0, Nargs::Any),
+                    "item0",
+                ))
+            })
+            .command(1, |sub| {
+                sub.about("abc def").add(Parameter::argument(
+                    Collection::new(&mut items_1, Nargs::Any),
+                    "item1",
+                ))
+            });
+
+        // Execute
+        let parser = scp.build_parser().unwrap();
+
+        // Verify
+        assert_eq!(parser.details(), ("program".to_string(), None));
+        assert_eq!(parser.sub_details("x"), None);
+        assert_eq!(
+            parser.sub_details("0"),
+            Some(("program 0".to_string(), None))
+        );
+        assert_eq!(
+            parser.sub_details("1"),
+            Some(("program 1".to_string(), Some("abc def".to_string())))
+        );
+
+        // We testing that build sets up the right parser.
+        // So the verification involves invoking the parser with the various permutations.
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+        assert_eq!(flag, expected_flag);
+        assert_eq!(sub, expected_sub);
+        assert_eq!(items_0, expected_items_0);
+        assert_eq!(items_1, expected_items_1);
+    }
+
+    #[test]
+    fn repeat_command_build() {
+        // Setup
+        let mut sub: u32 = 0;
+        let mut items_0: Vec<u32> = Vec::default();
+        let mut items_1: Vec<u32> = Vec::default();
+        let clp = CommandLineParser::new("program");
+        let scp = clp
+            .branch(Condition::new(Scalar::new(&mut sub), "sub"))
+            .command(0, |sub| {
+                sub.add(Parameter::argument(
+                    Collection::new(&mut items_0, Nargs::Any),
+                    "item0",
+                ))
+            })
+            .command(0, |sub| {
+                sub.add(Parameter::argument(
+                    Collection::new(&mut items_1, Nargs::Any),
+                    "item1",
+                ))
+            });
+
+        // Execute
+        let parser = scp.build_parser().unwrap();
+
+        // Verify
+        // We testing that build sets up the right parser.
+        // So the verification involves invoking the parser with the various permutations.
+        parser.parse_tokens(&["0", "1", "2", "3"]).unwrap();
+        assert_eq!(sub, 0);
+        assert_eq!(items_0, Vec::default());
+        assert_eq!(items_1, vec![1, 2, 3]);
+    }
+
+    #[rstest]
+    #[case(vec!["abc", "0"], false, "abc", 0, vec![])]
+    #[case(vec!["abc", "0", "1"], false, "abc", 0, vec![1])]
+    #[case(vec!["abc", "0", "1", "3", "2"], false, "abc", 0, vec![1, 3, 2])]
+    #[case(vec!["--flag", "abc", "0"], true, "abc", 0, vec![])]
+    #[case(vec!["--flag", "abc", "0", "1"], true, "abc", 0, vec![1])]
+    #[case(vec!["--flag", "abc", "0", "1", "3", "2"], true, "abc", 0, vec![1, 3, 2])]
+    #[case(vec!["abc", "--flag", "0"], true, "abc", 0, vec![])]
+    #[case(vec!["abc", "--flag", "0", "1"], true, "abc", 0, vec![1])]
+    #[case(vec!["abc", "--flag", "0", "1", "3", "2"], true, "abc", 0, vec![1, 3, 2])]
+    fn root_arguments_branch_build(
+        #[case] tokens: Vec<&str>,
+        #[case] expected_flag: bool,
+        #[case] expected_root: &str,
+        #[case] expected_sub: u32,
+        #[case] expected_items: Vec<u32>,
+    ) {
+        // Setup
+        let mut flag: bool = false;
+        let mut root: String = String::default();
+        let mut sub: u32 = 0;
+        let mut items: Vec<u32> = Vec::default();
+        let clp = CommandLineParser::new("program");
+        let scp = clp
+            .add(Parameter::option(
+                Switch::new(&mut flag, true),
+                "flag",
+                Some('f'),
+            ))
+            .add(Parameter::argument(Scalar::new(&mut root), "root"))
+            .branch(Condition::new(Scalar::new(&mut sub), "sub"))
+            .command(0, |sub| {
+                sub.add(Parameter::argument(
+                    Collection::new(&mut items, Nargs::Any),
+                    "item0",
+                ))
+            });
+
+        // Execute
+        let parser = scp.build_parser().unwrap();
+
+        // Verify
+        assert_eq!(parser.details(), ("program".to_string(), None));
+
+        // We testing that build sets up the right parser.
+        // So the verification involves invoking the parser with the various permutations.
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+        assert_eq!(flag, expected_flag);
+        assert_eq!(&root, expected_root);
+        assert_eq!(sub, expected_sub);
+        assert_eq!(items, expected_items);
+    }
+
+    #[test]
+    fn empty_build_help() {
+        // Setup
+        let clp = CommandLineParser::new("program");
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Verify
+        // We testing that build sets up the right parser.
+        // So the verification involves invoking the parser with --help and spot-checking the output.
+        let error_code = parser.parse_tokens(&["--help"]).unwrap_err();
+        assert_eq!(error_code, 0);
+
+        let message = receiver.consume_message();
+        assert_contains!(message, "usage: program [-h]\n");
+    }
+
+    #[test]
+    fn build_help() {
+        // Setup
+        let mut flag: bool = false;
+        let mut items: Vec<u32> = Vec::default();
+        let mut clp = CommandLineParser::new("program");
+        clp = clp
+            .add(Parameter::option(
+                Switch::new(&mut flag, true),
+                "flag",
+                Some('f'),
+            ))
+            .add(Parameter::argument(
+                Collection::new(&mut items, Nargs::Any),
+                "item",
+            ));
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Verify
+        // We testing that build sets up the right parser.
+        // So the verification involves invoking the parser with --help and spot-checking the output.
+        let error_code = parser.parse_tokens(&["--help"]).unwrap_err();
+        assert_eq!(error_code, 0);
+
+        let message = receiver.consume_message();
+        assert_contains!(message, "usage: program [-h] [-f] [ITEM ...]\n");
+        assert_contains!(message, "-f, --flag");
+    }
+
+    #[test]
+    fn branch_build_help() {
+        // Setup
+        let mut flag: bool = false;
+        let mut sub: u32 = 0;
+        let clp = CommandLineParser::new("program");
+        let scp = clp
+            .add(Parameter::option(
+                Switch::new(&mut flag, true),
+                "flag",
+                Some('f'),
+            ))
+            .branch(
+                Condition::new(Scalar::new(&mut sub), "sub")
+                    .choice(0, "zero")
+                    .choice(1, "one"),
+            )
+            .command(0, |sub| sub)
+            .command(1, |sub| sub);
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Verify
+        // We testing that build sets up the right parser.
+        // So the verification involves invoking the parser with --help and spot-checking the output.
+        let error_code = parser.parse_tokens(&["--help"]).unwrap_err();
+        assert_eq!(error_code, 0);
+
+        let message = receiver.consume_message();
+        assert_contains!(message, "usage: program [-h] [-f] SUB\n");
+        assert_contains!(message, "SUB          {0, 1}");
+        assert_contains!(message, "0          zero");
+        assert_contains!(message, "1          one");
+        assert_contains!(message, "-f, --flag");
+    }
+
+    #[test]
+    fn sub0_command_build_help() {
+        // Setup
+        let mut flag: bool = false;
+        let mut sub: u32 = 0;
+        let mut items: Vec<u32> = Vec::default();
+        let mut extra: bool = false;
+        let clp = CommandLineParser::new("program");
+        let scp = clp
+            .add(Parameter::option(
+                Switch::new(&mut flag, true),
+                "flag",
+                Some('f'),
+            ))
+            .branch(
+                Condition::new(Scalar::new(&mut sub), "sub")
+                    .choice(0, "zero")
+                    .choice(1, "one"),
+            )
+            .command(0, |sub| sub)
+            .command(1, |sub| {
+                sub.add(Parameter::argument(
+                    Collection::new(&mut items, Nargs::Any),
+                    "item",
+                ))
+                .add(Parameter::option(
+                    Switch::new(&mut extra, true),
+                    "extra",
+                    Some('e'),
+                ))
+            });
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Verify
+        // We testing that build sets up the right parser.
+        // So the verification involves invoking the parser with --help and spot-checking the output.
+        let error_code = parser.parse_tokens(&["0", "--help"]).unwrap_err();
+        assert_eq!(error_code, 0);
+
+        let message = receiver.consume_message();
+        assert_contains!(message, "usage: program 0 [-h]\n");
+    }
+
+    #[test]
+    fn sub1_command_build_help() {
+        // Setup
+        let mut flag: bool = false;
+        let mut sub: u32 = 0;
+        let mut items: Vec<u32> = Vec::default();
+        let mut extra: bool = false;
+        let clp = CommandLineParser::new("program");
+        let scp = clp
+            .add(Parameter::option(
+                Switch::new(&mut flag, true),
+                "flag",
+                Some('f'),
+            ))
+            .branch(
+                Condition::new(Scalar::new(&mut sub), "sub")
+                    .choice(0, "zero")
+                    .choice(1, "one"),
+            )
+            .command(0, |sub| sub)
+            .command(1, |sub| {
+                sub.add(Parameter::argument(
+                    Collection::new(&mut items, Nargs::Any),
+                    "item",
+                ))
+                .add(Parameter::option(
+                    Switch::new(&mut extra, true),
+                    "extra",
+                    Some('e'),
+                ))
+            });
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Verify
+        // We testing that build sets up the right parser.
+        // So the verification involves invoking the parser with --help and spot-checking the output.
+        let error_code = parser.parse_tokens(&["1", "--help"]).unwrap_err();
+        assert_eq!(error_code, 0);
+
+        let message = receiver.consume_message();
+        assert_contains!(message, "usage: program 1 [-h] [-e] [ITEM ...]\n");
+        assert_contains!(message, "-e, --extra");
+    }
+
+    #[test]
+    fn root_arguments_branch_build_help() {
+        // Setup
+        let mut flag: bool = false;
+        let mut root: String = String::default();
+        let mut sub: u32 = 0;
+        let mut items: Vec<u32> = Vec::default();
+        let clp = CommandLineParser::new("program");
+        let scp = clp
+            .add(Parameter::option(
+                Switch::new(&mut flag, true),
+                "flag",
+                Some('f'),
+            ))
+            .add(Parameter::argument(Scalar::new(&mut root), "root"))
+            .branch(Condition::new(Scalar::new(&mut sub), "sub"))
+            .command(0, |sub| {
+                sub.add(Parameter::argument(
+                    Collection::new(&mut items, Nargs::Any),
+                    "item0",
+                ))
+            });
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Verify
+        // We testing that build sets up the right parser.
+        // So the verification involves invoking the parser with --help and spot-checking the output.
+        let error_code = parser.parse_tokens(&["--help"]).unwrap_err();
+        assert_eq!(error_code, 0);
+
+        let message = receiver.consume_message();
+        assert_contains!(message, "usage: program [-h] [-f] ROOT SUB\n");
+    }
+
+    #[test]
+    fn contextual_help_build() {
+        // Setup
+        let mut level: u32 = 0;
+        let mut level_curve: u32 = 0;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(Scalar::new(&mut level), "level", None))
+            .add(
+                Parameter::option(Scalar::new(&mut level_curve), "level-curve", None)
+                    .help("Expert tuning.")
+                    .contextual_help("level"),
+            );
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Verify
+        let error_code = parser
+            .parse_tokens(&["--level-curve", "2", "--level", "x"])
+            .unwrap_err();
+        assert_eq!(error_code, 1);
+        assert_eq!(
+            receiver.consume_hints(),
+            Some("hint: see also '--level-curve LEVEL_CURVE': Expert tuning.".to_string())
+        );
+    }
+
+    #[test]
+    fn contextual_help_build_help() {
+        // Setup
+        let mut level: u32 = 0;
+        let mut level_curve: u32 = 0;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(Scalar::new(&mut level), "level", None))
+            .add(
+                Parameter::option(Scalar::new(&mut level_curve), "level-curve", None)
+                    .help("Expert tuning.")
+                    .contextual_help("level"),
+            );
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Verify
+        let error_code = parser.parse_tokens(&["--help"]).unwrap_err();
+        assert_eq!(error_code, 0);
+        assert_eq!(receiver.consume_hints(), None);
+
+        let message = receiver.consume_message();
+        assert_contains!(message, "usage: program [-h] [--level LEVEL]\n");
+        assert!(!message.contains("level-curve"));
+    }
+
+    #[test]
+    fn contextual_help_unknown_trigger() {
+        // Setup
+        let mut level: u32 = 0;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(Scalar::new(&mut level), "level", None).contextual_help("moot"));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let result = clp.build_with_interface(Box::new(sender)).unwrap_err();
+
+        // Verify
+        assert_matches!(result, ConfigError(message) => {
+            assert_eq!(message, "contextual help refers to unknown parameter 'moot'.".to_string());
+        });
+    }
+
+    #[test]
+    fn contextual_help_argument() {
+        // Setup
+        let mut level: u32 = 0;
+        let mut item: u32 = 0;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(Scalar::new(&mut level), "level", None))
+            .add(Parameter::argument(Scalar::new(&mut item), "item").contextual_help("level"));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let result = clp.build_with_interface(Box::new(sender)).unwrap_err();
+
+        // Verify
+        assert_matches!(result, ConfigError(message) => {
+            assert_eq!(message, "parameter 'item' cannot use contextual help: only options may be hidden.".to_string());
+        });
+    }
+
+    #[rstest]
+    #[case(vec![], None)]
+    #[case(vec!["--items"], Some(vec![]))]
+    #[case(vec!["--items", "1", "3"], Some(vec![1, 3]))]
+    fn optional_collection_build(#[case] tokens: Vec<&str>, #[case] expected: Option<Vec<u32>>) {
+        // Setup
+        let mut items: Option<Vec<u32>> = None;
+        let clp = CommandLineParser::new("program").add(Parameter::option(
+            Collection::new(&mut items, Nargs::Any),
+            "items",
+            None,
+        ));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(items, expected);
+    }
+
+    #[test]
+    fn usage_flag_build() {
+        // Setup
         let mut flag: bool = false;
+        let mut item: u32 = 0;
+        let clp = CommandLineParser::new("program")
+            .about("This is the about message.")
+            .add(Parameter::option(Switch::new(&mut flag, true), "flag", Some('f')).help("Flag."))
+            .add(Parameter::argument(Scalar::new(&mut item), "item").help("Item."))
+            .add_usage_flag();
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Verify
+        let error_code = parser.parse_tokens(&["--usage"]).unwrap_err();
+        assert_eq!(error_code, 0);
+
+        let message = receiver.consume_message();
+        assert_eq!(
+            message,
+            "usage: program [-h] [--usage] [-f] ITEM".to_string()
+        );
+    }
+
+    #[test]
+    fn version_build() {
+        // Setup
+        let mut item: u32 = 0;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::argument(Scalar::new(&mut item), "item").help("Item."))
+            .version("1.2.3");
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Verify
+        let error_code = parser.parse_tokens(&["--version"]).unwrap_err();
+        assert_eq!(error_code, 0);
+
+        let message = receiver.consume_message();
+        assert_eq!(message, "program 1.2.3".to_string());
+    }
+
+    #[rstest]
+    #[case(vec!["--help"], true)]
+    #[case(vec!["-h", "not-a-u32"], true)]
+    #[case(vec!["--version"], false)]
+    #[case(vec!["--version", "not-a-u32"], false)]
+    fn parse_tokens_outcome(#[case] tokens: Vec<&str>, #[case] help: bool) {
+        // Setup
+        let mut item: u32 = 0;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::argument(Scalar::new(&mut item), "item").help("Item."))
+            .version("1.2.3");
+        let (sender, receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let outcome = parser.parse_tokens_outcome(tokens.as_slice()).unwrap();
+
+        // Verify
+        if help {
+            let text = match outcome {
+                ParseOutcome::HelpRequested { text } => text,
+                outcome => panic!("unexpected outcome {outcome:?}"),
+            };
+            assert_contains!(text, "usage: program [-h] [--version] ITEM");
+            assert_contains!(text, " --version ");
+        } else {
+            assert_eq!(
+                outcome,
+                ParseOutcome::VersionRequested {
+                    text: "program 1.2.3".to_string()
+                }
+            );
+        }
+
+        assert_eq!(item, 0);
+        let (message, error, error_context) = receiver.consume();
+        assert_eq!(message, None);
+        assert_eq!(error, None);
+        assert_eq!(error_context, None);
+    }
+
+    #[test]
+    fn parse_tokens_outcome_parsed() {
+        // Setup
+        let mut item: u32 = 0;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::argument(Scalar::new(&mut item), "item"))
+            .version("1.2.3");
+        let (sender, _receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let outcome = parser.parse_tokens_outcome(&["2"]).unwrap();
+
+        // Verify
+        assert_eq!(
+            outcome,
+            ParseOutcome::Parsed {
+                counts: HashMap::from([("item".to_string(), 1)]),
+                consumed: HashMap::from([("item".to_string(), vec![(0, "2".to_string())])]),
+            }
+        );
+        assert_eq!(item, 2);
+    }
+
+    #[rstest]
+    #[case(vec!["a", "b", "c"], vec![("item", 3)])]
+    #[case(vec!["a", "b", "c", "--tag", "x"], vec![("item", 3), ("tag", 1)])]
+    #[case(vec!["--verbose", "a"], vec![("verbose", 0), ("item", 1)])]
+    fn parse_tokens_outcome_counts(
+        #[case] tokens: Vec<&str>,
+        #[case] expected: Vec<(&str, usize)>,
+    ) {
+        // Setup
+        let mut verbose: bool = false;
+        let mut tag: String = String::default();
+        let mut items: Vec<String> = Vec::default();
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(
+                Switch::new(&mut verbose, true),
+                "verbose",
+                None,
+            ))
+            .add(Parameter::option(Scalar::new(&mut tag), "tag", None))
+            .add(Parameter::argument(
+                Collection::new(&mut items, Nargs::AtLeastOne),
+                "item",
+            ));
+        let (sender, _receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let outcome = parser.parse_tokens_outcome(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_matches!(outcome, ParseOutcome::Parsed { counts, .. } => {
+            assert_eq!(
+                counts,
+                expected
+                    .into_iter()
+                    .map(|(name, count)| (name.to_string(), count))
+                    .collect()
+            );
+        });
+    }
+
+    #[test]
+    fn parse_tokens_outcome_counts_sub_command() {
+        // Setup
+        let mut sub: String = "".to_string();
+        let mut items: Vec<String> = Vec::default();
+        let clp = CommandLineParser::new("program");
+        let scp = clp
+            .branch(Condition::new(Scalar::new(&mut sub), "sub"))
+            .command("a".to_string(), |sub| {
+                sub.add(Parameter::argument(
+                    Collection::new(&mut items, Nargs::AtLeastOne),
+                    "item",
+                ))
+            });
+        let (sender, _receiver) = channel_interface();
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let outcome = parser.parse_tokens_outcome(&["a", "x", "y"]).unwrap();
+
+        // Verify
+        assert_eq!(
+            outcome,
+            ParseOutcome::Parsed {
+                counts: HashMap::from([("sub".to_string(), 1), ("item".to_string(), 2)]),
+                consumed: HashMap::from([
+                    ("sub".to_string(), vec![(0, "a".to_string())]),
+                    (
+                        "item".to_string(),
+                        vec![(1, "x".to_string()), (2, "y".to_string())]
+                    ),
+                ]),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_tokens_outcome_consumed() {
+        // Setup
+        let mut range: Vec<u32> = Vec::default();
+        let mut source: String = String::default();
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(
+                Collection::new(&mut range, Nargs::Precisely(2)),
+                "range",
+                None,
+            ))
+            .add(Parameter::argument(Scalar::new(&mut source), "source"));
+        let (sender, _receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let outcome = parser
+            .parse_tokens_outcome(&["--range", "1", "10", "file.txt"])
+            .unwrap();
+
+        // Verify
+        assert_matches!(outcome, ParseOutcome::Parsed { consumed, .. } => {
+            assert_eq!(
+                consumed,
+                HashMap::from([
+                    (
+                        "range".to_string(),
+                        vec![(7, "1".to_string()), (8, "10".to_string())]
+                    ),
+                    ("source".to_string(), vec![(10, "file.txt".to_string())]),
+                ])
+            );
+        });
+        assert_eq!(range, vec![1, 10]);
+        assert_eq!(&source, "file.txt");
+    }
+
+    #[test]
+    fn try_parse_tokens() {
+        // Setup
+        let mut verbose: bool = false;
+        let mut value: u32 = 0;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(
+                Switch::new(&mut verbose, true),
+                "verbose",
+                Some('v'),
+            ))
+            .add(Parameter::argument(Scalar::new(&mut value), "value"));
+        let (sender, receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let outcome = parser.try_parse_tokens(&["-v", "5"]).unwrap();
+
+        // Verify
+        assert_matches!(outcome, ParseOutcome::Parsed { counts, .. } => {
+            assert_eq!(
+                counts,
+                HashMap::from([("verbose".to_string(), 0), ("value".to_string(), 1)])
+            );
+        });
+        assert!(verbose);
+        assert_eq!(value, 5);
+
+        let (message, error, error_context) = receiver.consume();
+        assert_eq!(message, None);
+        assert_eq!(error, None);
+        assert_eq!(error_context, None);
+    }
+
+    #[test]
+    fn try_parse_tokens_help() {
+        // Setup
+        let mut value: u32 = 0;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::argument(Scalar::new(&mut value), "value"));
+        let (sender, receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let outcome = parser.try_parse_tokens(&["--help"]).unwrap();
+
+        // Verify
+        assert_matches!(outcome, ParseOutcome::HelpRequested { text } => {
+            assert_contains!(text, "usage: program [-h] VALUE");
+        });
+
+        let (message, error, error_context) = receiver.consume();
+        assert_eq!(message, None);
+        assert_eq!(error, None);
+        assert_eq!(error_context, None);
+    }
+
+    #[rstest]
+    #[case(vec!["-v"], 2)]
+    #[case(vec!["-v", "1", "2"], 3)]
+    #[case(vec!["--verbos", "1"], 0)]
+    fn try_parse_tokens_match_error(#[case] tokens: Vec<&str>, #[case] offset: usize) {
+        // Setup
+        let mut verbose: bool = false;
+        let mut value: u32 = 0;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(
+                Switch::new(&mut verbose, true),
+                "verbose",
+                Some('v'),
+            ))
+            .add(Parameter::argument(Scalar::new(&mut value), "value"));
+        let (sender, receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let failure = parser.try_parse_tokens(tokens.as_slice()).unwrap_err();
+
+        // Verify
+        assert_matches!(failure.error(), ParseError::MatchPhase(_));
+        assert_eq!(failure.error_context(), &ErrorContext::new(offset, &tokens));
+        assert!(!verbose);
+        assert_eq!(value, 0);
+
+        let (message, error, error_context) = receiver.consume();
+        assert_eq!(message, None);
+        assert_eq!(error, None);
+        assert_eq!(error_context, None);
+    }
+
+    #[test]
+    fn try_parse_tokens_capture_error() {
+        // Setup
+        let mut value: u32 = 0;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::argument(Scalar::new(&mut value), "value"));
+        let (sender, receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let failure = parser.try_parse_tokens(&["x"]).unwrap_err();
+
+        // Verify
+        assert_eq!(
+            failure.to_string(),
+            "Parse error during capture: cannot convert 'x' to u32.\nx\n^"
+        );
+        let (error, error_context) = failure.into_parts();
+        assert_eq!(
+            error,
+            ParseError::CapturePhase(
+                "value".to_string(),
+                InvalidCapture::InvalidConversion {
+                    token: "x".to_string(),
+                    type_name: "u32",
+                }
+            )
+        );
+        assert_eq!(error_context.offset(), 0);
+        assert_eq!(error_context.tokens(), vec!["x"]);
+
+        let (message, error, error_context) = receiver.consume();
+        assert_eq!(message, None);
+        assert_eq!(error, None);
+        assert_eq!(error_context, None);
+    }
+
+    #[rstest]
+    #[case(vec!["--help"], "[OPTIONS] ITEM")]
+    #[case(vec!["0", "--help"], "[OPTIONS]")]
+    fn summarize_options_build_help(#[case] tokens: Vec<&str>, #[case] expected: &str) {
+        // Setup
+        let mut sub: u32 = 0;
+        let mut alpha: bool = false;
+        let mut bravo: bool = false;
+        let mut charlie: bool = false;
+        let mut delta: bool = false;
+        let scp = CommandLineParser::new("program")
+            .summarize_options(1)
+            .add(Parameter::option(
+                Switch::new(&mut alpha, true),
+                "alpha",
+                None,
+            ))
+            .add(Parameter::option(
+                Switch::new(&mut bravo, true),
+                "bravo",
+                None,
+            ))
+            .branch(Condition::new(Scalar::new(&mut sub), "item"))
+            .command(0, |sub| {
+                sub.add(Parameter::option(
+                    Switch::new(&mut charlie, true),
+                    "charlie",
+                    None,
+                ))
+                .add(Parameter::option(
+                    Switch::new(&mut delta, true),
+                    "delta",
+                    None,
+                ))
+            });
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Verify
+        let error_code = parser.parse_tokens(tokens.as_slice()).unwrap_err();
+        assert_eq!(error_code, 0);
+
+        let message = receiver.consume_message();
+        assert_contains!(message, &format!("[-h] {expected}\n"));
+    }
+
+    #[rstest]
+    #[case(vec!["--help"])]
+    #[case(vec!["0", "--help"])]
+    fn section_order_build_help(#[case] tokens: Vec<&str>) {
+        // Setup
+        let mut sub: u32 = 0;
+        let mut file: String = "".to_string();
+        let scp = CommandLineParser::new("program")
+            .section_order(&[Section::Options, Section::Positionals])
+            .branch(Condition::new(Scalar::new(&mut sub), "sub"))
+            .command(0, |sub| {
+                sub.add(Parameter::argument(Scalar::new(&mut file), "file"))
+            });
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Verify
+        let error_code = parser.parse_tokens(tokens.as_slice()).unwrap_err();
+        assert_eq!(error_code, 0);
+
+        let message = receiver.consume_message();
+        let options = message.find("options:").unwrap();
+        let positionals = message.find("positional arguments:").unwrap();
+        assert!(options < positionals);
+    }
+
+    #[rstest]
+    #[case(vec!["--help"])]
+    #[case(vec!["0", "--help"])]
+    fn inline_defaults_build_help(#[case] tokens: Vec<&str>) {
+        // Setup
+        let mut sub: u32 = 0;
+        let mut level: u32 = 3;
+        let mut sub_level: u32 = 3;
+        let scp = CommandLineParser::new("program")
+            .inline_defaults()
+            .add(
+                Parameter::option(Scalar::new(&mut level), "level", None)
+                    .help("Level.")
+                    .meta(vec!["type: u32", "initial: 3"]),
+            )
+            .branch(Condition::new(Scalar::new(&mut sub), "sub"))
+            .command(0, |sub| {
+                sub.add(
+                    Parameter::option(Scalar::new(&mut sub_level), "level", None)
+                        .help("Level.")
+                        .meta(vec!["type: u32", "initial: 3"]),
+                )
+            });
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Verify
+        let error_code = parser.parse_tokens(tokens.as_slice()).unwrap_err();
+        assert_eq!(error_code, 0);
+
+        let message = receiver.consume_message();
+        assert_contains!(message, "Level. (default:");
+        assert!(!message.contains("initial: 3"));
+    }
+
+    #[rstest]
+    #[case(vec!["--help"])]
+    #[case(vec!["0", "--help"])]
+    fn no_meta_build_help(#[case] tokens: Vec<&str>) {
+        // Setup
+        let mut sub: u32 = 0;
+        let mut level: u32 = 3;
+        let mut sub_level: u32 = 3;
+        let scp = CommandLineParser::new("program")
+            .no_meta()
+            .add(
+                Parameter::option(Scalar::new(&mut level), "level", None)
+                    .help("Level.")
+                    .meta(vec!["type: u32", "initial: 3"]),
+            )
+            .branch(Condition::new(Scalar::new(&mut sub), "sub").meta(vec!["type: u32"]))
+            .command(0, |sub| {
+                sub.add(
+                    Parameter::option(Scalar::new(&mut sub_level), "level", None)
+                        .help("Level.")
+                        .meta(vec!["type: u32", "initial: 3"]),
+                )
+            });
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Verify
+        let error_code = parser.parse_tokens(tokens.as_slice()).unwrap_err();
+        assert_eq!(error_code, 0);
+
+        let message = receiver.consume_message();
+        assert_contains!(message, "Level.");
+        assert!(!message.contains("type: u32"));
+        assert!(!message.contains("initial: 3"));
+    }
+
+    #[rstest]
+    #[case(vec!["--quiet", "1"], 1, 0)]
+    #[case(vec!["1", "--quiet"], 1, 0)]
+    #[case(vec!["0", "--quiet", "2"], 0, 2)]
+    #[case(vec!["--quiet", "0", "2"], 0, 2)]
+    fn quiet_flag_build(
+        #[case] tokens: Vec<&str>,
+        #[case] expected_sub: u32,
+        #[case] expected_item: u32,
+    ) {
+        // Setup
+        let mut sub: u32 = 0;
+        let mut item: u32 = 0;
+        let scp = CommandLineParser::new("program")
+            .add_quiet_flag()
+            .branch(Condition::new(Scalar::new(&mut sub), "sub"))
+            .command(0, |sub| {
+                sub.add(Parameter::argument(Scalar::new(&mut item), "item"))
+            })
+            .command(1, |sub| sub);
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(sub, expected_sub);
+        assert_eq!(item, expected_item);
+        assert_eq!(receiver.consume_warnings(), None);
+    }
+
+    #[test]
+    fn quiet_flag_help() {
+        // Setup
+        let mut item: u32 = 0;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::argument(Scalar::new(&mut item), "item"))
+            .add_quiet_flag();
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Verify
+        let error_code = parser.parse_tokens(&["--help"]).unwrap_err();
+        assert_eq!(error_code, 0);
+
+        let message = receiver.consume_message();
+        assert_contains!(message, "[--quiet]");
+        assert_contains!(message, " --quiet ");
+    }
+
+    #[test]
+    fn quiet_flag_error() {
+        // Setup
+        let mut item: u32 = 0;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::argument(Scalar::new(&mut item), "item"))
+            .add_quiet_flag();
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Verify
+        let error_code = parser.parse_tokens(&["--quiet", "abc"]).unwrap_err();
+        assert_eq!(error_code, 1);
+
+        let (message, error, error_context) = receiver.consume();
+        assert_eq!(message, None);
+        assert!(error.is_some());
+        assert!(error_context.is_some());
+    }
+
+    #[rstest]
+    #[case(vec!["--lvl", "2"], Some("warning: '--lvl' is deprecated: Use '--level'."))]
+    #[case(vec!["--quiet", "--lvl", "2"], None)]
+    #[case(vec!["--lvl", "2", "--quiet"], None)]
+    #[case(vec!["--level", "2"], None)]
+    fn quiet_flag_deprecated(#[case] tokens: Vec<&str>, #[case] expected: Option<&str>) {
+        // Setup
+        let mut level: u32 = 0;
+        let mut lvl: u32 = 0;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(Scalar::new(&mut level), "level", None))
+            .add(Parameter::option(Scalar::new(&mut lvl), "lvl", None).deprecated("Use '--level'."))
+            .add_quiet_flag();
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(level + lvl, 2);
+        assert_eq!(receiver.consume_warnings(), expected.map(|s| s.to_string()));
+    }
+
+    #[rstest]
+    #[case(vec!["file.txt"], None)]
+    #[case(vec!["*.txt"], Some("warning: '*.txt' for 'file' looks like an unexpanded glob (the shell may not have matched any files)."))]
+    #[case(vec!["--pattern", "data?.csv", "file.txt"], Some("warning: 'data?.csv' for '--pattern' looks like an unexpanded glob (the shell may not have matched any files)."))]
+    #[case(vec!["--quiet", "*.txt"], None)]
+    fn warn_on_glob(#[case] tokens: Vec<&str>, #[case] expected: Option<&str>) {
+        // Setup
+        let mut pattern: String = String::default();
+        let mut file: String = String::default();
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(
+                Scalar::new(&mut pattern).warn_on_glob(),
+                "pattern",
+                None,
+            ))
+            .add(Parameter::argument(
+                Scalar::new(&mut file).warn_on_glob(),
+                "file",
+            ))
+            .add_quiet_flag();
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(&file, tokens.last().unwrap());
+        assert_eq!(receiver.consume_warnings(), expected.map(|s| s.to_string()));
+    }
+
+    #[test]
+    fn glob_without_warning() {
+        // Setup
+        let mut file: String = String::default();
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::argument(Scalar::new(&mut file), "file"));
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(&["*.txt"]).unwrap();
+
+        // Verify
+        assert_eq!(&file, "*.txt");
+        assert_eq!(receiver.consume_warnings(), None);
+    }
+
+    #[test]
+    fn quiet_flag_duplicate() {
+        // Setup
+        let mut quiet: bool = false;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(
+                Switch::new(&mut quiet, true),
+                "quiet",
+                None,
+            ))
+            .add_quiet_flag();
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let result = clp.build_with_interface(Box::new(sender));
+
+        // Verify
+        assert_matches!(result, Err(ConfigError(_)));
+    }
+
+    #[rstest]
+    #[case(vec!["--help"], "$ program 0")]
+    #[case(vec!["0", "--help"], "$ program 0 1")]
+    fn example_build_help(#[case] tokens: Vec<&str>, #[case] expected: &str) {
+        // Setup
+        let mut sub: u32 = 0;
+        let mut item: u32 = 0;
+        let scp = CommandLineParser::new("program")
+            .example("program 0", "Root.")
+            .branch(Condition::new(Scalar::new(&mut sub), "sub"))
+            .command(0, |sub| {
+                sub.add(Parameter::argument(Scalar::new(&mut item), "item"))
+                    .example("program 0 1", "Sub.")
+            });
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Verify
+        let error_code = parser.parse_tokens(tokens.as_slice()).unwrap_err();
+        assert_eq!(error_code, 0);
+
+        let message = receiver.consume_message();
+        assert_contains!(message, "examples:");
+        assert_contains!(message, expected);
+    }
+
+    fn legacy_tokens(tokens: Vec<String>) -> Vec<String> {
+        tokens
+            .into_iter()
+            .flat_map(|token| match token.strip_prefix("-j") {
+                Some(jobs) if !jobs.is_empty() => vec!["--jobs".to_string(), jobs.to_string()],
+                _ if token == "--threads" => vec!["--jobs".to_string()],
+                _ => vec![token],
+            })
+            .collect()
+    }
+
+    #[rstest]
+    #[case(vec![], 1)]
+    #[case(vec!["--jobs", "2"], 2)]
+    #[case(vec!["--threads", "3"], 3)]
+    #[case(vec!["-j4"], 4)]
+    fn preprocess_tokens(#[case] tokens: Vec<&str>, #[case] expected: u32) {
+        // Setup
+        let mut jobs: u32 = 1;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(Scalar::new(&mut jobs), "jobs", None))
+            .preprocess_tokens(legacy_tokens);
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(jobs, expected);
+    }
+
+    #[rstest]
+    #[case(vec!["0", "--threads", "3"], 3)]
+    #[case(vec!["0", "-j4"], 4)]
+    fn preprocess_tokens_sub_command(#[case] tokens: Vec<&str>, #[case] expected: u32) {
+        // Setup
+        let mut sub: u32 = 0;
+        let mut jobs: u32 = 1;
+        let scp = CommandLineParser::new("program")
+            .preprocess_tokens(legacy_tokens)
+            .branch(Condition::new(Scalar::new(&mut sub), "sub"))
+            .command(0, |sub| {
+                sub.add(Parameter::option(Scalar::new(&mut jobs), "jobs", None))
+            });
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(jobs, expected);
+    }
+
+    #[test]
+    fn preprocess_tokens_error() {
+        // Setup
+        let mut jobs: u32 = 1;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(Scalar::new(&mut jobs), "jobs", None))
+            .preprocess_tokens(legacy_tokens);
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        let error_code = parser.parse_tokens(&["-jx"]).unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 1);
+        let (_, error, error_context) = receiver.consume();
+        let error = error.unwrap();
+        assert_contains!(error, "cannot convert 'x' to u32.");
+        // The error context reports the rewritten tokens.
+        assert_eq!(error_context, Some(ErrorContext::new(6, &["--jobs", "x"])));
+    }
+
+    #[test]
+    fn empty_token() {
+        // Setup
+        let mut name: String = "abc".to_string();
+        let mut values: Vec<u32> = Vec::default();
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::argument(Scalar::new(&mut name), "name"))
+            .add(Parameter::argument(
+                Collection::new(&mut values, Nargs::Any),
+                "values",
+            ));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(&["", "1", "2"]).unwrap();
+
+        // Verify
+        assert_eq!(name, "");
+        assert_eq!(values, vec![1, 2]);
+    }
+
+    #[test]
+    fn empty_token_error() {
+        // Setup
+        let mut name: String = "abc".to_string();
+        let mut values: Vec<u32> = Vec::default();
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::argument(Scalar::new(&mut name), "name"))
+            .add(Parameter::argument(
+                Collection::new(&mut values, Nargs::Any),
+                "values",
+            ));
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        let error_code = parser.parse_tokens(&["", "1", "x"]).unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 1);
+        let (_, error, error_context) = receiver.consume();
+        let error = error.unwrap();
+        assert_contains!(error, "cannot convert 'x' to u32.");
+        let error_context = error_context.unwrap();
+        assert_eq!(error_context, ErrorContext::new(3, &["", "1", "x"]));
+        assert_eq!(error_context.to_string(), "\"\" 1 x\n     ^");
+    }
+
+    #[test]
+    fn unknown_option_policy_error() {
+        // Setup
+        let mut value: u32 = 0;
+        let clp = CommandLineParser::new("program")
+            .unknown_option_policy(UnknownOptionPolicy::Error)
+            .add(Parameter::argument(Scalar::new(&mut value), "value"));
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        let error_code = parser.parse_tokens(&["--moot", "1"]).unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 1);
+        let (_, error, error_context) = receiver.consume();
+        let error = error.unwrap();
+        assert_contains!(error, "option 'MOOT' does not exist.");
+        assert_eq!(error_context, Some(ErrorContext::new(0, &["--moot", "1"])));
+    }
+
+    #[rstest]
+    #[case(vec!["--moot", "1"], vec!["--moot"])]
+    #[case(vec!["1", "--moot=x", "-m"], vec!["--moot=x", "-m"])]
+    #[case(vec!["-vm", "1"], vec!["-vm"])]
+    #[case(vec!["-v", "1"], vec![])]
+    fn unknown_option_policy_collect(#[case] tokens: Vec<&str>, #[case] expected: Vec<&str>) {
+        // Setup
+        let mut verbose: bool = false;
+        let mut value: u32 = 0;
+        let mut unknown: Vec<String> = Vec::default();
+        let clp = CommandLineParser::new("program")
+            .unknown_option_policy(UnknownOptionPolicy::Collect(&mut unknown))
+            .add(Parameter::option(
+                Switch::new(&mut verbose, true),
+                "verbose",
+                Some('v'),
+            ))
+            .add(Parameter::argument(Scalar::new(&mut value), "value"));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(value, 1);
+        assert_eq!(unknown, expected);
+    }
+
+    #[test]
+    fn unknown_option_policy_ignore() {
+        // Setup
+        let mut value: u32 = 0;
+        let clp = CommandLineParser::new("program")
+            .unknown_option_policy(UnknownOptionPolicy::Ignore)
+            .add(Parameter::argument(Scalar::new(&mut value), "value"));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(&["--moot", "1", "-m"]).unwrap();
+
+        // Verify
+        assert_eq!(value, 1);
+    }
+
+    #[test]
+    fn unknown_option_policy_split() {
+        // Setup
+        let mut before: Vec<String> = Vec::default();
+        let mut after: Vec<String> = Vec::default();
+        let mut unknown: Vec<String> = Vec::default();
+        let clp = CommandLineParser::new("program")
+            .unknown_option_policy(UnknownOptionPolicy::Collect(&mut unknown))
+            .add(Parameter::argument(
+                Collection::split(&mut before, &mut after),
+                "items",
+            ));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser
+            .parse_tokens(&["a", "--moot", "--", "--flag"])
+            .unwrap();
+
+        // Verify
+        assert_eq!(before, vec!["a"]);
+        assert_eq!(after, vec!["--flag"]);
+        assert_eq!(unknown, vec!["--moot"]);
+    }
+
+    #[test]
+    fn collection_one_of() {
+        // Setup
+        let mut features: HashSet<Camel> = HashSet::default();
+        let clp = CommandLineParser::new("program").add(Parameter::option(
+            Collection::new(&mut features, Nargs::AtLeastOne)
+                .one_of(vec![Camel::FooBar, Camel::BazQux]),
+            "enable",
+            None,
+        ));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser
+            .parse_tokens(&["--enable", "BazQux", "FooBar"])
+            .unwrap();
+
+        // Verify
+        assert_eq!(features, HashSet::from([Camel::FooBar, Camel::BazQux]));
+    }
+
+    #[test]
+    fn collection_one_of_error() {
+        // Setup
+        let mut features: HashSet<Camel> = HashSet::default();
+        let clp = CommandLineParser::new("program").add(Parameter::option(
+            Collection::new(&mut features, Nargs::AtLeastOne)
+                .one_of(vec![Camel::FooBar, Camel::BazQux]),
+            "enable",
+            None,
+        ));
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        let error_code = parser
+            .parse_tokens(&["--enable", "FooBar", "Moot"])
+            .unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 1);
+        let (_, error, _) = receiver.consume();
+        let error = error.unwrap();
+        assert_contains!(error, "'Moot' is not one of 'FooBar', 'BazQux'.");
+    }
+
+    #[test]
+    fn usage_flag_duplicate() {
+        // Setup
+        let mut usage: bool = false;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(
+                Switch::new(&mut usage, true),
+                "usage",
+                None,
+            ))
+            .add_usage_flag();
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let result = clp.build_with_interface(Box::new(sender));
+
+        // Verify
+        assert_matches!(result, Err(ConfigError(_)));
+    }
+
+    #[rstest]
+    #[case(vec!["--range", "x", "2"], "cannot convert 'x' to u32 (MIN).")]
+    #[case(vec!["--range", "1", "x"], "cannot convert 'x' to u32 (MAX).")]
+    fn value_names_error(#[case] tokens: Vec<&str>, #[case] expected: &str) {
+        // Setup
+        let mut range: Vec<u32> = Vec::default();
+        let clp = CommandLineParser::new("program").add(
+            Parameter::option(
+                Collection::new(&mut range, Nargs::Precisely(2)),
+                "range",
+                None,
+            )
+            .value_names(vec!["MIN", "MAX"]),
+        );
+        let (sender, receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let error_code = parser.parse_tokens(tokens.as_slice()).unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 1);
+        let (_, error, _) = receiver.consume();
+        let error = error.unwrap();
+        assert_contains!(error, expected);
+    }
+
+    #[test]
+    fn value_names_build_help() {
+        // Setup
+        let mut range: Vec<u32> = Vec::default();
+        let clp = CommandLineParser::new("program").add(
+            Parameter::option(
+                Collection::new(&mut range, Nargs::Precisely(2)),
+                "range",
+                None,
+            )
+            .value_names(vec!["MIN", "MAX"]),
+        );
+        let (sender, receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let error_code = parser.parse_tokens(&["--help"]).unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 0);
+        let message = receiver.consume_message();
+        assert_contains!(message, "usage: program [-h] [--range MIN MAX]");
+        assert_contains!(message, "\n --range MIN MAX");
+    }
+
+    #[rstest]
+    #[case(Nargs::Precisely(3))]
+    #[case(Nargs::AtLeastOne)]
+    fn value_names_mismatch(#[case] nargs: Nargs) {
+        // Setup
+        let mut range: Vec<u32> = Vec::default();
+        let clp = CommandLineParser::new("program").add(
+            Parameter::option(Collection::new(&mut range, nargs), "range", None)
+                .value_names(vec!["MIN", "MAX"]),
+        );
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let result = clp.build_with_interface(Box::new(sender));
+
+        // Verify
+        assert_matches!(result, Err(ConfigError(message)) => {
+            assert_eq!(
+                message,
+                "parameter 'range' cannot name 2 values: it must take precisely 2 values."
+            );
+        });
+    }
+
+    #[test]
+    fn value_names_overflow() {
+        // Setup
+        let mut range: Vec<u32> = Vec::default();
+        // 257 names would truncate to the 1 value of the collection.
+        let names: Vec<String> = (0..257).map(|i| format!("V{i}")).collect();
+        let clp = CommandLineParser::new("program").add(
+            Parameter::option(
+                Collection::new(&mut range, Nargs::Precisely(1)),
+                "range",
+                None,
+            )
+            .value_names(names),
+        );
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let result = clp.build_with_interface(Box::new(sender));
+
+        // Verify
+        assert_matches!(result, Err(ConfigError(message)) => {
+            assert_eq!(
+                message,
+                "parameter 'range' cannot name 257 values: it must take precisely 257 values."
+            );
+        });
+    }
+
+    #[test]
+    fn placeholder_build_help() {
+        // Setup
+        let mut verbose: bool = false;
+        let clp = CommandLineParser::new("program").add(
+            Parameter::option(Switch::new(&mut verbose, true), "verbose", Some('v'))
+                .placeholder("(on)"),
+        );
+        let (sender, receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let error_code = parser.parse_tokens(&["--help"]).unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 0);
+        let message = receiver.consume_message();
+        assert_contains!(message, "usage: program [-h] [-v]");
+        assert_contains!(message, "\n -v (on), --verbose (on)");
+    }
+
+    #[test]
+    fn placeholder_option() {
+        // Setup
+        let mut value: u32 = 0;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(Scalar::new(&mut value), "value", None).placeholder("(on)"));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let result = clp.build_with_interface(Box::new(sender));
+
+        // Verify
+        assert_matches!(result, Err(ConfigError(message)) => {
+            assert_eq!(
+                message,
+                "parameter 'value' cannot have a placeholder: only switches may have a placeholder."
+            );
+        });
+    }
+
+    #[test]
+    fn order_build_help() {
+        // Setup
+        let mut verbose: bool = false;
+        let mut output: String = String::default();
+        let mut jobs: u32 = 0;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(
+                Switch::new(&mut verbose, true),
+                "verbose",
+                None,
+            ))
+            .add(Parameter::option(Scalar::new(&mut output), "output", None).order(-1))
+            .add(Parameter::option(Scalar::new(&mut jobs), "jobs", None).order(1));
+        let (sender, receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let error_code = parser.parse_tokens(&["--help"]).unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 0);
+        let message = receiver.consume_message();
+        assert_contains!(
+            message,
+            "usage: program [-h] [--output OUTPUT] [--verbose] [--jobs JOBS]"
+        );
+        let output_index = message.find("\n --output").unwrap();
+        let verbose_index = message.find("\n --verbose").unwrap();
+        let jobs_index = message.find("\n --jobs").unwrap();
+        assert!(output_index < verbose_index);
+        assert!(verbose_index < jobs_index);
+    }
+
+    #[test]
+    fn order_argument() {
+        // Setup
+        let mut value: u32 = 0;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::argument(Scalar::new(&mut value), "value").order(1));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let result = clp.build_with_interface(Box::new(sender));
+
+        // Verify
+        assert_matches!(result, Err(ConfigError(message)) => {
+            assert_eq!(
+                message,
+                "parameter 'value' cannot have an order: only options may have an order."
+            );
+        });
+    }
+
+    #[test]
+    fn since_build_help() {
+        // Setup
+        let mut fast: bool = false;
+        let clp = CommandLineParser::new("program").add(
+            Parameter::option(Switch::new(&mut fast, true), "fast", None)
+                .help("Go fast.")
+                .since("2.1"),
+        );
+        let (sender, receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let error_code = parser.parse_tokens(&["--help"]).unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 0);
+        let message = receiver.consume_message();
+        assert_contains!(message, "\n --fast       Go fast. (since\n");
+    }
+
+    #[test]
+    fn since_argument() {
+        // Setup
+        let mut value: u32 = 0;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::argument(Scalar::new(&mut value), "value").since("2.1"));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let result = clp.build_with_interface(Box::new(sender));
+
+        // Verify
+        assert_matches!(result, Err(ConfigError(message)) => {
+            assert_eq!(
+                message,
+                "parameter 'value' cannot have a since version: only options may have a since version."
+            );
+        });
+    }
+
+    #[test]
+    fn version_duplicate() {
+        // Setup
+        let mut version: bool = false;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(
+                Switch::new(&mut version, true),
+                "version",
+                None,
+            ))
+            .version("1.2.3");
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let result = clp.build_with_interface(Box::new(sender));
+
+        // Verify
+        assert_matches!(result, Err(ConfigError(_)));
+    }
+
+    #[rstest]
+    #[case(true)]
+    #[case(false)]
+    fn option_argument_duplicate(#[case] option_first: bool) {
+        // Setup
+        let mut option: u32 = 0;
+        let mut argument: u32 = 0;
+        let option = Parameter::option(Scalar::new(&mut option), "value", None);
+        let argument = Parameter::argument(Scalar::new(&mut argument), "value");
+        let clp = if option_first {
+            CommandLineParser::new("program").add(option).add(argument)
+        } else {
+            CommandLineParser::new("program").add(argument).add(option)
+        };
+
+        // Execute
+        let result = clp.build_parser().unwrap_err();
+
+        // Verify
+        assert_matches!(result, ConfigError(message) => {
+            assert_eq!(message, "cannot duplicate the parameter 'value'.".to_string());
+        });
+    }
+
+    #[test]
+    fn option_argument_duplicate_sub_command() {
+        // Setup
+        let mut sub: String = "".to_string();
+        let mut option: u32 = 0;
+        let mut argument: u32 = 0;
+        let clp = CommandLineParser::new("program");
+        let scp = clp
+            .branch(Condition::new(Scalar::new(&mut sub), "sub"))
+            .command("abc".to_string(), |sub| {
+                sub.add(Parameter::option(Scalar::new(&mut option), "value", None))
+                    .add(Parameter::argument(Scalar::new(&mut argument), "value"))
+            });
+
+        // Execute
+        let result = scp.build_parser().unwrap_err();
+
+        // Verify
+        assert_matches!(result, ConfigError(message) => {
+            assert_eq!(message, "cannot duplicate the parameter 'value'.".to_string());
+        });
+    }
+
+    #[rstest]
+    #[case(vec![], false)]
+    #[case(vec!["-q"], true)]
+    #[case(vec!["-s"], true)]
+    #[case(vec!["--quiet"], true)]
+    fn switch_short_aliases(#[case] tokens: Vec<&str>, #[case] expected: bool) {
+        // Setup
+        let mut quiet: bool = false;
+        let clp = CommandLineParser::new("program").add(Parameter::option(
+            Switch::new(&mut quiet, true).short_aliases(&['q', 's']),
+            "quiet",
+            None,
+        ));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(quiet, expected);
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Mode {
+        Off,
+        On,
+        Auto,
+    }
+
+    #[rstest]
+    #[case(vec![], Mode::Off)]
+    #[case(vec!["--mode"], Mode::On)]
+    #[case(vec!["--mode", "--mode"], Mode::Auto)]
+    #[case(vec!["--mode", "--mode", "--mode"], Mode::Off)]
+    #[case(vec!["--mode", "--mode", "--mode", "--mode"], Mode::On)]
+    #[case(vec!["-mmm"], Mode::Off)]
+    #[case(vec!["-m", "--mode", "-mm"], Mode::On)]
+    fn cycle(#[case] tokens: Vec<&str>, #[case] expected: Mode) {
+        // Setup
+        let mut mode = Mode::Off;
+        let clp = CommandLineParser::new("program").add(Parameter::option(
+            Cycle::new(&mut mode, &[Mode::On, Mode::Auto, Mode::Off]),
+            "mode",
+            Some('m'),
+        ));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(mode, expected);
+    }
+
+    #[test]
+    fn cycle_empty() {
+        // Setup
+        let mut mode = Mode::Off;
+        let clp = CommandLineParser::new("program").add(Parameter::option(
+            Cycle::new(&mut mode, &[]),
+            "mode",
+            Some('m'),
+        ));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let result = clp.build_with_interface(Box::new(sender)).unwrap_err();
+
+        // Verify
+        assert_matches!(result, ConfigError(message) => {
+            assert_eq!(message, "parameter 'mode' cannot cycle: it must have at least one state.".to_string());
+        });
+    }
+
+    #[test]
+    fn cycle_alongside_switch() {
+        // Setup
+        let mut mode = Mode::Off;
+        let mut verbose = false;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(
+                Cycle::new(&mut mode, &[Mode::On, Mode::Auto, Mode::Off]),
+                "mode",
+                Some('m'),
+            ))
+            .add(Parameter::option(
+                Switch::new(&mut verbose, true),
+                "verbose",
+                Some('v'),
+            ));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(vec!["-mv", "-m"].as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(mode, Mode::Auto);
+        assert!(verbose);
+    }
+
+    #[rstest]
+    #[case(vec![], true)]
+    #[case(vec!["--enable-x"], false)]
+    fn switch_inverted_target(#[case] tokens: Vec<&str>, #[case] expected: bool) {
+        // Setup
+        let mut disable_x: bool = true;
+        let clp = CommandLineParser::new("program").add(Parameter::option(
+            Switch::new(&mut disable_x, false),
+            "enable-x",
+            None,
+        ));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(disable_x, expected);
+    }
+
+    #[rstest]
+    #[case(vec![], false)]
+    #[case(vec!["--feature"], true)]
+    #[case(vec!["--feature=Enabled"], true)]
+    #[case(vec!["--feature", "off"], false)]
+    #[case(vec!["--feature", "off", "x"], false)]
+    fn switch_bool_literals(#[case] tokens: Vec<&str>, #[case] expected: bool) {
+        // Setup
+        let mut feature: bool = false;
+        let mut items: Vec<String> = Vec::default();
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(
+                Switch::new(&mut feature, true)
+                    .bool_literals(&["enabled", "on"], &["disabled", "off"]),
+                "feature",
+                None,
+            ))
+            .add(Parameter::argument(
+                Collection::new(&mut items, Nargs::Any),
+                "item",
+            ));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(feature, expected);
+    }
+
+    #[test]
+    fn switch_bool_literals_unknown() {
+        // Setup
+        let mut feature: bool = false;
+        let clp = CommandLineParser::new("program").add(Parameter::option(
+            Switch::new(&mut feature, true).bool_literals(&["enabled"], &["disabled"]),
+            "feature",
+            None,
+        ));
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        let error_code = parser.parse_tokens(&["--feature=yes"]).unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 1);
+        let (_, error, _) = receiver.consume();
+        let error = error.unwrap();
+        assert_contains!(
+            error,
+            "cannot convert 'yes' to bool: expected one of 'enabled', 'disabled'."
+        );
+    }
+
+    #[rstest]
+    #[case(vec![], true)]
+    #[case(vec!["--config", "."], true)]
+    #[case(vec!["--config", "blarg-does-not-exist"], false)]
+    fn optional_must_exist(#[case] tokens: Vec<&str>, #[case] expected_ok: bool) {
+        // Setup
+        let mut config: Option<PathBuf> = None;
+        let clp = CommandLineParser::new("program").add(Parameter::option(
+            Optional::new(&mut config).must_exist(),
+            "config",
+            None,
+        ));
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        let result = parser.parse_tokens(tokens.as_slice());
+
+        // Verify
+        assert_eq!(result.is_ok(), expected_ok);
+        assert_eq!(config.is_some(), expected_ok && !tokens.is_empty());
+
+        if !expected_ok {
+            let (_, error, _) = receiver.consume();
+            let error = error.unwrap();
+            assert_contains!(error, "path 'blarg-does-not-exist' does not exist.");
+        }
+    }
+
+    #[rstest]
+    #[case(vec!["a", "1"], Some(vec![("a", 1)]))]
+    #[case(vec!["a", "1", "b", "2"], Some(vec![("a", 1), ("b", 2)]))]
+    #[case(vec!["-v", "a", "1", "b", "2"], Some(vec![("a", 1), ("b", 2)]))]
+    #[case(vec![], None)]
+    #[case(vec!["a"], None)]
+    #[case(vec!["a", "1", "b"], None)]
+    fn collection_repeatable_group(
+        #[case] tokens: Vec<&str>,
+        #[case] expected: Option<Vec<(&str, u32)>>,
+    ) {
+        // Setup
+        let mut verbose: bool = false;
+        let mut users: Vec<(String, u32)> = Vec::default();
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(
+                Switch::new(&mut verbose, true),
+                "verbose",
+                Some('v'),
+            ))
+            .add(Parameter::argument(
+                Collection::repeatable_group(&mut users),
+                "user",
+            ));
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        let result = parser.parse_tokens(tokens.as_slice());
+
+        // Verify
+        match expected {
+            Some(expected) => {
+                result.unwrap();
+                assert_eq!(
+                    users,
+                    expected
+                        .into_iter()
+                        .map(|(name, age)| (name.to_string(), age))
+                        .collect::<Vec<_>>()
+                );
+            }
+            None => {
+                assert_eq!(result, Err(1));
+                let (_, error, _) = receiver.consume();
+                let error = error.unwrap();
+                assert_contains!(error, "not enough tokens provided to parameter 'USER'.");
+            }
+        }
+    }
+
+    #[test]
+    fn collection_repeatable_group_option() {
+        // Setup
+        let mut users: Vec<(String, u32)> = Vec::default();
+        let clp = CommandLineParser::new("program").add(Parameter::option(
+            Collection::repeatable_group(&mut users),
+            "user",
+            None,
+        ));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let result = clp.build_with_interface(Box::new(sender));
+
+        // Verify
+        assert_matches!(result, Err(ConfigError(message)) => {
+            assert_eq!(message, "parameter 'user' cannot use a repeatable group: only arguments may repeat a group.".to_string());
+        });
+    }
+
+    #[test]
+    fn collection_vec_deque() {
+        // Setup
+        let mut items: VecDeque<String> = VecDeque::default();
+        let clp = CommandLineParser::new("program").add(Parameter::argument(
+            Collection::new(&mut items, Nargs::AtLeastOne),
+            "item",
+        ));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(vec!["a", "b", "c"].as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(items, VecDeque::from(["a", "b", "c"].map(String::from)));
+    }
+
+    #[test]
+    fn collection_btree_set() {
+        // Setup
+        let mut items: BTreeSet<u32> = BTreeSet::default();
+        let clp = CommandLineParser::new("program").add(Parameter::argument(
+            Collection::new(&mut items, Nargs::AtLeastOne),
+            "item",
+        ));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(vec!["3", "1", "2"].as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(items.into_iter().collect::<Vec<u32>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn collection_btree_set_duplicate() {
+        // Setup
+        let mut items: BTreeSet<u32> = BTreeSet::default();
+        let clp = CommandLineParser::new("program").add(Parameter::argument(
+            Collection::new(&mut items, Nargs::AtLeastOne),
+            "item",
+        ));
+        let (sender, receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let error_code = parser
+            .parse_tokens(vec!["3", "1", "2", "1"].as_slice())
+            .unwrap_err();
+
+        // Verify
+        // Like a HashSet, a BTreeSet rejects the duplicate item.
+        assert_eq!(error_code, 1);
+        let (_, error, _) = receiver.consume();
+        let error = error.unwrap();
+        assert_contains!(error, "cannot collect '1': set already contains item.");
+    }
+
+    #[rstest]
+    #[case(vec!["a", "b"])]
+    #[case(vec!["a", "b", "c"])]
+    #[case(vec!["a", "b", "c", "d"])]
+    fn collection_range(#[case] tokens: Vec<&str>) {
+        // Setup
+        let mut items: Vec<String> = Vec::default();
+        let clp = CommandLineParser::new("program").add(Parameter::argument(
+            Collection::new(&mut items, Nargs::Range(2, 4)),
+            "item",
+        ));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(items, tokens);
+    }
+
+    #[rstest]
+    #[case(vec![], "not enough tokens provided to parameter 'ITEM'.")]
+    #[case(vec!["a"], "not enough tokens provided to parameter 'ITEM'.")]
+    #[case(vec!["a", "b", "c", "d", "e"], "no more arguments to match against.")]
+    fn collection_range_invalid(#[case] tokens: Vec<&str>, #[case] expected: &str) {
+        // Setup
+        let mut items: Vec<String> = Vec::default();
+        let clp = CommandLineParser::new("program").add(Parameter::argument(
+            Collection::new(&mut items, Nargs::Range(2, 4)),
+            "item",
+        ));
+        let (sender, receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let error_code = parser.parse_tokens(tokens.as_slice()).unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 1);
+        let (_, error, _) = receiver.consume();
+        let error = error.unwrap();
+        assert_contains!(error, expected);
+    }
+
+    #[rstest]
+    #[case(
+        true,
+        Nargs::Range(4, 2),
+        "parameter 'item' cannot match any number of values: its minimum 4 exceeds its maximum 2."
+    )]
+    #[case(
+        false,
+        Nargs::Range(4, 2),
+        "parameter 'item' cannot match any number of values: its minimum 4 exceeds its maximum 2."
+    )]
+    #[case(
+        true,
+        Nargs::Range(2, 300),
+        "parameter 'item' cannot match 300 values: it may match at most 255 values."
+    )]
+    #[case(
+        false,
+        Nargs::Range(256, 256),
+        "parameter 'item' cannot match 256 values: it may match at most 255 values."
+    )]
+    fn collection_range_misconfigured(
+        #[case] option: bool,
+        #[case] nargs: Nargs,
+        #[case] expected: &str,
+    ) {
+        // Setup
+        let mut items: Vec<String> = Vec::default();
+        let parameter = if option {
+            Parameter::option(Collection::new(&mut items, nargs), "item", None)
+        } else {
+            Parameter::argument(Collection::new(&mut items, nargs), "item")
+        };
+        let clp = CommandLineParser::new("program").add(parameter);
+
+        // Execute
+        let result = clp.build_parser().unwrap_err();
+
+        // Verify
+        assert_matches!(result, ConfigError(message) => {
+            assert_eq!(message, expected.to_string());
+        });
+    }
+
+    #[rstest]
+    #[case(vec!["--tags", "a,b", "c"], vec!["a", "b", "c"])]
+    #[case(vec!["--tags", "c", "a,b"], vec!["c", "a", "b"])]
+    #[case(vec!["--tags=a,b"], vec!["a", "b"])]
+    #[case(vec!["--tags", "a", "b,c", "d"], vec!["a", "b", "c", "d"])]
+    fn collection_comma_separated(#[case] tokens: Vec<&str>, #[case] expected: Vec<&str>) {
+        // Setup
+        let mut tags: Vec<String> = Vec::default();
+        let clp = CommandLineParser::new("program").add(Parameter::option(
+            Collection::new(&mut tags, Nargs::AtLeastOne).comma_separated(),
+            "tags",
+            None,
+        ));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(tags, expected);
+    }
+
+    #[rstest]
+    #[case(vec!["--labels", "env=prod,team=core"], vec![("env", "prod"), ("team", "core")])]
+    #[case(vec!["--labels", "env=prod"], vec![("env", "prod")])]
+    #[case(vec!["--labels=env=prod,team=a=b"], vec![("env", "prod"), ("team", "a=b")])]
+    fn collection_pairs_comma_separated(
+        #[case] tokens: Vec<&str>,
+        #[case] expected: Vec<(&str, &str)>,
+    ) {
+        // Setup
+        let mut labels: HashMap<String, String> = HashMap::default();
+        let clp = CommandLineParser::new("program").add(Parameter::option(
+            Collection::pairs(&mut labels, Nargs::Precisely(1), '=').comma_separated(),
+            "labels",
+            None,
+        ));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(
+            labels,
+            expected
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect::<HashMap<String, String>>()
+        );
+    }
+
+    #[test]
+    fn collection_pairs_comma_separated_malformed() {
+        // Setup
+        let mut labels: HashMap<String, String> = HashMap::default();
+        let clp = CommandLineParser::new("program").add(Parameter::option(
+            Collection::pairs(&mut labels, Nargs::Precisely(1), '=').comma_separated(),
+            "labels",
+            None,
+        ));
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        let error_code = parser
+            .parse_tokens(&["--labels", "env=prod,team"])
+            .unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 1);
+        let (_, error, _) = receiver.consume();
+        let error = error.unwrap();
+        assert_contains!(error, "cannot split 'team' into a pair on '='.");
+    }
+
+    #[rstest]
+    #[case(vec!["a", "b", "--", "c", "d"], vec!["a", "b"], vec!["c", "d"], false)]
+    #[case(vec!["a", "b"], vec!["a", "b"], vec![], false)]
+    #[case(vec!["-v", "--", "-v", "--help"], vec![], vec!["-v", "--help"], true)]
+    #[case(vec!["a", "--", "c", "-v"], vec!["a"], vec!["c", "-v"], false)]
+    fn collection_split(
+        #[case] tokens: Vec<&str>,
+        #[case] expected_before: Vec<&str>,
+        #[case] expected_after: Vec<&str>,
+        #[case] expected_verbose: bool,
+    ) {
+        // Setup
+        let mut verbose: bool = false;
+        let mut before: Vec<String> = Vec::default();
+        let mut after: Vec<String> = Vec::default();
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(
+                Switch::new(&mut verbose, true),
+                "verbose",
+                Some('v'),
+            ))
+            .add(Parameter::argument(
+                Collection::split(&mut before, &mut after),
+                "files",
+            ));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(verbose, expected_verbose);
+        assert_eq!(before, expected_before);
+        assert_eq!(after, expected_after);
+    }
+
+    #[rstest]
+    #[case(vec!["--command", "echo", "hi"], false, "echo hi")]
+    #[case(vec!["--command", "echo", "hello world"], false, "echo 'hello world'")]
+    #[case(vec!["--command", "grep", "it's", "a b.txt"], false, "grep 'it'\\''s' 'a b.txt'")]
+    #[case(vec!["--command", "ls", "-la", "my dir"], false, "ls -la 'my dir'")]
+    #[case(vec!["-v", "--command", "ls", "-v", "--verbose"], true, "ls -v --verbose")]
+    #[case(vec!["--command", "git", "--help"], false, "git --help")]
+    #[case(vec!["--command", "echo", "--", "-x"], false, "echo -- -x")]
+    fn collection_shell_command(
+        #[case] tokens: Vec<&str>,
+        #[case] expected_verbose: bool,
+        #[case] expected: &str,
+    ) {
+        // Setup
+        let mut verbose: bool = false;
+        let mut command: String = String::default();
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(
+                Switch::new(&mut verbose, true),
+                "verbose",
+                Some('v'),
+            ))
+            .add(Parameter::option(
+                Collection::shell_command(&mut command),
+                "command",
+                None,
+            ));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(verbose, expected_verbose);
+        assert_eq!(&command, expected);
+    }
+
+    #[rstest]
+    #[case(vec![], None)]
+    #[case(vec!["-v"], Some("-v"))]
+    #[case(vec!["--verbose"], Some("--verbose"))]
+    #[case(vec!["-fv"], Some("-v"))]
+    #[case(vec!["--force", "-v"], Some("-v"))]
+    fn switch_record_spelling(#[case] tokens: Vec<&str>, #[case] expected: Option<&str>) {
+        // Setup
+        let mut verbose: bool = false;
+        let mut force: bool = false;
+        let mut spelling: Option<String> = None;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(
+                Switch::new(&mut verbose, true).record_spelling(&mut spelling),
+                "verbose",
+                Some('v'),
+            ))
+            .add(Parameter::option(
+                Switch::new(&mut force, true),
+                "force",
+                Some('f'),
+            ));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(verbose, expected.is_some());
+        assert_eq!(spelling, expected.map(|s| s.to_string()));
+    }
+
+    #[test]
+    fn switch_short_aliases_collision() {
+        // Setup
+        let mut quiet: bool = false;
+        let mut size: u32 = 0;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(
+                Switch::new(&mut quiet, true).short_aliases(&['q', 's']),
+                "quiet",
+                None,
+            ))
+            .add(Parameter::option(Scalar::new(&mut size), "size", Some('s')));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let result = clp.build_with_interface(Box::new(sender));
+
+        // Verify
+        assert_matches!(result, Err(ConfigError(_)));
+    }
+
+    #[rstest]
+    #[case(vec!["--from-file", "a"], Some("a"), None, false)]
+    #[case(vec!["--from-stdin"], None, None, true)]
+    #[case(vec!["--from-file", "a", "--from-url", "b"], Some("a"), Some("b"), false)]
+    #[case(vec!["--from-url", "b", "--from-stdin"], None, Some("b"), true)]
+    fn required_group(
+        #[case] tokens: Vec<&str>,
+        #[case] expected_file: Option<&str>,
+        #[case] expected_url: Option<&str>,
+        #[case] expected_stdin: bool,
+    ) {
+        // Setup
+        let mut from_file: Option<String> = None;
+        let mut from_url: Option<String> = None;
+        let mut from_stdin: bool = false;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(
+                Optional::new(&mut from_file),
+                "from-file",
+                None,
+            ))
+            .add(Parameter::option(
+                Optional::new(&mut from_url),
+                "from-url",
+                None,
+            ))
+            .add(Parameter::option(
+                Switch::new(&mut from_stdin, true),
+                "from-stdin",
+                None,
+            ))
+            .required_group(&["from-file", "from-url", "from-stdin"]);
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(from_file.as_deref(), expected_file);
+        assert_eq!(from_url.as_deref(), expected_url);
+        assert_eq!(from_stdin, expected_stdin);
+    }
+
+    #[test]
+    fn required_group_missing() {
+        // Setup
+        let mut from_file: Option<String> = None;
+        let mut from_url: Option<String> = None;
+        let mut from_stdin: bool = false;
+        let mut item: u32 = 0;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(
+                Optional::new(&mut from_file),
+                "from-file",
+                None,
+            ))
+            .add(Parameter::option(
+                Optional::new(&mut from_url),
+                "from-url",
+                None,
+            ))
+            .add(Parameter::option(
+                Switch::new(&mut from_stdin, true),
+                "from-stdin",
+                None,
+            ))
+            .add(Parameter::argument(Scalar::new(&mut item), "item"))
+            .required_group(&["from-file", "from-url", "from-stdin"]);
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        let error_code = parser.parse_tokens(&["1"]).unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 1);
+        let (_, error, _) = receiver.consume();
+        let error = error.unwrap();
+        assert_contains!(
+            error,
+            "at least one of --from-file, --from-url, --from-stdin is required."
+        );
+    }
+
+    #[rstest]
+    #[case(vec!["from-file", "moot"], "required group refers to unknown option 'moot'.")]
+    #[case(vec!["from-file", "item"], "required group refers to unknown option 'item'.")]
+    #[case(vec![], "required group must contain at least one option.")]
+    fn required_group_invalid(#[case] group: Vec<&str>, #[case] expected: &str) {
+        // Setup
+        let mut from_file: Option<String> = None;
+        let mut item: u32 = 0;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(
+                Optional::new(&mut from_file),
+                "from-file",
+                None,
+            ))
+            .add(Parameter::argument(Scalar::new(&mut item), "item"))
+            .required_group(group.as_slice());
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let result = clp.build_with_interface(Box::new(sender)).unwrap_err();
+
+        // Verify
+        assert_matches!(result, ConfigError(message) => {
+            assert_eq!(message, expected.to_string());
+        });
+    }
+
+    #[rstest]
+    #[case(vec!["--config", "app.toml"])]
+    #[case(vec!["--config=app.toml"])]
+    #[case(vec!["-c", "app.toml", "--verbose"])]
+    fn required_option(#[case] tokens: Vec<&str>) {
+        // Setup
+        let mut config: String = String::default();
+        let mut verbose: bool = false;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(Scalar::new(&mut config), "config", Some('c')).required())
+            .add(Parameter::option(
+                Switch::new(&mut verbose, true),
+                "verbose",
+                None,
+            ));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(&config, "app.toml");
+    }
+
+    #[rstest]
+    #[case(vec![])]
+    #[case(vec!["--verbose"])]
+    fn required_option_missing(#[case] tokens: Vec<&str>) {
+        // Setup
+        let mut config: String = String::default();
+        let mut verbose: bool = false;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(Scalar::new(&mut config), "config", Some('c')).required())
+            .add(Parameter::option(
+                Switch::new(&mut verbose, true),
+                "verbose",
+                None,
+            ));
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        let error_code = parser.parse_tokens(tokens.as_slice()).unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 1);
+        let (_, error, _) = receiver.consume();
+        let error = error.unwrap();
+        assert_contains!(error, "required option '--config' was not provided.");
+        assert!(!verbose);
+    }
+
+    #[test]
+    fn required_option_environment() {
+        // Setup
+        std::env::set_var("BLARG_REQUIRED_OPTION_CONFIG", "env.toml");
+        let mut config: String = String::default();
+        let clp = CommandLineParser::new("program").add(
+            Parameter::option(Scalar::new(&mut config), "config", None)
+                .env("BLARG_REQUIRED_OPTION_CONFIG")
+                .required(),
+        );
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(&[]).unwrap();
+
+        // Verify
+        assert_eq!(&config, "env.toml");
+    }
+
+    #[test]
+    fn required_option_build_help() {
+        // Setup
+        let mut config: String = String::default();
+        let mut verbose: bool = false;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(Scalar::new(&mut config), "config", None).required())
+            .add(Parameter::option(
+                Switch::new(&mut verbose, true),
+                "verbose",
+                Some('v'),
+            ));
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        let error_code = parser.parse_tokens(&["--help"]).unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 0);
+        let message = receiver.consume_message();
+        assert_contains!(message, "usage: program [-h] --config CONFIG [-v]\n");
+    }
+
+    #[rstest]
+    #[case(vec!["--fast"], 3, false)]
+    #[case(vec!["--fast", "--verbose"], 3, true)]
+    #[case(vec!["--level", "2"], 2, false)]
+    #[case(vec![], 1, false)]
+    fn preset_flag(
+        #[case] tokens: Vec<&str>,
+        #[case] expected: u32,
+        #[case] expected_verbose: bool,
+    ) {
+        // Setup
+        let mut level: u32 = 1;
+        let mut verbose: bool = false;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(Scalar::new(&mut level), "level", None))
+            .add(Parameter::option(
+                Switch::new(&mut verbose, true),
+                "verbose",
+                None,
+            ))
+            .preset_flag("fast", &[("level", "3")]);
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(level, expected);
+        assert_eq!(verbose, expected_verbose);
+    }
+
+    #[rstest]
+    #[case(vec!["--fast", "--level=1"])]
+    #[case(vec!["--level", "1", "--fast"])]
+    fn preset_flag_conflict(#[case] tokens: Vec<&str>) {
+        // Setup
+        let mut level: u32 = 1;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(Scalar::new(&mut level), "level", None))
+            .preset_flag("fast", &[("level", "3")]);
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        let error_code = parser.parse_tokens(tokens.as_slice()).unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 1);
+        let (_, error, _) = receiver.consume();
+        let error = error.unwrap();
+        assert_contains!(
+            error,
+            "option '--level' conflicts with the preset '--fast'."
+        );
+        assert_eq!(level, 1);
+    }
+
+    #[test]
+    fn preset_flag_invalid_value() {
+        // Setup
+        let mut level: u32 = 1;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(Scalar::new(&mut level), "level", None))
+            .preset_flag("fast", &[("level", "high")]);
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        let error_code = parser.parse_tokens(&["--fast"]).unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 1);
+        let (_, error, _) = receiver.consume();
+        let error = error.unwrap();
+        assert_contains!(error, "cannot convert 'high' to u32.");
+    }
+
+    #[rstest]
+    #[case("fast", "moot", "preset 'fast' refers to unknown option 'moot'.")]
+    #[case("level", "level", "cannot duplicate the parameter 'level'.")]
+    #[case("help", "level", "cannot duplicate the parameter 'help'.")]
+    fn preset_flag_invalid(#[case] name: &str, #[case] option: &str, #[case] expected: &str) {
+        // Setup
+        let mut level: u32 = 1;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(Scalar::new(&mut level), "level", None))
+            .preset_flag(name, &[(option, "3")]);
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let result = clp.build_with_interface(Box::new(sender)).unwrap_err();
+
+        // Verify
+        assert_matches!(result, ConfigError(message) => {
+            assert_eq!(message, expected.to_string());
+        });
+    }
+
+    #[test]
+    fn preset_flag_build_help() {
+        // Setup
+        let mut level: u32 = 1;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(Scalar::new(&mut level), "level", None))
+            .preset_flag("fast", &[("level", "3")]);
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        let error_code = parser.parse_tokens(&["--help"]).unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 0);
+        let message = receiver.consume_message();
+        assert_contains!(message, "usage: program [-h] [--fast] [--level LEVEL]\n");
+        assert_contains!(
+            message,
+            " --fast          Same as --level\n                 3.\n"
+        );
+    }
+
+    #[test]
+    fn required_argument() {
+        // Setup
+        let mut item: u32 = 0;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::argument(Scalar::new(&mut item), "item").required());
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let result = clp.build_with_interface(Box::new(sender)).unwrap_err();
+
+        // Verify
+        assert_matches!(result, ConfigError(message) => {
+            assert_eq!(message, "parameter 'item' cannot be required: only options may be required.".to_string());
+        });
+    }
+
+    #[test]
+    #[cfg(feature = "unit_test")]
+    fn test_dummies() {
+        // Setup
+        pub fn setup_fn<'a>(value: &'a mut u32) -> impl FnOnce(SubCommand<'a>) -> SubCommand<'a> {
+            |sub| sub.add(Parameter::argument(Scalar::new(value), "value"))
+        }
+
+        let mut x: u32 = 1;
+        let parser = setup_fn(&mut x)(SubCommand::test_dummy())
+            .build_parser()
+            .unwrap();
+        let tokens = vec!["2"];
+
+        // Execute
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(x, 2);
+    }
+
+    #[derive(PartialEq)]
+    enum Nefarious {
+        Foo,
+        Bar,
+    }
+
+    impl std::fmt::Display for Nefarious {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Nefarious::Foo => write!(f, "foo"),
+                Nefarious::Bar => write!(f, "bar"),
+            }
+        }
+    }
+
+    impl std::str::FromStr for Nefarious {
+        type Err = String;
+
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            match value.to_lowercase().as_str() {
+                "bar" => Ok(Nefarious::Foo),
+                _ => Err(format!("unknown: {}", value)),
+            }
+        }
+    }
+
+    #[test]
+    fn not_invertable_command() {
+        // Setup
+        let mut nefarious = Nefarious::Bar;
+        let clp = CommandLineParser::new("program");
+        let scp = clp
+            .branch(Condition::new(Scalar::new(&mut nefarious), "abc"))
+            .command(Nefarious::Foo, |sub| sub);
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let result = scp.build_with_interface(Box::new(sender)).unwrap_err();
+
+        // Verify
+        assert_matches!(result, ConfigError(message) => {
+            assert_eq!(message, "parameter 'abc' contains invalid sub-command 'foo': FromStr does not invert Display.".to_string());
+        });
+    }
+
+    #[test]
+    fn choices_case_collision() {
+        // Setup
+        let mut sub: String = "".to_string();
+        let scp = CommandLineParser::new("program")
+            .branch(Condition::new(Scalar::new(&mut sub), "sub").choices_case(Case::Kebab))
+            .command("FooBar".to_string(), |sub| sub)
+            .command("foo_bar".to_string(), |sub| sub);
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let result = scp.build_with_interface(Box::new(sender)).unwrap_err();
+
+        // Verify
+        assert_matches!(result, ConfigError(message) => {
+            assert_eq!(message, "parameter 'sub' contains colliding sub-commands 'FooBar' and 'foo_bar': both are presented as 'foo-bar'.".to_string());
+        });
+    }
+
+    #[test]
+    fn choices_case_repeated() {
+        // Setup
+        let mut sub: String = "".to_string();
+        let mut value: u32 = 0;
+        let scp = CommandLineParser::new("program")
+            .branch(Condition::new(Scalar::new(&mut sub), "sub").choices_case(Case::Kebab))
+            .command("FooBar".to_string(), |sub| sub)
+            .command("FooBar".to_string(), |sub| {
+                sub.add(Parameter::argument(Scalar::new(&mut value), "value"))
+            });
+        let (sender, _receiver) = channel_interface();
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        parser.parse_tokens(&["foo-bar", "1"]).unwrap();
+
+        // Verify
+        assert_eq!(&sub, "FooBar");
+        assert_eq!(value, 1);
+    }
+
+    #[test]
+    fn nefarious_command() {
+        // Setup
+        let mut nefarious = Nefarious::Bar;
+        let clp = CommandLineParser::new("program");
+        let scp = clp
+            .branch(Condition::new(Scalar::new(&mut nefarious), "abc"))
+            .command(Nefarious::Bar, |sub| sub);
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let result = scp.build_with_interface(Box::new(sender)).unwrap_err();
+
+        // Verify
+        assert_matches!(result, ConfigError(message) => {
+            assert_eq!(message, "parameter 'abc' contains invalid sub-command 'bar': FromStr does not invert Display.".to_string());
+        });
+    }
+
+    #[derive(Debug, PartialEq, Eq, Hash)]
+    enum Camel {
+        FooBar,
+        BazQux,
+    }
+
+    impl std::fmt::Display for Camel {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Camel::FooBar => write!(f, "FooBar"),
+                Camel::BazQux => write!(f, "BazQux"),
+            }
+        }
+    }
+
+    impl std::str::FromStr for Camel {
+        type Err = String;
+
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+            match value {
+                "FooBar" => Ok(Camel::FooBar),
+                "BazQux" => Ok(Camel::BazQux),
+                _ => Err(format!("unknown: {}", value)),
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(vec!["foo-bar"], Camel::FooBar, vec![])]
+    #[case(vec!["baz-qux", "1", "2"], Camel::BazQux, vec![1, 2])]
+    fn choices_case_build(
+        #[case] tokens: Vec<&str>,
+        #[case] expected_camel: Camel,
+        #[case] expected_items: Vec<u32>,
+    ) {
+        // Setup
+        let mut camel = Camel::FooBar;
+        let mut items: Vec<u32> = Vec::default();
+        let clp = CommandLineParser::new("program");
+        let scp = clp
+            .branch(Condition::new(Scalar::new(&mut camel), "camel").choices_case(Case::Kebab))
+            .command(Camel::FooBar, |sub| sub)
+            .command(Camel::BazQux, |sub| {
+                sub.add(Parameter::argument(
+                    Collection::new(&mut items, Nargs::Any),
+                    "item",
+                ))
+            });
+
+        // Execute
+        let parser = scp.build_parser().unwrap();
+
+        // Verify
+        assert_eq!(
+            parser.sub_details("foo-bar"),
+            Some(("program foo-bar".to_string(), None))
+        );
+        assert_eq!(parser.sub_details("FooBar"), None);
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+        assert_eq!(camel, expected_camel);
+        assert_eq!(items, expected_items);
+    }
+
+    #[test]
+    fn choices_case_original_invalid() {
+        // Setup
+        let mut camel = Camel::BazQux;
+        let clp = CommandLineParser::new("program");
+        let scp = clp
+            .branch(Condition::new(Scalar::new(&mut camel), "camel").choices_case(Case::Kebab))
+            .command(Camel::FooBar, |sub| sub)
+            .command(Camel::BazQux, |sub| sub);
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+        let error_code = parser.parse_tokens(&["FooBar"]).unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 1);
+        let (_, error, _) = receiver.consume();
+        let error = error.unwrap();
+        assert_contains!(error, "unknown sub-command 'FooBar'.");
+    }
+
+    #[rstest]
+    #[case(vec!["FooBa"], Some("FooBar"))]
+    #[case(vec!["bazqux"], Some("BazQux"))]
+    #[case(vec!["wat"], None)]
+    fn enum_sub_command_not_found(#[case] tokens: Vec<&str>, #[case] expected: Option<&str>) {
+        // Setup
+        let mut camel = Camel::FooBar;
+        let clp = CommandLineParser::new("program");
+        let scp = clp
+            .branch(Condition::new(Scalar::new(&mut camel), "camel"))
+            .command(Camel::FooBar, |sub| sub)
+            .command(Camel::BazQux, |sub| sub);
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+        let error_code = parser.parse_tokens(tokens.as_slice()).unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 1);
+        let (_, error, _) = receiver.consume();
+        let error = error.unwrap();
+        let variant = tokens[0];
+        match expected {
+            Some(candidate) => {
+                assert_contains!(
+                    error,
+                    &format!("unknown sub-command '{variant}'. Did you mean '{candidate}'?")
+                );
+            }
+            None => {
+                assert_contains!(error, &format!("unknown sub-command '{variant}'."));
+                assert!(!error.contains("Did you mean"));
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(vec!["wat"], vec![])]
+    #[case(vec!["wat", "--x", "y"], vec!["--x", "y"])]
+    #[case(vec!["--verbose", "FooBa", "1"], vec!["1"])]
+    fn enum_sub_command_not_found_remaining(
+        #[case] tokens: Vec<&str>,
+        #[case] expected_remaining: Vec<&str>,
+    ) {
+        // Setup
+        let mut camel = Camel::FooBar;
+        let mut verbose = false;
+        let clp = CommandLineParser::new("program").add(Parameter::option(
+            Switch::new(&mut verbose, true),
+            "verbose",
+            None,
+        ));
+        let scp = clp
+            .branch(Condition::new(Scalar::new(&mut camel), "camel"))
+            .command(Camel::FooBar, |sub| sub)
+            .command(Camel::BazQux, |sub| sub);
+        let (sender, receiver) = channel_interface();
+        let mut handled: Option<ParseError> = None;
+
+        // Execute
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+        let error_code = parser
+            .parse_tokens_with(tokens.as_slice(), |error| {
+                handled.replace(error);
+                2
+            })
+            .unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 2);
+        let expected_variant = tokens
+            .iter()
+            .find(|token| !token.starts_with("--"))
+            .unwrap();
+        assert_matches!(handled.unwrap(), ParseError::UnknownSubCommand { variant, remaining, .. } => {
+            assert_eq!(&variant, expected_variant);
+            assert_eq!(remaining, expected_remaining);
+        });
+        assert_eq!(camel, Camel::FooBar);
+
+        let (message, error, _) = receiver.consume();
+        assert_eq!(message, None);
+        assert_eq!(error, None);
+    }
+
+    #[rstest]
+    #[case(false)]
+    #[case(true)]
+    fn help_on_unknown(#[case] enabled: bool) {
+        // Setup
+        let mut sub: String = "".to_string();
+        let clp = CommandLineParser::new("program");
+        let mut scp = clp
+            .branch(
+                Condition::new(Scalar::new(&mut sub), "sub")
+                    .choice("status".to_string(), "Show the status."),
+            )
+            .command("status".to_string(), |sub| sub);
+
+        if enabled {
+            scp = scp.help_on_unknown();
+        }
+
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+        let error_code = parser.parse_tokens(&["wat"]).unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 1);
+        let (message, error, _) = receiver.consume();
+        let error = error.unwrap();
+        assert_contains!(error, "unknown sub-command 'wat'.");
+
+        if enabled {
+            let message = message.unwrap();
+            assert_contains!(message, "usage: program [-h] SUB\n");
+            assert_contains!(message, "\n   status     Show the status.");
+        } else {
+            assert_eq!(message, None);
+        }
+    }
+
+    #[rstest]
+    #[case(false)]
+    #[case(true)]
+    fn help_on_unknown_enum(#[case] enabled: bool) {
+        // Setup
+        let mut camel = Camel::FooBar;
+        let clp = CommandLineParser::new("program");
+        let mut scp = clp
+            .branch(
+                Condition::new(Scalar::new(&mut camel), "camel")
+                    .choice(Camel::FooBar, "Foo the bar.")
+                    .choice(Camel::BazQux, "Baz the qux."),
+            )
+            .command(Camel::FooBar, |sub| sub)
+            .command(Camel::BazQux, |sub| sub);
+
+        if enabled {
+            scp = scp.help_on_unknown();
+        }
+
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+        let error_code = parser.parse_tokens(&["FooBa"]).unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 1);
+        let (message, error, _) = receiver.consume();
+        let error = error.unwrap();
+        assert_contains!(error, "unknown sub-command 'FooBa'. Did you mean 'FooBar'?");
+
+        if enabled {
+            let message = message.unwrap();
+            assert_contains!(message, "usage: program [-h] CAMEL\n");
+            assert_contains!(message, "\n   FooBar     Foo the bar.");
+            assert_contains!(message, "\n   BazQux     Baz the qux.");
+        } else {
+            assert_eq!(message, None);
+        }
+    }
+
+    #[test]
+    fn help_on_unknown_known() {
+        // Setup
+        let mut sub: String = "".to_string();
+        let mut value: u32 = 0;
+        let clp = CommandLineParser::new("program");
+        let scp = clp
+            .branch(Condition::new(Scalar::new(&mut sub), "sub"))
+            .command("status".to_string(), |sub| {
+                sub.add(Parameter::argument(Scalar::new(&mut value), "value"))
+            })
+            .help_on_unknown();
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+        let error_code = parser.parse_tokens(&["status", "x"]).unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 1);
+        let (message, error, _) = receiver.consume();
+        assert_eq!(message, None);
+        let error = error.unwrap();
+        assert_contains!(error, "cannot convert 'x' to u32.");
+    }
+
+    #[test]
+    fn choices_case_build_help() {
+        // Setup
+        let mut camel = Camel::FooBar;
+        let clp = CommandLineParser::new("program");
+        let scp = clp
+            .branch(
+                Condition::new(Scalar::new(&mut camel), "camel")
+                    .choice(Camel::FooBar, "foo bar")
+                    .choice(Camel::BazQux, "baz qux")
+                    .choices_case(Case::Kebab),
+            )
+            .command(Camel::FooBar, |sub| sub)
+            .command(Camel::BazQux, |sub| sub);
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Verify
+        let error_code = parser.parse_tokens(&["--help"]).unwrap_err();
+        assert_eq!(error_code, 0);
+
+        let message = receiver.consume_message();
+        assert_contains!(message, "CAMEL        {baz-qux,\n              foo-bar}");
+        assert_contains!(message, "baz-qux    baz qux");
+        assert_contains!(message, "foo-bar    foo bar");
+    }
+
+    #[test]
+    fn hidden_choices_build_help() {
+        // Setup
+        let mut door: u32 = 0;
+        let clp = CommandLineParser::new("program").add(
+            Parameter::argument(Scalar::new(&mut door), "door")
+                .choice(1, "Enter door #1.")
+                .choice(2, "Enter door #2.")
+                .choice(3, "Enter the secret door #3.")
+                .hidden_choices(vec![3]),
+        );
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Verify
+        let error_code = parser.parse_tokens(&["--help"]).unwrap_err();
+        assert_eq!(error_code, 0);
+
+        let message = receiver.consume_message();
+        assert_contains!(message, "DOOR         {1, 2}");
+        assert_contains!(message, "1          Enter door #1.");
+        assert_contains!(message, "2          Enter door #2.");
+        assert!(!message.contains("secret"));
+    }
+
+    #[test]
+    fn hidden_choices_build() {
+        // Setup
+        let mut door: u32 = 0;
+        let clp = CommandLineParser::new("program").add(
+            Parameter::argument(Scalar::new(&mut door), "door")
+                .choice(1, "Enter door #1.")
+                .choice(3, "Enter the secret door #3.")
+                .hidden_choices(vec![3]),
+        );
+
+        // Execute
+        let parser = clp.build_parser().unwrap();
+
+        // Verify
+        parser.parse_tokens(&["3"]).unwrap();
+        assert_eq!(door, 3);
+    }
+
+    #[rstest]
+    #[case(vec!["--region", "us-east"], None)]
+    #[case(vec!["--region", "eu-west"], None)]
+    #[case(vec![], None)]
+    #[case(vec!["--region", "ap-south"], Some("'ap-south' is not one of 'eu-west', 'us-east'."))]
+    #[case(vec!["--region", "US-EAST"], Some("'US-EAST' is not one of 'eu-west', 'us-east'."))]
+    fn requires_value_from(#[case] tokens: Vec<&str>, #[case] expected: Option<&str>) {
+        // Setup
+        let regions: Vec<String> = ["eu", "us"]
+            .iter()
+            .zip(["west", "east"])
+            .map(|(area, direction)| format!("{area}-{direction}"))
+            .collect();
+        let allowed: Vec<&str> = regions.iter().map(String::as_str).collect();
+        let mut region: String = "".to_string();
+        let clp = CommandLineParser::new("program").add(
+            Parameter::option(Scalar::new(&mut region), "region", None)
+                .requires_value_from(&allowed),
+        );
+        let (sender, receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let result = parser.parse_tokens(tokens.as_slice());
+
+        // Verify
+        match expected {
+            None => {
+                result.unwrap();
+                assert_eq!(region, tokens.last().copied().unwrap_or_default());
+            }
+            Some(message) => {
+                assert_eq!(result.unwrap_err(), 1);
+                let (_, error, _) = receiver.consume();
+                let error = error.unwrap();
+                assert_contains!(error, message);
+            }
+        }
+    }
+
+    #[test]
+    fn requires_value_from_build_help() {
+        // Setup
+        let regions: Vec<String> = vec!["us-east".to_string(), "eu-west".to_string()];
+        let allowed: Vec<&str> = regions.iter().map(String::as_str).collect();
+        let mut region: String = "".to_string();
+        let clp = CommandLineParser::new("program").add(
+            Parameter::option(Scalar::new(&mut region), "region", None)
+                .help("The region.")
+                .choice("us-east".to_string(), "The east.")
+                .requires_value_from(&allowed),
+        );
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Verify
+        let error_code = parser.parse_tokens(&["--help"]).unwrap_err();
+        assert_eq!(error_code, 0);
+
+        let message = receiver.consume_message();
+        assert_contains!(
+            message,
+            "--region REGION   {eu-west,\n                   us-east} The"
+        );
+        assert_contains!(message, "\n   eu-west\n");
+        assert_contains!(message, "\n   us-east         The east.");
+    }
+
+    #[rstest]
+    #[case(vec!["--end", "10", "--start", "5"], None)]
+    #[case(vec!["--end", "10"], None)]
+    #[case(vec!["--end", "5", "--start", "10"], Some("end '5' must exceed start '10'."))]
+    #[case(vec!["--start", "10", "--end", "10"], Some("end '10' must exceed start '10'."))]
+    fn validate_with_context(#[case] tokens: Vec<&str>, #[case] expected: Option<&str>) {
+        // Setup
+        let mut start: u32 = 0;
+        let mut end: u32 = 0;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(Scalar::new(&mut start), "start", None))
+            .add(
+                Parameter::option(Scalar::new(&mut end), "end", None).validate_with_context(
+                    |value, context| match context.get("start") {
+                        Some(start) if value.parse::<u32>().ok() <= start.parse::<u32>().ok() => {
+                            Err(format!("end '{value}' must exceed start '{start}'."))
+                        }
+                        _ => Ok(()),
+                    },
+                ),
+            );
+        let (sender, receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let result = parser.parse_tokens(tokens.as_slice());
+
+        // Verify
+        match expected {
+            None => {
+                result.unwrap();
+            }
+            Some(message) => {
+                assert_eq!(result.unwrap_err(), 1);
+                let (_, error, _) = receiver.consume();
+                let error = error.unwrap();
+                assert_contains!(error, message);
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(vec!["--action", "start", "8080"], Ok(("start", Some("8080"))))]
+    #[case(vec!["--action", "restart"], Ok(("restart", None)))]
+    #[case(vec!["--action", "stop", "--verbose"], Ok(("stop", None)))]
+    #[case(vec!["--action", "pause", "8080"], Err("cannot convert 'pause' to a keyword (ex: 'start', 'stop', 'restart')."))]
+    fn keyword_with_arg(
+        #[case] tokens: Vec<&str>,
+        #[case] expected: Result<(&str, Option<&str>), &str>,
+    ) {
+        // Setup
+        let mut action: (String, Option<String>) = ("stop".to_string(), None);
+        let mut verbose: bool = false;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(
+                Scalar::keyword_with_arg(&mut action, &["start", "stop", "restart"]),
+                "action",
+                None,
+            ))
+            .add(Parameter::option(
+                Switch::new(&mut verbose, true),
+                "verbose",
+                None,
+            ));
+        let (sender, receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let result = parser.parse_tokens(tokens.as_slice());
+
+        // Verify
+        match expected {
+            Ok((keyword, value)) => {
+                result.unwrap();
+                assert_eq!(action, (keyword.to_string(), value.map(|s| s.to_string())));
+            }
+            Err(message) => {
+                assert_eq!(result.unwrap_err(), 1);
+                let (_, error, _) = receiver.consume();
+                let error = error.unwrap();
+                assert_contains!(error, message);
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(vec!["--tags", "x", "y"], None)]
+    #[case(vec!["--tags", "x", "y", "x"], Some("duplicate value 'x'."))]
+    #[case(vec!["--tags", "x", "x", "y"], Some("duplicate value 'x'."))]
+    fn reject_duplicates(#[case] tokens: Vec<&str>, #[case] expected: Option<&str>) {
+        // Setup
+        let mut tags: Vec<String> = Vec::default();
+        let clp = CommandLineParser::new("program").add(Parameter::option(
+            Collection::new(&mut tags, Nargs::AtLeastOne).reject_duplicates(),
+            "tags",
+            None,
+        ));
+        let (sender, receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let result = parser.parse_tokens(tokens.as_slice());
+
+        // Verify
+        match expected {
+            None => {
+                result.unwrap();
+                assert_eq!(tags, vec!["x".to_string(), "y".to_string()]);
+            }
+            Some(message) => {
+                assert_eq!(result.unwrap_err(), 1);
+                let (_, error, _) = receiver.consume();
+                let error = error.unwrap();
+                assert_contains!(error, message);
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(vec!["in.txt"], "program --output out.txt in.txt")]
+    #[case(vec!["in.txt", "-v"], "program --verbose --output out.txt in.txt")]
+    #[case(vec!["-o", "x.txt", "in.txt"], "program --output x.txt in.txt")]
+    #[case(vec!["in.txt", "--output=x.txt", "-v"], "program --verbose --output x.txt in.txt")]
+    #[case(vec!["in.txt", "--level", "2"], "program --output out.txt --level 2 in.txt")]
+    fn parse_tokens_resolved(#[case] tokens: Vec<&str>, #[case] expected: &str) {
+        // Setup
+        let mut verbose: bool = false;
+        let mut output: String = "out.txt".to_string();
+        let mut level: Option<u32> = None;
+        let mut input: String = "".to_string();
+        let clp = CommandLineParser::new("program")
+            .add(
+                Parameter::option(Switch::new(&mut verbose, true), "verbose", Some('v'))
+                    .initial(false),
+            )
+            .add(
+                Parameter::option(Scalar::new(&mut output), "output", Some('o')).initial("out.txt"),
+            )
+            .add(Parameter::option(Optional::new(&mut level), "level", None))
+            .add(Parameter::argument(Scalar::new(&mut input), "input"));
+        let parser = clp.build_parser().unwrap();
+
+        // Execute
+        let result = parser.parse_tokens_resolved(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case(vec!["checkout"], "program checkout --remote origin")]
+    #[case(vec!["--dry-run", "checkout", "--force"], "program --dry-run checkout --force --remote origin")]
+    #[case(vec!["checkout", "--remote", "upstream"], "program checkout --remote upstream")]
+    fn parse_tokens_resolved_sub_command(#[case] tokens: Vec<&str>, #[case] expected: &str) {
+        // Setup
+        let mut sub: String = "".to_string();
+        let mut dry_run: bool = false;
+        let mut force: bool = false;
+        let mut remote: String = "origin".to_string();
+        let clp = CommandLineParser::new("program").add(Parameter::option(
+            Switch::new(&mut dry_run, true),
+            "dry-run",
+            None,
+        ));
+        let scp = clp
+            .branch(Condition::new(Scalar::new(&mut sub), "sub"))
+            .command("checkout".to_string(), |sub| {
+                sub.add(Parameter::option(
+                    Switch::new(&mut force, true),
+                    "force",
+                    None,
+                ))
+                .add(Parameter::option(Scalar::new(&mut remote), "remote", None).initial("origin"))
+            });
+        let parser = scp.build_parser().unwrap();
+
+        // Execute
+        let result = parser.parse_tokens_resolved(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(result, expected);
+    }
+
+    #[rstest]
+    #[case(vec!["a", "1"], Ok(()), vec!["a"])]
+    #[case(vec!["b"], Ok(()), vec!["b"])]
+    #[case(vec!["bee"], Ok(()), vec!["b"])]
+    #[case(vec!["c"], Ok(()), vec![])]
+    #[case(vec!["a", "x"], Err(1), vec![])]
+    #[case(vec!["a", "--help"], Err(0), vec![])]
+    #[case(vec!["d"], Err(1), vec![])]
+    fn command_with_handler(
+        #[case] tokens: Vec<&str>,
+        #[case] expected: Result<(), i32>,
+        #[case] expected_dispatched: Vec<&str>,
+    ) {
+        // Setup
+        let mut sub: String = "".to_string();
+        let mut value: u32 = 0;
+        let dispatched: RefCell<Vec<&str>> = RefCell::new(Vec::default());
+        let clp = CommandLineParser::new("program");
+        let scp = clp
+            .branch(Condition::new(Scalar::new(&mut sub), "sub"))
+            .command_with_handler(
+                "a".to_string(),
+                |sub| sub.add(Parameter::argument(Scalar::new(&mut value), "value")),
+                || dispatched.borrow_mut().push("a"),
+            )
+            .command_with_handler(
+                "b".to_string(),
+                |sub| sub,
+                || dispatched.borrow_mut().push("b"),
+            )
+            .command("c".to_string(), |sub| sub)
+            .alias("bee", "b".to_string());
+        let (sender, _receiver) = channel_interface();
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let result = parser.parse_tokens(tokens.as_slice());
+
+        // Verify
+        assert_eq!(result, expected);
+        assert_eq!(dispatched.into_inner(), expected_dispatched);
+    }
+
+    #[test]
+    fn command_with_handler_repeated() {
+        // Setup
+        let dispatched: Cell<u32> = Cell::new(0);
+        let mut sub: String = "".to_string();
+        let clp = CommandLineParser::new("program");
+        let scp = clp
+            .branch(Condition::new(Scalar::new(&mut sub), "sub"))
+            .command_with_handler("a".to_string(), |sub| sub, || dispatched.set(1))
+            .command("a".to_string(), |sub| sub);
+        let (sender, _receiver) = channel_interface();
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        parser.parse_tokens(&["a"]).unwrap();
+
+        // Verify
+        assert_eq!(dispatched.get(), 0);
+    }
+
+    #[rstest]
+    #[case(vec!["checkout"], false)]
+    #[case(vec!["checkout", "--force"], true)]
+    #[case(vec!["co"], false)]
+    #[case(vec!["co", "--force"], true)]
+    fn alias_build(#[case] tokens: Vec<&str>, #[case] expected_force: bool) {
+        // Setup
+        let mut sub: String = "".to_string();
+        let mut force: bool = false;
+        let clp = CommandLineParser::new("program");
+        let scp = clp
+            .branch(Condition::new(Scalar::new(&mut sub), "sub"))
+            .command("checkout".to_string(), |sub| {
+                sub.add(Parameter::option(
+                    Switch::new(&mut force, true),
+                    "force",
+                    None,
+                ))
+            })
+            .alias("co", "checkout".to_string());
+
+        // Execute
+        let parser = scp.build_parser().unwrap();
+
+        // Verify
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+        assert_eq!(&sub, "checkout");
+        assert_eq!(force, expected_force);
+    }
+
+    #[rstest]
+    #[case(vec!["checkout", "--help"])]
+    #[case(vec!["co", "--help"])]
+    fn alias_build_help(#[case] tokens: Vec<&str>) {
+        // Setup
+        let mut sub = Camel::FooBar;
+        let mut force: bool = false;
+        let clp = CommandLineParser::new("program");
+        let scp = clp
+            .branch(Condition::new(Scalar::new(&mut sub), "sub").choices_case(Case::Lower))
+            .command(Camel::FooBar, |sub| sub)
+            .command(Camel::BazQux, |sub| {
+                sub.add(Parameter::option(
+                    Switch::new(&mut force, true),
+                    "force",
+                    None,
+                ))
+            })
+            .alias("checkout", Camel::BazQux)
+            .alias("co", Camel::BazQux);
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Verify
+        let error_code = parser.parse_tokens(tokens.as_slice()).unwrap_err();
+        assert_eq!(error_code, 0);
+
+        let message = receiver.consume_message();
+        assert_contains!(message, "usage: program bazqux [-h] [--force]\n");
+    }
+
+    #[rstest]
+    #[case("a", "sub-command alias 'a' collides with a sub-command.")]
+    #[case("x", "sub-command alias 'x' refers to unknown sub-command 'b'.")]
+    fn alias_invalid(#[case] alias: &str, #[case] expected: &str) {
+        // Setup
+        let mut sub: String = "".to_string();
+        let clp = CommandLineParser::new("program");
+        let scp = clp
+            .branch(Condition::new(Scalar::new(&mut sub), "sub"))
+            .command("a".to_string(), |sub| sub)
+            .alias(alias, if alias == "a" { "a" } else { "b" }.to_string());
+
+        // Execute
+        let result = scp.build_parser().unwrap_err();
+
+        // Verify
+        assert_matches!(result, ConfigError(message) => {
+            assert_eq!(message, expected.to_string());
+        });
+    }
+
+    #[test]
+    fn command_group_build_help() {
+        // Setup
+        let mut sub: String = "".to_string();
+        let clp = CommandLineParser::new("program");
+        let scp = clp
+            .branch(
+                Condition::new(Scalar::new(&mut sub), "sub")
+                    .choice("push".to_string(), "do the push")
+                    .choice("pull".to_string(), "do the pull")
+                    .choice("status".to_string(), "do the status"),
+            )
+            .command("push".to_string(), |sub| sub)
+            .command("pull".to_string(), |sub| sub)
+            .command("status".to_string(), |sub| sub)
+            .command_group("Remote commands", &["push".to_string()])
+            .command_group("Remote commands", &["pull".to_string()]);
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Verify
+        let error_code = parser.parse_tokens(&["--help"]).unwrap_err();
+        assert_eq!(error_code, 0);
+
+        let message = receiver.consume_message();
+        assert_contains!(
+            message,
+            "   Remote commands:\n     pull     do the pull\n     push     do the push\n   other commands:\n     status   do the"
+        );
+    }
+
+    #[rstest]
+    #[case(
+        vec![("a", vec!["b"])],
+        "command group 'a' refers to unknown sub-command 'b'."
+    )]
+    #[case(
+        vec![("a", vec!["a"]), ("b", vec!["a"])],
+        "sub-command 'a' cannot belong to multiple command groups."
+    )]
+    fn command_group_invalid(#[case] groups: Vec<(&str, Vec<&str>)>, #[case] expected: &str) {
+        // Setup
+        let mut sub: String = "".to_string();
+        let clp = CommandLineParser::new("program");
+        let mut scp = clp
+            .branch(Condition::new(Scalar::new(&mut sub), "sub"))
+            .command("a".to_string(), |sub| sub);
+
+        for (name, variants) in groups {
+            let variants: Vec<String> = variants.into_iter().map(|v| v.to_string()).collect();
+            scp = scp.command_group(name, &variants);
+        }
+
+        // Execute
+        let result = scp.build_parser().unwrap_err();
+
+        // Verify
+        assert_matches!(result, ConfigError(message) => {
+            assert_eq!(message, expected.to_string());
+        });
+    }
+
+    #[test]
+    fn messages_build_help() {
+        // Setup
+        let mut sub: u32 = 0;
+        let mut item: u32 = 0;
+        let clp = CommandLineParser::new("program");
+        let scp = clp
+            .messages(
+                Messages::default()
+                    .usage("Verwendung:")
+                    .positional_arguments("Argumente:")
+                    .options("Optionen:"),
+            )
+            .branch(Condition::new(Scalar::new(&mut sub), "sub"))
+            .command(0, |sub| {
+                sub.add(Parameter::argument(Scalar::new(&mut item), "item"))
+            });
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Verify
+        let error_code = parser.parse_tokens(&["0", "--help"]).unwrap_err();
+        assert_eq!(error_code, 0);
+
+        let message = receiver.consume_message();
+        assert_contains!(message, "Verwendung: program 0 [-h] ITEM\n");
+        assert_contains!(message, "\nArgumente:\n");
+        assert_contains!(message, "\nOptionen:\n");
+    }
+
+    #[rstest]
+    #[case(vec!["run"], false, vec![])]
+    #[case(vec!["-v", "run", "--flag", "x"], true, vec!["--flag", "x"])]
+    #[case(vec!["run", "-v", "--help"], false, vec!["-v", "--help"])]
+    fn stop_at_first_positional(
+        #[case] tokens: Vec<&str>,
+        #[case] expected_verbose: bool,
+        #[case] expected_remainder: Vec<&str>,
+    ) {
+        // Setup
+        let mut verbose: bool = false;
+        let mut command: String = "".to_string();
+        let mut remainder: Vec<String> = Vec::default();
+        let clp = CommandLineParser::new("program")
+            .stop_at_first_positional(&mut remainder)
+            .add(Parameter::option(
+                Switch::new(&mut verbose, true),
+                "verbose",
+                Some('v'),
+            ))
+            .add(Parameter::argument(Scalar::new(&mut command), "command"));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(verbose, expected_verbose);
+        assert_eq!(&command, "run");
+        assert_eq!(remainder, expected_remainder);
+    }
+
+    #[rstest]
+    #[case(Nargs::Any)]
+    #[case(Nargs::AtLeastOne)]
+    fn branch_greedy_argument(#[case] nargs: Nargs) {
+        // Setup
+        let mut items: Vec<String> = Vec::default();
+        let mut sub: u32 = 0;
+        let scp = CommandLineParser::new("program")
+            .add(Parameter::argument(
+                Collection::new(&mut items, nargs),
+                "items",
+            ))
+            .branch(Condition::new(Scalar::new(&mut sub), "sub"))
+            .command(0, |sub| sub);
+
+        // Execute
+        let result = scp.build_parser().unwrap_err();
+
+        // Verify
+        assert_matches!(result, ConfigError(message) => {
+            assert_eq!(message, "parameter 'items' cannot take an unbounded number of values in a branching parser: it would consume the sub-command.".to_string());
+        });
+    }
+
+    #[test]
+    fn branch_bounded_argument() {
+        // Setup
+        let mut items: Vec<String> = Vec::default();
+        let mut sub: u32 = 0;
+        let scp = CommandLineParser::new("program")
+            .add(Parameter::argument(
+                Collection::new(&mut items, Nargs::Precisely(2)),
+                "items",
+            ))
+            .branch(Condition::new(Scalar::new(&mut sub), "sub"))
+            .command(0, |sub| sub);
+
+        // Execute
+        let parser = scp.build_parser().unwrap();
+        parser.parse_tokens(&["a", "b", "0"]).unwrap();
+
+        // Verify
+        assert_eq!(items, vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(sub, 0);
+    }
+
+    #[test]
+    fn stop_at_first_positional_branch() {
+        // Setup
+        let mut sub: u32 = 0;
+        let mut remainder: Vec<String> = Vec::default();
+        let scp = CommandLineParser::new("program")
+            .stop_at_first_positional(&mut remainder)
+            .branch(Condition::new(Scalar::new(&mut sub), "sub"))
+            .command(0, |sub| sub);
+
+        // Execute
+        let result = scp.build_parser().unwrap_err();
+
+        // Verify
+        assert_matches!(result, ConfigError(message) => {
+            assert_eq!(message, "cannot stop at the first positional argument of a branching parser.".to_string());
+        });
+    }
+
+    #[rstest]
+    #[case(vec!["a"], false, vec![])]
+    #[case(vec!["a", "b"], false, vec!["b"])]
+    #[case(vec!["a", "b", "--c"], false, vec!["b", "--c"])]
+    #[case(vec!["-v", "a", "b", "-v"], true, vec!["b", "-v"])]
+    fn allow_trailing(
+        #[case] tokens: Vec<&str>,
+        #[case] expected_verbose: bool,
+        #[case] expected_remaining: Vec<&str>,
+    ) {
+        // Setup
+        let mut verbose: bool = false;
+        let mut source: String = "".to_string();
+        let clp = CommandLineParser::new("program")
+            .allow_trailing()
+            .add(Parameter::option(
+                Switch::new(&mut verbose, true),
+                "verbose",
+                Some('v'),
+            ))
+            .add(Parameter::argument(Scalar::new(&mut source), "source"));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        let remaining = parser.parse_partial(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(verbose, expected_verbose);
+        assert_eq!(&source, "a");
+        assert_eq!(remaining, expected_remaining);
+    }
+
+    #[test]
+    fn allow_trailing_disabled() {
+        // Setup
+        let mut source: String = "".to_string();
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::argument(Scalar::new(&mut source), "source"));
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        let error_code = parser.parse_partial(vec!["a", "b"].as_slice()).unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 1);
+        let (message, error, _) = receiver.consume();
+        assert_eq!(message, None);
+        assert_eq!(
+            error,
+            Some("Parse error during matching: no more arguments to match against.".to_string())
+        );
+    }
+
+    #[test]
+    fn allow_trailing_sub_command() {
+        // Setup
+        let mut sub: u32 = 0;
+        let mut source: String = "".to_string();
+        let scp = CommandLineParser::new("program")
+            .allow_trailing()
+            .branch(Condition::new(Scalar::new(&mut sub), "sub"))
+            .command(1, |sub_command| {
+                sub_command.add(Parameter::argument(Scalar::new(&mut source), "source"))
+            });
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+        let remaining = parser
+            .parse_partial(vec!["1", "a", "b", "--c"].as_slice())
+            .unwrap();
+
+        // Verify
+        assert_eq!(sub, 1);
+        assert_eq!(&source, "a");
+        assert_eq!(remaining, vec!["b", "--c"]);
+    }
+
+    #[test]
+    fn lazy_option() {
+        // Setup
+        let mut port: Lazy<u16> = Lazy::new();
+        let clp = CommandLineParser::new("program").add(Parameter::option(
+            Scalar::lazy(&mut port),
+            "port",
+            None,
+        ));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(&["--port", "http"]).unwrap();
+
+        // Verify
+        assert_eq!(port.token(), Some("http"));
+        assert_eq!(port.get(), Err("cannot convert 'http' to u16.".to_string()));
+    }
+
+    #[test]
+    fn allow_file_ref() {
+        // Setup
+        let path =
+            std::env::temp_dir().join(format!("blarg-{}-allow-file-ref.pem", std::process::id()));
+        std::fs::write(&path, "secret").unwrap();
+        let file_ref = format!("@{}", path.to_str().unwrap());
+        let mut cert: String = String::default();
+        let mut user: String = String::default();
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(
+                Scalar::new(&mut cert).allow_file_ref(),
+                "cert",
+                None,
+            ))
+            .add(Parameter::option(
+                Scalar::new(&mut user).allow_file_ref(),
+                "user",
+                None,
+            ));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser
+            .parse_tokens(&["--cert", file_ref.as_str(), "--user", "@@admin"])
+            .unwrap();
+
+        // Verify
+        assert_eq!(cert, "secret".to_string());
+        assert_eq!(user, "@admin".to_string());
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn allow_file_ref_missing() {
+        // Setup
+        let mut cert: String = String::default();
+        let clp = CommandLineParser::new("program").add(Parameter::option(
+            Scalar::new(&mut cert).allow_file_ref(),
+            "cert",
+            None,
+        ));
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        let error_code = parser
+            .parse_tokens(&["--cert", "@/blarg/missing/cert.pem"])
+            .unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 1);
+        let (_, error, _) = receiver.consume();
+        let error = error.unwrap();
+        assert_contains!(error, "cannot read file '/blarg/missing/cert.pem': ");
+    }
+
+    #[rstest]
+    #[case(vec![], vec![])]
+    #[case(vec!["1"], vec![1])]
+    fn zero_or_one_argument(#[case] tokens: Vec<&str>, #[case] expected: Vec<u32>) {
+        // Setup
+        let mut maybe: Vec<u32> = Vec::default();
+        let clp = CommandLineParser::new("program").add(Parameter::argument(
+            Collection::new(&mut maybe, Nargs::ZeroOrOne),
+            "maybe",
+        ));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(maybe, expected);
+    }
+
+    #[rstest]
+    #[case(true, Some("3.14"))]
+    #[case(false, None)]
+    fn decimal(#[case] locale_comma: bool, #[case] expected: Option<&str>) {
+        // Setup
+        let mut ratio: f64 = 0.0;
+        let clp = CommandLineParser::new("program").add(Parameter::option(
+            Scalar::decimal(&mut ratio, locale_comma),
+            "ratio",
+            None,
+        ));
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        let result = parser.parse_tokens(&["--ratio", "3,14"]);
+
+        // Verify
+        match expected {
+            Some(expected) => {
+                assert_eq!(result, Ok(()));
+                assert_eq!(ratio.to_string(), expected);
+            }
+            None => {
+                assert_eq!(result, Err(1));
+                let (_, error, _) = receiver.consume();
+                let error = error.unwrap();
+                assert_contains!(error, "cannot convert '3,14' to f64.");
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(vec!["--opacity", "50%"], Some(0.5))]
+    #[case(vec!["--opacity", "0.5"], Some(0.5))]
+    #[case(vec!["--opacity", "100%"], Some(1.0))]
+    #[case(vec!["--opacity", "150%"], None)]
+    fn percentage(#[case] tokens: Vec<&str>, #[case] expected: Option<f64>) {
+        // Setup
+        let mut opacity: f64 = 0.0;
+        let clp = CommandLineParser::new("program").add(Parameter::option(
+            Scalar::percentage(&mut opacity),
+            "opacity",
+            None,
+        ));
+        let (sender, receiver) = channel_interface();
+
+        // Execute
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        let result = parser.parse_tokens(tokens.as_slice());
+
+        // Verify
+        match expected {
+            Some(expected) => {
+                assert_eq!(result, Ok(()));
+                assert_eq!(opacity, expected);
+            }
+            None => {
+                assert_eq!(result, Err(1));
+                let (_, error, _) = receiver.consume();
+                let error = error.unwrap();
+                assert_contains!(error, "'150%' is out of range for a percentage");
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(vec!["--offset", "+1h"], 3_600, vec![])]
+    #[case(vec!["--offset", "-30m"], -1_800, vec![])]
+    #[case(vec!["--offset=-30m", "-5"], -1_800, vec![-5])]
+    #[case(vec!["-5", "-6", "-o", "-30m"], -1_800, vec![-5, -6])]
+    fn signed_duration_negative_numbers(
+        #[case] tokens: Vec<&str>,
+        #[case] expected_offset: i64,
+        #[case] expected_items: Vec<i32>,
+    ) {
+        // Setup
+        let mut offset: i64 = 0;
+        let mut items: Vec<i32> = Vec::default();
+        let clp = CommandLineParser::new("program")
+            .negative_numbers()
+            .add(Parameter::option(
+                Scalar::signed_duration(&mut offset),
+                "offset",
+                Some('o'),
+            ))
+            .add(Parameter::argument(
+                Collection::new(&mut items, Nargs::Any),
+                "item",
+            ));
+
+        // Execute
+        let parser = clp.build_parser().unwrap();
+
+        // Verify
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+        assert_eq!(offset, expected_offset);
+        assert_eq!(items, expected_items);
+    }
+
+    #[rstest]
+    #[case(vec!["-5"], -5)]
+    #[case(vec!["5"], 5)]
+    #[case(vec!["-0"], 0)]
+    fn negative_numbers_argument(#[case] tokens: Vec<&str>, #[case] expected: i32) {
+        // Setup
+        let mut value: i32 = 1;
+        let clp = CommandLineParser::new("program")
+            .negative_numbers()
+            .add(Parameter::argument(Scalar::new(&mut value), "value"));
+        let (sender, _receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(value, expected);
+    }
+
+    #[rstest]
+    #[case(vec!["-2.5"], -2.5)]
+    #[case(vec!["-0.125"], -0.125)]
+    #[case(vec!["-3"], -3.0)]
+    fn negative_numbers_argument_float(#[case] tokens: Vec<&str>, #[case] expected: f64) {
+        // Setup
+        let mut value: f64 = 1.0;
+        let clp = CommandLineParser::new("program")
+            .negative_numbers()
+            .add(Parameter::argument(Scalar::new(&mut value), "value"));
+        let (sender, _receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(value, expected);
+    }
+
+    #[rstest]
+    #[case(vec!["-1", "-2"], true, -2)]
+    #[case(vec!["-2"], false, -2)]
+    #[case(vec!["-2", "-1"], true, -2)]
+    fn negative_numbers_argument_digit_short(
+        #[case] tokens: Vec<&str>,
+        #[case] expected_one: bool,
+        #[case] expected_value: i32,
+    ) {
+        // Setup
+        let mut one: bool = false;
+        let mut value: i32 = 0;
+        let clp = CommandLineParser::new("program")
+            .negative_numbers()
+            .add(Parameter::option(
+                Switch::new(&mut one, true),
+                "one",
+                Some('1'),
+            ))
+            .add(Parameter::argument(Scalar::new(&mut value), "value"));
+        let (sender, _receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        // The declared short option '-1' is never matched as the value.
+        assert_eq!(one, expected_one);
+        assert_eq!(value, expected_value);
+    }
+
+    #[test]
+    fn negative_numbers_argument_disabled() {
+        // Setup
+        let mut value: i32 = 0;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::argument(Scalar::new(&mut value), "value"));
+        let (sender, receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let error_code = parser.parse_tokens(vec!["-5"].as_slice()).unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 1);
+        let (_, error, _) = receiver.consume();
+        let error = error.unwrap();
+        assert_contains!(error, "short option '5' does not exist.");
+        assert_eq!(value, 0);
+    }
+
+    #[rstest]
+    #[case(false, vec!["--offset", "-30m"], "short option '3' does not exist.")]
+    #[case(true, vec!["--offset", "-30x"], "cannot convert '-30x' to a signed duration (ex: '+1h', '-30m').")]
+    fn signed_duration_invalid(
+        #[case] negative_numbers: bool,
+        #[case] tokens: Vec<&str>,
+        #[case] expected: &str,
+    ) {
+        // Setup
+        let mut offset: i64 = 0;
+        let mut clp = CommandLineParser::new("program").add(Parameter::option(
+            Scalar::signed_duration(&mut offset),
+            "offset",
+            None,
+        ));
+        if negative_numbers {
+            clp = clp.negative_numbers();
+        }
+        let (sender, receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let error_code = parser.parse_tokens(tokens.as_slice()).unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 1);
+        let (_, error, _) = receiver.consume();
+        let error = error.unwrap();
+        assert_contains!(error, expected);
+    }
+
+    #[rstest]
+    #[case(vec!["--", "--notanoption"], false, "--notanoption")]
+    #[case(vec!["--", "-weird.txt"], false, "-weird.txt")]
+    #[case(vec!["-v", "--", "-v"], true, "-v")]
+    #[case(vec!["--", "--"], false, "--")]
+    fn end_of_options(
+        #[case] tokens: Vec<&str>,
+        #[case] expected_verbose: bool,
+        #[case] expected: &str,
+    ) {
+        // Setup
+        let mut verbose: bool = false;
+        let mut file: String = String::default();
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(
+                Switch::new(&mut verbose, true),
+                "verbose",
+                Some('v'),
+            ))
+            .add(Parameter::argument(Scalar::new(&mut file), "file"));
+        let (sender, _receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(verbose, expected_verbose);
+        assert_eq!(&file, expected);
+    }
+
+    #[test]
+    fn end_of_options_error_context() {
+        // Setup
+        let tokens = vec!["--", "a", "-b"];
+        let mut file: String = String::default();
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::argument(Scalar::new(&mut file), "file"));
+        let (sender, receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let error_code = parser.parse_tokens(tokens.as_slice()).unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 1);
+        let (_, error, error_context) = receiver.consume();
+        let error = error.unwrap();
+        assert_contains!(error, "no more arguments to match against.");
+        assert_eq!(error_context, Some(ErrorContext::new(3, &tokens)));
+    }
+
+    #[rstest]
+    #[case(false, vec!["-v", "a", "b"], true, vec!["a", "b"])]
+    #[case(false, vec!["a", "-v"], true, vec!["a"])]
+    #[case(true, vec!["-v", "a", "b"], true, vec!["a", "b"])]
+    #[case(true, vec!["a", "-v", "b"], false, vec!["a", "-v", "b"])]
+    #[case(true, vec!["a", "--", "b"], false, vec!["a", "--", "b"])]
+    fn options_before_positionals(
+        #[case] strict: bool,
+        #[case] tokens: Vec<&str>,
+        #[case] expected_verbose: bool,
+        #[case] expected_items: Vec<&str>,
+    ) {
+        // Setup
+        let mut verbose: bool = false;
+        let mut items: Vec<String> = Vec::default();
+        let mut clp = CommandLineParser::new("program")
+            .add(Parameter::option(
+                Switch::new(&mut verbose, true),
+                "verbose",
+                Some('v'),
+            ))
+            .add(Parameter::argument(
+                Collection::new(&mut items, Nargs::Any),
+                "item",
+            ));
+        if strict {
+            clp = clp.options_before_positionals();
+        }
+        let (sender, _receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(verbose, expected_verbose);
+        assert_eq!(items, expected_items);
+    }
+
+    #[test]
+    fn options_before_positionals_rejected() {
+        // Setup
+        let mut verbose: bool = false;
+        let mut item: String = String::default();
+        let clp = CommandLineParser::new("program")
+            .options_before_positionals()
+            .add(Parameter::option(
+                Switch::new(&mut verbose, true),
+                "verbose",
+                Some('v'),
+            ))
+            .add(Parameter::argument(Scalar::new(&mut item), "item"));
+        let (sender, receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let error_code = parser.parse_tokens(&["a", "-v"]).unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 1);
+        let (_, error, _) = receiver.consume();
+        assert!(error.is_some());
+        assert!(!verbose);
+    }
+
+    #[rstest]
+    #[case(vec!["a", "b", ";", "c", "d"], vec!["a", "b"], vec!["c", "d"])]
+    #[case(vec!["a", ";", "-v", "c"], vec!["a"], vec!["c"])]
+    #[case(vec!["a", "b", "c"], vec!["a", "b", "c"], vec![])]
+    fn argument_terminator(
+        #[case] tokens: Vec<&str>,
+        #[case] expected_sources: Vec<&str>,
+        #[case] expected_targets: Vec<&str>,
+    ) {
+        // Setup
+        let mut verbose: bool = false;
+        let mut sources: Vec<String> = Vec::default();
+        let mut targets: Vec<String> = Vec::default();
+        let clp = CommandLineParser::new("program")
+            .argument_terminator(';')
+            .add(Parameter::option(
+                Switch::new(&mut verbose, true),
+                "verbose",
+                Some('v'),
+            ))
+            .add(Parameter::argument(
+                Collection::new(&mut sources, Nargs::AtLeastOne),
+                "source",
+            ))
+            .add(Parameter::argument(
+                Collection::new(&mut targets, Nargs::Any),
+                "target",
+            ));
+        let (sender, _receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(sources, expected_sources);
+        assert_eq!(targets, expected_targets);
+    }
+
+    #[test]
+    fn argument_terminator_disabled() {
+        // Setup
+        let mut sources: Vec<String> = Vec::default();
+        let clp = CommandLineParser::new("program").add(Parameter::argument(
+            Collection::new(&mut sources, Nargs::AtLeastOne),
+            "source",
+        ));
+        let (sender, _receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        parser.parse_tokens(&["a", ";", "b"]).unwrap();
+
+        // Verify
+        assert_eq!(sources, vec!["a", ";", "b"]);
+    }
+
+    #[test]
+    fn lint_clean() {
+        // Setup
+        let mut verbose: bool = false;
+        let mut items: Vec<u32> = Vec::default();
+        let mut sources: Vec<String> = Vec::default();
+        let mut targets: Vec<String> = Vec::default();
+        let clp = CommandLineParser::new("program")
+            .argument_terminator(';')
+            .add(Parameter::option(
+                Switch::new(&mut verbose, true),
+                "verbose",
+                Some('v'),
+            ))
+            .add(Parameter::option(
+                Collection::new(&mut items, Nargs::Range(1, 3)),
+                "item",
+                None,
+            ))
+            .add(Parameter::argument(
+                Collection::new(&mut sources, Nargs::AtLeastOne),
+                "source",
+            ))
+            .add(Parameter::argument(
+                Collection::new(&mut targets, Nargs::Any),
+                "target",
+            ));
+        let (sender, _receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let warnings = parser.lint();
+
+        // Verify
+        assert_eq!(warnings, vec![]);
+    }
+
+    #[test]
+    fn lint_problematic() {
+        // Setup
+        let mut items: Vec<u32> = Vec::default();
+        let mut sources: Vec<String> = Vec::default();
+        let mut targets: Vec<String> = Vec::default();
+        let mut last: String = String::default();
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(
+                Collection::new(&mut items, Nargs::Range(1, 3)),
+                "item",
+                None,
+            ))
+            .add(Parameter::argument(
+                Collection::new(&mut sources, Nargs::AtLeastOne),
+                "source",
+            ))
+            .add(Parameter::argument(
+                Collection::new(&mut targets, Nargs::Any),
+                "target",
+            ))
+            .add(Parameter::argument(Scalar::new(&mut last), "last"));
+        let (sender, _receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let warnings = parser.lint();
+
+        // Verify
+        assert_eq!(
+            warnings,
+            vec![
+                ConfigWarning::UnreachableArgument("source".to_string(), "target".to_string()),
+                ConfigWarning::UnreachableArgument("source".to_string(), "last".to_string()),
+            ]
+        );
+        assert_eq!(
+            warnings
+                .iter()
+                .map(ConfigWarning::is_fatal)
+                .collect::<Vec<bool>>(),
+            vec![false, false]
+        );
+    }
+
+    #[test]
+    fn lint_empty_range_message() {
+        // Setup
+        let warning = ConfigWarning::EmptyRange("item".to_string(), 3, 1);
+
+        // Execute
+        let message = warning.to_string();
+
+        // Verify
+        assert!(warning.is_fatal());
+        assert_eq!(
+            message,
+            "parameter 'item' cannot match any number of values: its minimum 3 exceeds its maximum 1."
+        );
+    }
+
+    #[rstest]
+    #[case(false, vec![])]
+    #[case(true, vec![ConfigWarning::DigitShort("one".to_string(), '1')])]
+    fn lint_digit_short(#[case] negative_numbers: bool, #[case] expected: Vec<ConfigWarning>) {
+        // Setup
+        let mut one: bool = false;
+        let mut value: i32 = 0;
+        let mut clp = CommandLineParser::new("program")
+            .add(Parameter::option(
+                Switch::new(&mut one, true),
+                "one",
+                Some('1'),
+            ))
+            .add(Parameter::argument(Scalar::new(&mut value), "value"));
+
+        if negative_numbers {
+            clp = clp.negative_numbers();
+        }
+
+        let (sender, _receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let warnings = parser.lint();
+
+        // Verify
+        assert_eq!(warnings, expected);
+        assert!(!warnings.iter().any(ConfigWarning::is_fatal));
+    }
+
+    #[test]
+    fn lint_digit_short_message() {
+        // Setup
+        let warning = ConfigWarning::DigitShort("one".to_string(), '1');
+
+        // Execute
+        let message = warning.to_string();
+
+        // Verify
+        assert_eq!(
+            message,
+            "option 'one' has the digit short name '-1': it shadows the negative numbers beginning with '-1'."
+        );
+    }
+
+    #[test]
+    fn lint_sub_command() {
+        // Setup
+        let mut sub: String = "".to_string();
+        let mut before: Vec<String> = Vec::default();
+        let mut after: Vec<String> = Vec::default();
         let mut items: Vec<u32> = Vec::default();
+        let mut last: u32 = 0;
+        let clp = CommandLineParser::new("program");
+        let scp = clp
+            .branch(Condition::new(Scalar::new(&mut sub), "sub"))
+            .command("a".to_string(), |sub| {
+                sub.add(Parameter::argument(
+                    Collection::split(&mut before, &mut after),
+                    "files",
+                ))
+            })
+            .command("b".to_string(), |sub| {
+                sub.add(Parameter::argument(
+                    Collection::new(&mut items, Nargs::AtLeastOne),
+                    "items",
+                ))
+                .add(Parameter::argument(Scalar::new(&mut last), "last"))
+            });
+        let (sender, _receiver) = channel_interface();
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let warnings = parser.lint();
+
+        // Verify
+        assert_eq!(
+            warnings,
+            vec![ConfigWarning::UnreachableArgument(
+                "items".to_string(),
+                "last".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn grammar() {
+        // Setup
+        let mut verbose: bool = false;
+        let mut level: u32 = 0;
+        let mut range: Vec<u32> = Vec::default();
+        let mut source: String = "".to_string();
+        let mut targets: Vec<String> = Vec::default();
         let clp = CommandLineParser::new("program")
-            .about("abc def")
             .add(Parameter::option(
-                Switch::new(&mut flag, true),
-                "flag",
-                Some('f'),
+                Switch::new(&mut verbose, true),
+                "verbose",
+                Some('v'),
+            ))
+            .add(Parameter::option(
+                Scalar::new(&mut level),
+                "log-level",
+                None,
             ))
+            .add(
+                Parameter::option(
+                    Collection::new(&mut range, Nargs::Precisely(2)),
+                    "range",
+                    None,
+                )
+                .value_names(vec!["MIN", "MAX"]),
+            )
+            .add(Parameter::argument(Scalar::new(&mut source), "source"))
             .add(Parameter::argument(
-                Collection::new(&mut items, Nargs::Any),
-                "item",
+                Collection::new(&mut targets, Nargs::Range(0, 3)),
+                "target",
             ));
+        let (sender, _receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
 
         // Execute
-        let parser = clp.build_parser().unwrap();
+        let grammar = parser.grammar();
 
         // Verify
         assert_eq!(
-            parser.details(),
-            ("program".to_string(), Some("abc def".to_string()))
+            grammar,
+            r#"program ::= program-option* SOURCE TARGET{0,3}
+program-option ::= ("-h" | "--help")
+               | "--log-level" LOG_LEVEL
+               | "--range" MIN MAX
+               | ("-v" | "--verbose")"#
         );
-
-        // We testing that build sets up the right parser.
-        // So the verification involves invoking the parser with the various permutations.
-        parser.parse_tokens(tokens.as_slice()).unwrap();
-        assert_eq!(flag, expected_flag);
-        assert_eq!(items, expected_items);
     }
 
-    #[rstest]
-    #[case(vec!["0"], false, 0, vec![], vec![])]
-    #[case(vec!["0", "1"], false, 0, vec![1], vec![])]
-    #[case(vec!["0", "1", "3", "2"], false, 0, vec![1, 3, 2], vec![])]
-    #[case(vec!["1"], false, 1, vec![], vec![])]
-    #[case(vec!["1", "1"], false, 1, vec![], vec![1])]
-    #[case(vec!["1", "1", "3", "2"], false, 1, vec![], vec![1, 3, 2])]
-    #[case(vec!["--flag", "0"], true, 0, vec![], vec![])]
-    #[case(vec!["--flag", "0", "1"], true, 0, vec![1], vec![])]
-    #[case(vec!["--flag", "0", "1", "3", "2"], true, 0, vec![1, 3, 2], vec![])]
-    #[case(vec!["--flag", "1"], true, 1, vec![], vec![])]
-    #[case(vec!["--flag", "1", "1"], true, 1, vec![], vec![1])]
-    #[case(vec!["--flag", "1", "1", "3", "2"], true, 1, vec![], vec![1, 3, 2])]
-    fn branch_build(
-        #[case] tokens: Vec<&str>,
-        #[case] expected_flag: bool,
-        #[case] expected_sub: u32,
-        #[case] expected_items_0: Vec<u32>,
-        #[case] expected_items_1: Vec<u32>,
-    ) {
+    #[test]
+    fn grammar_sub_command() {
         // Setup
-        let mut flag: bool = false;
-        let mut sub: u32 = 0;
-        let mut items_0: Vec<u32> = Vec::default();
-        let mut items_1: Vec<u32> = Vec::default();
+        let mut sub: String = "".to_string();
+        let mut items: Vec<u32> = Vec::default();
+        let mut force: bool = false;
         let clp = CommandLineParser::new("program");
         let scp = clp
-            .add(Parameter::option(
-                Switch::new(&mut flag, true),
-                "flag",
-                Some('f'),
-            ))
-            .branch(Condition::new(Scalar::new(&mut sub), "sub"))
-            .command(0, |sub| {
+            .branch(Condition::new(Scalar::new(&mut sub), "sub-command"))
+            .command("add".to_string(), |sub| {
                 sub.add(Parameter::argument(
-                    Collection::new(&mut items_0, Nargs::Any),
-                    "item0",
+                    Collection::new(&mut items, Nargs::AtLeastOne),
+                    "item",
                 ))
             })
-            .command(1, |sub| {
-                sub.about("abc def").add(Parameter::argument(
-                    Collection::new(&mut items_1, Nargs::Any),
-                    "item1",
+            .command("clear".to_string(), |sub| {
+                sub.add(Parameter::option(
+                    Switch::new(&mut force, true),
+                    "force",
+                    Some('f'),
                 ))
             });
+        let (sender, _receiver) = channel_interface();
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
 
         // Execute
-        let parser = scp.build_parser().unwrap();
+        let grammar = parser.grammar();
 
         // Verify
-        assert_eq!(parser.details(), ("program".to_string(), None));
-        assert_eq!(parser.sub_details("x"), None);
-        assert_eq!(
-            parser.sub_details("0"),
-            Some(("program 0".to_string(), None))
-        );
         assert_eq!(
-            parser.sub_details("1"),
-            Some(("program 1".to_string(), Some("abc def".to_string())))
+            grammar,
+            r#"program ::= program-option* SUB_COMMAND
+program-option ::= ("-h" | "--help")
+SUB_COMMAND ::= "add" program-add | "clear" program-clear
+program-add ::= program-add-option* ITEM+
+program-add-option ::= ("-h" | "--help")
+program-clear ::= program-clear-option*
+program-clear-option ::= ("-f" | "--force")
+                     | ("-h" | "--help")"#
         );
+    }
 
-        // We testing that build sets up the right parser.
-        // So the verification involves invoking the parser with the various permutations.
-        parser.parse_tokens(tokens.as_slice()).unwrap();
-        assert_eq!(flag, expected_flag);
-        assert_eq!(sub, expected_sub);
-        assert_eq!(items_0, expected_items_0);
-        assert_eq!(items_1, expected_items_1);
+    #[test]
+    fn write_help() {
+        // Setup
+        let mut item: u32 = 0;
+        let clp = CommandLineParser::new("program")
+            .about("The program.")
+            .add(Parameter::argument(Scalar::new(&mut item), "item").help("The item."));
+        let (sender, receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        let mut buffer: Vec<u8> = Vec::default();
+
+        // Execute
+        parser.write_help(&mut buffer).unwrap();
+
+        // Verify
+        let help = String::from_utf8(buffer).unwrap();
+        assert!(help.starts_with("usage: program [-h] ITEM\n"));
+        assert_contains!(help, "The program.");
+        assert_contains!(help, " ITEM         The item.\n");
+        assert_eq!(parser.parse_tokens(&["--help"]), Err(0));
+        assert_eq!(help, format!("{}\n", receiver.consume_message()));
     }
 
     #[test]
-    fn repeat_command_build() {
+    fn write_help_error() {
         // Setup
-        let mut sub: u32 = 0;
-        let mut items_0: Vec<u32> = Vec::default();
-        let mut items_1: Vec<u32> = Vec::default();
-        let clp = CommandLineParser::new("program");
-        let scp = clp
-            .branch(Condition::new(Scalar::new(&mut sub), "sub"))
-            .command(0, |sub| {
-                sub.add(Parameter::argument(
-                    Collection::new(&mut items_0, Nargs::Any),
-                    "item0",
-                ))
-            })
-            .command(0, |sub| {
-                sub.add(Parameter::argument(
-                    Collection::new(&mut items_1, Nargs::Any),
-                    "item1",
+        struct Broken;
+
+        impl std::io::Write for Broken {
+            fn write(&mut self, _buf: &[u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::new(
+                    std::io::ErrorKind::BrokenPipe,
+                    "broken",
                 ))
-            });
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let (sender, _receiver) = channel_interface();
+        let parser = CommandLineParser::new("program")
+            .build_with_interface(Box::new(sender))
+            .unwrap();
 
         // Execute
-        let parser = scp.build_parser().unwrap();
+        let result = parser.write_help(&mut Broken);
 
         // Verify
-        // We testing that build sets up the right parser.
-        // So the verification involves invoking the parser with the various permutations.
-        parser.parse_tokens(&["0", "1", "2", "3"]).unwrap();
-        assert_eq!(sub, 0);
-        assert_eq!(items_0, Vec::default());
-        assert_eq!(items_1, vec![1, 2, 3]);
+        assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::BrokenPipe);
     }
 
     #[rstest]
-    #[case(vec!["abc", "0"], false, "abc", 0, vec![])]
-    #[case(vec!["abc", "0", "1"], false, "abc", 0, vec![1])]
-    #[case(vec!["abc", "0", "1", "3", "2"], false, "abc", 0, vec![1, 3, 2])]
-    #[case(vec!["--flag", "abc", "0"], true, "abc", 0, vec![])]
-    #[case(vec!["--flag", "abc", "0", "1"], true, "abc", 0, vec![1])]
-    #[case(vec!["--flag", "abc", "0", "1", "3", "2"], true, "abc", 0, vec![1, 3, 2])]
-    #[case(vec!["abc", "--flag", "0"], true, "abc", 0, vec![])]
-    #[case(vec!["abc", "--flag", "0", "1"], true, "abc", 0, vec![1])]
-    #[case(vec!["abc", "--flag", "0", "1", "3", "2"], true, "abc", 0, vec![1, 3, 2])]
-    fn root_arguments_branch_build(
+    #[case(vec![], "from-env", "explicit-env", false)]
+    #[case(vec!["--output", "given"], "given", "explicit-env", false)]
+    #[case(vec!["--dry-run"], "from-env", "explicit-env", true)]
+    #[case(vec!["--log-file", "given"], "from-env", "given", false)]
+    fn env_prefix(
         #[case] tokens: Vec<&str>,
-        #[case] expected_flag: bool,
-        #[case] expected_root: &str,
-        #[case] expected_sub: u32,
-        #[case] expected_items: Vec<u32>,
+        #[case] expected_output: &str,
+        #[case] expected_log_file: &str,
+        #[case] expected_dry_run: bool,
     ) {
         // Setup
-        let mut flag: bool = false;
-        let mut root: String = String::default();
-        let mut sub: u32 = 0;
-        let mut items: Vec<u32> = Vec::default();
-        let clp = CommandLineParser::new("program");
-        let scp = clp
+        std::env::set_var("BLARG_ENV_PREFIX_OUTPUT", "from-env");
+        std::env::set_var("BLARG_ENV_PREFIX_LOG_FILE", "derived-env");
+        std::env::set_var("BLARG_ENV_PREFIX_EXPLICIT", "explicit-env");
+        std::env::set_var("BLARG_ENV_PREFIX_DRY_RUN", "true");
+        let mut output: String = "default".to_string();
+        let mut log_file: String = "default".to_string();
+        let mut dry_run: bool = false;
+        let clp = CommandLineParser::new("program")
+            .env_prefix("BLARG_ENV_PREFIX_")
+            .add(Parameter::option(Scalar::new(&mut output), "output", None))
+            .add(
+                Parameter::option(Scalar::new(&mut log_file), "log-file", None)
+                    .env("BLARG_ENV_PREFIX_EXPLICIT"),
+            )
             .add(Parameter::option(
-                Switch::new(&mut flag, true),
-                "flag",
-                Some('f'),
-            ))
-            .add(Parameter::argument(Scalar::new(&mut root), "root"))
+                Switch::new(&mut dry_run, true),
+                "dry-run",
+                None,
+            ));
+        let (sender, _receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(output, expected_output);
+        assert_eq!(log_file, expected_log_file);
+        assert_eq!(dry_run, expected_dry_run);
+    }
+
+    #[test]
+    fn env_prefix_sub_command() {
+        // Setup
+        std::env::set_var("BLARG_ENV_PREFIX_SUB_COMMAND_OUTPUT", "from-env");
+        let mut sub: String = "".to_string();
+        let mut output: String = "default".to_string();
+        let clp = CommandLineParser::new("program").env_prefix("BLARG_ENV_PREFIX_SUB_COMMAND_");
+        let scp = clp
             .branch(Condition::new(Scalar::new(&mut sub), "sub"))
-            .command(0, |sub| {
-                sub.add(Parameter::argument(
-                    Collection::new(&mut items, Nargs::Any),
-                    "item0",
-                ))
+            .command("a".to_string(), |sub| {
+                sub.add(Parameter::option(Scalar::new(&mut output), "output", None))
             });
+        let (sender, _receiver) = channel_interface();
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
 
         // Execute
-        let parser = scp.build_parser().unwrap();
+        parser.parse_tokens(&["a"]).unwrap();
 
         // Verify
-        assert_eq!(parser.details(), ("program".to_string(), None));
-
-        // We testing that build sets up the right parser.
-        // So the verification involves invoking the parser with the various permutations.
-        parser.parse_tokens(tokens.as_slice()).unwrap();
-        assert_eq!(flag, expected_flag);
-        assert_eq!(&root, expected_root);
-        assert_eq!(sub, expected_sub);
-        assert_eq!(items, expected_items);
+        assert_eq!(output, "from-env");
     }
 
-    #[test]
-    fn empty_build_help() {
+    #[rstest]
+    #[case(
+        false,
+        " --log-file LOG_FILE                      env: BLARG_ENV_BUILD_HELP_LOG\n"
+    )]
+    #[case(true, " --log-file LOG_FILE\n")]
+    fn env_build_help(#[case] no_meta: bool, #[case] expected: &str) {
         // Setup
-        let clp = CommandLineParser::new("program");
+        let mut output: String = "default".to_string();
+        let mut log_file: String = "default".to_string();
+        let mut clp = CommandLineParser::new("program")
+            .env_prefix("BLARG_ENV_BUILD_HELP_")
+            .add(Parameter::option(Scalar::new(&mut output), "output", None))
+            .add(
+                Parameter::option(Scalar::new(&mut log_file), "log-file", None)
+                    .env("BLARG_ENV_BUILD_HELP_LOG"),
+            );
+
+        if no_meta {
+            clp = clp.no_meta();
+        }
+
         let (sender, receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
 
         // Execute
-        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        let error_code = parser.parse_tokens(&["--help"]).unwrap_err();
 
         // Verify
-        // We testing that build sets up the right parser.
-        // So the verification involves invoking the parser with --help and spot-checking the output.
-        let error_code = parser.parse_tokens(&["--help"]).unwrap_err();
         assert_eq!(error_code, 0);
-
         let message = receiver.consume_message();
-        assert_contains!(message, "usage: program [-h]\n");
+        assert_contains!(message, expected);
+        assert_eq!(
+            message.contains("env: BLARG_ENV_BUILD_HELP_OUTPUT"),
+            !no_meta
+        );
     }
 
     #[test]
-    fn build_help() {
+    fn env_unset() {
         // Setup
-        let mut flag: bool = false;
-        let mut items: Vec<u32> = Vec::default();
-        let mut clp = CommandLineParser::new("program");
-        clp = clp
+        let mut output: String = "default".to_string();
+        let clp = CommandLineParser::new("program").add(
+            Parameter::option(Scalar::new(&mut output), "output", None)
+                .env("BLARG_ENV_UNSET_DOES_NOT_EXIST"),
+        );
+        let (sender, _receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        parser.parse_tokens(&[]).unwrap();
+
+        // Verify
+        assert_eq!(output, "default");
+    }
+
+    #[rstest]
+    #[case(
+        "BLARG_ENV_INVALID_VALUE_CONVERSION",
+        "x",
+        "cannot convert 'x' to u32.",
+        "BLARG_ENV_INVALID_VALUE_CONVERSION=x\n                                   ^"
+    )]
+    #[case(
+        "BLARG_ENV_INVALID_VALUE_VALIDATION",
+        "7",
+        "must be even.",
+        "BLARG_ENV_INVALID_VALUE_VALIDATION=7\n                                   ^"
+    )]
+    fn env_invalid_value(
+        #[case] variable: &str,
+        #[case] value: &str,
+        #[case] expected_error: &str,
+        #[case] expected_context: &str,
+    ) {
+        // Setup
+        std::env::set_var(variable, value);
+        let mut verbose: bool = false;
+        let mut count: u32 = 0;
+        let clp = CommandLineParser::new("program")
             .add(Parameter::option(
-                Switch::new(&mut flag, true),
-                "flag",
-                Some('f'),
+                Switch::new(&mut verbose, true),
+                "verbose",
+                None,
             ))
-            .add(Parameter::argument(
-                Collection::new(&mut items, Nargs::Any),
-                "item",
+            .add(
+                Parameter::option(Scalar::new(&mut count), "count", None)
+                    .env(variable)
+                    .validate_with_context(|value, _| {
+                        if value.parse::<u32>().unwrap() % 2 == 0 {
+                            Ok(())
+                        } else {
+                            Err("must be even.".to_string())
+                        }
+                    }),
+            );
+        let (sender, receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        let error_code = parser.parse_tokens(&["--verbose"]).unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 1);
+        let (_, error, error_context) = receiver.consume();
+        assert_eq!(
+            error.unwrap(),
+            format!(
+                "Parse error during capture from the environment variable '{variable}': {expected_error}"
+            )
+        );
+        assert_eq!(error_context.unwrap().to_string(), expected_context);
+    }
+
+    #[rstest]
+    #[case(true)]
+    #[case(false)]
+    fn env_invalid(#[case] option: bool) {
+        // Setup
+        let mut value: bool = false;
+        let parameter = if option {
+            Parameter::option(Switch::new(&mut value, true), "value", None)
+        } else {
+            Parameter::argument(Scalar::new(&mut value), "value")
+        };
+        let clp = CommandLineParser::new("program").add(parameter.env("BLARG_ENV_INVALID"));
+        let (sender, _receiver) = channel_interface();
+
+        // Execute
+        let result = clp.build_with_interface(Box::new(sender));
+
+        // Verify
+        assert_matches!(result, Err(ConfigError(message)) => {
+            assert_eq!(
+                message,
+                "parameter 'value' cannot read from the environment: only options which take values may read from the environment."
+            );
+        });
+    }
+
+    #[rstest]
+    #[case(vec![], None)]
+    #[case(vec!["--password", "given"], Some("given"))]
+    #[case(vec!["--password=given"], Some("given"))]
+    #[case(vec!["--password"], Some("prompted"))]
+    #[case(vec!["--password", "--verbose"], Some("prompted"))]
+    fn prompt_if_empty(#[case] tokens: Vec<&str>, #[case] expected: Option<&str>) {
+        // Setup
+        let mut verbose: bool = false;
+        let mut password: Option<String> = None;
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(
+                Switch::new(&mut verbose, true),
+                "verbose",
+                None,
+            ))
+            .add(Parameter::option(
+                Optional::new(&mut password).prompt_if_empty(),
+                "password",
+                None,
             ));
         let (sender, receiver) = channel_interface();
+        let sender = sender.with_responses(vec!["prompted"]);
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+
+        // Execute
+        parser.parse_tokens(tokens.as_slice()).unwrap();
+
+        // Verify
+        assert_eq!(password, expected.map(|p| p.to_string()));
+        let (message, error, _) = receiver.consume();
+        assert_eq!(message, None);
+        assert_eq!(error, None);
+    }
+
+    #[test]
+    fn prompt_if_empty_without_terminal() {
+        // Setup
+        let mut password: Option<String> = None;
+        let clp = CommandLineParser::new("program").add(Parameter::option(
+            Optional::new(&mut password).prompt_if_empty(),
+            "password",
+            None,
+        ));
+        let (sender, receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
 
         // Execute
-        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
+        let error_code = parser.parse_tokens(&["--password"]).unwrap_err();
 
         // Verify
-        // We testing that build sets up the right parser.
-        // So the verification involves invoking the parser with --help and spot-checking the output.
-        let error_code = parser.parse_tokens(&["--help"]).unwrap_err();
-        assert_eq!(error_code, 0);
-
-        let message = receiver.consume_message();
-        assert_contains!(message, "usage: program [-h] [-f] [ITEM ...]\n");
-        assert_contains!(message, "-f, --flag");
+        assert_eq!(error_code, 1);
+        let (_, error, _) = receiver.consume();
+        let error = error.unwrap();
+        assert_contains!(
+            error,
+            "cannot prompt for '--password' without a terminal that hides the response."
+        );
+        assert_eq!(password, None);
     }
 
-    #[test]
-    fn branch_build_help() {
+    #[rstest]
+    #[case(vec!["--bind", "localhost:80", "::1"], "'localhost:80' is not a valid host:port")]
+    #[case(vec!["--bind", "127.0.0.1:80", "localhost"], "'localhost' is not a valid IP address")]
+    fn socket_addr_ip_addr_invalid(#[case] tokens: Vec<&str>, #[case] expected: &str) {
         // Setup
-        let mut flag: bool = false;
-        let mut sub: u32 = 0;
-        let clp = CommandLineParser::new("program");
-        let scp = clp
+        let mut bind: std::net::SocketAddr = "0.0.0.0:80".parse().unwrap();
+        let mut host: std::net::IpAddr = "0.0.0.0".parse().unwrap();
+        let clp = CommandLineParser::new("program")
             .add(Parameter::option(
-                Switch::new(&mut flag, true),
-                "flag",
-                Some('f'),
+                Scalar::socket_addr(&mut bind),
+                "bind",
+                None,
             ))
-            .branch(
-                Condition::new(Scalar::new(&mut sub), "sub")
-                    .choice(0, "zero")
-                    .choice(1, "one"),
-            )
-            .command(0, |sub| sub)
-            .command(1, |sub| sub);
+            .add(Parameter::argument(Scalar::ip_addr(&mut host), "host"));
         let (sender, receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
 
         // Execute
-        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+        let error_code = parser.parse_tokens(tokens.as_slice()).unwrap_err();
 
         // Verify
-        // We testing that build sets up the right parser.
-        // So the verification involves invoking the parser with --help and spot-checking the output.
-        let error_code = parser.parse_tokens(&["--help"]).unwrap_err();
-        assert_eq!(error_code, 0);
-
-        let message = receiver.consume_message();
-        assert_contains!(message, "usage: program [-h] [-f] SUB\n");
-        assert_contains!(message, "SUB          {0, 1}");
-        assert_contains!(message, "0            zero");
-        assert_contains!(message, "1            one");
-        assert_contains!(message, "-f, --flag");
+        assert_eq!(error_code, 1);
+        let (_, error, _) = receiver.consume();
+        let error = error.unwrap();
+        assert_contains!(error, expected);
     }
 
-    #[test]
-    fn sub0_command_build_help() {
+    #[rstest]
+    #[case(vec!["--coord", "3,4"], None)]
+    #[case(vec!["--coord", "3"], Some("cannot split '3' into 2 parts on ',' (found 1)."))]
+    #[case(vec!["--coord", "3,x"], Some("cannot convert 'x' to i32."))]
+    fn tuple2(#[case] tokens: Vec<&str>, #[case] expected: Option<&str>) {
         // Setup
-        let mut flag: bool = false;
-        let mut sub: u32 = 0;
-        let mut items: Vec<u32> = Vec::default();
-        let mut extra: bool = false;
-        let clp = CommandLineParser::new("program");
-        let scp = clp
-            .add(Parameter::option(
-                Switch::new(&mut flag, true),
-                "flag",
-                Some('f'),
-            ))
-            .branch(
-                Condition::new(Scalar::new(&mut sub), "sub")
-                    .choice(0, "zero")
-                    .choice(1, "one"),
-            )
-            .command(0, |sub| sub)
-            .command(1, |sub| {
-                sub.add(Parameter::argument(
-                    Collection::new(&mut items, Nargs::Any),
-                    "item",
-                ))
-                .add(Parameter::option(
-                    Switch::new(&mut extra, true),
-                    "extra",
-                    Some('e'),
-                ))
-            });
+        let mut coord: (i32, i32) = (0, 0);
+        let clp = CommandLineParser::new("program").add(Parameter::option(
+            Scalar::tuple2(&mut coord, ','),
+            "coord",
+            None,
+        ));
         let (sender, receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
 
         // Execute
-        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+        let result = parser.parse_tokens(tokens.as_slice());
 
         // Verify
-        // We testing that build sets up the right parser.
-        // So the verification involves invoking the parser with --help and spot-checking the output.
-        let error_code = parser.parse_tokens(&["0", "--help"]).unwrap_err();
-        assert_eq!(error_code, 0);
-
-        let message = receiver.consume_message();
-        assert_contains!(message, "usage: program 0 [-h]\n");
+        match expected {
+            None => {
+                result.unwrap();
+                assert_eq!(coord, (3, 4));
+            }
+            Some(expected) => {
+                assert_eq!(result.unwrap_err(), 1);
+                let (_, error, _) = receiver.consume();
+                let error = error.unwrap();
+                assert_contains!(error, expected);
+            }
+        }
     }
 
-    #[test]
-    fn sub1_command_build_help() {
+    #[rstest]
+    #[case(vec!["release-1"], None)]
+    #[case(vec!["main"], Some("'main' does not match pattern 'release-*'."))]
+    fn pattern(#[case] tokens: Vec<&str>, #[case] expected: Option<&str>) {
         // Setup
-        let mut flag: bool = false;
-        let mut sub: u32 = 0;
-        let mut items: Vec<u32> = Vec::default();
-        let mut extra: bool = false;
-        let clp = CommandLineParser::new("program");
-        let scp = clp
-            .add(Parameter::option(
-                Switch::new(&mut flag, true),
-                "flag",
-                Some('f'),
-            ))
-            .branch(
-                Condition::new(Scalar::new(&mut sub), "sub")
-                    .choice(0, "zero")
-                    .choice(1, "one"),
-            )
-            .command(0, |sub| sub)
-            .command(1, |sub| {
-                sub.add(Parameter::argument(
-                    Collection::new(&mut items, Nargs::Any),
-                    "item",
-                ))
-                .add(Parameter::option(
-                    Switch::new(&mut extra, true),
-                    "extra",
-                    Some('e'),
-                ))
-            });
+        let mut branch: String = "".to_string();
+        let clp = CommandLineParser::new("program").add(Parameter::argument(
+            Scalar::new(&mut branch).pattern("release-*"),
+            "branch",
+        ));
         let (sender, receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
 
         // Execute
-        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+        let result = parser.parse_tokens(tokens.as_slice());
 
         // Verify
-        // We testing that build sets up the right parser.
-        // So the verification involves invoking the parser with --help and spot-checking the output.
-        let error_code = parser.parse_tokens(&["1", "--help"]).unwrap_err();
-        assert_eq!(error_code, 0);
-
-        let message = receiver.consume_message();
-        assert_contains!(message, "usage: program 1 [-h] [-e] [ITEM ...]\n");
-        assert_contains!(message, "-e, --extra");
+        match expected {
+            None => {
+                result.unwrap();
+                assert_eq!(&branch, "release-1");
+            }
+            Some(expected) => {
+                assert_eq!(result.unwrap_err(), 1);
+                let (_, error, _) = receiver.consume();
+                let error = error.unwrap();
+                assert_contains!(error, expected);
+            }
+        }
     }
 
-    #[test]
-    fn root_arguments_branch_build_help() {
+    #[rstest]
+    #[case("checkout", vec!["checkout", "--help"])]
+    #[case("co", vec!["co", "--help"])]
+    #[case("status", vec!["status", "-h"])]
+    fn sub_command_help(#[case] name: &str, #[case] tokens: Vec<&str>) {
         // Setup
-        let mut flag: bool = false;
-        let mut root: String = String::default();
-        let mut sub: u32 = 0;
-        let mut items: Vec<u32> = Vec::default();
-        let clp = CommandLineParser::new("program");
-        let scp = clp
-            .add(Parameter::option(
-                Switch::new(&mut flag, true),
-                "flag",
-                Some('f'),
-            ))
-            .add(Parameter::argument(Scalar::new(&mut root), "root"))
+        let mut sub: String = "".to_string();
+        let mut item: u32 = 0;
+        let scp = CommandLineParser::new("program")
             .branch(Condition::new(Scalar::new(&mut sub), "sub"))
-            .command(0, |sub| {
-                sub.add(Parameter::argument(
-                    Collection::new(&mut items, Nargs::Any),
-                    "item0",
-                ))
-            });
+            .command("checkout".to_string(), |sub| {
+                sub.about("Switch branches.")
+                    .add(Parameter::argument(Scalar::new(&mut item), "item").help("The item."))
+            })
+            .command("status".to_string(), |sub| sub)
+            .alias("co", "checkout".to_string());
         let (sender, receiver) = channel_interface();
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
 
         // Execute
-        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
+        let help = parser.sub_command_help(name).unwrap();
 
         // Verify
-        // We testing that build sets up the right parser.
-        // So the verification involves invoking the parser with --help and spot-checking the output.
-        let error_code = parser.parse_tokens(&["--help"]).unwrap_err();
-        assert_eq!(error_code, 0);
-
-        let message = receiver.consume_message();
-        assert_contains!(message, "usage: program [-h] [-f] ROOT SUB\n");
+        assert_eq!(parser.parse_tokens(tokens.as_slice()), Err(0));
+        assert_eq!(receiver.consume_message(), help);
     }
 
     #[test]
-    #[cfg(feature = "unit_test")]
-    fn test_dummies() {
+    fn sub_command_help_unknown() {
         // Setup
-        pub fn setup_fn<'a>(value: &'a mut u32) -> impl FnOnce(SubCommand<'a>) -> SubCommand<'a> {
-            |sub| sub.add(Parameter::argument(Scalar::new(value), "value"))
-        }
-
-        let mut x: u32 = 1;
-        let parser = setup_fn(&mut x)(SubCommand::test_dummy())
-            .build_parser()
-            .unwrap();
-        let tokens = vec!["2"];
+        let mut sub: String = "".to_string();
+        let scp = CommandLineParser::new("program")
+            .branch(Condition::new(Scalar::new(&mut sub), "sub"))
+            .command("status".to_string(), |sub| sub);
+        let (sender, _receiver) = channel_interface();
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
 
         // Execute
-        parser.parse_tokens(tokens.as_slice()).unwrap();
+        let help = parser.sub_command_help("checkout");
 
         // Verify
-        assert_eq!(x, 2);
+        assert_eq!(help, None);
     }
 
-    #[derive(PartialEq)]
-    enum Nefarious {
-        Foo,
-        Bar,
-    }
+    #[rstest]
+    #[case("input.txt", false, "input.txt", vec![])]
+    #[case("--verbose 'my input.txt'", true, "my input.txt", vec![])]
+    #[case("\"my input.txt\" -v", true, "my input.txt", vec![])]
+    #[case("my\\ input.txt --tag 'a b' \"c \\\"d\\\"\"", false, "my input.txt", vec!["a b", "c \"d\""])]
+    #[case("'' --tag ''", false, "", vec![""])]
+    fn parse_line(
+        #[case] line: &str,
+        #[case] expected_verbose: bool,
+        #[case] expected_input: &str,
+        #[case] expected_tags: Vec<&str>,
+    ) {
+        // Setup
+        let mut verbose: bool = false;
+        let mut input: String = String::default();
+        let mut tags: Vec<String> = Vec::default();
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::option(
+                Switch::new(&mut verbose, true),
+                "verbose",
+                Some('v'),
+            ))
+            .add(Parameter::option(
+                Collection::new(&mut tags, Nargs::AtLeastOne),
+                "tag",
+                None,
+            ))
+            .add(Parameter::argument(Scalar::new(&mut input), "input"));
+        let (sender, receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
 
-    impl std::fmt::Display for Nefarious {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            match self {
-                Nefarious::Foo => write!(f, "foo"),
-                Nefarious::Bar => write!(f, "bar"),
-            }
-        }
+        // Execute
+        parser.parse_line(line).unwrap();
+
+        // Verify
+        assert_eq!(verbose, expected_verbose);
+        assert_eq!(&input, expected_input);
+        assert_eq!(tags, expected_tags);
+
+        let (message, error, error_context) = receiver.consume();
+        assert_eq!(message, None);
+        assert_eq!(error, None);
+        assert_eq!(error_context, None);
     }
 
-    impl std::str::FromStr for Nefarious {
-        type Err = String;
+    #[rstest]
+    #[case("run 'my input.txt", 4, "un-terminated single quote")]
+    #[case("run \"my input.txt", 4, "un-terminated double quote")]
+    #[case("run input.txt\\", 13, "nothing to escape")]
+    fn parse_line_invalid(#[case] line: &str, #[case] offset: usize, #[case] expected: &str) {
+        // Setup
+        let mut command: String = String::default();
+        let mut input: String = String::default();
+        let clp = CommandLineParser::new("program")
+            .add(Parameter::argument(Scalar::new(&mut command), "command"))
+            .add(Parameter::argument(Scalar::new(&mut input), "input"));
+        let (sender, receiver) = channel_interface();
+        let parser = clp.build_with_interface(Box::new(sender)).unwrap();
 
-        fn from_str(value: &str) -> Result<Self, Self::Err> {
-            match value.to_lowercase().as_str() {
-                "bar" => Ok(Nefarious::Foo),
-                _ => Err(format!("unknown: {}", value)),
-            }
-        }
+        // Execute
+        let error_code = parser.parse_line(line).unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 1);
+        assert_eq!(&command, "");
+        assert_eq!(&input, "");
+
+        let (message, error, error_context) = receiver.consume();
+        assert_eq!(message, None);
+        let error = error.unwrap();
+        assert_contains!(error, "Parse error during tokenizing");
+        assert_contains!(error, expected);
+        assert_eq!(error_context, Some(ErrorContext::new(offset, &[line])));
     }
 
     #[test]
-    fn not_invertable_command() {
+    fn parse_line_sub_command() {
         // Setup
-        let mut nefarious = Nefarious::Bar;
-        let clp = CommandLineParser::new("program");
-        let scp = clp
-            .branch(Condition::new(Scalar::new(&mut nefarious), "abc"))
-            .command(Nefarious::Foo, |sub| sub);
+        let mut sub: String = "".to_string();
+        let mut message: String = "".to_string();
+        let scp = CommandLineParser::new("program")
+            .branch(Condition::new(Scalar::new(&mut sub), "sub"))
+            .command("commit".to_string(), |sub| {
+                sub.add(Parameter::option(
+                    Scalar::new(&mut message),
+                    "message",
+                    Some('m'),
+                ))
+            });
         let (sender, _receiver) = channel_interface();
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
 
         // Execute
-        let result = scp.build_with_interface(Box::new(sender)).unwrap_err();
+        parser.parse_line("commit -m 'fix the \"parser\"'").unwrap();
 
         // Verify
-        assert_matches!(result, ConfigError(message) => {
-            assert_eq!(message, "parameter 'abc' contains invalid sub-command 'foo': FromStr does not invert Display.".to_string());
-        });
+        assert_eq!(&sub, "commit");
+        assert_eq!(&message, "fix the \"parser\"");
     }
 
     #[test]
-    fn nefarious_command() {
+    fn into_parts() {
         // Setup
-        let mut nefarious = Nefarious::Bar;
-        let clp = CommandLineParser::new("program");
-        let scp = clp
-            .branch(Condition::new(Scalar::new(&mut nefarious), "abc"))
-            .command(Nefarious::Bar, |sub| sub);
-        let (sender, _receiver) = channel_interface();
+        let mut sub: String = "".to_string();
+        let mut item: u32 = 0;
+        let scp = CommandLineParser::new("program")
+            .about("abc def")
+            .branch(Condition::new(Scalar::new(&mut sub), "sub"))
+            .command("checkout".to_string(), |sub| {
+                sub.add(Parameter::argument(Scalar::new(&mut item), "item"))
+            })
+            .command("status".to_string(), |sub| sub)
+            .alias("co", "checkout".to_string());
+        let (sender, receiver) = channel_interface();
+        let parser = scp.build_with_interface(Box::new(sender)).unwrap();
 
         // Execute
-        let result = scp.build_with_interface(Box::new(sender)).unwrap_err();
+        let parts = parser.into_parts();
 
         // Verify
-        assert_matches!(result, ConfigError(message) => {
-            assert_eq!(message, "parameter 'abc' contains invalid sub-command 'bar': FromStr does not invert Display.".to_string());
-        });
+        assert_eq!(parts.program(), "program");
+        assert_eq!(parts.sub_commands(), vec!["checkout", "status"]);
+        assert_eq!(parts.usage(), "usage: program [-h] SUB");
+        assert_contains!(parts.help(), "usage: program [-h] SUB\n abc def\n");
+        assert_eq!(
+            parts.sub_command_help("co"),
+            parts.sub_command_help("checkout")
+        );
+        assert_contains!(
+            parts.sub_command_help("checkout").unwrap(),
+            "usage: program checkout [-h] ITEM\n"
+        );
+        assert_eq!(parts.sub_command_help("x"), None);
+
+        parts.into_parser().parse_tokens(&["co", "2"]).unwrap();
+        assert_eq!(&sub, "checkout");
+        assert_eq!(item, 2);
+        assert_eq!(receiver.consume(), (None, None, None));
     }
 }
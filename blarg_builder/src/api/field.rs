@@ -1,51 +1,1036 @@
-use std::cell::RefCell;
-use std::collections::HashSet;
+use std::cell::{Cell, OnceCell, RefCell};
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
 use std::marker::PhantomData;
+use std::net::{IpAddr, SocketAddr};
+use std::path::PathBuf;
 use std::rc::Rc;
 use std::str::FromStr;
 
 use crate::api::capture::*;
+use crate::constant::TERMINATOR;
 use crate::model::Nargs;
 use crate::prelude::Collectable;
 
-/// An option parameter that takes a single value (precisely 1).
+/// The conversion of an input token into the type `T`, as a closure.
+type ConverterClosure<T> = Box<dyn Fn(&str) -> Result<T, InvalidCapture>>;
+/// The conversion of an input token into the type `T`.
+///
+/// A plain function (ex: via `FromStr`) puts no lifetime bound on `T`, unlike a boxed closure.
+enum Converter<T> {
+    Function(fn(&str) -> Result<T, InvalidCapture>),
+    Closure(ConverterClosure<T>),
+}
+
+impl<T> Converter<T> {
+    fn closure(closure: impl Fn(&str) -> Result<T, InvalidCapture> + 'static) -> Self {
+        Converter::Closure(Box::new(closure))
+    }
+
+    fn convert(&self, token: &str) -> Result<T, InvalidCapture> {
+        match self {
+            Converter::Function(function) => function(token),
+            Converter::Closure(closure) => closure(token),
+        }
+    }
+}
+
+/// The check of whether a converted value duplicates a previously collected value.
+type Duplicate<T> = Box<dyn FnMut(&T) -> bool>;
+/// The conversion of a group of input tokens into the record type `T`.
+type RecordConverter<T> = Box<dyn Fn(&[String]) -> Result<T, InvalidCapture>>;
+/// The deferred conversion of an input token into the type `T`, shared by each capture of a [`Lazy`].
+type LazyConverter<T> = Rc<dyn Fn(&str) -> Result<T, InvalidCapture>>;
+
+/// The mapping of an accepted input token to the token its conversion expects.
+pub(crate) type TokenAliases = Rc<RefCell<HashMap<String, String>>>;
+
+/// Convert via [`std::str::FromStr`] - the conversion used by all fields unless otherwise specified.
+fn convert<T: FromStr>(token: &str) -> Result<T, InvalidCapture> {
+    T::from_str(token).map_err(|_| InvalidCapture::InvalidConversion {
+        token: token.to_string(),
+        type_name: std::any::type_name::<T>(),
+    })
+}
+
+/// The standard integer types, which may be converted from a radix prefixed token (ex: `0xff`).
+pub trait Integer: Sized {
+    /// Convert the `digits` (without any radix prefix) in the given `radix`.
+    fn from_str_radix(digits: &str, radix: u32) -> Result<Self, std::num::ParseIntError>;
+}
+
+macro_rules! integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                fn from_str_radix(digits: &str, radix: u32) -> Result<Self, std::num::ParseIntError> {
+                    <$t>::from_str_radix(digits, radix)
+                }
+            }
+        )*
+    };
+}
+
+integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// A record of several values, converted from a group of consecutive input tokens (ex: `NAME AGE`).
+/// Used with [`Collection::repeatable_group`].
+pub trait Record: Sized {
+    /// The number of input tokens which form each record.
+    const ARITY: u8;
+
+    /// Convert the `tokens` (precisely `ARITY` of them) into the record.
+    fn convert(tokens: &[String]) -> Result<Self, InvalidCapture>;
+}
+
+macro_rules! record {
+    ($arity:expr; $($t:ident $i:tt),*) => {
+        impl<$($t: FromStr),*> Record for ($($t,)*) {
+            const ARITY: u8 = $arity;
+
+            fn convert(tokens: &[String]) -> Result<Self, InvalidCapture> {
+                Ok(($(convert::<$t>(&tokens[$i])?,)*))
+            }
+        }
+    };
+}
+
+record!(2; A 0, B 1);
+record!(3; A 0, B 1, C 2);
+record!(4; A 0, B 1, C 2, D 3);
+
+/// Convert an integer, ignoring underscores (ex: `1_000`) and respecting the `0x`, `0o`, and `0b` radix prefixes.
+fn convert_integer<T: Integer>(token: &str) -> Result<T, InvalidCapture> {
+    let invalid = || InvalidCapture::InvalidConversion {
+        token: token.to_string(),
+        type_name: std::any::type_name::<T>(),
+    };
+    let stripped = token.replace('_', "");
+    let (sign, unsigned) = match stripped.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", stripped.strip_prefix('+').unwrap_or(&stripped)),
+    };
+    let (radix, digits) = match unsigned.get(..2) {
+        Some("0x") | Some("0X") => (16, &unsigned[2..]),
+        Some("0o") | Some("0O") => (8, &unsigned[2..]),
+        Some("0b") | Some("0B") => (2, &unsigned[2..]),
+        _ => (10, unsigned),
+    };
+
+    // The sign may only appear before the radix prefix.
+    if digits.starts_with(['-', '+']) {
+        return Err(invalid());
+    }
+
+    T::from_str_radix(&format!("{sign}{digits}"), radix).map_err(|_| invalid())
+}
+
+/// Convert a signed duration (ex: `-1h30m`) into seconds.
+fn convert_signed_duration(token: &str) -> Result<i64, InvalidCapture> {
+    let invalid = || InvalidCapture::InvalidValue {
+        token: token.to_string(),
+        message: format!("cannot convert '{token}' to a signed duration (ex: '+1h', '-30m')."),
+    };
+    let (negative, unsigned) = match token.strip_prefix('-') {
+        Some(unsigned) => (true, unsigned),
+        None => (false, token.strip_prefix('+').unwrap_or(token)),
+    };
+
+    if unsigned.is_empty() {
+        return Err(invalid());
+    }
+
+    let mut seconds: i64 = 0;
+    let mut amount = String::default();
+
+    for c in unsigned.chars() {
+        if c.is_ascii_digit() {
+            amount.push(c);
+            continue;
+        }
+
+        let unit: i64 = match c {
+            'd' => 86_400,
+            'h' => 3_600,
+            'm' => 60,
+            's' => 1,
+            _ => return Err(invalid()),
+        };
+
+        if amount.is_empty() {
+            return Err(invalid());
+        }
+
+        seconds = std::mem::take(&mut amount)
+            .parse::<i64>()
+            .ok()
+            .and_then(|a| a.checked_mul(unit))
+            .and_then(|a| seconds.checked_add(a))
+            .ok_or_else(invalid)?;
+    }
+
+    // Every amount must be followed by its unit.
+    if !amount.is_empty() {
+        return Err(invalid());
+    }
+
+    Ok(if negative { -seconds } else { seconds })
+}
+
+/// Convert a decimal which uses `,` as its decimal separator (ex: `2,5`).
+fn convert_decimal_comma(token: &str) -> Result<f64, InvalidCapture> {
+    f64::from_str(&token.replace(',', ".")).map_err(|_| InvalidCapture::InvalidConversion {
+        token: token.to_string(),
+        type_name: std::any::type_name::<f64>(),
+    })
+}
+
+/// Convert a percentage (ex: `50%` or `0.5`) into a fraction in `0.0..=1.0`.
+fn convert_percentage(token: &str) -> Result<f64, InvalidCapture> {
+    let (amount, scale) = match token.strip_suffix('%') {
+        Some(amount) => (amount, 100.0),
+        None => (token, 1.0),
+    };
+    let value = f64::from_str(amount).map_err(|_| InvalidCapture::InvalidValue {
+        token: token.to_string(),
+        message: format!("cannot convert '{token}' to a percentage (ex: '50%', '0.5')."),
+    })?;
+
+    if (0.0..=scale).contains(&value) {
+        Ok(value / scale)
+    } else {
+        Err(InvalidCapture::InvalidValue {
+            token: token.to_string(),
+            message: format!(
+                "'{token}' is out of range for a percentage (expected 0% to 100%, or 0.0 to 1.0)."
+            ),
+        })
+    }
+}
+
+/// Convert a socket address (ex: `127.0.0.1:8080`).
+fn convert_socket_addr(token: &str) -> Result<SocketAddr, InvalidCapture> {
+    SocketAddr::from_str(token).map_err(|_| InvalidCapture::InvalidValue {
+        token: token.to_string(),
+        message: format!(
+            "'{token}' is not a valid host:port (ex: '127.0.0.1:8080', '[::1]:8080')."
+        ),
+    })
+}
+
+/// Convert an IP address (ex: `127.0.0.1`).
+fn convert_ip_addr(token: &str) -> Result<IpAddr, InvalidCapture> {
+    IpAddr::from_str(token).map_err(|_| InvalidCapture::InvalidValue {
+        token: token.to_string(),
+        message: format!("'{token}' is not a valid IP address (ex: '127.0.0.1', '::1')."),
+    })
+}
+
+/// Convert a hexadecimal string into its bytes, two digits per byte (ex: `deadbeef`).
+fn convert_hex_bytes(token: &str) -> Result<Vec<u8>, InvalidCapture> {
+    let invalid = |reason: String| InvalidCapture::InvalidValue {
+        token: token.to_string(),
+        message: format!("cannot convert '{token}' to hex bytes: {reason}."),
+    };
+
+    if let Some(c) = token.chars().find(|c| !c.is_ascii_hexdigit()) {
+        return Err(invalid(format!("'{c}' is not a hex digit")));
+    }
+
+    if !token.len().is_multiple_of(2) {
+        return Err(invalid("odd number of digits".to_string()));
+    }
+
+    Ok((0..token.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&token[i..i + 2], 16)
+                .expect("internal error - hex digits must convert to a byte")
+        })
+        .collect())
+}
+
+/// Convert a base64 string (standard alphabet, with optional `=` padding) into its bytes (ex: `aGVsbG8=`).
+fn convert_base64_bytes(token: &str) -> Result<Vec<u8>, InvalidCapture> {
+    let invalid = |reason: String| InvalidCapture::InvalidValue {
+        token: token.to_string(),
+        message: format!("cannot convert '{token}' to base64 bytes: {reason}."),
+    };
+    let data = token.trim_end_matches('=');
+    let padding = token.len() - data.len();
+
+    // Each group of 4 characters encodes 3 bytes, so a single trailing character is incomplete.
+    // When present, the padding must complete the final group.
+    if data.len() % 4 == 1 || (padding > 0 && (padding > 2 || !token.len().is_multiple_of(4))) {
+        return Err(invalid("invalid length".to_string()));
+    }
+
+    let mut bytes = Vec::with_capacity(data.len() * 3 / 4);
+    let mut buffer: u32 = 0;
+    let mut bits = 0;
+
+    for c in data.chars() {
+        let sextet = match c {
+            'A'..='Z' => c as u32 - 'A' as u32,
+            'a'..='z' => c as u32 - 'a' as u32 + 26,
+            '0'..='9' => c as u32 - '0' as u32 + 52,
+            '+' => 62,
+            '/' => 63,
+            _ => return Err(invalid(format!("'{c}' is not a base64 character"))),
+        };
+        buffer = (buffer << 6) | sextet;
+        bits += 6;
+
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    Ok(bytes)
+}
+
+/// Require the converted `path` to exist (as a file or directory).
+fn check_exists(token: &str, path: PathBuf) -> Result<PathBuf, InvalidCapture> {
+    if path.exists() {
+        Ok(path)
+    } else {
+        Err(InvalidCapture::InvalidValue {
+            token: token.to_string(),
+            message: format!("path '{token}' does not exist."),
+        })
+    }
+}
+
+/// Whether the `value` matches the wildcard `pattern`, where `*` matches any sequence of characters (including none), and `?` matches any single character.
+fn matches_pattern(pattern: &str, value: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let value: Vec<char> = value.chars().collect();
+    let (mut p, mut v) = (0, 0);
+    // The position of the most recent `*`, and the value position it was tentatively matched up to.
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while v < value.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, v));
+                p += 1;
+            }
+            Some(c) if *c == '?' || *c == value[v] => {
+                p += 1;
+                v += 1;
+            }
+            _ => match backtrack {
+                // Let the `*` absorb one more character, and try again.
+                Some((star, matched)) => {
+                    backtrack = Some((star, matched + 1));
+                    p = star + 1;
+                    v = matched + 1;
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/// Quote the `token` for a POSIX shell, so that the shell sees it as a single word (ex: `a b` becomes `'a b'`).
+/// Tokens made up entirely of shell-safe characters are left as is.
+fn shell_quote(token: &str) -> String {
+    let safe = |c: char| c.is_ascii_alphanumeric() || "@%+=:,./_-".contains(c);
+
+    if !token.is_empty() && token.chars().all(safe) {
+        token.to_string()
+    } else {
+        // Close the quote, emit an escaped single quote, and re-open it.
+        format!("'{}'", token.replace('\'', "'\\''"))
+    }
+}
+
+/// A token quoted for a POSIX shell (ex: `a b` becomes `'a b'`), as collected by [`Collection::shell_command`].
+pub struct ShellWord(String);
+
+/// An option parameter that takes a single value (precisely 1, except for [`Scalar::keyword_with_arg`]).
 pub struct Scalar<'a, T> {
     variable: Rc<RefCell<&'a mut T>>,
+    converter: Converter<T>,
+    nargs: Nargs,
+    warn_on_glob: bool,
+    aliases: Option<TokenAliases>,
 }
 
 impl<'a, T> CliOption for Scalar<'a, T> {}
 impl<'a, T> CliArgument for Scalar<'a, T> {}
 
-impl<'a, T> Scalar<'a, T> {
+impl<'a, T: FromStr> Scalar<'a, T> {
     /// Create a scalar parameter.
     pub fn new(variable: &'a mut T) -> Self {
         Self {
             variable: Rc::new(RefCell::new(variable)),
+            converter: Converter::Function(convert::<T>),
+            nargs: Nargs::Precisely(1),
+            warn_on_glob: false,
+            aliases: None,
+        }
+    }
+}
+
+impl<'a, T: Integer> Scalar<'a, T> {
+    /// Create a scalar parameter for an integer, which accepts underscores (ex: `1_000_000`) and radix prefixes (ex: `0xff`, `0o17`, `0b1010`).
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    ///
+    /// let mut mask: u32 = 0;
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::argument(Scalar::integer(&mut mask), "mask"))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["0xff_ff"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(mask, 65535);
+    /// ```
+    pub fn integer(variable: &'a mut T) -> Self {
+        Self {
+            variable: Rc::new(RefCell::new(variable)),
+            converter: Converter::Function(convert_integer::<T>),
+            nargs: Nargs::Precisely(1),
+            warn_on_glob: false,
+            aliases: None,
         }
     }
 }
 
-impl<'a, T> GenericCapturable<'a, T> for Scalar<'a, T>
+impl<'a> Scalar<'a, i64> {
+    /// Create a scalar parameter for a signed duration, in seconds.
+    /// The duration is an optional sign followed by one or more amounts of units (ex: `+1h`, `-30m`, `1h30m`).
+    /// The units are `d` (days), `h` (hours), `m` (minutes), and `s` (seconds).
+    ///
+    /// Use with [`CommandLineParser::negative_numbers`](./struct.CommandLineParser.html#method.negative_numbers) to accept negative durations as separate tokens (ex: `--offset -30m`).
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    ///
+    /// let mut offset: i64 = 0;
+    /// let parser = CommandLineParser::new("program")
+    ///     .negative_numbers()
+    ///     .add(Parameter::option(Scalar::signed_duration(&mut offset), "offset", None))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["--offset", "-30m"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(offset, -1800);
+    /// ```
+    pub fn signed_duration(variable: &'a mut i64) -> Self {
+        Self {
+            variable: Rc::new(RefCell::new(variable)),
+            converter: Converter::Function(convert_signed_duration),
+            nargs: Nargs::Precisely(1),
+            warn_on_glob: false,
+            aliases: None,
+        }
+    }
+}
+
+impl<'a> Scalar<'a, f64> {
+    /// Create a scalar parameter for a decimal number.
+    /// When `locale_comma`, the `,` is accepted as the decimal separator (ex: `2,5`), as in many locales.
+    /// Otherwise, the decimal is converted via `f64::from_str` (as in `Scalar::new`).
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    ///
+    /// let mut ratio: f64 = 0.0;
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(Scalar::decimal(&mut ratio, true), "ratio", None))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["--ratio", "2,5"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(ratio, 2.5);
+    /// ```
+    pub fn decimal(variable: &'a mut f64, locale_comma: bool) -> Self {
+        Self {
+            variable: Rc::new(RefCell::new(variable)),
+            converter: if locale_comma {
+                Converter::Function(convert_decimal_comma)
+            } else {
+                Converter::Function(convert::<f64>)
+            },
+            nargs: Nargs::Precisely(1),
+            warn_on_glob: false,
+            aliases: None,
+        }
+    }
+
+    /// Create a scalar parameter for a percentage, normalized to a fraction in `0.0..=1.0`.
+    /// The percentage is either a fraction (ex: `0.5`), or a number from `0` to `100` followed by `%` (ex: `50%`).
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    ///
+    /// let mut opacity: f64 = 1.0;
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(Scalar::percentage(&mut opacity), "opacity", None))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["--opacity", "50%"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(opacity, 0.5);
+    /// ```
+    pub fn percentage(variable: &'a mut f64) -> Self {
+        Self {
+            variable: Rc::new(RefCell::new(variable)),
+            converter: Converter::Function(convert_percentage),
+            nargs: Nargs::Precisely(1),
+            warn_on_glob: false,
+            aliases: None,
+        }
+    }
+}
+
+impl<'a> Scalar<'a, SocketAddr> {
+    /// Create a scalar parameter for a socket address, with a descriptive error for malformed input.
+    /// The address is an IPv4 or IPv6 host followed by its port (ex: `127.0.0.1:8080`, `[::1]:8080`).
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    /// use std::net::SocketAddr;
+    ///
+    /// let mut bind: SocketAddr = "0.0.0.0:80".parse().unwrap();
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(Scalar::socket_addr(&mut bind), "bind", None).meta(vec!["type: host:port"]))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["--bind", "127.0.0.1:8080"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(bind, "127.0.0.1:8080".parse().unwrap());
+    /// ```
+    pub fn socket_addr(variable: &'a mut SocketAddr) -> Self {
+        Self {
+            variable: Rc::new(RefCell::new(variable)),
+            converter: Converter::Function(convert_socket_addr),
+            nargs: Nargs::Precisely(1),
+            warn_on_glob: false,
+            aliases: None,
+        }
+    }
+}
+
+impl<'a> Scalar<'a, IpAddr> {
+    /// Create a scalar parameter for an IPv4 or IPv6 address, with a descriptive error for malformed input.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    /// use std::net::{IpAddr, Ipv4Addr};
+    ///
+    /// let mut host: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::argument(Scalar::ip_addr(&mut host), "host").meta(vec!["type: IP address"]))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["::1"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(host, "::1".parse::<IpAddr>().unwrap());
+    /// ```
+    pub fn ip_addr(variable: &'a mut IpAddr) -> Self {
+        Self {
+            variable: Rc::new(RefCell::new(variable)),
+            converter: Converter::Function(convert_ip_addr),
+            nargs: Nargs::Precisely(1),
+            warn_on_glob: false,
+            aliases: None,
+        }
+    }
+}
+
+impl<'a> Scalar<'a, Vec<u8>> {
+    /// Create a scalar parameter for bytes, encoded as hexadecimal (ex: `deadbeef`).
+    /// Both lowercase and uppercase digits are accepted, where each byte takes precisely two digits.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    ///
+    /// let mut key: Vec<u8> = Vec::default();
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(Scalar::hex_bytes(&mut key), "key", None))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["--key", "deadBEEF"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(key, vec![0xde, 0xad, 0xbe, 0xef]);
+    /// ```
+    pub fn hex_bytes(variable: &'a mut Vec<u8>) -> Self {
+        Self {
+            variable: Rc::new(RefCell::new(variable)),
+            converter: Converter::Function(convert_hex_bytes),
+            nargs: Nargs::Precisely(1),
+            warn_on_glob: false,
+            aliases: None,
+        }
+    }
+
+    /// Create a scalar parameter for bytes, encoded as base64 (ex: `aGVsbG8=`).
+    /// Uses the standard alphabet (`+` and `/`), where the trailing `=` padding is optional.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    ///
+    /// let mut key: Vec<u8> = Vec::default();
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(Scalar::base64_bytes(&mut key), "key-b64", None))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["--key-b64", "aGVsbG8="].as_slice()).unwrap();
+    ///
+    /// assert_eq!(key, b"hello".to_vec());
+    /// ```
+    pub fn base64_bytes(variable: &'a mut Vec<u8>) -> Self {
+        Self {
+            variable: Rc::new(RefCell::new(variable)),
+            converter: Converter::Function(convert_base64_bytes),
+            nargs: Nargs::Precisely(1),
+            warn_on_glob: false,
+            aliases: None,
+        }
+    }
+}
+
+impl<'a> Scalar<'a, (String, Option<String>)> {
+    /// Create a scalar parameter for one of the `keywords`, optionally followed by a value for that keyword (ex: `--action start 8080`).
+    /// The keyword and its value are captured together, where the value is `None` if not specified.
+    ///
+    /// This offers a lighter-weight alternative to sub-commands.
+    /// The parameter takes `1` to `2` values, so a keyword without a value must not be directly followed by an argument.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    ///
+    /// let mut action: (String, Option<String>) = ("stop".to_string(), None);
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(
+    ///         Scalar::keyword_with_arg(&mut action, &["start", "stop", "restart"]),
+    ///         "action",
+    ///         None,
+    ///     ))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["--action", "start", "8080"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(action, ("start".to_string(), Some("8080".to_string())));
+    /// ```
+    pub fn keyword_with_arg(variable: &'a mut (String, Option<String>), keywords: &[&str]) -> Self {
+        let keywords: Vec<String> = keywords.iter().map(|k| k.to_string()).collect();
+        // The keyword of the first token, awaiting its (optional) value in the second token.
+        let keyword: Cell<Option<String>> = Cell::new(None);
+        Self {
+            variable: Rc::new(RefCell::new(variable)),
+            converter: Converter::closure(move |token| match keyword.take() {
+                Some(keyword) => Ok((keyword, Some(token.to_string()))),
+                None if keywords.iter().any(|k| k == token) => {
+                    keyword.set(Some(token.to_string()));
+                    Ok((token.to_string(), None))
+                }
+                None => Err(InvalidCapture::InvalidValue {
+                    token: token.to_string(),
+                    message: format!(
+                        "cannot convert '{token}' to a keyword (ex: {}).",
+                        keywords
+                            .iter()
+                            .map(|k| format!("'{k}'"))
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    ),
+                }),
+            }),
+            nargs: Nargs::Range(1, 2),
+            warn_on_glob: false,
+            aliases: None,
+        }
+    }
+}
+
+impl<'a, A, B> Scalar<'a, (A, B)>
 where
-    T: FromStr,
+    A: 'static + FromStr,
+    B: 'static + FromStr,
 {
+    /// Create a scalar parameter for a pair, where the value is split on the `delimiter` into precisely two parts (ex: `3,4`).
+    /// Each part is converted via its [`std::str::FromStr`].
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    ///
+    /// let mut coord: (i32, i32) = (0, 0);
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(Scalar::tuple2(&mut coord, ','), "coord", None))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["--coord", "3,-4"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(coord, (3, -4));
+    /// ```
+    pub fn tuple2(variable: &'a mut (A, B), delimiter: char) -> Self {
+        Self {
+            variable: Rc::new(RefCell::new(variable)),
+            converter: Converter::closure(move |token| {
+                let parts: Vec<&str> = token.split(delimiter).collect();
+
+                match parts[..] {
+                    [a, b] => Ok((convert::<A>(a)?, convert::<B>(b)?)),
+                    _ => Err(InvalidCapture::InvalidValue {
+                        token: token.to_string(),
+                        message: format!(
+                            "cannot split '{token}' into 2 parts on '{delimiter}' (found {}).",
+                            parts.len()
+                        ),
+                    }),
+                }
+            }),
+            nargs: Nargs::Precisely(1),
+            warn_on_glob: false,
+            aliases: None,
+        }
+    }
+}
+
+/// A value which is converted from its input token on first access, rather than during the parse.
+/// Used with [`Scalar::lazy`], for conversions which are expensive (ex: opening a database connection) and only conditionally needed.
+///
+/// The conversion happens at most once; subsequent accesses return the same value.
+/// A conversion error surfaces at access time, describing the input token.
+pub struct Lazy<T> {
+    token: Option<String>,
+    converter: LazyConverter<T>,
+    value: OnceCell<T>,
+}
+
+impl<T: 'static + FromStr> Lazy<T> {
+    /// Create a lazy value, converted via its [`std::str::FromStr`].
+    pub fn new() -> Self {
+        Self {
+            token: None,
+            converter: Rc::new(convert::<T>),
+            value: OnceCell::new(),
+        }
+    }
+}
+
+impl<T: 'static + FromStr> Default for Lazy<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: 'static> Lazy<T> {
+    /// Create a lazy value, converted via the `converter`.
+    /// The `converter` explains a failed conversion with its error message.
+    pub fn with_converter(converter: impl Fn(&str) -> Result<T, String> + 'static) -> Self {
+        Self {
+            token: None,
+            converter: Rc::new(move |token| {
+                converter(token).map_err(|message| InvalidCapture::InvalidValue {
+                    token: token.to_string(),
+                    message: format!(
+                        "cannot convert '{token}' to {}: {}.",
+                        std::any::type_name::<T>(),
+                        message.trim_end_matches('.')
+                    ),
+                })
+            }),
+            value: OnceCell::new(),
+        }
+    }
+}
+
+impl<T> Lazy<T> {
+    /// The input token, when captured.
+    pub fn token(&self) -> Option<&str> {
+        self.token.as_deref()
+    }
+
+    /// Get the value, converting the input token on the first access.
+    /// Returns `Ok(None)` when nothing was captured, or an error message describing the failed conversion.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Lazy, Parameter, Scalar};
+    ///
+    /// let mut port: Lazy<u16> = Lazy::new();
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(Scalar::lazy(&mut port), "port", None))
+    ///     .build();
+    ///
+    /// // The parse succeeds, since the value isn't converted yet.
+    /// parser.parse_tokens(vec!["--port", "x"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(port.get(), Err("cannot convert 'x' to u16.".to_string()));
+    /// ```
+    pub fn get(&self) -> Result<Option<&T>, String> {
+        if let Some(value) = self.value.get() {
+            return Ok(Some(value));
+        }
+
+        match &self.token {
+            Some(token) => {
+                let value = (self.converter)(token).map_err(|error| error.to_string())?;
+                Ok(Some(self.value.get_or_init(|| value)))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for Lazy<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Lazy")
+            .field("token", &self.token)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, T: 'static> Scalar<'a, Lazy<T>> {
+    /// Create a scalar parameter which defers the conversion of its value until accessed (see [`Lazy::get`]).
+    /// The parse only captures the input token.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Lazy, Parameter, Scalar};
+    ///
+    /// let mut database: Lazy<String> =
+    ///     Lazy::with_converter(|token| Ok(format!("connection to {token}")));
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(Scalar::lazy(&mut database), "database", None))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["--database", "db.sqlite"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(database.token(), Some("db.sqlite"));
+    /// assert_eq!(
+    ///     database.get(),
+    ///     Ok(Some(&"connection to db.sqlite".to_string()))
+    /// );
+    /// ```
+    pub fn lazy(variable: &'a mut Lazy<T>) -> Self {
+        let converter = variable.converter.clone();
+        Self {
+            variable: Rc::new(RefCell::new(variable)),
+            converter: Converter::closure(move |token| {
+                Ok(Lazy {
+                    token: Some(token.to_string()),
+                    converter: converter.clone(),
+                    value: OnceCell::new(),
+                })
+            }),
+            nargs: Nargs::Precisely(1),
+            warn_on_glob: false,
+            aliases: None,
+        }
+    }
+}
+
+impl<'a, T> Scalar<'a, T> {
+    /// Warn when the value resembles an unexpanded glob (contains `*` or `?`), since the shell passes a glob which matches nothing as is.
+    /// This is only a warning; the value is captured as usual.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    ///
+    /// let mut file: String = "".to_string();
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::argument(Scalar::new(&mut file).warn_on_glob(), "file"))
+    ///     .build();
+    ///
+    /// // Warns "warning: '*.txt' for 'file' looks like an unexpanded glob (the shell may not have matched any files)."
+    /// parser.parse_tokens(vec!["*.txt"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(&file, "*.txt");
+    /// ```
+    pub fn warn_on_glob(mut self) -> Self {
+        self.warn_on_glob = true;
+        self
+    }
+
+    /// Convert via the `aliases` (when present) before applying the original conversion.
+    pub(crate) fn with_aliases(mut self, aliases: TokenAliases) -> Self {
+        self.aliases.replace(aliases);
+        self
+    }
+}
+
+impl<'a, T: 'static> Scalar<'a, T> {
+    /// Interpret a value which begins with `@` as a reference to a file, whose entire contents are converted instead (ex: `@cert.pem`).
+    /// A single trailing line ending (`\n` or `\r\n`) is stripped from the contents (ex: `@port.txt` converts `8080\n` as `8080`).
+    /// A value which begins with `@@` is converted literally, without its first `@` (ex: `@@user` converts `@user`).
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    ///
+    /// let mut cert: String = "".to_string();
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(Scalar::new(&mut cert).allow_file_ref(), "cert", None))
+    ///     .build();
+    ///
+    /// // Where 'cert.pem' contains the certificate.
+    /// parser.parse_tokens(vec!["--cert", "@cert.pem"].as_slice()).unwrap();
+    /// ```
+    pub fn allow_file_ref(self) -> Self {
+        let converter = self.converter;
+        Self {
+            variable: self.variable,
+            converter: Converter::closure(move |token| {
+                if token.starts_with("@@") {
+                    return converter.convert(&token[1..]);
+                }
+
+                match token.strip_prefix('@') {
+                    Some(path) => {
+                        let content = std::fs::read_to_string(path).map_err(|error| {
+                            InvalidCapture::InvalidValue {
+                                token: token.to_string(),
+                                message: format!("cannot read file '{path}': {error}."),
+                            }
+                        })?;
+                        let content = content
+                            .strip_suffix('\n')
+                            .map(|content| content.strip_suffix('\r').unwrap_or(content))
+                            .unwrap_or(&content);
+                        converter.convert(content)
+                    }
+                    None => converter.convert(token),
+                }
+            }),
+            nargs: self.nargs,
+            warn_on_glob: self.warn_on_glob,
+            aliases: self.aliases,
+        }
+    }
+}
+
+impl<'a, T: 'static + std::fmt::Display> Scalar<'a, T> {
+    /// Require the value to match the wildcard `pattern`, where `*` matches any sequence of characters (including none), and `?` matches any single character.
+    ///
+    /// The pattern is checked against the converted value, as rendered by its [`std::fmt::Display`].
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    ///
+    /// let mut branch: String = "".to_string();
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::argument(Scalar::new(&mut branch).pattern("release-*"), "branch"))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["release-1.2"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(&branch, "release-1.2");
+    /// ```
+    pub fn pattern(self, pattern: &str) -> Self {
+        let pattern = pattern.to_string();
+        let converter = self.converter;
+        Self {
+            variable: self.variable,
+            converter: Converter::closure(move |token| {
+                let value = converter.convert(token)?;
+                let rendered = value.to_string();
+
+                if matches_pattern(&pattern, &rendered) {
+                    Ok(value)
+                } else {
+                    Err(InvalidCapture::InvalidValue {
+                        token: token.to_string(),
+                        message: format!("'{rendered}' does not match pattern '{pattern}'."),
+                    })
+                }
+            }),
+            nargs: self.nargs,
+            warn_on_glob: self.warn_on_glob,
+            aliases: self.aliases,
+        }
+    }
+}
+
+impl<'a> Scalar<'a, PathBuf> {
+    /// Require the path to exist (as a file or directory).
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    /// use std::path::PathBuf;
+    ///
+    /// let mut directory: PathBuf = PathBuf::default();
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::argument(Scalar::new(&mut directory).must_exist(), "directory"))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["."].as_slice()).unwrap();
+    ///
+    /// assert_eq!(directory, PathBuf::from("."));
+    /// ```
+    pub fn must_exist(self) -> Self {
+        let converter = self.converter;
+        Self {
+            variable: self.variable,
+            converter: Converter::closure(move |token| {
+                check_exists(token, converter.convert(token)?)
+            }),
+            nargs: self.nargs,
+            warn_on_glob: self.warn_on_glob,
+            aliases: self.aliases,
+        }
+    }
+}
+
+impl<'a, T> GenericCapturable<'a, T> for Scalar<'a, T> {
     fn matched(&mut self) {
         // Do nothing.
     }
 
     fn capture(&mut self, token: &str) -> Result<(), InvalidCapture> {
-        let result: Result<T, InvalidCapture> =
-            T::from_str(token).map_err(|_| InvalidCapture::InvalidConversion {
-                token: token.to_string(),
-                type_name: std::any::type_name::<T>(),
-            });
-        let value = result?;
+        let alias = self
+            .aliases
+            .as_ref()
+            .and_then(|aliases| aliases.borrow().get(token).cloned());
+        let value = self.converter.convert(alias.as_deref().unwrap_or(token))?;
         **self.variable.borrow_mut() = value;
         Ok(())
     }
 
     fn nargs(&self) -> Nargs {
-        Nargs::Precisely(1)
+        self.nargs
+    }
+
+    fn warn_on_glob(&self) -> bool {
+        self.warn_on_glob
     }
 }
 
@@ -53,18 +1038,161 @@ where
 pub struct Switch<'a, T> {
     variable: Rc<RefCell<&'a mut T>>,
     target: Option<T>,
+    short_aliases: Vec<char>,
+    spelling: Option<&'a mut Option<String>>,
+    literals: Option<Converter<T>>,
 }
 
 impl<'a, T> CliOption for Switch<'a, T> {}
 
 impl<'a, T> Switch<'a, T> {
     /// Create a switch parameter.
+    ///
+    /// When the switch is present, the `variable` is set to the `target`, regardless of its initial value.
+    /// When absent, the `variable` keeps its initial value.
+    /// The `target` need not be the inverse of the initial value; for example, the flag `--enable-x` may set `disable_x` to `false`.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Switch};
+    ///
+    /// let mut disable_x: bool = true;
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(
+    ///         Switch::new(&mut disable_x, false),
+    ///         "enable-x",
+    ///         None,
+    ///     ))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["--enable-x"].as_slice()).unwrap();
+    ///
+    /// assert!(!disable_x);
+    /// ```
     pub fn new(variable: &'a mut T, target: T) -> Self {
         Self {
             variable: Rc::new(RefCell::new(variable)),
             target: Some(target),
+            short_aliases: Vec::default(),
+            spelling: None,
+            literals: None,
         }
     }
+
+    /// Allow the switch to also be set by each of the `shorts` (ex: both `-q` and `-s`).
+    /// If repeated, only the final aliases will apply.
+    ///
+    /// The aliases are in addition to the short specified on the option [`Parameter`](./struct.Parameter.html).
+    /// Each alias must not collide with the short of any other option.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Switch};
+    ///
+    /// let mut quiet: bool = false;
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(
+    ///         Switch::new(&mut quiet, true).short_aliases(&['q', 's']),
+    ///         "quiet",
+    ///         None,
+    ///     ))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["-s"].as_slice()).unwrap();
+    ///
+    /// assert!(quiet);
+    /// ```
+    pub fn short_aliases(mut self, shorts: &[char]) -> Self {
+        self.short_aliases = shorts.to_vec();
+        self
+    }
+
+    /// Record the flag spelling with which the switch was set into `spelling` (ex: `Some("-v")` or `Some("--verbose")`).
+    /// Remains untouched when the switch is not set.
+    ///
+    /// A short flag is recorded on its own, even when combined with other short flags (ex: `-qv` records `-v`).
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Switch};
+    ///
+    /// let mut verbose: bool = false;
+    /// let mut spelling: Option<String> = None;
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(
+    ///         Switch::new(&mut verbose, true).record_spelling(&mut spelling),
+    ///         "verbose",
+    ///         Some('v'),
+    ///     ))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["-v"].as_slice()).unwrap();
+    ///
+    /// assert!(verbose);
+    /// assert_eq!(spelling, Some("-v".to_string()));
+    /// ```
+    pub fn record_spelling(mut self, spelling: &'a mut Option<String>) -> Self {
+        self.spelling.replace(spelling);
+        self
+    }
+}
+
+impl<'a> Switch<'a, bool> {
+    /// Allow the switch to take an explicit value, which must be one of the `truthy` or `falsy` literals (compared case-insensitively).
+    /// If repeated, only the final literals will apply.
+    ///
+    /// When the switch is present without a value, the `variable` is set to the `target` as usual.
+    /// When given a value (ex: `--feature=disabled` or `--feature disabled`), the `variable` is set to `true` for a truthy literal and `false` for a falsy literal.
+    /// Any other value is rejected, listing the accepted literals.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Switch};
+    ///
+    /// let mut feature: bool = true;
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(
+    ///         Switch::new(&mut feature, true).bool_literals(&["enabled", "1"], &["disabled", "0"]),
+    ///         "feature",
+    ///         None,
+    ///     ))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["--feature=Disabled"].as_slice()).unwrap();
+    ///
+    /// assert!(!feature);
+    /// ```
+    pub fn bool_literals(mut self, truthy: &[&str], falsy: &[&str]) -> Self {
+        let truthy: Vec<String> = truthy.iter().map(|t| t.to_lowercase()).collect();
+        let falsy: Vec<String> = falsy.iter().map(|f| f.to_lowercase()).collect();
+        self.literals = Some(Converter::closure(move |token| {
+            let literal = token.to_lowercase();
+
+            if truthy.contains(&literal) {
+                Ok(true)
+            } else if falsy.contains(&literal) {
+                Ok(false)
+            } else {
+                Err(InvalidCapture::InvalidValue {
+                    token: token.to_string(),
+                    message: format!(
+                        "cannot convert '{token}' to bool: expected one of {}.",
+                        truthy
+                            .iter()
+                            .chain(falsy.iter())
+                            .map(|l| format!("'{l}'"))
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    ),
+                })
+            }
+        }));
+        self
+    }
 }
 
 impl<'a, T> GenericCapturable<'a, T> for Switch<'a, T> {
@@ -75,55 +1203,225 @@ impl<'a, T> GenericCapturable<'a, T> for Switch<'a, T> {
             .expect("internal error - must be able to take the Switch#target");
     }
 
+    fn spelled(&mut self, spelling: &str) {
+        if let Some(recorded) = &mut self.spelling {
+            recorded.replace(spelling.to_string());
+        }
+    }
+
+    fn capture(&mut self, token: &str) -> Result<(), InvalidCapture> {
+        match &self.literals {
+            Some(converter) => {
+                **self.variable.borrow_mut() = converter.convert(token)?;
+                Ok(())
+            }
+            None => unreachable!("internal error - must not capture on a Switch"),
+        }
+    }
+
+    fn nargs(&self) -> Nargs {
+        if self.literals.is_some() {
+            Nargs::ZeroOrOne
+        } else {
+            Nargs::Precisely(0)
+        }
+    }
+
+    fn short_aliases(&self) -> Vec<char> {
+        self.short_aliases.clone()
+    }
+}
+
+/// An option parameter that takes no values (precisely 0), and which cycles through its states each time it is repeated.
+pub struct Cycle<'a, T> {
+    variable: Rc<RefCell<&'a mut T>>,
+    states: Vec<T>,
+    occurrences: usize,
+}
+
+impl<'a, T> CliOption for Cycle<'a, T> {}
+
+impl<'a, T: Clone> Cycle<'a, T> {
+    /// Create a cycle parameter.
+    ///
+    /// Each occurrence of the option advances the `variable` to the next of the `states`, in order.
+    /// The first occurrence sets the first state, and the occurrence after the final state wraps around to the first state again.
+    /// When absent, the `variable` keeps its initial value.
+    /// The `states` must not be empty (otherwise, building the parser fails with a `ConfigError`).
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Cycle, Parameter};
+    ///
+    /// let mut mode: String = "off".to_string();
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(
+    ///         Cycle::new(&mut mode, &["on".to_string(), "auto".to_string(), "off".to_string()]),
+    ///         "mode",
+    ///         Some('m'),
+    ///     ))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["--mode", "-mm"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(&mode, "off");
+    /// ```
+    pub fn new(variable: &'a mut T, states: &[T]) -> Self {
+        Self {
+            variable: Rc::new(RefCell::new(variable)),
+            states: states.to_vec(),
+            occurrences: 0,
+        }
+    }
+}
+
+impl<'a, T: Clone> GenericCapturable<'a, T> for Cycle<'a, T> {
+    fn matched(&mut self) {
+        let state = self
+            .states
+            .get(self.occurrences % self.states.len().max(1))
+            .expect("internal error - the Cycle#states must not be empty");
+        **self.variable.borrow_mut() = state.clone();
+        self.occurrences += 1;
+    }
+
     fn capture(&mut self, _token: &str) -> Result<(), InvalidCapture> {
-        unreachable!("internal error - must not capture on a Switch");
+        unreachable!("internal error - must not capture on a Cycle");
     }
 
     fn nargs(&self) -> Nargs {
         Nargs::Precisely(0)
     }
+
+    fn repeatable(&self) -> bool {
+        true
+    }
+
+    fn misconfiguration(&self) -> Option<String> {
+        if self.states.is_empty() {
+            Some("cannot cycle: it must have at least one state.".to_string())
+        } else {
+            None
+        }
+    }
 }
 
 /// An option parameter that maps down to [`Option`], taking a single value (precisely 1).
 pub struct Optional<'a, T> {
     variable: Rc<RefCell<&'a mut Option<T>>>,
+    converter: Converter<T>,
+    prompt: bool,
 }
 
 impl<'a, T> CliOption for Optional<'a, T> {}
 
-impl<'a, T> Optional<'a, T> {
+impl<'a, T: FromStr> Optional<'a, T> {
     /// Create an optional parameter.
     pub fn new(variable: &'a mut Option<T>) -> Self {
         Self {
             variable: Rc::new(RefCell::new(variable)),
+            converter: Converter::Function(convert::<T>),
+            prompt: false,
         }
     }
 }
 
-impl<'a, T> GenericCapturable<'a, T> for Optional<'a, T>
-where
-    T: FromStr,
-{
+impl<'a, T> Optional<'a, T> {
+    /// Prompt for the value when the option is matched without one (ex: a bare `--password`).
+    /// The option then takes between 0 and 1 values.
+    ///
+    /// The prompt hides the response as it is typed, which is only supported on unix.
+    /// Without a terminal to prompt on (ex: piped input), or when the response cannot be hidden, the parse fails rather than prompting.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Optional, Parameter};
+    ///
+    /// let mut password: Option<String> = None;
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(
+    ///         Optional::new(&mut password).prompt_if_empty(),
+    ///         "password",
+    ///         None,
+    ///     ))
+    ///     .build();
+    ///
+    /// // Given a value, there's no prompt (whereas a bare `--password` prompts for it).
+    /// parser.parse_tokens(vec!["--password", "hunter2"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(password, Some("hunter2".to_string()));
+    /// ```
+    pub fn prompt_if_empty(mut self) -> Self {
+        self.prompt = true;
+        self
+    }
+}
+
+impl<'a> Optional<'a, PathBuf> {
+    /// Require the path to exist (as a file or directory).
+    /// When the option isn't matched, the path remains `None` without any check.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Optional, Parameter};
+    /// use std::path::PathBuf;
+    ///
+    /// let mut config: Option<PathBuf> = None;
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(Optional::new(&mut config).must_exist(), "config", None))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["--config", "."].as_slice()).unwrap();
+    ///
+    /// assert_eq!(config, Some(PathBuf::from(".")));
+    /// ```
+    pub fn must_exist(self) -> Self {
+        let converter = self.converter;
+        Self {
+            variable: self.variable,
+            converter: Converter::closure(move |token| {
+                check_exists(token, converter.convert(token)?)
+            }),
+            prompt: self.prompt,
+        }
+    }
+}
+
+impl<'a, T> GenericCapturable<'a, T> for Optional<'a, T> {
     fn matched(&mut self) {
         // Do nothing
     }
 
     fn capture(&mut self, token: &str) -> Result<(), InvalidCapture> {
-        let result: Result<T, InvalidCapture> =
-            T::from_str(token).map_err(|_| InvalidCapture::InvalidConversion {
-                token: token.to_string(),
-                type_name: std::any::type_name::<T>(),
-            });
-        let value = result?;
+        let value = self.converter.convert(token)?;
         self.variable.borrow_mut().replace(value);
         Ok(())
     }
 
     fn nargs(&self) -> Nargs {
-        Nargs::Precisely(1)
+        if self.prompt {
+            Nargs::Range(0, 1)
+        } else {
+            Nargs::Precisely(1)
+        }
+    }
+
+    fn prompt(&self) -> bool {
+        self.prompt
     }
 }
 
+/// How to split each value into the items of a [`Collection`].
+enum Split {
+    /// Split on the character (ex: `,`).
+    Delimiter(char),
+    /// Split on the platform's path list separator, as with the `PATH` environment variable.
+    Paths,
+}
+
 /// A parameter that takes multiple values (specifiable [`Nargs`]).
 pub struct Collection<'a, C, T>
 where
@@ -131,6 +1429,15 @@ where
 {
     variable: Rc<RefCell<&'a mut C>>,
     nargs: Nargs,
+    converter: Converter<T>,
+    split: Option<Split>,
+    from_file: bool,
+    duplicate: Option<Duplicate<T>>,
+    after_terminator: Option<Rc<RefCell<&'a mut C>>>,
+    terminated: bool,
+    rest: bool,
+    record: Option<(u8, RecordConverter<T>)>,
+    pending: Vec<String>,
     _phantom: PhantomData<T>,
 }
 
@@ -140,6 +1447,7 @@ impl<'a, C, T> CliArgument for Collection<'a, C, T> where C: 'a + Collectable<T>
 
 impl<'a, C, T> Collection<'a, C, T>
 where
+    T: FromStr,
     C: 'a + Collectable<T>,
 {
     /// Create a collection parameter.
@@ -147,39 +1455,536 @@ where
         Self {
             variable: Rc::new(RefCell::new(variable)),
             nargs,
+            converter: Converter::Function(convert::<T>),
+            split: None,
+            from_file: false,
+            duplicate: None,
+            after_terminator: None,
+            terminated: false,
+            rest: false,
+            record: None,
+            pending: Vec::default(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, C, T> Collection<'a, C, T>
+where
+    T: 'static + FromStr,
+    C: 'a + Collectable<T>,
+{
+    /// Create an argument collection parameter which is split in two by the `--` token (any number of values).
+    /// The values before `--` are collected into `before`, while those after are collected into `after`.
+    ///
+    /// After the `--`, all tokens are collected as values (even those which look like options).
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Collection, Parameter};
+    ///
+    /// let mut files: Vec<String> = Vec::default();
+    /// let mut extra: Vec<String> = Vec::default();
+    /// let parser = CommandLineParser::new("diff")
+    ///     .add(Parameter::argument(Collection::split(&mut files, &mut extra), "files"))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["a", "b", "--", "-c", "d"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(files, vec!["a".to_string(), "b".to_string()]);
+    /// assert_eq!(extra, vec!["-c".to_string(), "d".to_string()]);
+    /// ```
+    pub fn split(before: &'a mut C, after: &'a mut C) -> Self {
+        let mut collection = Self::new(before, Nargs::Any);
+        collection.after_terminator = Some(Rc::new(RefCell::new(after)));
+        collection
+    }
+}
+
+impl<'a, C, T> Collection<'a, C, T>
+where
+    T: 'static + Record,
+    C: 'a + Collectable<T>,
+{
+    /// Create an argument collection parameter of records, where each record is formed by a group of consecutive values (at least one record).
+    /// The group repeats, so the number of values must be a multiple of the record's arity (ex: `NAME AGE NAME AGE`).
+    ///
+    /// Records are tuples (of 2 to 4 elements), where each element is converted from its own value.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Collection, Parameter};
+    ///
+    /// let mut users: Vec<(String, u32)> = Vec::default();
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::argument(Collection::repeatable_group(&mut users), "user"))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["alice", "30", "bob", "40"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(users, vec![("alice".to_string(), 30), ("bob".to_string(), 40)]);
+    /// ```
+    pub fn repeatable_group(variable: &'a mut C) -> Self {
+        Self {
+            variable: Rc::new(RefCell::new(variable)),
+            nargs: Nargs::AtLeastOne,
+            converter: Converter::closure(|_| {
+                unreachable!("internal error - records must be converted from their group")
+            }),
+            split: None,
+            from_file: false,
+            duplicate: None,
+            after_terminator: None,
+            terminated: false,
+            rest: false,
+            record: Some((T::ARITY, Box::new(T::convert))),
+            pending: Vec::default(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, C, K, V> Collection<'a, C, (K, V)>
+where
+    K: 'static + FromStr,
+    V: 'static + FromStr,
+    C: 'a + Collectable<(K, V)>,
+{
+    /// Create a collection parameter of key-value pairs.
+    /// Each value is split on the first `delimiter` into its key `K` and value `V`.
+    ///
+    /// Combine with a `Vec<(K, V)>` to preserve the order and any duplicate keys.
+    /// Combine with a `HashMap<K, V>` and [`Collection::comma_separated`] to collect several pairs from a single value (ex: `env=prod,team=core`).
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Collection, Nargs, Parameter};
+    ///
+    /// let mut headers: Vec<(String, String)> = Vec::default();
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(
+    ///         Collection::pairs(&mut headers, Nargs::AtLeastOne, ':'),
+    ///         "header",
+    ///         None,
+    ///     ))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["--header", "Accept:json", "Accept:xml"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(
+    ///     headers,
+    ///     vec![
+    ///         ("Accept".to_string(), "json".to_string()),
+    ///         ("Accept".to_string(), "xml".to_string()),
+    ///     ]
+    /// );
+    /// ```
+    ///
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Collection, Nargs, Parameter};
+    /// use std::collections::HashMap;
+    ///
+    /// let mut labels: HashMap<String, String> = HashMap::default();
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(
+    ///         Collection::pairs(&mut labels, Nargs::Precisely(1), '=').comma_separated(),
+    ///         "labels",
+    ///         None,
+    ///     ))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["--labels", "env=prod,team=core"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(labels.get("env"), Some(&"prod".to_string()));
+    /// assert_eq!(labels.get("team"), Some(&"core".to_string()));
+    /// ```
+    pub fn pairs(variable: &'a mut C, nargs: Nargs, delimiter: char) -> Self {
+        Self {
+            variable: Rc::new(RefCell::new(variable)),
+            nargs,
+            converter: Converter::closure(move |token| match token.split_once(delimiter) {
+                Some((key, value)) => Ok((convert::<K>(key)?, convert::<V>(value)?)),
+                None => Err(InvalidCapture::InvalidValue {
+                    token: token.to_string(),
+                    message: format!("cannot split '{token}' into a pair on '{delimiter}'."),
+                }),
+            }),
+            split: None,
+            from_file: false,
+            duplicate: None,
+            after_terminator: None,
+            terminated: false,
+            rest: false,
+            record: None,
+            pending: Vec::default(),
             _phantom: PhantomData,
         }
     }
 }
 
+impl<'a, C> Collection<'a, C, PathBuf>
+where
+    C: 'a + Collectable<PathBuf>,
+{
+    /// Create a collection parameter of paths, where each value is split on the platform's path list separator (via [`std::env::split_paths`]).
+    /// The separator is `;` on Windows and `:` otherwise (ex: `/a:/b:/c`).
+    /// Empty entries (ex: from `/a::/b`) are skipped.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Collection, Nargs, Parameter};
+    /// use std::path::PathBuf;
+    ///
+    /// let mut paths: Vec<PathBuf> = Vec::default();
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(
+    ///         Collection::path_list(&mut paths, Nargs::Precisely(1)),
+    ///         "path",
+    ///         None,
+    ///     ))
+    ///     .build();
+    ///
+    /// # #[cfg(not(windows))]
+    /// parser.parse_tokens(vec!["--path", "/a:/b"].as_slice()).unwrap();
+    /// # #[cfg(windows)]
+    /// # parser.parse_tokens(vec!["--path", "/a;/b"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(paths, vec![PathBuf::from("/a"), PathBuf::from("/b")]);
+    /// ```
+    pub fn path_list(variable: &'a mut C, nargs: Nargs) -> Self {
+        Self {
+            variable: Rc::new(RefCell::new(variable)),
+            nargs,
+            converter: Converter::Function(convert::<PathBuf>),
+            split: Some(Split::Paths),
+            from_file: false,
+            duplicate: None,
+            after_terminator: None,
+            terminated: false,
+            rest: false,
+            record: None,
+            pending: Vec::default(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a> Collection<'a, String, ShellWord> {
+    /// Create an option collection parameter which takes the rest of the tokens as a shell command line (at least one value).
+    /// Once matched, all the subsequent tokens are taken as values (even those which look like options).
+    ///
+    /// The values are joined by a space, each quoted for a POSIX shell.
+    /// This preserves the original tokens when forwarding them through a child shell (ex: `sh -c "$command"`).
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Collection, Parameter, Switch};
+    ///
+    /// let mut verbose: bool = false;
+    /// let mut command: String = String::default();
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(Switch::new(&mut verbose, true), "verbose", Some('v')))
+    ///     .add(Parameter::option(
+    ///         Collection::shell_command(&mut command),
+    ///         "command",
+    ///         None,
+    ///     ))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["--command", "ls", "-v", "my dir"].as_slice()).unwrap();
+    ///
+    /// assert!(!verbose);
+    /// assert_eq!(&command, "ls -v 'my dir'");
+    /// ```
+    pub fn shell_command(variable: &'a mut String) -> Self {
+        Self {
+            variable: Rc::new(RefCell::new(variable)),
+            nargs: Nargs::AtLeastOne,
+            converter: Converter::Function(|token| Ok(ShellWord(shell_quote(token)))),
+            split: None,
+            from_file: false,
+            duplicate: None,
+            after_terminator: None,
+            terminated: false,
+            rest: true,
+            record: None,
+            pending: Vec::default(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<'a, C, T> Collection<'a, C, T>
+where
+    C: 'a + Collectable<T>,
+{
+    /// Interpret each value as the path to a file, whose lines are the items of the collection.
+    /// Blank lines are skipped.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Collection, Nargs, Parameter};
+    ///
+    /// let mut hosts: Vec<String> = Vec::default();
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(
+    ///         Collection::new(&mut hosts, Nargs::Precisely(1)).from_file(),
+    ///         "hosts-file",
+    ///         None,
+    ///     ))
+    ///     .build();
+    ///
+    /// // Where 'hosts.txt' contains a host per line.
+    /// parser.parse_tokens(vec!["--hosts-file", "hosts.txt"].as_slice()).unwrap();
+    /// ```
+    pub fn from_file(mut self) -> Self {
+        self.from_file = true;
+        self
+    }
+
+    /// Split each value on commas, so that the items may be separated by commas and/or spaces (ex: `a,b c`).
+    /// The items are collected in the order they appear.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Collection, Nargs, Parameter};
+    ///
+    /// let mut tags: Vec<String> = Vec::default();
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(
+    ///         Collection::new(&mut tags, Nargs::AtLeastOne).comma_separated(),
+    ///         "tags",
+    ///         None,
+    ///     ))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["--tags", "a,b", "c"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(tags, vec!["a".to_string(), "b".to_string(), "c".to_string()]);
+    /// ```
+    pub fn comma_separated(mut self) -> Self {
+        self.split = Some(Split::Delimiter(','));
+        self
+    }
+
+    fn collect(&mut self, token: &str) -> Result<(), InvalidCapture> {
+        let items: Vec<String> = match self.split {
+            Some(Split::Delimiter(delimiter)) => token.split(delimiter).map(String::from).collect(),
+            // Skip the empty entries (ex: from `a::b`), rather than collecting empty paths.
+            Some(Split::Paths) => std::env::split_paths(token)
+                .filter(|path| !path.as_os_str().is_empty())
+                .map(|path| path.to_string_lossy().into_owned())
+                .collect(),
+            None => vec![token.to_string()],
+        };
+
+        for item in items {
+            let value = self.converter.convert(&item)?;
+            self.add(&item, value)?;
+        }
+
+        Ok(())
+    }
+
+    fn add(&mut self, item: &str, value: T) -> Result<(), InvalidCapture> {
+        if let Some(duplicate) = &mut self.duplicate {
+            if duplicate(&value) {
+                return Err(InvalidCapture::InvalidValue {
+                    token: item.to_string(),
+                    message: format!("duplicate value '{item}'."),
+                });
+            }
+        }
+
+        let variable = match &self.after_terminator {
+            Some(after) if self.terminated => after,
+            _ => &self.variable,
+        };
+
+        (**variable.borrow_mut())
+            .add(value)
+            .map_err(|message| InvalidCapture::InvalidAdd {
+                token: item.to_string(),
+                message,
+            })
+    }
+}
+
+impl<'a, C, T> Collection<'a, C, T>
+where
+    T: 'static + PartialEq + Clone,
+    C: 'a + Collectable<T>,
+{
+    /// Reject a value which equals a previously collected value, rather than adding it to the collection.
+    ///
+    /// Unlike a `HashSet`, this applies to any collection, and compares the converted values (ex: `1` and `01` are duplicates for a `u32`).
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Collection, Nargs, Parameter};
+    ///
+    /// let mut tags: Vec<String> = Vec::default();
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(
+    ///         Collection::new(&mut tags, Nargs::AtLeastOne).reject_duplicates(),
+    ///         "tags",
+    ///         None,
+    ///     ))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["--tags", "x", "y"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(tags, vec!["x".to_string(), "y".to_string()]);
+    /// ```
+    pub fn reject_duplicates(mut self) -> Self {
+        let mut collected: Vec<T> = Vec::default();
+        self.duplicate = Some(Box::new(move |value| {
+            if collected.contains(value) {
+                true
+            } else {
+                collected.push(value.clone());
+                false
+            }
+        }));
+        self
+    }
+}
+
+impl<'a, C, T> Collection<'a, C, T>
+where
+    T: 'static + std::fmt::Display,
+    C: 'a + Collectable<T>,
+{
+    /// Require each value to be one of the `choices`, as rendered by its [`std::fmt::Display`].
+    /// An unknown value is rejected with an error listing the `choices`.
+    ///
+    /// Combine with a `HashSet<T>` to collect the set of enabled choices (ex: feature names).
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Collection, Nargs, Parameter};
+    /// use std::collections::HashSet;
+    ///
+    /// let mut features: HashSet<String> = HashSet::default();
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(
+    ///         Collection::new(&mut features, Nargs::AtLeastOne)
+    ///             .one_of(vec!["tls".to_string(), "zstd".to_string()]),
+    ///         "enable",
+    ///         None,
+    ///     ))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["--enable", "tls", "zstd"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(features, HashSet::from(["tls".to_string(), "zstd".to_string()]));
+    /// ```
+    pub fn one_of(mut self, choices: Vec<T>) -> Self {
+        let choices: Vec<String> = choices.iter().map(|choice| choice.to_string()).collect();
+        let converter = self.converter;
+        self.converter = Converter::closure(move |token| {
+            if choices.iter().any(|choice| choice == token) {
+                converter.convert(token)
+            } else {
+                Err(InvalidCapture::InvalidValue {
+                    token: token.to_string(),
+                    message: format!(
+                        "'{token}' is not one of {}.",
+                        choices
+                            .iter()
+                            .map(|choice| format!("'{choice}'"))
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    ),
+                })
+            }
+        });
+        self
+    }
+}
+
 impl<'a, C, T> GenericCapturable<'a, T> for Collection<'a, C, T>
 where
-    T: FromStr,
     C: 'a + Collectable<T>,
 {
     fn matched(&mut self) {
-        // Do nothing.
+        (**self.variable.borrow_mut()).matched();
     }
 
     fn capture(&mut self, token: &str) -> Result<(), InvalidCapture> {
-        let result: Result<T, InvalidCapture> =
-            T::from_str(token).map_err(|_| InvalidCapture::InvalidConversion {
-                token: token.to_string(),
-                type_name: std::any::type_name::<T>(),
-            });
-        let value = result?;
-        (**self.variable.borrow_mut())
-            .add(value)
-            .map_err(|message| InvalidCapture::InvalidAdd {
+        if let Some(after) = &self.after_terminator {
+            if !self.terminated && token == TERMINATOR {
+                self.terminated = true;
+                (**after.borrow_mut()).matched();
+                return Ok(());
+            }
+        }
+
+        if let Some((arity, converter)) = &self.record {
+            self.pending.push(token.to_string());
+
+            if self.pending.len() < *arity as usize {
+                return Ok(());
+            }
+
+            let value = converter(&self.pending)?;
+            self.pending.clear();
+            return self.add(token, value);
+        }
+
+        if !self.from_file {
+            return self.collect(token);
+        }
+
+        let content =
+            std::fs::read_to_string(token).map_err(|error| InvalidCapture::InvalidValue {
                 token: token.to_string(),
-                message,
+                message: format!("cannot read file '{token}': {error}."),
             })?;
+
+        for (index, line) in content.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            self.collect(line)
+                .map_err(|error| InvalidCapture::InvalidValue {
+                    token: line.to_string(),
+                    message: format!("line {n} of '{token}': {error}", n = index + 1),
+                })?;
+        }
+
         Ok(())
     }
 
     fn nargs(&self) -> Nargs {
         self.nargs
     }
+
+    fn terminator(&self) -> bool {
+        self.after_terminator.is_some()
+    }
+
+    fn rest(&self) -> bool {
+        self.rest
+    }
+
+    fn group(&self) -> u8 {
+        match &self.record {
+            Some((arity, _)) => *arity,
+            None => 1,
+        }
+    }
 }
 
 impl<T> Collectable<T> for Vec<T> {
@@ -189,19 +1994,75 @@ impl<T> Collectable<T> for Vec<T> {
     }
 }
 
-impl<T: Eq + std::hash::Hash> Collectable<T> for HashSet<T> {
+/// A queue, where each item is pushed to the back (ex: `a b c` collects `[a, b, c]`).
+impl<T> Collectable<T> for VecDeque<T> {
+    fn add(&mut self, item: T) -> Result<(), String> {
+        self.push_back(item);
+        Ok(())
+    }
+}
+
+impl<T: Eq + std::hash::Hash> Collectable<T> for HashSet<T> {
+    fn add(&mut self, item: T) -> Result<(), String> {
+        if self.insert(item) {
+            Ok(())
+        } else {
+            Err("set already contains item".to_string())
+        }
+    }
+}
+
+impl<T: Ord> Collectable<T> for BTreeSet<T> {
+    fn add(&mut self, item: T) -> Result<(), String> {
+        if self.insert(item) {
+            Ok(())
+        } else {
+            Err("set already contains item".to_string())
+        }
+    }
+}
+
+impl<K: Eq + std::hash::Hash, V> Collectable<(K, V)> for HashMap<K, V> {
+    fn add(&mut self, (key, value): (K, V)) -> Result<(), String> {
+        match self.entry(key) {
+            std::collections::hash_map::Entry::Occupied(_) => {
+                Err("map already contains key".to_string())
+            }
+            std::collections::hash_map::Entry::Vacant(entry) => {
+                entry.insert(value);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// A shell command line, where each word is joined on by a space (ex: `echo 'a b'`).
+impl Collectable<ShellWord> for String {
+    fn add(&mut self, item: ShellWord) -> Result<(), String> {
+        if !self.is_empty() {
+            self.push(' ');
+        }
+
+        self.push_str(&item.0);
+        Ok(())
+    }
+}
+
+/// An optional collection, which is `None` until matched (ex: `Option<Vec<T>>`).
+impl<T, C: Collectable<T> + Default> Collectable<T> for Option<C> {
     fn add(&mut self, item: T) -> Result<(), String> {
-        if self.insert(item) {
-            Ok(())
-        } else {
-            Err("set already contains item".to_string())
-        }
+        self.get_or_insert_with(C::default).add(item)
+    }
+
+    fn matched(&mut self) {
+        self.get_or_insert_with(C::default).matched();
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rstest::rstest;
 
     #[test]
     fn vec() {
@@ -221,6 +2082,56 @@ mod tests {
         assert_eq!(message, "set already contains item".to_string());
     }
 
+    #[test]
+    fn vec_deque() {
+        let mut collection: VecDeque<u32> = VecDeque::default();
+        collection.add(1).unwrap();
+        collection.add(0).unwrap();
+        collection.add(1).unwrap();
+        assert_eq!(collection, VecDeque::from([1, 0, 1]));
+    }
+
+    #[test]
+    fn btree_set() {
+        let mut collection: BTreeSet<u32> = BTreeSet::default();
+        collection.add(1).unwrap();
+        collection.add(0).unwrap();
+        let message = collection.add(1).unwrap_err();
+        assert_eq!(collection.into_iter().collect::<Vec<u32>>(), vec![0, 1]);
+        assert_eq!(message, "set already contains item".to_string());
+    }
+
+    #[rstest]
+    #[case(vec![], "")]
+    #[case(vec!["echo"], "echo")]
+    #[case(vec!["ls", "-la", "/tmp/a.txt"], "ls -la /tmp/a.txt")]
+    #[case(vec!["echo", "hello world"], "echo 'hello world'")]
+    #[case(vec!["echo", ""], "echo ''")]
+    #[case(vec!["echo", "it's"], "echo 'it'\\''s'")]
+    #[case(vec!["echo", "$HOME", "*.txt"], "echo '$HOME' '*.txt'")]
+    fn shell_command(#[case] items: Vec<&str>, #[case] expected: &str) {
+        // Setup
+        let mut collection = String::default();
+
+        // Execute
+        for item in items {
+            collection.add(ShellWord(shell_quote(item))).unwrap();
+        }
+
+        // Verify
+        assert_eq!(collection, expected);
+    }
+
+    #[test]
+    fn option_vec() {
+        let mut collection: Option<Vec<u32>> = None;
+        collection.matched();
+        assert_eq!(collection, Some(vec![]));
+        collection.add(1).unwrap();
+        collection.add(0).unwrap();
+        assert_eq!(collection, Some(vec![1, 0]));
+    }
+
     #[test]
     fn value_capture() {
         // Integer
@@ -236,6 +2147,526 @@ mod tests {
         assert!(variable);
     }
 
+    #[test]
+    fn capture_non_static() {
+        // A type which borrows from its context, so it isn't 'static.
+        #[derive(Debug, PartialEq)]
+        struct Borrowing<'t>(u32, PhantomData<&'t ()>);
+
+        impl<'t> FromStr for Borrowing<'t> {
+            type Err = std::num::ParseIntError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                Ok(Borrowing(u32::from_str(s)?, PhantomData))
+            }
+        }
+
+        fn capture<'t>(_context: &'t str) {
+            let mut variable: Borrowing<'t> = Borrowing(0, PhantomData);
+            Scalar::new(&mut variable).capture("5").unwrap();
+            assert_eq!(variable, Borrowing(5, PhantomData));
+
+            let mut variable: Option<Borrowing<'t>> = None;
+            Optional::new(&mut variable).capture("5").unwrap();
+            assert_eq!(variable, Some(Borrowing(5, PhantomData)));
+
+            let mut variable: Vec<Borrowing<'t>> = Vec::default();
+            Collection::new(&mut variable, Nargs::Any)
+                .capture("5")
+                .unwrap();
+            assert_eq!(variable, vec![Borrowing(5, PhantomData)]);
+        }
+
+        capture(&String::from("context"));
+    }
+
+    #[rstest]
+    #[case(false)]
+    #[case(true)]
+    fn value_warn_on_glob(#[case] warn: bool) {
+        // Setup
+        let mut variable: String = String::default();
+        let mut value = Scalar::new(&mut variable);
+        if warn {
+            value = value.warn_on_glob();
+        }
+
+        // Execute
+        let actual = GenericCapturable::warn_on_glob(&value);
+        value.capture("*.txt").unwrap();
+
+        // Verify
+        assert_eq!(actual, warn);
+        assert_eq!(&variable, "*.txt");
+    }
+
+    #[rstest]
+    #[case("1_000", 1000)]
+    #[case("0xff", 255)]
+    #[case("0XFF", 255)]
+    #[case("0o17", 15)]
+    #[case("0b1010", 10)]
+    #[case("-0b1010", -10)]
+    #[case("+42", 42)]
+    #[case("-1_000_000", -1000000)]
+    fn value_capture_integer(#[case] token: &str, #[case] expected: i64) {
+        let mut variable: i64 = i64::default();
+        let mut value = Scalar::integer(&mut variable);
+        value.capture(token).unwrap();
+        assert_eq!(variable, expected);
+    }
+
+    #[rstest]
+    #[case("0xfg")]
+    #[case("0b102")]
+    #[case("0x")]
+    #[case("0x-1")]
+    #[case("-1")]
+    #[case("1.0")]
+    fn value_capture_integer_invalid(#[case] token: &str) {
+        let mut variable: u8 = u8::default();
+        let mut value = Scalar::integer(&mut variable);
+        let error = value.capture(token).unwrap_err();
+        assert_eq!(
+            error,
+            InvalidCapture::InvalidConversion {
+                token: token.to_string(),
+                type_name: "u8",
+            }
+        );
+    }
+
+    #[rstest]
+    #[case("2,5", true, 2.5)]
+    #[case("2.5", true, 2.5)]
+    #[case("-0,25", true, -0.25)]
+    #[case("2.5", false, 2.5)]
+    fn value_capture_decimal(
+        #[case] token: &str,
+        #[case] locale_comma: bool,
+        #[case] expected: f64,
+    ) {
+        let mut variable: f64 = f64::default();
+        let mut value = Scalar::decimal(&mut variable, locale_comma);
+        value.capture(token).unwrap();
+        assert_eq!(variable, expected);
+    }
+
+    #[rstest]
+    #[case("3,14", false)]
+    #[case("1,000,5", true)]
+    #[case("abc", true)]
+    fn value_capture_decimal_invalid(#[case] token: &str, #[case] locale_comma: bool) {
+        let mut variable: f64 = f64::default();
+        let mut value = Scalar::decimal(&mut variable, locale_comma);
+        let error = value.capture(token).unwrap_err();
+        assert_eq!(
+            error,
+            InvalidCapture::InvalidConversion {
+                token: token.to_string(),
+                type_name: "f64",
+            }
+        );
+    }
+
+    #[rstest]
+    #[case("50%", 0.5)]
+    #[case("0.5", 0.5)]
+    #[case("100%", 1.0)]
+    #[case("0%", 0.0)]
+    #[case("1", 1.0)]
+    #[case("12.5%", 0.125)]
+    fn value_capture_percentage(#[case] token: &str, #[case] expected: f64) {
+        let mut variable: f64 = f64::default();
+        let mut value = Scalar::percentage(&mut variable);
+        value.capture(token).unwrap();
+        assert_eq!(variable, expected);
+    }
+
+    #[rstest]
+    #[case(
+        "150%",
+        "'150%' is out of range for a percentage (expected 0% to 100%, or 0.0 to 1.0)."
+    )]
+    #[case(
+        "1.5",
+        "'1.5' is out of range for a percentage (expected 0% to 100%, or 0.0 to 1.0)."
+    )]
+    #[case(
+        "-1%",
+        "'-1%' is out of range for a percentage (expected 0% to 100%, or 0.0 to 1.0)."
+    )]
+    #[case(
+        "NaN",
+        "'NaN' is out of range for a percentage (expected 0% to 100%, or 0.0 to 1.0)."
+    )]
+    #[case("half", "cannot convert 'half' to a percentage (ex: '50%', '0.5').")]
+    #[case("%", "cannot convert '%' to a percentage (ex: '50%', '0.5').")]
+    #[case("50%%", "cannot convert '50%%' to a percentage (ex: '50%', '0.5').")]
+    fn value_capture_percentage_invalid(#[case] token: &str, #[case] expected: &str) {
+        let mut variable: f64 = f64::default();
+        let mut value = Scalar::percentage(&mut variable);
+        let error = value.capture(token).unwrap_err();
+        assert_eq!(
+            error,
+            InvalidCapture::InvalidValue {
+                token: token.to_string(),
+                message: expected.to_string(),
+            }
+        );
+    }
+
+    #[rstest]
+    #[case("+1h", 3_600)]
+    #[case("-30m", -1_800)]
+    #[case("45s", 45)]
+    #[case("1h30m", 5_400)]
+    #[case("-1d2h3m4s", -93_784)]
+    #[case("0m", 0)]
+    fn value_capture_signed_duration(#[case] token: &str, #[case] expected: i64) {
+        let mut variable: i64 = i64::default();
+        let mut value = Scalar::signed_duration(&mut variable);
+        value.capture(token).unwrap();
+        assert_eq!(variable, expected);
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("-")]
+    #[case("30")]
+    #[case("m")]
+    #[case("1x")]
+    #[case("1h30")]
+    #[case("+-1h")]
+    #[case("1h-30m")]
+    #[case("9999999999999999999s")]
+    fn value_capture_signed_duration_invalid(#[case] token: &str) {
+        let mut variable: i64 = i64::default();
+        let mut value = Scalar::signed_duration(&mut variable);
+        let error = value.capture(token).unwrap_err();
+        assert_eq!(
+            error,
+            InvalidCapture::InvalidValue {
+                token: token.to_string(),
+                message: format!(
+                    "cannot convert '{token}' to a signed duration (ex: '+1h', '-30m')."
+                ),
+            }
+        );
+    }
+
+    #[rstest]
+    #[case("127.0.0.1:8080")]
+    #[case("0.0.0.0:0")]
+    #[case("[::1]:8080")]
+    fn value_capture_socket_addr(#[case] token: &str) {
+        let mut variable: SocketAddr = "1.1.1.1:1".parse().unwrap();
+        let mut value = Scalar::socket_addr(&mut variable);
+        value.capture(token).unwrap();
+        assert_eq!(variable, token.parse::<SocketAddr>().unwrap());
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("127.0.0.1")]
+    #[case("localhost:8080")]
+    #[case("127.0.0.1:99999")]
+    #[case("::1:8080")]
+    fn value_capture_socket_addr_invalid(#[case] token: &str) {
+        let mut variable: SocketAddr = "1.1.1.1:1".parse().unwrap();
+        let mut value = Scalar::socket_addr(&mut variable);
+        let error = value.capture(token).unwrap_err();
+        assert_eq!(
+            error,
+            InvalidCapture::InvalidValue {
+                token: token.to_string(),
+                message: format!(
+                    "'{token}' is not a valid host:port (ex: '127.0.0.1:8080', '[::1]:8080')."
+                ),
+            }
+        );
+    }
+
+    #[rstest]
+    #[case("", vec![])]
+    #[case("00", vec![0])]
+    #[case("deadbeef", vec![0xde, 0xad, 0xbe, 0xef])]
+    #[case("DEADbeef", vec![0xde, 0xad, 0xbe, 0xef])]
+    fn value_capture_hex_bytes(#[case] token: &str, #[case] expected: Vec<u8>) {
+        let mut variable: Vec<u8> = vec![1];
+        let mut value = Scalar::hex_bytes(&mut variable);
+        value.capture(token).unwrap();
+        assert_eq!(variable, expected);
+    }
+
+    #[rstest]
+    #[case("abc", "odd number of digits")]
+    #[case("0", "odd number of digits")]
+    #[case("0g", "'g' is not a hex digit")]
+    #[case("0xff", "'x' is not a hex digit")]
+    #[case("dé", "'é' is not a hex digit")]
+    fn value_capture_hex_bytes_invalid(#[case] token: &str, #[case] reason: &str) {
+        let mut variable: Vec<u8> = Vec::default();
+        let mut value = Scalar::hex_bytes(&mut variable);
+        let error = value.capture(token).unwrap_err();
+        assert_eq!(
+            error,
+            InvalidCapture::InvalidValue {
+                token: token.to_string(),
+                message: format!("cannot convert '{token}' to hex bytes: {reason}."),
+            }
+        );
+    }
+
+    #[rstest]
+    #[case("", b"")]
+    #[case("aGVsbG8=", b"hello")]
+    #[case("aGVsbG8", b"hello")]
+    #[case("aGk=", b"hi")]
+    #[case("aA==", b"h")]
+    #[case("aGVsbG8h", b"hello!")]
+    #[case("+/8=", &[0xfb, 0xff])]
+    fn value_capture_base64_bytes(#[case] token: &str, #[case] expected: &[u8]) {
+        let mut variable: Vec<u8> = vec![1];
+        let mut value = Scalar::base64_bytes(&mut variable);
+        value.capture(token).unwrap();
+        assert_eq!(variable, expected);
+    }
+
+    #[rstest]
+    #[case("aGVsbG8*", "'*' is not a base64 character")]
+    #[case("aGVs bG8", "' ' is not a base64 character")]
+    #[case("a", "invalid length")]
+    #[case("aGVsb", "invalid length")]
+    #[case("aGVsbG8==", "invalid length")]
+    #[case("aA===", "invalid length")]
+    fn value_capture_base64_bytes_invalid(#[case] token: &str, #[case] reason: &str) {
+        let mut variable: Vec<u8> = Vec::default();
+        let mut value = Scalar::base64_bytes(&mut variable);
+        let error = value.capture(token).unwrap_err();
+        assert_eq!(
+            error,
+            InvalidCapture::InvalidValue {
+                token: token.to_string(),
+                message: format!("cannot convert '{token}' to base64 bytes: {reason}."),
+            }
+        );
+    }
+
+    #[test]
+    fn value_capture_lazy() {
+        let conversions = Rc::new(Cell::new(0));
+        let counter = conversions.clone();
+        let mut variable: Lazy<u32> = Lazy::with_converter(move |token| {
+            counter.set(counter.get() + 1);
+            token.parse::<u32>().map_err(|error| error.to_string())
+        });
+        assert_eq!(variable.get(), Ok(None));
+        let mut value = Scalar::lazy(&mut variable);
+        value.capture("5").unwrap();
+        assert_eq!(conversions.get(), 0);
+        assert_eq!(variable.token(), Some("5"));
+        assert_eq!(variable.get(), Ok(Some(&5)));
+        assert_eq!(conversions.get(), 1);
+        assert_eq!(variable.get(), Ok(Some(&5)));
+        assert_eq!(conversions.get(), 1);
+    }
+
+    #[test]
+    fn value_capture_lazy_invalid() {
+        let mut variable: Lazy<u32> = Lazy::new();
+        let mut value = Scalar::lazy(&mut variable);
+        value.capture("x").unwrap();
+        assert_eq!(
+            variable.get(),
+            Err("cannot convert 'x' to u32.".to_string())
+        );
+    }
+
+    #[test]
+    fn value_capture_lazy_converter_invalid() {
+        let mut variable: Lazy<u32> =
+            Lazy::with_converter(|token| token.parse::<u32>().map_err(|error| error.to_string()));
+        let mut value = Scalar::lazy(&mut variable);
+        value.capture("-1").unwrap();
+        assert_eq!(
+            variable.get(),
+            Err("cannot convert '-1' to u32: invalid digit found in string.".to_string())
+        );
+    }
+
+    #[rstest]
+    #[case("3,4", (3, 4))]
+    #[case("-3,0", (-3, 0))]
+    fn value_capture_tuple2(#[case] token: &str, #[case] expected: (i32, i32)) {
+        let mut variable: (i32, i32) = (1, 1);
+        let mut value = Scalar::tuple2(&mut variable, ',');
+        value.capture(token).unwrap();
+        assert_eq!(variable, expected);
+    }
+
+    #[rstest]
+    #[case("3", 1)]
+    #[case("3,4,5", 3)]
+    fn value_capture_tuple2_arity(#[case] token: &str, #[case] found: usize) {
+        let mut variable: (i32, i32) = (1, 1);
+        let mut value = Scalar::tuple2(&mut variable, ',');
+        let error = value.capture(token).unwrap_err();
+        assert_eq!(
+            error,
+            InvalidCapture::InvalidValue {
+                token: token.to_string(),
+                message: format!("cannot split '{token}' into 2 parts on ',' (found {found})."),
+            }
+        );
+    }
+
+    #[test]
+    fn value_capture_tuple2_invalid() {
+        let mut variable: (i32, i32) = (1, 1);
+        let mut value = Scalar::tuple2(&mut variable, ',');
+        let error = value.capture("3,x").unwrap_err();
+        assert_eq!(
+            error,
+            InvalidCapture::InvalidConversion {
+                token: "x".to_string(),
+                type_name: "i32",
+            }
+        );
+    }
+
+    #[rstest]
+    #[case("127.0.0.1")]
+    #[case("::1")]
+    #[case("fe80::1")]
+    fn value_capture_ip_addr(#[case] token: &str) {
+        let mut variable: IpAddr = "1.1.1.1".parse().unwrap();
+        let mut value = Scalar::ip_addr(&mut variable);
+        value.capture(token).unwrap();
+        assert_eq!(variable, token.parse::<IpAddr>().unwrap());
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("localhost")]
+    #[case("256.0.0.1")]
+    #[case("127.0.0.1:8080")]
+    fn value_capture_ip_addr_invalid(#[case] token: &str) {
+        let mut variable: IpAddr = "1.1.1.1".parse().unwrap();
+        let mut value = Scalar::ip_addr(&mut variable);
+        let error = value.capture(token).unwrap_err();
+        assert_eq!(
+            error,
+            InvalidCapture::InvalidValue {
+                token: token.to_string(),
+                message: format!("'{token}' is not a valid IP address (ex: '127.0.0.1', '::1')."),
+            }
+        );
+    }
+
+    #[rstest]
+    #[case(vec!["start"], ("start", None))]
+    #[case(vec!["start", "8080"], ("start", Some("8080")))]
+    #[case(vec!["stop", "now"], ("stop", Some("now")))]
+    fn value_capture_keyword_with_arg(
+        #[case] tokens: Vec<&str>,
+        #[case] expected: (&str, Option<&str>),
+    ) {
+        let mut variable: (String, Option<String>) = (String::default(), None);
+        let mut value = Scalar::keyword_with_arg(&mut variable, &["start", "stop"]);
+        assert_eq!(value.nargs(), Nargs::Range(1, 2));
+        for token in tokens {
+            value.capture(token).unwrap();
+        }
+        drop(value);
+        assert_eq!(
+            variable,
+            (expected.0.to_string(), expected.1.map(|s| s.to_string()))
+        );
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("8080")]
+    #[case("START")]
+    fn value_capture_keyword_with_arg_invalid(#[case] token: &str) {
+        let mut variable: (String, Option<String>) = (String::default(), None);
+        let mut value = Scalar::keyword_with_arg(&mut variable, &["start", "stop"]);
+        let error = value.capture(token).unwrap_err();
+        assert_eq!(
+            error,
+            InvalidCapture::InvalidValue {
+                token: token.to_string(),
+                message: format!("cannot convert '{token}' to a keyword (ex: 'start', 'stop')."),
+            }
+        );
+    }
+
+    #[rstest]
+    #[case("", "", true)]
+    #[case("", "a", false)]
+    #[case("a", "a", true)]
+    #[case("a", "b", false)]
+    #[case("*", "", true)]
+    #[case("*", "abc", true)]
+    #[case("a*", "a", true)]
+    #[case("a*", "abc", true)]
+    #[case("a*", "ba", false)]
+    #[case("*c", "abc", true)]
+    #[case("*c", "abcd", false)]
+    #[case("a*c*e", "abcde", true)]
+    #[case("a*c*e", "acce", true)]
+    #[case("a*c*e", "abde", false)]
+    #[case("?", "", false)]
+    #[case("?", "a", true)]
+    #[case("?", "ab", false)]
+    #[case("a?c", "abc", true)]
+    #[case("a?c", "ac", false)]
+    #[case("?*", "", false)]
+    #[case("*?", "a", true)]
+    #[case("**", "abc", true)]
+    #[case("a*b?", "aXbYbZ", true)]
+    fn pattern_wildcards(#[case] pattern: &str, #[case] value: &str, #[case] expected: bool) {
+        assert_eq!(matches_pattern(pattern, value), expected);
+    }
+
+    #[rstest]
+    #[case("10", 10)]
+    #[case("19", 19)]
+    #[case("010", 10)]
+    fn value_capture_pattern(#[case] token: &str, #[case] expected: u32) {
+        let mut variable: u32 = 0;
+        let mut value = Scalar::new(&mut variable).pattern("1?");
+        value.capture(token).unwrap();
+        drop(value);
+        assert_eq!(variable, expected);
+    }
+
+    #[rstest]
+    #[case("1", "1")]
+    #[case("100", "100")]
+    #[case("01", "1")]
+    fn value_capture_pattern_invalid(#[case] token: &str, #[case] rendered: &str) {
+        let mut variable: u32 = 0;
+        let mut value = Scalar::new(&mut variable).pattern("1?");
+        let error = value.capture(token).unwrap_err();
+        assert_eq!(
+            error,
+            InvalidCapture::InvalidValue {
+                token: token.to_string(),
+                message: format!("'{rendered}' does not match pattern '1?'."),
+            }
+        );
+    }
+
+    #[test]
+    fn value_capture_pattern_inconvertable() {
+        let mut variable: u32 = 0;
+        let mut value = Scalar::new(&mut variable).pattern("1?");
+        let error = value.capture("1x").unwrap_err();
+        assert_matches!(error, InvalidCapture::InvalidConversion { .. });
+    }
+
     #[test]
     #[should_panic]
     fn switch_capture() {
@@ -247,6 +2678,42 @@ mod tests {
         };
     }
 
+    #[rstest]
+    #[case("enabled", true)]
+    #[case("ENABLED", true)]
+    #[case("1", true)]
+    #[case("Disabled", false)]
+    #[case("0", false)]
+    fn switch_bool_literals(#[case] token: &str, #[case] expected: bool) {
+        let mut variable: bool = !expected;
+        let mut switch =
+            Switch::new(&mut variable, true).bool_literals(&["Enabled", "1"], &["disabled", "0"]);
+        assert_eq!(switch.nargs(), Nargs::ZeroOrOne);
+        switch.matched();
+        switch.capture(token).unwrap();
+        assert_eq!(variable, expected);
+    }
+
+    #[rstest]
+    #[case("true")]
+    #[case("on")]
+    #[case("")]
+    fn switch_bool_literals_unknown(#[case] token: &str) {
+        let mut variable: bool = false;
+        let mut switch =
+            Switch::new(&mut variable, true).bool_literals(&["enabled", "1"], &["disabled", "0"]);
+        let error = switch.capture(token).unwrap_err();
+        assert_eq!(
+            error,
+            InvalidCapture::InvalidValue {
+                token: token.to_string(),
+                message: format!(
+                    "cannot convert '{token}' to bool: expected one of 'enabled', '1', 'disabled', '0'."
+                ),
+            }
+        );
+    }
+
     #[test]
     fn optional_capture() {
         // Option<u32>
@@ -256,6 +2723,26 @@ mod tests {
         assert_eq!(variable, Some(1));
     }
 
+    #[rstest]
+    #[case(false, Nargs::Precisely(1))]
+    #[case(true, Nargs::Range(0, 1))]
+    fn optional_prompt_if_empty(#[case] prompt: bool, #[case] expected: Nargs) {
+        // Setup
+        let mut variable: Option<u32> = None;
+        let mut optional = Optional::new(&mut variable);
+        if prompt {
+            optional = optional.prompt_if_empty();
+        }
+
+        // Execute
+        let nargs = GenericCapturable::nargs(&optional);
+        let actual_prompt = GenericCapturable::prompt(&optional);
+
+        // Verify
+        assert_eq!(nargs, expected);
+        assert_eq!(actual_prompt, prompt);
+    }
+
     #[test]
     fn collection_capture() {
         // Vec<u32>
@@ -278,6 +2765,438 @@ mod tests {
         });
     }
 
+    #[rstest]
+    #[case(vec![], vec![], vec![])]
+    #[case(vec!["1", "2"], vec![1, 2], vec![])]
+    #[case(vec!["1", "--", "2"], vec![1], vec![2])]
+    #[case(vec!["--", "1", "2"], vec![], vec![1, 2])]
+    fn collection_split_capture(
+        #[case] tokens: Vec<&str>,
+        #[case] expected_before: Vec<u32>,
+        #[case] expected_after: Vec<u32>,
+    ) {
+        let mut before: Vec<u32> = Vec::default();
+        let mut after: Vec<u32> = Vec::default();
+        let mut collection = Collection::split(&mut before, &mut after);
+        assert!(collection.terminator());
+        assert_eq!(collection.nargs(), Nargs::Any);
+
+        for token in tokens {
+            collection.capture(token).unwrap();
+        }
+
+        drop(collection);
+        assert_eq!(before, expected_before);
+        assert_eq!(after, expected_after);
+    }
+
+    #[test]
+    fn collection_shell_command_capture() {
+        let mut variable: String = String::default();
+        let mut collection = Collection::shell_command(&mut variable);
+        assert!(collection.rest());
+        assert!(!collection.terminator());
+        assert_eq!(collection.nargs(), Nargs::AtLeastOne);
+
+        for token in ["ls", "-la", "my dir"] {
+            collection.capture(token).unwrap();
+        }
+
+        drop(collection);
+        assert_eq!(variable, "ls -la 'my dir'");
+    }
+
+    #[test]
+    fn collection_split_capture_terminated() {
+        let mut before: Vec<String> = Vec::default();
+        let mut after: Vec<String> = Vec::default();
+        let mut collection = Collection::split(&mut before, &mut after);
+        collection.capture("--").unwrap();
+        // Only the first `--` splits the values.
+        collection.capture("--").unwrap();
+        drop(collection);
+        assert_eq!(before, Vec::<String>::default());
+        assert_eq!(after, vec!["--".to_string()]);
+    }
+
+    #[rstest]
+    #[case(vec!["a"], vec!["a"])]
+    #[case(vec!["a,b"], vec!["a", "b"])]
+    #[case(vec!["a", "b"], vec!["a", "b"])]
+    #[case(vec!["a,b", "c"], vec!["a", "b", "c"])]
+    #[case(vec!["c", "b,a"], vec!["c", "b", "a"])]
+    #[case(vec!["a,b,c", "d,e"], vec!["a", "b", "c", "d", "e"])]
+    fn collection_comma_separated_capture(#[case] tokens: Vec<&str>, #[case] expected: Vec<&str>) {
+        let mut variable: Vec<String> = Vec::default();
+        let mut collection = Collection::new(&mut variable, Nargs::Any).comma_separated();
+
+        for token in tokens {
+            collection.capture(token).unwrap();
+        }
+
+        drop(collection);
+        assert_eq!(variable, expected);
+    }
+
+    #[test]
+    fn collection_one_of_capture() {
+        let mut variable: HashSet<String> = HashSet::default();
+        let mut collection = Collection::new(&mut variable, Nargs::Any)
+            .one_of(vec!["a".to_string(), "b".to_string()])
+            .comma_separated();
+        collection.capture("a,b").unwrap();
+        let error = collection.capture("c").unwrap_err();
+        assert_matches!(error, InvalidCapture::InvalidValue { token, message } => {
+            assert_eq!(token, "c");
+            assert_eq!(message, "'c' is not one of 'a', 'b'.");
+        });
+        drop(collection);
+        assert_eq!(variable, HashSet::from(["a".to_string(), "b".to_string()]));
+    }
+
+    #[test]
+    fn collection_repeatable_group_capture() {
+        let mut variable: Vec<(String, u32)> = Vec::default();
+        let mut collection = Collection::repeatable_group(&mut variable);
+        assert_eq!(collection.group(), 2);
+        assert_eq!(collection.nargs(), Nargs::AtLeastOne);
+        collection.capture("a").unwrap();
+        collection.capture("1").unwrap();
+        collection.capture("b").unwrap();
+        collection.capture("2").unwrap();
+        // A partial record isn't collected.
+        collection.capture("c").unwrap();
+        drop(collection);
+        assert_eq!(variable, vec![("a".to_string(), 1), ("b".to_string(), 2)]);
+
+        let mut variable: Vec<(u32, String, bool)> = Vec::default();
+        let mut collection = Collection::repeatable_group(&mut variable);
+        assert_eq!(collection.group(), 3);
+        collection.capture("1").unwrap();
+        collection.capture("x").unwrap();
+        let error = collection.capture("not-a-bool").unwrap_err();
+        drop(collection);
+        assert_eq!(variable, vec![]);
+        assert_eq!(
+            error,
+            InvalidCapture::InvalidConversion {
+                token: "not-a-bool".to_string(),
+                type_name: "bool",
+            }
+        );
+    }
+
+    #[test]
+    fn collection_optional_capture() {
+        // Option<Vec<u32>>
+        let mut variable: Option<Vec<u32>> = None;
+        let mut collection = Collection::new(&mut variable, Nargs::Any);
+        collection.matched();
+        drop(collection);
+        assert_eq!(variable, Some(vec![]));
+
+        let mut variable: Option<Vec<u32>> = None;
+        let mut collection = Collection::new(&mut variable, Nargs::Any);
+        collection.matched();
+        collection.capture("1").unwrap();
+        collection.capture("0").unwrap();
+        drop(collection);
+        assert_eq!(variable, Some(vec![1, 0]));
+    }
+
+    #[test]
+    #[cfg(not(windows))]
+    fn collection_path_list_capture() {
+        // Vec<PathBuf>
+        let mut variable: Vec<PathBuf> = Vec::default();
+        let mut collection = Collection::path_list(&mut variable, Nargs::Any);
+        collection.capture("a:b:c").unwrap();
+        collection.capture("/d").unwrap();
+        collection.capture(":e::f:").unwrap();
+        collection.capture("").unwrap();
+        assert_eq!(
+            variable,
+            vec![
+                PathBuf::from("a"),
+                PathBuf::from("b"),
+                PathBuf::from("c"),
+                PathBuf::from("/d"),
+                PathBuf::from("e"),
+                PathBuf::from("f"),
+            ]
+        );
+
+        // HashSet<PathBuf>
+        let mut variable: HashSet<PathBuf> = HashSet::default();
+        let mut collection = Collection::path_list(&mut variable, Nargs::Any);
+        let error = collection.capture("a:b:a").unwrap_err();
+        assert_eq!(
+            variable,
+            HashSet::from([PathBuf::from("a"), PathBuf::from("b")])
+        );
+        assert_matches!(error, InvalidCapture::InvalidAdd { token, message } => {
+            assert_eq!(token, "a".to_string());
+            assert_eq!(message, "set already contains item".to_string());
+        });
+    }
+
+    #[test]
+    #[cfg(windows)]
+    fn collection_path_list_capture() {
+        let mut variable: Vec<PathBuf> = Vec::default();
+        let mut collection = Collection::path_list(&mut variable, Nargs::Any);
+        collection.capture("a;b;c").unwrap();
+        collection.capture(";d;;").unwrap();
+        assert_eq!(
+            variable,
+            vec![
+                PathBuf::from("a"),
+                PathBuf::from("b"),
+                PathBuf::from("c"),
+                PathBuf::from("d"),
+            ]
+        );
+    }
+
+    fn temp_file(name: &str, content: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("blarg-{}-{name}", std::process::id()));
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[rstest]
+    #[case(vec!["1", "2", "3"], None)]
+    #[case(vec!["1", "2", "1"], Some("1"))]
+    #[case(vec!["1", "01"], Some("01"))]
+    #[case(vec!["1,2", "3,2"], Some("2"))]
+    fn collection_reject_duplicates_capture(
+        #[case] tokens: Vec<&str>,
+        #[case] expected: Option<&str>,
+    ) {
+        // Setup
+        let mut variable: Vec<u32> = Vec::default();
+        let mut collection = Collection::new(&mut variable, Nargs::Any).reject_duplicates();
+        collection.split = Some(Split::Delimiter(','));
+
+        // Execute
+        let result: Result<Vec<()>, InvalidCapture> = tokens
+            .into_iter()
+            .map(|token| collection.capture(token))
+            .collect();
+
+        // Verify
+        match expected {
+            None => {
+                result.unwrap();
+            }
+            Some(duplicate) => {
+                assert_matches!(result.unwrap_err(), InvalidCapture::InvalidValue { token, message } => {
+                    assert_eq!(token, duplicate.to_string());
+                    assert_eq!(message, format!("duplicate value '{duplicate}'."));
+                });
+            }
+        }
+    }
+
+    #[test]
+    fn collection_from_file_capture() {
+        let path = temp_file("hosts.txt", "alpha\nbeta\n\ngamma\n");
+        let mut variable: Vec<String> = Vec::default();
+        let mut collection = Collection::new(&mut variable, Nargs::Precisely(1)).from_file();
+        collection.capture(path.to_str().unwrap()).unwrap();
+        assert_eq!(
+            variable,
+            vec!["alpha".to_string(), "beta".to_string(), "gamma".to_string()]
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn collection_from_file_capture_invalid() {
+        // Missing file
+        let path = std::env::temp_dir().join(format!("blarg-{}-missing.txt", std::process::id()));
+        let token = path.to_str().unwrap();
+        let mut variable: Vec<u32> = Vec::default();
+        let mut collection = Collection::new(&mut variable, Nargs::Precisely(1)).from_file();
+        let error = collection.capture(token).unwrap_err();
+        assert_matches!(error, InvalidCapture::InvalidValue { token: t, message } => {
+            assert_eq!(t, token.to_string());
+            assert!(message.starts_with(&format!("cannot read file '{token}': ")));
+        });
+
+        // Conversion
+        let path = temp_file("numbers.txt", "1\nabc\n3");
+        let token = path.to_str().unwrap();
+        let mut variable: Vec<u32> = Vec::default();
+        let mut collection = Collection::new(&mut variable, Nargs::Precisely(1)).from_file();
+        let error = collection.capture(token).unwrap_err();
+        assert_matches!(error, InvalidCapture::InvalidValue { token: t, message } => {
+            assert_eq!(t, "abc".to_string());
+            assert_eq!(message, format!("line 2 of '{token}': cannot convert 'abc' to u32."));
+        });
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn value_capture_allow_file_ref() {
+        let path = temp_file("cert.pem", "-----BEGIN-----\nabc\n");
+        let token = format!("@{}", path.to_str().unwrap());
+        let mut variable: String = String::default();
+        let mut scalar = Scalar::new(&mut variable).allow_file_ref();
+        scalar.capture(&token).unwrap();
+        assert_eq!(variable, "-----BEGIN-----\nabc".to_string());
+        std::fs::remove_file(path).unwrap();
+
+        let path = temp_file("port.txt", "8080\n");
+        let token = format!("@{}", path.to_str().unwrap());
+        let mut variable: u16 = 0;
+        let mut scalar = Scalar::new(&mut variable).allow_file_ref();
+        scalar.capture(&token).unwrap();
+        assert_eq!(variable, 8080);
+        std::fs::remove_file(path).unwrap();
+
+        let path = temp_file("windows.txt", "value\r\n\r\n");
+        let token = format!("@{}", path.to_str().unwrap());
+        let mut variable: String = String::default();
+        let mut scalar = Scalar::new(&mut variable).allow_file_ref();
+        scalar.capture(&token).unwrap();
+        assert_eq!(variable, "value\r\n".to_string());
+        std::fs::remove_file(path).unwrap();
+
+        let mut variable: String = String::default();
+        let mut scalar = Scalar::new(&mut variable).allow_file_ref();
+        scalar.capture("@@literal").unwrap();
+        assert_eq!(variable, "@literal".to_string());
+
+        let mut variable: String = String::default();
+        let mut scalar = Scalar::new(&mut variable).allow_file_ref();
+        scalar.capture("plain").unwrap();
+        assert_eq!(variable, "plain".to_string());
+    }
+
+    #[test]
+    fn value_capture_allow_file_ref_invalid() {
+        // Missing file
+        let path = std::env::temp_dir().join(format!("blarg-{}-missing.pem", std::process::id()));
+        let token = format!("@{}", path.to_str().unwrap());
+        let mut variable: String = String::default();
+        let mut scalar = Scalar::new(&mut variable).allow_file_ref();
+        let error = scalar.capture(&token).unwrap_err();
+        assert_matches!(error, InvalidCapture::InvalidValue { token: t, message } => {
+            assert_eq!(t, token);
+            assert!(message.starts_with(&format!("cannot read file '{}': ", path.to_str().unwrap())));
+        });
+
+        // Conversion
+        let path = temp_file("number.txt", "abc");
+        let token = format!("@{}", path.to_str().unwrap());
+        let mut variable: u32 = 0;
+        let mut scalar = Scalar::new(&mut variable).allow_file_ref();
+        let error = scalar.capture(&token).unwrap_err();
+        assert_matches!(error, InvalidCapture::InvalidConversion { token: t, type_name } => {
+            assert_eq!(t, "abc".to_string());
+            assert_eq!(type_name, "u32");
+        });
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn path_must_exist_capture() {
+        // Existing
+        let path = temp_file("exists.txt", "");
+        let token = path.to_str().unwrap();
+        let mut scalar_variable: PathBuf = PathBuf::default();
+        let mut scalar = Scalar::new(&mut scalar_variable).must_exist();
+        scalar.capture(token).unwrap();
+        drop(scalar);
+        assert_eq!(scalar_variable, path);
+
+        let mut optional_variable: Option<PathBuf> = None;
+        let mut optional = Optional::new(&mut optional_variable).must_exist();
+        optional.capture(token).unwrap();
+        drop(optional);
+        assert_eq!(optional_variable, Some(path.clone()));
+        std::fs::remove_file(&path).unwrap();
+
+        // Missing
+        let path = std::env::temp_dir().join(format!("blarg-{}-missing", std::process::id()));
+        let token = path.to_str().unwrap();
+        let expected = InvalidCapture::InvalidValue {
+            token: token.to_string(),
+            message: format!("path '{token}' does not exist."),
+        };
+        let mut scalar_variable: PathBuf = PathBuf::default();
+        let mut scalar = Scalar::new(&mut scalar_variable).must_exist();
+        assert_eq!(scalar.capture(token).unwrap_err(), expected);
+
+        let mut optional_variable: Option<PathBuf> = None;
+        let mut optional = Optional::new(&mut optional_variable).must_exist();
+        assert_eq!(optional.capture(token).unwrap_err(), expected);
+        drop(optional);
+        assert_eq!(optional_variable, None);
+    }
+
+    #[test]
+    fn collection_pairs_capture() {
+        // Vec<(String, String)>
+        let mut variable: Vec<(String, String)> = Vec::default();
+        let mut collection = Collection::pairs(&mut variable, Nargs::Any, ':');
+        collection.capture("Accept:json").unwrap();
+        collection.capture("Accept:xml").unwrap();
+        collection.capture("Host:a:b").unwrap();
+        assert_eq!(
+            variable,
+            vec![
+                ("Accept".to_string(), "json".to_string()),
+                ("Accept".to_string(), "xml".to_string()),
+                ("Host".to_string(), "a:b".to_string()),
+            ]
+        );
+
+        // Vec<(String, u32)>
+        let mut variable: Vec<(String, u32)> = Vec::default();
+        let mut collection = Collection::pairs(&mut variable, Nargs::Any, '=');
+        collection.capture("a=1").unwrap();
+        let error = collection.capture("a").unwrap_err();
+        assert_matches!(error, InvalidCapture::InvalidValue { token, message } => {
+            assert_eq!(token, "a".to_string());
+            assert_eq!(message, "cannot split 'a' into a pair on '='.".to_string());
+        });
+        let error = collection.capture("a=x").unwrap_err();
+        assert_matches!(error, InvalidCapture::InvalidConversion { token, type_name } => {
+            assert_eq!(token, "x".to_string());
+            assert_eq!(type_name, "u32");
+        });
+        assert_eq!(variable, vec![("a".to_string(), 1)]);
+    }
+
+    #[test]
+    fn collection_pairs_comma_separated_capture() {
+        let mut variable: HashMap<String, u32> = HashMap::default();
+        let mut collection = Collection::pairs(&mut variable, Nargs::Any, '=').comma_separated();
+        collection.capture("a=1,b=2").unwrap();
+        collection.capture("c=3").unwrap();
+        let error = collection.capture("d=4,e").unwrap_err();
+        assert_matches!(error, InvalidCapture::InvalidValue { token, message } => {
+            assert_eq!(token, "e".to_string());
+            assert_eq!(message, "cannot split 'e' into a pair on '='.".to_string());
+        });
+        let error = collection.capture("a=5").unwrap_err();
+        assert_matches!(error, InvalidCapture::InvalidAdd { token, message } => {
+            assert_eq!(token, "a=5".to_string());
+            assert_eq!(message, "map already contains key".to_string());
+        });
+        assert_eq!(
+            variable,
+            HashMap::from([
+                ("a".to_string(), 1),
+                ("b".to_string(), 2),
+                ("c".to_string(), 3),
+                ("d".to_string(), 4),
+            ])
+        );
+    }
+
     #[test]
     fn value_overwritten() {
         let mut variable: u32 = u32::default();
@@ -295,12 +3214,46 @@ mod tests {
         assert_eq!(variable, 0);
     }
 
-    #[test]
-    fn switch_matched() {
+    #[rstest]
+    #[case(false, false)]
+    #[case(false, true)]
+    #[case(true, false)]
+    #[case(true, true)]
+    fn switch_matched(#[case] initial: bool, #[case] target: bool) {
         let mut variable: u32 = u32::default();
         let mut switch = Switch::new(&mut variable, 2);
         switch.matched();
         assert_eq!(variable, 2);
+
+        // The target applies regardless of the initial value.
+        let mut variable: bool = initial;
+        let mut switch = Switch::new(&mut variable, target);
+        switch.matched();
+        assert_eq!(variable, target);
+    }
+
+    #[test]
+    fn cycle_matched() {
+        let mut variable: u32 = u32::default();
+        let mut cycle = Cycle::new(&mut variable, &[1, 2, 3]);
+        assert_eq!(cycle.nargs(), Nargs::Precisely(0));
+        assert!(cycle.repeatable());
+        assert_eq!(cycle.misconfiguration(), None);
+        cycle.matched();
+        cycle.matched();
+        cycle.matched();
+        cycle.matched();
+        assert_eq!(variable, 1);
+    }
+
+    #[test]
+    fn cycle_empty() {
+        let mut variable: u32 = u32::default();
+        let cycle = Cycle::new(&mut variable, &[]);
+        assert_eq!(
+            cycle.misconfiguration(),
+            Some("cannot cycle: it must have at least one state.".to_string())
+        );
     }
 
     #[test]
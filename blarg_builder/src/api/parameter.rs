@@ -1,12 +1,14 @@
-use crate::api::{CliArgument, CliOption, GenericCapturable, Scalar};
+use crate::api::{
+    CliArgument, CliOption, GenericCapturable, Scalar, TokenAliases, ValidationContext, Validator,
+};
 use crate::matcher::{ArgumentConfig, Bound, OptionConfig};
-use crate::model::Nargs;
+use crate::model::{Case, Nargs};
 use crate::parser::{
     AnonymousCapturable, ArgumentCapture, ArgumentParameter, OptionCapture, OptionParameter,
 };
 use crate::prelude::Choices;
 use crate::InvalidCapture;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 pub(crate) struct AnonymousCapture<'a, T: 'a> {
     field: Box<dyn GenericCapturable<'a, T> + 'a>,
@@ -25,6 +27,10 @@ impl<'a, T> AnonymousCapturable for AnonymousCapture<'a, T> {
         self.field.matched();
     }
 
+    fn spelled(&mut self, spelling: &str) {
+        self.field.spelled(spelling);
+    }
+
     fn capture(&mut self, value: &str) -> Result<(), InvalidCapture> {
         self.field.capture(value)
     }
@@ -42,15 +48,134 @@ pub(super) struct ParameterInner<'a, T> {
     nargs: Nargs,
     name: String,
     short: Option<char>,
+    short_aliases: Vec<char>,
+    terminator: bool,
+    rest: bool,
+    prompt: bool,
+    warn_on_glob: bool,
+    group: u8,
+    repeatable: bool,
+    misconfiguration: Option<String>,
     help: Option<String>,
     meta: Option<Vec<String>>,
     choices: HashMap<String, String>,
+    hidden_choices: HashSet<String>,
+    contextual: Option<String>,
+    validator: Option<Validator<'a>>,
+    allowed: Option<Vec<String>>,
+    initial: Option<String>,
+    deprecated: Option<String>,
+    value_names: Vec<String>,
+    placeholder: Option<String>,
+    env: Option<String>,
+    order: Option<i32>,
+    since: Option<String>,
+    required: bool,
 }
 
 impl<'a, T> ParameterInner<'a, T> {
     pub(super) fn class(&self) -> ParameterClass {
         self.class
     }
+
+    pub(super) fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub(super) fn contextual(&self) -> Option<&String> {
+        self.contextual.as_ref()
+    }
+
+    pub(super) fn group(&self) -> u8 {
+        self.group
+    }
+
+    pub(super) fn prompt(&self) -> bool {
+        self.prompt
+    }
+
+    pub(super) fn misconfiguration(&self) -> Option<&String> {
+        self.misconfiguration.as_ref()
+    }
+
+    pub(super) fn warn_on_glob(&self) -> bool {
+        self.warn_on_glob
+    }
+
+    pub(super) fn deprecated(&self) -> Option<&String> {
+        self.deprecated.as_ref()
+    }
+
+    pub(super) fn value_names(&self) -> &[String] {
+        &self.value_names
+    }
+
+    pub(super) fn placeholder(&self) -> Option<&String> {
+        self.placeholder.as_ref()
+    }
+
+    pub(super) fn env(&self) -> Option<&String> {
+        self.env.as_ref()
+    }
+
+    pub(super) fn order(&self) -> Option<i32> {
+        self.order
+    }
+
+    pub(super) fn since(&self) -> Option<&String> {
+        self.since.as_ref()
+    }
+
+    pub(super) fn required(&self) -> bool {
+        self.required
+    }
+
+    pub(super) fn nargs(&self) -> Nargs {
+        self.nargs
+    }
+
+    /// Take the validator, checking the allowed values (if any) before running the validator from [`Parameter::validate_with_context`].
+    pub(super) fn take_validator(&mut self) -> Option<Validator<'a>> {
+        let validator = self.validator.take();
+
+        match self.allowed.take() {
+            Some(allowed) => Some(Box::new(move |value, context| {
+                if !allowed.iter().any(|allowed_value| allowed_value == value) {
+                    return Err(format!(
+                        "'{value}' is not one of {}.",
+                        allowed
+                            .iter()
+                            .map(|allowed_value| format!("'{allowed_value}'"))
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    ));
+                }
+
+                match &validator {
+                    Some(validator) => validator(value, context),
+                    None => Ok(()),
+                }
+            })),
+            None => validator,
+        }
+    }
+
+    /// The documented choices, excluding those which are hidden.
+    fn listed_choices(&self) -> HashMap<String, String> {
+        self.choices
+            .iter()
+            .filter(|(choice, _)| !self.hidden_choices.contains(*choice))
+            .map(|(choice, description)| (choice.clone(), description.clone()))
+            .collect()
+    }
+
+    pub(super) fn initial(&self) -> Option<&String> {
+        match self.nargs {
+            // A switch (ex: `--verbose`) cannot express its initial value as a token.
+            Nargs::Precisely(0) => None,
+            _ => self.initial.as_ref(),
+        }
+    }
 }
 
 impl<'a, T> std::fmt::Debug for ParameterInner<'a, T> {
@@ -92,6 +217,9 @@ impl<'a, T> From<&ParameterInner<'a, T>> for OptionConfig {
             value.short.clone(),
             Bound::from(value.nargs),
         )
+        .with_short_aliases(value.short_aliases.clone())
+        .with_repeatable(value.repeatable)
+        .with_rest(value.rest)
     }
 }
 
@@ -111,14 +239,22 @@ impl<'a, T> From<&ParameterInner<'a, T>> for OptionParameter {
             value.nargs,
             value.help.clone(),
             value.meta.clone(),
-            value.choices.clone(),
+            value.listed_choices(),
         )
+        .with_short_aliases(value.short_aliases.clone())
+        .with_value_names(value.value_names.clone())
+        .with_placeholder(value.placeholder.clone())
+        .with_order(value.order.unwrap_or_default())
+        .with_since(value.since.clone())
+        .with_required(value.required)
     }
 }
 
 impl<'a, T> From<&ParameterInner<'a, T>> for ArgumentConfig {
     fn from(value: &ParameterInner<'a, T>) -> Self {
         ArgumentConfig::new(value.name.clone(), Bound::from(value.nargs))
+            .with_terminator(value.terminator)
+            .with_group(value.group)
     }
 }
 
@@ -137,8 +273,9 @@ impl<'a, T> From<&ParameterInner<'a, T>> for ArgumentParameter {
             value.nargs,
             value.help.clone(),
             value.meta.clone(),
-            value.choices.clone(),
+            value.listed_choices(),
         )
+        .with_value_names(value.value_names.clone())
     }
 }
 
@@ -188,7 +325,11 @@ impl<'a, T> From<&ParameterInner<'a, T>> for ArgumentParameter {
 /// // FromStr does not invert Display!
 /// assert_ne!(FooBar::from_str("foo").unwrap().to_string(), "foo");
 /// ```
-pub struct Condition<'a, T>(Parameter<'a, T>);
+pub struct Condition<'a, T> {
+    parameter: Parameter<'a, T>,
+    case: Option<Case>,
+    aliases: TokenAliases,
+}
 
 impl<'a, T: std::str::FromStr + std::fmt::Display> Condition<'a, T> {
     /// Create a condition parameter.
@@ -233,7 +374,12 @@ impl<'a, T: std::str::FromStr + std::fmt::Display> Condition<'a, T> {
     /// };
     /// ```
     pub fn new(value: Scalar<'a, T>, name: &'static str) -> Self {
-        Condition(Parameter::argument(value, name))
+        let aliases = TokenAliases::default();
+        Condition {
+            parameter: Parameter::argument(value.with_aliases(aliases.clone()), name),
+            case: None,
+            aliases,
+        }
     }
 
     /// Document the help message for this sub-command condition.
@@ -256,9 +402,9 @@ impl<'a, T: std::str::FromStr + std::fmt::Display> Condition<'a, T> {
     ///     .help("--this will get discarded--")
     ///     .help("Choose the 'case' to execute.  Description may include multiple sentences.");
     /// ```
-    pub fn help(self, description: impl Into<String>) -> Self {
-        let inner = self.0;
-        Self(inner.help(description))
+    pub fn help(mut self, description: impl Into<String>) -> Self {
+        self.parameter = self.parameter.help(description);
+        self
     }
 
     /// Document the meta message(s) for this sub-command condition.
@@ -281,13 +427,74 @@ impl<'a, T: std::str::FromStr + std::fmt::Display> Condition<'a, T> {
     ///     .meta(vec!["--this will get discarded--"])
     ///     .meta(vec!["final extra", "details"]);
     /// ```
-    pub fn meta(self, description: Vec<impl Into<String>>) -> Self {
-        let inner = self.0;
-        Self(inner.meta(description))
+    pub fn meta(mut self, description: Vec<impl Into<String>>) -> Self {
+        self.parameter = self.parameter.meta(description);
+        self
     }
 
-    pub(super) fn consume(self) -> Parameter<'a, T> {
-        self.0
+    /// Present the sub-command choices in the specified `case`.
+    /// If repeated, only the final case will apply to the sub-command condition.
+    ///
+    /// The case applies to the `std::fmt::Display` of each variant, both in the help message and when matching input.
+    /// The type `T` itself is unaffected - its `std::str::FromStr` still receives the original `std::fmt::Display`.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{Case, CommandLineParser, Condition, Scalar};
+    /// use std::str::FromStr;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// enum Task {
+    ///     RunAll,
+    ///     RunOne,
+    /// }
+    /// # impl std::fmt::Display for Task {
+    /// #     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    /// #         write!(f, "{self:?}")
+    /// #     }
+    /// # }
+    /// # impl FromStr for Task {
+    /// #     type Err = String;
+    /// #
+    /// #     fn from_str(value: &str) -> Result<Self, Self::Err> {
+    /// #         match value {
+    /// #             "RunAll" => Ok(Task::RunAll),
+    /// #             "RunOne" => Ok(Task::RunOne),
+    /// #             _ => Err(format!("unknown: {}", value)),
+    /// #         }
+    /// #     }
+    /// # }
+    ///
+    /// let mut task: Task = Task::RunOne;
+    /// let parser = CommandLineParser::new("program")
+    ///     .branch(Condition::new(Scalar::new(&mut task), "task").choices_case(Case::Kebab))
+    ///     .command(Task::RunAll, |sub| sub)
+    ///     .command(Task::RunOne, |sub| sub)
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["run-all"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(task, Task::RunAll);
+    /// ```
+    pub fn choices_case(mut self, case: Case) -> Self {
+        self.case.replace(case);
+        self
+    }
+
+    pub(super) fn consume(self) -> (Parameter<'a, T>, ChoiceKeys) {
+        let mut parameter = self.parameter;
+        let keys = ChoiceKeys {
+            case: self.case,
+            aliases: self.aliases,
+        };
+        parameter.0.choices = parameter
+            .0
+            .choices
+            .into_iter()
+            .map(|(choice, description)| (keys.key(&choice), description))
+            .collect();
+        (parameter, keys)
     }
 }
 
@@ -344,9 +551,48 @@ impl<'a, T: std::str::FromStr + std::fmt::Display> Choices<T> for Condition<'a,
     ///     .choice(FooBar::Foo, "Do foo'y things.")
     ///     .choice(FooBar::Bar, "Do bar'y things.  Description may include multiple sentences.");
     /// ```
-    fn choice(self, variant: T, description: impl Into<String>) -> Self {
-        let inner = self.0;
-        Self(inner.choice(variant, description))
+    fn choice(mut self, variant: T, description: impl Into<String>) -> Self {
+        self.parameter = self.parameter.choice(variant, description);
+        self
+    }
+}
+
+/// The presentation of the sub-command choice keys, as configured on the [`Condition`].
+pub(super) struct ChoiceKeys {
+    case: Option<Case>,
+    aliases: TokenAliases,
+}
+
+impl ChoiceKeys {
+    /// Derive the key of a sub-command choice from its `display`, recording the key as an accepted input.
+    pub(super) fn key(&self, display: &str) -> String {
+        match self.case {
+            Some(case) => {
+                let key = case.apply(display);
+                self.aliases
+                    .borrow_mut()
+                    .insert(key.clone(), display.to_string());
+                key
+            }
+            None => display.to_string(),
+        }
+    }
+
+    /// The other sub-command choice (by its display) whose key collides with that of `display`, if any.
+    pub(super) fn collision(&self, display: &str) -> Option<String> {
+        let key = self.case?.apply(display);
+        self.aliases
+            .borrow()
+            .get(&key)
+            .filter(|other| *other != display)
+            .cloned()
+    }
+
+    /// Record the `alias` as an accepted input for the sub-command choice `display`.
+    pub(super) fn alias(&self, alias: &str, display: &str) {
+        self.aliases
+            .borrow_mut()
+            .insert(alias.to_string(), display.to_string());
     }
 }
 
@@ -371,15 +617,44 @@ impl<'a, T> Parameter<'a, T> {
         short: Option<char>,
     ) -> Self {
         let nargs = field.nargs();
+        let short_aliases = field.short_aliases();
+        let rest = field.rest();
+        let prompt = field.prompt();
+        let warn_on_glob = field.warn_on_glob();
+        let group = field.group();
+        let repeatable = field.repeatable();
+        let misconfiguration = field
+            .misconfiguration()
+            .or_else(|| nargs.misconfiguration());
         Self(ParameterInner {
             class: ParameterClass::Opt,
             field: AnonymousCapture::bind(field),
             nargs,
             name: name.into(),
             short,
+            short_aliases,
+            terminator: false,
+            rest,
+            prompt,
+            warn_on_glob,
+            group,
+            repeatable,
+            misconfiguration,
             help: None,
             meta: None,
             choices: HashMap::default(),
+            hidden_choices: HashSet::default(),
+            contextual: None,
+            validator: None,
+            allowed: None,
+            initial: None,
+            deprecated: None,
+            value_names: Vec::default(),
+            placeholder: None,
+            env: None,
+            order: None,
+            since: None,
+            required: false,
         })
     }
 
@@ -398,15 +673,41 @@ impl<'a, T> Parameter<'a, T> {
         name: impl Into<String>,
     ) -> Self {
         let nargs = field.nargs();
+        let terminator = field.terminator();
+        let warn_on_glob = field.warn_on_glob();
+        let group = field.group();
+        let misconfiguration = field
+            .misconfiguration()
+            .or_else(|| nargs.misconfiguration());
         Self(ParameterInner {
             class: ParameterClass::Arg,
             field: AnonymousCapture::bind(field),
             nargs,
             name: name.into(),
             short: None,
+            short_aliases: Vec::default(),
+            terminator,
+            rest: false,
+            prompt: false,
+            warn_on_glob,
+            group,
+            repeatable: false,
+            misconfiguration,
             help: None,
             meta: None,
             choices: HashMap::default(),
+            hidden_choices: HashSet::default(),
+            contextual: None,
+            validator: None,
+            allowed: None,
+            initial: None,
+            deprecated: None,
+            value_names: Vec::default(),
+            placeholder: None,
+            env: None,
+            order: None,
+            since: None,
+            required: false,
         })
     }
 
@@ -462,6 +763,333 @@ impl<'a, T> Parameter<'a, T> {
         Self(inner)
     }
 
+    /// Hide this option from the help message, instead mentioning it when an error concerns the `trigger` parameter.
+    /// If repeated, only the final trigger will apply to the parameter.
+    ///
+    /// Contextual help is intended for advanced options which would otherwise clutter the help message.
+    /// The `trigger` must name another parameter on the same parser.
+    /// Only options may be hidden; contextual help on an argument results in a configuration error.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    ///
+    /// let mut level: u32 = 0;
+    /// let mut level_curve: f32 = 1.0;
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(Scalar::new(&mut level), "level", None))
+    ///     .add(
+    ///         Parameter::option(Scalar::new(&mut level_curve), "level-curve", None)
+    ///             .help("Expert tuning for the level.")
+    ///             .contextual_help("level"),
+    ///     )
+    ///     .build();
+    ///
+    /// // An error concerning '--level' would mention '--level-curve'.
+    /// parser.parse_tokens(vec!["--level", "2"].as_slice()).unwrap();
+    /// assert_eq!(level, 2);
+    /// ```
+    pub fn contextual_help(self, trigger: impl Into<String>) -> Self {
+        let mut inner = self.0;
+        inner.contextual = Some(trigger.into());
+        Self(inner)
+    }
+
+    /// Validate each value token of this parameter against the previously declared parameters.
+    /// If repeated, only the final validator will apply to the parameter.
+    ///
+    /// The `validator` receives the value token along with a [`ValidationContext`]: a snapshot of the value tokens of the parameters declared before this one.
+    /// Validation runs after all the values have been captured, in declaration order.
+    /// An `Err` message is reported as a parse error for this parameter.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    ///
+    /// let mut start: u32 = 0;
+    /// let mut end: u32 = 0;
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(Scalar::new(&mut start), "start", None))
+    ///     .add(
+    ///         Parameter::option(Scalar::new(&mut end), "end", None).validate_with_context(
+    ///             |value, context| match context.get("start") {
+    ///                 Some(start) if value.parse::<u32>().ok() <= start.parse::<u32>().ok() => {
+    ///                     Err(format!("end '{value}' must exceed start '{start}'."))
+    ///                 }
+    ///                 _ => Ok(()),
+    ///             },
+    ///         ),
+    ///     )
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["--end", "10", "--start", "5"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(start, 5);
+    /// assert_eq!(end, 10);
+    /// ```
+    pub fn validate_with_context(
+        self,
+        validator: impl Fn(&str, &ValidationContext) -> Result<(), String> + 'a,
+    ) -> Self {
+        let mut inner = self.0;
+        inner.validator = Some(Box::new(validator));
+        Self(inner)
+    }
+
+    /// Require each value of this parameter to be one of the `allowed` values, as built at runtime (ex: from a configuration file).
+    /// An unknown value is rejected with an error listing the `allowed` values.
+    ///
+    /// The `allowed` values are listed as choices in the help message, preserving any documented description (see [`Choices::choice`]).
+    /// If repeated, only the final `allowed` values will apply to the parameter.
+    /// This composes with [`Parameter::validate_with_context`]: a value must be allowed before it is validated.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    ///
+    /// let regions: Vec<String> = vec!["us-east".to_string(), "eu-west".to_string()];
+    /// let allowed: Vec<&str> = regions.iter().map(String::as_str).collect();
+    /// let mut region: String = "".to_string();
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(Scalar::new(&mut region), "region", None).requires_value_from(&allowed))
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["--region", "eu-west"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(&region, "eu-west");
+    /// ```
+    pub fn requires_value_from(self, allowed: &[&str]) -> Self {
+        let mut inner = self.0;
+        let allowed: Vec<String> = allowed.iter().map(|value| value.to_string()).collect();
+
+        for value in &allowed {
+            inner.choices.entry(value.clone()).or_default();
+        }
+
+        inner.allowed = Some(allowed);
+        Self(inner)
+    }
+
+    /// Declare the initial value of this parameter, for use in the resolved command.
+    /// If repeated, only the final value will apply to the parameter.
+    ///
+    /// When the parameter isn't matched, [`GeneralParser::parse_tokens_resolved`](./struct.GeneralParser.html#method.parse_tokens_resolved) resolves it to the `value` token.
+    /// This only documents the initial value; it is up to the program to initialize the variable accordingly.
+    /// Switches ignore their initial value.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    ///
+    /// let mut level: u32 = 3;
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(Scalar::new(&mut level), "level", None).initial(3))
+    ///     .build();
+    ///
+    /// let resolved = parser.parse_tokens_resolved(vec![].as_slice()).unwrap();
+    ///
+    /// assert_eq!(resolved, "program --level 3");
+    /// assert_eq!(level, 3);
+    /// ```
+    pub fn initial(self, value: impl std::fmt::Display) -> Self {
+        let mut inner = self.0;
+        inner.initial = Some(value.to_string());
+        Self(inner)
+    }
+
+    /// Deprecate this parameter, with a `message` to explain its replacement.
+    /// If repeated, only the final message will apply to the parameter.
+    ///
+    /// The parameter continues to work, but matching it prints a warning (which the `--quiet` flag suppresses).
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    ///
+    /// let mut level: u32 = 0;
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(
+    ///         Parameter::option(Scalar::new(&mut level), "lvl", None)
+    ///             .deprecated("Use '--level' instead."),
+    ///     )
+    ///     .build();
+    ///
+    /// // Warns that "'--lvl' is deprecated: Use '--level' instead."
+    /// parser.parse_tokens(vec!["--lvl", "2"].as_slice()).unwrap();
+    /// assert_eq!(level, 2);
+    /// ```
+    pub fn deprecated(self, message: impl Into<String>) -> Self {
+        let mut inner = self.0;
+        inner.deprecated = Some(message.into());
+        Self(inner)
+    }
+
+    /// Name each of the values of this parameter (ex: `MIN` and `MAX` for a range).
+    /// If repeated, only the final names will apply to the parameter.
+    ///
+    /// The names replace the repeated parameter name in the help message grammar (ex: `--range MIN MAX`).
+    /// They also label the value which fails to capture (ex: `cannot convert 'x' to u32 (MAX).`).
+    /// The parameter must take precisely as many values as there are names; otherwise it results in a configuration error.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{Collection, CommandLineParser, Nargs, Parameter};
+    ///
+    /// let mut range: Vec<u32> = Vec::default();
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(
+    ///         Parameter::option(Collection::new(&mut range, Nargs::Precisely(2)), "range", None)
+    ///             .value_names(vec!["MIN", "MAX"]),
+    ///     )
+    ///     .build();
+    ///
+    /// // Errors with "cannot convert 'x' to u32 (MAX)."
+    /// assert_eq!(parser.parse_tokens(vec!["--range", "1", "x"].as_slice()), Err(1));
+    /// ```
+    pub fn value_names(self, names: Vec<impl Into<String>>) -> Self {
+        let mut inner = self.0;
+        inner.value_names = names.into_iter().map(|n| n.into()).collect();
+        Self(inner)
+    }
+
+    /// Annotate a switch with the `text`, in place of its (empty) grammar in the detailed section of the help message.
+    /// Only switches (options taking precisely 0 values) may have a placeholder.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Switch};
+    ///
+    /// let mut verbose: bool = false;
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(
+    ///         Parameter::option(Switch::new(&mut verbose, true), "verbose", None)
+    ///             .placeholder("(on)"),
+    ///     )
+    ///     .build();
+    ///
+    /// // The help message details the switch as "--verbose (on)".
+    /// parser.parse_tokens(vec!["--verbose"].as_slice()).unwrap();
+    /// assert!(verbose);
+    /// ```
+    pub fn placeholder(self, text: impl Into<String>) -> Self {
+        let mut inner = self.0;
+        inner.placeholder.replace(text.into());
+        Self(inner)
+    }
+
+    /// Fall back to the environment `variable` when this option is not provided.
+    /// Only options which take values may read from the environment.
+    ///
+    /// When the option is absent from the input tokens and the `variable` is set, its value is captured as if it were the option's value.
+    /// A value which cannot be captured is reported as a [`ParseError::Environment`](./enum.ParseError.html#variant.Environment), naming the `variable`.
+    /// This overrides the variable name derived via [`CommandLineParser::env_prefix`](./struct.CommandLineParser.html#method.env_prefix).
+    /// The help message documents the variable as a meta message (ex: `env: PROGRAM_OUTPUT_FILE`), unless the meta messages are omitted.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    ///
+    /// let mut output: String = "out.txt".to_string();
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(Scalar::new(&mut output), "output", None).env("PROGRAM_OUTPUT_FILE"))
+    ///     .build();
+    ///
+    /// // Captures the value of `PROGRAM_OUTPUT_FILE` into `output`, when set.
+    /// parser.parse_tokens(vec![].as_slice()).unwrap();
+    /// ```
+    pub fn env(self, variable: impl Into<String>) -> Self {
+        let mut inner = self.0;
+        inner.env.replace(variable.into());
+        Self(inner)
+    }
+
+    /// Weight the position of this option in the help message, where lower weights are listed first (default: `0`).
+    /// Options of equal weight are listed alphabetically.
+    /// Only options may be weighted, since the arguments are listed positionally.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar, Switch};
+    ///
+    /// let mut verbose: bool = false;
+    /// let mut output: String = "out.txt".to_string();
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(Switch::new(&mut verbose, true), "verbose", None))
+    ///     .add(Parameter::option(Scalar::new(&mut output), "output", None).order(-1))
+    ///     .build();
+    ///
+    /// // The help message lists `--output` before `--verbose`.
+    /// parser.parse_tokens(vec!["--verbose"].as_slice()).unwrap();
+    /// ```
+    pub fn order(self, weight: i32) -> Self {
+        let mut inner = self.0;
+        inner.order.replace(weight);
+        Self(inner)
+    }
+
+    /// Annotate this option with the `version` in which it was introduced (ex: `(since 2.1)`), following its help in the detailed section of the help message.
+    /// Only options may be annotated, since the arguments form the usage of the program.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Switch};
+    ///
+    /// let mut fast: bool = false;
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(
+    ///         Parameter::option(Switch::new(&mut fast, true), "fast", None)
+    ///             .help("Go fast.")
+    ///             .since("2.1"),
+    ///     )
+    ///     .build();
+    ///
+    /// // The help message details the option as "--fast   Go fast. (since 2.1)".
+    /// parser.parse_tokens(vec!["--fast"].as_slice()).unwrap();
+    /// assert!(fast);
+    /// ```
+    pub fn since(self, version: impl Into<String>) -> Self {
+        let mut inner = self.0;
+        inner.since.replace(version.into());
+        Self(inner)
+    }
+
+    /// Require this option to be provided, despite its `--NAME` syntax.
+    /// Only options may be required, since the arguments are required by their [`Nargs`](./enum.Nargs.html).
+    ///
+    /// The requirement is checked after all the tokens have been matched, where an option read from the environment is considered provided.
+    /// The usage summary shows a required option without its surrounding brackets (ex: `--config CONFIG`).
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    ///
+    /// let mut config: String = String::default();
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(Scalar::new(&mut config), "config", None).required())
+    ///     .build();
+    ///
+    /// // Documents the usage summary as "usage: program [-h] --config CONFIG".
+    /// parser.parse_tokens(vec!["--config", "app.toml"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(&config, "app.toml");
+    /// ```
+    pub fn required(self) -> Self {
+        let mut inner = self.0;
+        inner.required = true;
+        Self(inner)
+    }
+
     pub(super) fn name(&self) -> String {
         self.0.name.clone()
     }
@@ -471,6 +1099,42 @@ impl<'a, T> Parameter<'a, T> {
     }
 }
 
+impl<'a, T: std::fmt::Display> Parameter<'a, T> {
+    /// Hide the choices of the `variants` from the help message, even if they are documented.
+    /// Repeat to hide additional choices.
+    ///
+    /// Hidden choices remain valid input (ex: experimental variants of an enum); they simply aren't listed.
+    /// This mirrors the `#[blarg(hidden)]` variant attribute of `BlargChoices`.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{prelude::*, CommandLineParser, Parameter, Scalar};
+    ///
+    /// let mut door: u32 = 0;
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(
+    ///         Parameter::argument(Scalar::new(&mut door), "door")
+    ///             .choice(1, "Enter door #1.")
+    ///             .choice(2, "Enter door #2.")
+    ///             .choice(3, "Enter the secret door #3.")
+    ///             .hidden_choices(vec![3]),
+    ///     )
+    ///     .build();
+    ///
+    /// parser.parse_tokens(vec!["3"].as_slice()).unwrap();
+    ///
+    /// assert_eq!(door, 3);
+    /// ```
+    pub fn hidden_choices(self, variants: Vec<T>) -> Self {
+        let mut inner = self.0;
+        inner
+            .hidden_choices
+            .extend(variants.into_iter().map(|variant| variant.to_string()));
+        Self(inner)
+    }
+}
+
 impl<'a, T: std::fmt::Display> Choices<T> for Parameter<'a, T> {
     /// Document a choice's help message for this parameter.
     /// If repeated for the same `variant` of `T`, only the final message will apply to the parameter.
@@ -512,6 +1176,7 @@ impl<'a, T: std::fmt::Display> Choices<T> for Parameter<'a, T> {
 mod tests {
     use super::*;
     use crate::api::{Parameter, Switch};
+    use rstest::rstest;
 
     #[test]
     fn option() {
@@ -592,6 +1257,82 @@ mod tests {
         );
     }
 
+    #[test]
+    fn option_requires_value_from() {
+        let mut value: String = String::default();
+        let mut option = Parameter::option(Scalar::new(&mut value), "value", None)
+            .choice("a".to_string(), "The a.")
+            .requires_value_from(&["a", "b"])
+            .consume();
+
+        assert_eq!(
+            option.choices,
+            HashMap::from([
+                ("a".to_string(), "The a.".to_string()),
+                ("b".to_string(), "".to_string())
+            ])
+        );
+        let validator = option.take_validator().unwrap();
+        let context = ValidationContext::default();
+        assert_eq!(validator("a", &context), Ok(()));
+        assert_eq!(validator("b", &context), Ok(()));
+        assert_eq!(
+            validator("c", &context),
+            Err("'c' is not one of 'a', 'b'.".to_string())
+        );
+    }
+
+    #[rstest]
+    #[case(true)]
+    #[case(false)]
+    fn option_requires_value_from_validate_with_context(#[case] validate_first: bool) {
+        let mut value: String = String::default();
+        let mut option = Parameter::option(Scalar::new(&mut value), "value", None);
+        let validator = |value: &str, _: &ValidationContext| {
+            if value == "b" {
+                Err("'b' is unavailable.".to_string())
+            } else {
+                Ok(())
+            }
+        };
+
+        if validate_first {
+            option = option
+                .validate_with_context(validator)
+                .requires_value_from(&["a", "b"]);
+        } else {
+            option = option
+                .requires_value_from(&["a", "b"])
+                .validate_with_context(validator);
+        }
+
+        let validator = option.consume().take_validator().unwrap();
+        let context = ValidationContext::default();
+        assert_eq!(validator("a", &context), Ok(()));
+        assert_eq!(
+            validator("b", &context),
+            Err("'b' is unavailable.".to_string())
+        );
+        assert_eq!(
+            validator("c", &context),
+            Err("'c' is not one of 'a', 'b'.".to_string())
+        );
+    }
+
+    #[test]
+    fn option_contextual_help() {
+        let mut flag: bool = false;
+        let option = Parameter::option(Switch::new(&mut flag, true), "flag", None)
+            .contextual_help("--this will get discarded--")
+            .contextual_help("other")
+            .consume();
+
+        assert_eq!(option.class, ParameterClass::Opt);
+        assert_eq!(option.name, "flag".to_string());
+        assert_eq!(option.help, None);
+        assert_eq!(option.contextual, Some("other".to_string()));
+    }
+
     #[test]
     fn argument() {
         let mut item: bool = false;
@@ -660,10 +1401,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn argument_hidden_choices() {
+        let mut item: u32 = 0;
+        let argument = Parameter::argument(Scalar::new(&mut item), "item")
+            .choice(1, "a")
+            .hidden_choices(vec![2])
+            .choice(2, "b")
+            .choice(3, "c")
+            .hidden_choices(vec![3, 4])
+            .consume();
+
+        assert_eq!(argument.choices.len(), 3);
+        assert_eq!(
+            argument.hidden_choices,
+            HashSet::from(["2".to_string(), "3".to_string(), "4".to_string()])
+        );
+        assert_eq!(
+            argument.listed_choices(),
+            HashMap::from([("1".to_string(), "a".to_string())])
+        );
+    }
+
     #[test]
     fn condition() {
         let mut item: bool = false;
-        let condition = Condition::new(Scalar::new(&mut item), "item")
+        let (condition, _) = Condition::new(Scalar::new(&mut item), "item")
             .choice(true, "b")
             .choice(false, "d")
             .choice(true, "e")
@@ -685,4 +1448,31 @@ mod tests {
             ])
         );
     }
+
+    #[test]
+    fn condition_choices_case() {
+        let mut item: bool = false;
+        let (condition, choice_keys) = Condition::new(Scalar::new(&mut item), "item")
+            .choice(true, "b")
+            .choice(false, "d")
+            .choices_case(Case::Upper)
+            .consume();
+        let argument = condition.consume();
+
+        assert_eq!(
+            argument.choices,
+            HashMap::from([
+                ("TRUE".to_string(), "b".to_string()),
+                ("FALSE".to_string(), "d".to_string())
+            ])
+        );
+        assert_eq!(choice_keys.key("true"), "TRUE".to_string());
+        assert_eq!(
+            *choice_keys.aliases.borrow(),
+            HashMap::from([
+                ("TRUE".to_string(), "true".to_string()),
+                ("FALSE".to_string(), "false".to_string())
+            ])
+        );
+    }
 }
@@ -1,3 +1,11 @@
 pub(crate) const HELP_NAME: &'static str = "help";
 pub(crate) const HELP_SHORT: char = 'h';
 pub(crate) const HELP_MESSAGE: &'static str = "Show this help message and exit.";
+pub(crate) const USAGE_NAME: &'static str = "usage";
+pub(crate) const USAGE_MESSAGE: &'static str = "Show the usage message and exit.";
+pub(crate) const VERSION_NAME: &'static str = "version";
+pub(crate) const VERSION_MESSAGE: &'static str = "Show the version and exit.";
+pub(crate) const QUIET_NAME: &'static str = "quiet";
+pub(crate) const QUIET_MESSAGE: &'static str = "Suppress warnings and notices.";
+pub(crate) const TERMINATOR: &'static str = "--";
+pub(crate) const EMPTY_TOKEN: &'static str = "\"\"";
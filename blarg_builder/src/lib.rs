@@ -10,8 +10,11 @@ mod parser;
 pub mod prelude;
 
 pub use api::*;
+pub use matcher::MatchError;
 pub use model::*;
-pub use parser::GeneralParser;
+pub use parser::{
+    ConfigWarning, ErrorContext, GeneralParser, ParseError, ParseFailure, ParseOutcome, ParserParts,
+};
 
 #[cfg(test)]
 #[macro_use]
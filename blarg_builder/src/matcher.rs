@@ -3,5 +3,6 @@ mod core;
 mod model;
 
 pub(crate) use self::api::*;
+pub use self::core::MatchError;
 pub(crate) use self::core::*;
 pub(crate) use self::model::*;
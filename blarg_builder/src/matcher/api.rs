@@ -1,6 +1,19 @@
+use std::collections::HashMap;
+
+use crate::constant::EMPTY_TOKEN;
 use crate::matcher::MatchTokens;
 use crate::model::Nargs;
 
+/// The width of the `token` towards the offsets, where an empty token (ex: from `program ""`) is shown as `""`.
+/// This way, each token (including an empty one) occupies a distinct offset in the error context.
+pub(crate) fn token_width(token: &str) -> usize {
+    if token.is_empty() {
+        EMPTY_TOKEN.len()
+    } else {
+        token.len()
+    }
+}
+
 pub(crate) type OffsetValue = (usize, String);
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -15,6 +28,12 @@ impl From<Nargs> for Bound {
             Nargs::Precisely(n) => Bound::Range(n, n),
             Nargs::Any => Bound::Lower(0),
             Nargs::AtLeastOne => Bound::Lower(1),
+            Nargs::ZeroOrOne => Bound::Range(0, 1),
+            // The matcher counts values in a u8, so saturate the larger bounds (which are rejected when the parser is built).
+            Nargs::Range(min, max) => Bound::Range(
+                u8::try_from(min).unwrap_or(u8::MAX),
+                u8::try_from(max).unwrap_or(u8::MAX),
+            ),
         }
     }
 }
@@ -47,6 +66,8 @@ mod test {
 pub(crate) struct ArgumentConfig {
     name: String,
     bound: Bound,
+    terminator: bool,
+    group: u8,
 }
 
 impl ArgumentConfig {
@@ -54,9 +75,23 @@ impl ArgumentConfig {
         Self {
             name: name.into(),
             bound,
+            terminator: false,
+            group: 1,
         }
     }
 
+    /// Match the `--` token as a value of this argument, after which all tokens are matched as its values.
+    pub(crate) fn with_terminator(mut self, terminator: bool) -> Self {
+        self.terminator = terminator;
+        self
+    }
+
+    /// Require the values of this argument to form complete groups (a multiple of `group` values).
+    pub(crate) fn with_group(mut self, group: u8) -> Self {
+        self.group = group;
+        self
+    }
+
     pub(crate) fn name(&self) -> &str {
         &self.name
     }
@@ -64,13 +99,24 @@ impl ArgumentConfig {
     pub(crate) fn bound(&self) -> Bound {
         self.bound
     }
+
+    pub(crate) fn terminator(&self) -> bool {
+        self.terminator
+    }
+
+    pub(crate) fn group(&self) -> u8 {
+        self.group
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub(crate) struct OptionConfig {
     name: String,
     short: Option<char>,
+    short_aliases: Vec<char>,
     bound: Bound,
+    repeatable: bool,
+    rest: bool,
 }
 
 impl OptionConfig {
@@ -78,10 +124,30 @@ impl OptionConfig {
         Self {
             name: name.into(),
             short,
+            short_aliases: Vec::default(),
             bound,
+            repeatable: false,
+            rest: false,
         }
     }
 
+    pub(crate) fn with_short_aliases(mut self, short_aliases: Vec<char>) -> Self {
+        self.short_aliases = short_aliases;
+        self
+    }
+
+    /// Match this option upon each of its occurrences, rather than rejecting the repeats.
+    pub(crate) fn with_repeatable(mut self, repeatable: bool) -> Self {
+        self.repeatable = repeatable;
+        self
+    }
+
+    /// Match all the subsequent tokens as the values of this option, once matched (even those which look like options).
+    pub(crate) fn with_rest(mut self, rest: bool) -> Self {
+        self.rest = rest;
+        self
+    }
+
     pub(crate) fn name(&self) -> &str {
         &self.name
     }
@@ -90,14 +156,28 @@ impl OptionConfig {
         &self.short
     }
 
+    pub(crate) fn short_aliases(&self) -> &[char] {
+        &self.short_aliases
+    }
+
     pub(crate) fn bound(&self) -> Bound {
         self.bound
     }
+
+    pub(crate) fn repeatable(&self) -> bool {
+        self.repeatable
+    }
+
+    pub(crate) fn rest(&self) -> bool {
+        self.rest
+    }
 }
 
 #[derive(Debug, PartialEq, Eq)]
 pub(crate) struct Matches {
     pub values: Vec<MatchTokens>,
+    /// The flag spelling (ex: `-v` or `--verbose`) with which each option was matched, by option name.
+    pub spellings: HashMap<String, String>,
 }
 
 #[cfg(test)]
@@ -111,5 +191,7 @@ mod tests {
         assert_eq!(Bound::from(Nargs::Precisely(1)), Bound::Range(1, 1));
         assert_eq!(Bound::from(Nargs::Any), Bound::Lower(0));
         assert_eq!(Bound::from(Nargs::AtLeastOne), Bound::Lower(1));
+        assert_eq!(Bound::from(Nargs::ZeroOrOne), Bound::Range(0, 1));
+        assert_eq!(Bound::from(Nargs::Range(2, 4)), Bound::Range(2, 4));
     }
 }
@@ -13,22 +13,45 @@ pub(crate) enum TokenMatcherError {
     DuplicateShortOption(char),
 }
 
+/// An error from aligning the input tokens to the arguments and options.
 #[derive(Debug, Error, PartialEq, Eq)]
-pub(crate) enum MatchError {
+#[non_exhaustive]
+pub enum MatchError {
+    /// Too few tokens were provided to the named parameter.
     #[error("not enough tokens provided to parameter '{0}'.")]
     Undercomplete(String),
 
+    /// Too many tokens were provided to the named parameter.
     #[error("too many tokens provided to parameter '{0}'.")]
     Overcomplete(String),
 
+    /// A token was provided after all the arguments were filled.
     #[error("no more arguments to match against.")]
     ArgumentsExhausted,
 
+    /// The named option does not exist.
     #[error("option '{0}' does not exist.")]
     InvalidOption(String),
 
+    /// The short option does not exist.
     #[error("short option '{0}' does not exist.")]
     InvalidShortOption(char),
+
+    /// None of the named options in a required group were provided.
+    #[error("at least one of {} is required.", flags(.0))]
+    RequiredGroup(Vec<String>),
+
+    /// The named required option was not provided.
+    #[error("required option '--{0}' was not provided.")]
+    RequiredOption(String),
+}
+
+fn flags(names: &[String]) -> String {
+    names
+        .iter()
+        .map(|name| format!("--{name}"))
+        .collect::<Vec<String>>()
+        .join(", ")
 }
 
 impl From<CloseError> for MatchError {
@@ -48,10 +71,23 @@ impl From<CloseError> for MatchError {
 pub(crate) struct TokenMatcher {
     option_bounds: HashMap<String, Bound>,
     short_options: HashMap<char, String>,
+    shorts: HashSet<char>,
+    repeatable: HashSet<String>,
+    rest: HashSet<String>,
     arguments: VecDeque<ArgumentConfig>,
     fed: usize,
     matches: Vec<MatchTokens>,
     buffer: Option<MatchBuffer>,
+    negative_numbers: bool,
+    options_before_positionals: bool,
+    sentinel: Option<String>,
+    digit_shorts: HashSet<char>,
+    argument_matched: bool,
+    spellings: HashMap<String, String>,
+    argument_terminators: HashSet<String>,
+    terminated: bool,
+    skip_unknown: bool,
+    unknown: Vec<String>,
 }
 
 impl TokenMatcher {
@@ -59,41 +95,117 @@ impl TokenMatcher {
         options: HashSet<OptionConfig>,
         arguments: VecDeque<ArgumentConfig>,
     ) -> Result<Self, TokenMatcherError> {
-        let mut option_bounds = HashMap::default();
-        let mut short_options = HashMap::default();
+        let argument_terminators = arguments
+            .iter()
+            .filter(|argument_config| argument_config.terminator())
+            .map(|argument_config| argument_config.name().to_string())
+            .collect();
+        let mut token_matcher = Self {
+            option_bounds: HashMap::default(),
+            short_options: HashMap::default(),
+            shorts: HashSet::default(),
+            repeatable: HashSet::default(),
+            rest: HashSet::default(),
+            arguments,
+            fed: 0,
+            matches: Vec::default(),
+            buffer: None,
+            negative_numbers: false,
+            options_before_positionals: false,
+            sentinel: None,
+            digit_shorts: HashSet::default(),
+            argument_matched: false,
+            spellings: HashMap::default(),
+            argument_terminators,
+            terminated: false,
+            skip_unknown: false,
+            unknown: Vec::default(),
+        };
 
         for option_config in options.into_iter() {
-            if option_bounds
-                .insert(option_config.name().to_string(), option_config.bound())
+            token_matcher.register(option_config)?;
+        }
+
+        Ok(token_matcher)
+    }
+
+    /// Add an option to be matched, in addition to those specified at construction.
+    pub(crate) fn with_option(
+        mut self,
+        option_config: OptionConfig,
+    ) -> Result<Self, TokenMatcherError> {
+        self.register(option_config)?;
+        Ok(self)
+    }
+
+    /// Match tokens which begin with a `-` followed by a digit (ex: `-5`) as values, unless the digit is a short option.
+    pub(crate) fn with_negative_numbers(mut self) -> Self {
+        self.negative_numbers = true;
+        self
+    }
+
+    /// Match all tokens against the arguments once an argument has been matched, so that options must precede the positionals.
+    pub(crate) fn with_options_before_positionals(mut self) -> Self {
+        self.options_before_positionals = true;
+        self
+    }
+
+    /// Match the `sentinel` token as the end of the current parameter's values (ex: `a b ; c d`), rather than as a value.
+    pub(crate) fn with_sentinel(mut self, sentinel: impl Into<String>) -> Self {
+        self.sentinel = Some(sentinel.into());
+        self
+    }
+
+    /// Skip over the tokens of unknown options (rather than rejecting them), recording them for [`TokenMatcher::take_unknown`].
+    pub(crate) fn with_skip_unknown(mut self) -> Self {
+        self.skip_unknown = true;
+        self
+    }
+
+    fn register(&mut self, option_config: OptionConfig) -> Result<(), TokenMatcherError> {
+        if self
+            .option_bounds
+            .insert(option_config.name().to_string(), option_config.bound())
+            .is_some()
+        {
+            return Err(TokenMatcherError::DuplicateOption(
+                option_config.name().to_ascii_uppercase(),
+            ));
+        }
+
+        if option_config.repeatable() {
+            self.repeatable.insert(option_config.name().to_string());
+        }
+
+        if option_config.rest() {
+            self.rest.insert(option_config.name().to_string());
+        }
+
+        for short in option_config
+            .short()
+            .iter()
+            .chain(option_config.short_aliases().iter())
+        {
+            if self
+                .short_options
+                .insert(short.clone(), option_config.name().to_string())
                 .is_some()
             {
-                return Err(TokenMatcherError::DuplicateOption(
-                    option_config.name().to_ascii_uppercase(),
-                ));
+                return Err(TokenMatcherError::DuplicateShortOption(short.clone()));
             }
 
-            if let Some(short) = option_config.short() {
-                if short_options
-                    .insert(short.clone(), option_config.name().to_string())
-                    .is_some()
-                {
-                    return Err(TokenMatcherError::DuplicateShortOption(short.clone()));
-                }
+            self.shorts.insert(*short);
+
+            if short.is_ascii_digit() {
+                self.digit_shorts.insert(*short);
             }
         }
 
-        Ok(Self {
-            option_bounds,
-            short_options,
-            arguments,
-            fed: 0,
-            matches: Vec::default(),
-            buffer: None,
-        })
+        Ok(())
     }
 
     pub(crate) fn feed(&mut self, token: &str) -> Result<(), MatchError> {
-        let token_length = token.len();
+        let token_length = token_width(token);
         // 1. Find a 'long' flag, such as:
         //  --initial
         //  --initial ..
@@ -106,19 +218,86 @@ impl TokenMatcher {
         //  -iv..
         //  -iv ..
         //  -iv=..
-        // 3. Match against an argument.
-        let result = if let Some(token) = token.strip_prefix("--") {
-            self.match_option(split_equals_delimiter(token))
-        } else if let Some(token) = token.strip_prefix("-") {
-            self.match_option_short(split_equals_delimiter(token))
-        } else {
-            self.match_argument(token)
+        // 3. Match against an argument (including negative numbers, when enabled).
+        // 4. After the `--` token, match all tokens against the arguments (the `--` itself is consumed, unless an argument accepts it as a value).
+        // 5. When skipping unknown options, record the unknown option token without matching it.
+        // 6. When options must precede the positionals, match all tokens after the first argument against the arguments.
+        // 7. When a sentinel is configured, end the current parameter's values upon the sentinel token.
+        // 8. After an option which takes the rest has been matched, match all tokens against the option.
+        let result = match Token::normalize(token) {
+            _ if self.terminated => self.match_argument(token),
+            Token::Long("", None) if self.accepts_terminator() => {
+                self.terminated = true;
+                self.match_argument(token)
+            }
+            _ if self.sentinel.as_deref() == Some(token) => self.update_buffer(None),
+            _ if self.options_before_positionals && self.argument_matched => {
+                self.match_argument(token)
+            }
+            Token::Long("", None) => self.terminate(),
+            Token::Short(names, _) if self.is_negative_number(names) => self.match_argument(token),
+            Token::Long(name, _) if self.skip_unknown && self.is_unknown(name) => {
+                self.unknown.push(token.to_string());
+                Ok(())
+            }
+            Token::Short(names, _)
+                if self.skip_unknown && names.chars().any(|c| !self.shorts.contains(&c)) =>
+            {
+                self.unknown.push(token.to_string());
+                Ok(())
+            }
+            Token::Long(name, value) => self.match_option((name, value)),
+            Token::Short(names, value) => self.match_option_short((names, value)),
+            Token::Value(value) => self.match_argument(value),
         };
 
         self.fed += token_length;
         result
     }
 
+    /// Whether the argument to match the next value accepts the `--` token.
+    fn accepts_terminator(&self) -> bool {
+        match &self.buffer {
+            Some(match_buffer) if match_buffer.is_open() => {
+                self.argument_terminators.contains(match_buffer.name())
+            }
+            _ => self
+                .arguments
+                .front()
+                .map(|argument_config| argument_config.terminator())
+                .unwrap_or(false),
+        }
+    }
+
+    /// End the options at the `--` token, so that all subsequent tokens are matched against the arguments.
+    fn terminate(&mut self) -> Result<(), MatchError> {
+        self.terminated = true;
+
+        match &self.buffer {
+            // The values of an option end at the `--`, whereas those of an argument continue after it.
+            Some(match_buffer) if self.spellings.contains_key(match_buffer.name()) => {
+                self.update_buffer(None)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Whether the long option `name` is neither registered, nor already matched.
+    fn is_unknown(&self, name: &str) -> bool {
+        !name.is_empty()
+            && !self.option_bounds.contains_key(name)
+            && !self.spellings.contains_key(name)
+    }
+
+    fn is_negative_number(&self, short_names: &str) -> bool {
+        self.negative_numbers
+            && short_names
+                .chars()
+                .next()
+                .map(|c| c.is_ascii_digit() && !self.digit_shorts.contains(&c))
+                .unwrap_or(false)
+    }
+
     fn match_argument(&mut self, token: &str) -> Result<(), MatchError> {
         let mut match_buffer = match self.buffer.take() {
             Some(match_buffer) => {
@@ -150,19 +329,68 @@ impl TokenMatcher {
 
     fn next_argument(&mut self) -> Result<MatchBuffer, MatchError> {
         match self.arguments.pop_front() {
-            Some(argument_config) => Ok(MatchBuffer::new(
-                argument_config.name(),
-                argument_config.bound(),
-            )),
+            Some(argument_config) => {
+                self.argument_matched = true;
+                Ok(
+                    MatchBuffer::new(argument_config.name(), argument_config.bound())
+                        .with_group(argument_config.group()),
+                )
+            }
             None => Err(MatchError::ArgumentsExhausted),
         }
     }
 
+    /// Take the unknown option tokens (as is) which have been skipped over.
+    pub(crate) fn take_unknown(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.unknown)
+    }
+
+    /// Whether any token has been matched to an argument (rather than an option).
+    pub(crate) fn argument_matched(&self) -> bool {
+        self.argument_matched
+    }
+
+    /// The arguments to be matched, in declaration order.
+    pub(crate) fn arguments(&self) -> &VecDeque<ArgumentConfig> {
+        &self.arguments
+    }
+
+    /// The bound of each option to be matched (by name).
+    pub(crate) fn option_bounds(&self) -> &HashMap<String, Bound> {
+        &self.option_bounds
+    }
+
+    /// The option name of each short option (including the aliases).
+    pub(crate) fn short_options(&self) -> &HashMap<char, String> {
+        &self.short_options
+    }
+
+    /// Whether tokens which begin with a `-` followed by a digit may be matched as values.
+    pub(crate) fn has_negative_numbers(&self) -> bool {
+        self.negative_numbers
+    }
+
+    /// Whether a sentinel token ends the current parameter's values.
+    pub(crate) fn has_sentinel(&self) -> bool {
+        self.sentinel.is_some()
+    }
+
+    /// Take the bound of the option `name` for a match, leaving a repeatable option in place for its next occurrence.
+    fn take_bound(&mut self, name: &str) -> Option<Bound> {
+        if self.repeatable.contains(name) {
+            self.option_bounds.get(name).copied()
+        } else {
+            self.option_bounds.remove(name)
+        }
+    }
+
     fn match_option(
         &mut self,
         (option_name, single_argument): (&str, Option<&str>),
     ) -> Result<(), MatchError> {
-        if let Some(bound) = self.option_bounds.remove(option_name) {
+        if let Some(bound) = self.take_bound(option_name) {
+            self.spellings
+                .insert(option_name.to_string(), format!("--{option_name}"));
             let mut match_buffer = MatchBuffer::new(option_name.to_string(), bound);
 
             let next_buffer = match single_argument {
@@ -175,7 +403,10 @@ impl TokenMatcher {
                     self.matches.push(match_tokens);
                     None
                 }
-                None => Some(match_buffer),
+                None => {
+                    self.terminated = self.rest.contains(option_name);
+                    Some(match_buffer)
+                }
             };
             self.update_buffer(next_buffer)
         } else {
@@ -188,8 +419,10 @@ impl TokenMatcher {
         (short_option_name, single_argument): (&str, Option<&str>),
     ) -> Result<(), MatchError> {
         for (index, single) in short_option_name.chars().enumerate() {
-            if let Some(name) = self.short_options.get(&single) {
-                if let Some(bound) = self.option_bounds.remove(name) {
+            if let Some(name) = self.short_options.get(&single).cloned() {
+                if let Some(bound) = self.take_bound(&name) {
+                    self.spellings.insert(name.clone(), format!("-{single}"));
+
                     // If this is the final character from the short option token (the variable 'short_option_name').
                     if index + 1 == short_option_name.len() {
                         // Only the final option may accept values.
@@ -210,6 +443,7 @@ impl TokenMatcher {
                             }
                             // If no equals delimited value was specified, allow the values to be fed as subsequent tokens.
                             None => {
+                                self.terminated = self.rest.contains(&name);
                                 self.update_buffer(Some(match_buffer))?;
                             }
                         };
@@ -219,12 +453,15 @@ impl TokenMatcher {
                         self.matches.push(match_tokens);
                     }
                 } else {
-                    unreachable!("internal error - mis-aligned short option.");
+                    // The option has already been matched (ex: via its long name or another short alias).
+                    return Err(MatchError::InvalidOption(name.to_ascii_uppercase()));
                 }
 
-                self.short_options
-                    .remove(&single)
-                    .expect("internal error - must be able to remove the selected short option");
+                if !self.repeatable.contains(&name) {
+                    self.short_options.remove(&single).expect(
+                        "internal error - must be able to remove the selected short option",
+                    );
+                }
             } else {
                 return Err(MatchError::InvalidShortOption(single));
             }
@@ -252,7 +489,8 @@ impl TokenMatcher {
         }
 
         for argument_config in &self.arguments {
-            let match_buffer = MatchBuffer::new(argument_config.name(), argument_config.bound());
+            let match_buffer = MatchBuffer::new(argument_config.name(), argument_config.bound())
+                .with_group(argument_config.group());
             if !match_buffer.can_close() {
                 return false;
             }
@@ -276,7 +514,8 @@ impl TokenMatcher {
         }
 
         for argument_config in self.arguments {
-            let match_buffer = MatchBuffer::new(argument_config.name(), argument_config.bound());
+            let match_buffer = MatchBuffer::new(argument_config.name(), argument_config.bound())
+                .with_group(argument_config.group());
             match match_buffer.close() {
                 Ok(match_tokens) => {
                     self.matches.push(match_tokens);
@@ -292,6 +531,7 @@ impl TokenMatcher {
 
         let matches = Matches {
             values: self.matches,
+            spellings: self.spellings,
         };
 
         if let Some(error) = close_error {
@@ -302,6 +542,35 @@ impl TokenMatcher {
     }
 }
 
+/// The normalized form of a single input token.
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'t> {
+    /// A long option: `--NAME` or `--NAME=VALUE`.
+    Long(&'t str, Option<&'t str>),
+    /// One or more short options: `-N`, `-NMO`, `-N=VALUE`, or `-NMO=VALUE`.
+    Short(&'t str, Option<&'t str>),
+    /// Any other token, including the bare `-` (ex: a conventional reference to stdin).
+    Value(&'t str),
+}
+
+impl<'t> Token<'t> {
+    fn normalize(token: &'t str) -> Self {
+        if let Some(long) = token.strip_prefix("--") {
+            let (name, value) = split_equals_delimiter(long);
+            Token::Long(name, value)
+        } else if let Some(short) = token.strip_prefix("-") {
+            match split_equals_delimiter(short) {
+                // Without any short names, this is not an option.
+                ("", _) => Token::Value(token),
+                (names, value) => Token::Short(names, value),
+            }
+        } else {
+            Token::Value(token)
+        }
+    }
+}
+
+/// Only the first `=` is a separator; any subsequent `=` belong to the value.
 fn split_equals_delimiter(token: &str) -> (&str, Option<&str>) {
     match token.split_once("=") {
         Some((n, v)) => (n, Some(v)),
@@ -318,6 +587,7 @@ impl Matches {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::model::Nargs;
     use rstest::rstest;
 
     #[test]
@@ -382,6 +652,50 @@ mod tests {
         }
     }
 
+    #[test]
+    fn option_short_aliases() {
+        let options =
+            HashSet::from([OptionConfig::new("quiet", None, Bound::Range(0, 0))
+                .with_short_aliases(vec!['q', 's'])]);
+
+        for token in ["-q", "-s"] {
+            let mut tp = TokenMatcher::new(options.clone(), VecDeque::default()).unwrap();
+            tp.feed(token).unwrap();
+            let matches = tp.close().unwrap();
+            assert!(matches.contains("quiet"));
+        }
+    }
+
+    #[test]
+    fn option_short_aliases_duplicate() {
+        let options = HashSet::from([
+            OptionConfig::new("quiet", Some('q'), Bound::Range(0, 0)).with_short_aliases(vec!['s']),
+            OptionConfig::new("size", Some('s'), Bound::Lower(0)),
+        ]);
+        let error = TokenMatcher::new(options, VecDeque::default()).unwrap_err();
+        assert_eq!(error, TokenMatcherError::DuplicateShortOption('s'));
+    }
+
+    #[rstest]
+    #[case(vec!["-q", "-s"])]
+    #[case(vec!["-qs"])]
+    #[case(vec!["--quiet", "-s"])]
+    fn option_short_aliases_repeat(#[case] tokens: Vec<&str>) {
+        let options =
+            HashSet::from([OptionConfig::new("quiet", None, Bound::Range(0, 0))
+                .with_short_aliases(vec!['q', 's'])]);
+        let mut tp = TokenMatcher::new(options, VecDeque::default()).unwrap();
+
+        let (last, head) = tokens.split_last().unwrap();
+        for token in head {
+            tp.feed(token).unwrap();
+        }
+        assert_eq!(
+            tp.feed(last).unwrap_err(),
+            MatchError::InvalidOption("QUIET".to_string())
+        );
+    }
+
     #[rstest]
     #[case(Bound::Lower(0), 0, true)]
     #[case(Bound::Lower(0), 1, true)]
@@ -491,6 +805,36 @@ mod tests {
         );
     }
 
+    #[rstest]
+    #[case(vec!["--mode", "--mode"], vec![0, 6])]
+    #[case(vec!["-m", "--mode", "-m"], vec![0, 2, 8])]
+    #[case(vec!["-mm", "-m"], vec![0, 0, 3])]
+    fn option_repeatable(#[case] tokens: Vec<&str>, #[case] expected_offsets: Vec<usize>) {
+        // Setup
+        let options = HashSet::from([
+            OptionConfig::new("mode", Some('m'), Bound::Range(0, 0)).with_repeatable(true)
+        ]);
+        let mut tp = TokenMatcher::new(options, VecDeque::default()).unwrap();
+
+        // Execute
+        for token in tokens.iter() {
+            tp.feed(token).unwrap();
+        }
+        let matches = tp.close().unwrap();
+
+        // Verify
+        assert_eq!(
+            matches.values,
+            expected_offsets
+                .iter()
+                .map(|_| MatchTokens {
+                    name: "mode".to_string(),
+                    values: Vec::default(),
+                })
+                .collect::<Vec<MatchTokens>>()
+        );
+    }
+
     #[rstest]
     #[case(vec!["-v"], true, None)]
     #[case(vec!["-f"], false, Some(vec![]))]
@@ -541,6 +885,494 @@ mod tests {
         };
     }
 
+    #[rstest]
+    #[case("--key", Token::Long("key", None))]
+    #[case("--key=", Token::Long("key", Some("")))]
+    #[case("--key=val", Token::Long("key", Some("val")))]
+    #[case("--key=123=456", Token::Long("key", Some("123=456")))]
+    #[case("-k", Token::Short("k", None))]
+    #[case("-k=", Token::Short("k", Some("")))]
+    #[case("-k=val", Token::Short("k", Some("val")))]
+    #[case("-k=123=456", Token::Short("k", Some("123=456")))]
+    #[case("-abc", Token::Short("abc", None))]
+    #[case("-abc=val", Token::Short("abc", Some("val")))]
+    #[case("val", Token::Value("val"))]
+    #[case("123=456", Token::Value("123=456"))]
+    #[case("-", Token::Value("-"))]
+    #[case("-=val", Token::Value("-=val"))]
+    fn token_normalize(#[case] token: &str, #[case] expected: Token) {
+        assert_eq!(Token::normalize(token), expected);
+    }
+
+    // The documented matching semantics (see the crate docs, footnotes #3).
+    #[rstest]
+    #[case(vec!["--key=val"], vec![("key", vec!["val"])])]
+    #[case(vec!["--key", "val"], vec![("key", vec!["val"])])]
+    #[case(vec!["-k=val"], vec![("key", vec!["val"])])]
+    #[case(vec!["-k", "val"], vec![("key", vec!["val"])])]
+    #[case(vec!["--key="], vec![("key", vec![""])])]
+    #[case(vec!["-k="], vec![("key", vec![""])])]
+    #[case(vec!["--key=123=456"], vec![("key", vec!["123=456"])])]
+    #[case(vec!["--key", "123=456"], vec![("key", vec!["123=456"])])]
+    #[case(vec!["-k=123=456"], vec![("key", vec!["123=456"])])]
+    #[case(vec!["-k", "123=456"], vec![("key", vec!["123=456"])])]
+    #[case(vec!["--greedy", "x", "y"], vec![("greedy", vec!["x", "y"])])]
+    #[case(vec!["-g", "x", "y"], vec![("greedy", vec!["x", "y"])])]
+    #[case(vec!["--greedy=x", "y", "z"], vec![("greedy", vec!["x"]), ("rest", vec!["y", "z"])])]
+    #[case(vec!["-g=x", "y", "z"], vec![("greedy", vec!["x"]), ("rest", vec!["y", "z"])])]
+    #[case(vec!["-ab"], vec![("apple", vec![]), ("banana", vec![])])]
+    #[case(vec!["-abk=val"], vec![("apple", vec![]), ("banana", vec![]), ("key", vec!["val"])])]
+    #[case(vec!["-abk", "val"], vec![("apple", vec![]), ("banana", vec![]), ("key", vec!["val"])])]
+    #[case(vec!["-abk=1=2"], vec![("apple", vec![]), ("banana", vec![]), ("key", vec!["1=2"])])]
+    #[case(vec!["-abg=x", "y"], vec![("apple", vec![]), ("banana", vec![]), ("greedy", vec!["x"]), ("rest", vec!["y"])])]
+    #[case(vec!["-abg", "x", "y"], vec![("apple", vec![]), ("banana", vec![]), ("greedy", vec!["x", "y"])])]
+    #[case(vec!["-agb"], vec![("apple", vec![]), ("greedy", vec![]), ("banana", vec![])])]
+    #[case(vec!["-"], vec![("rest", vec!["-"])])]
+    #[case(vec!["x=1", "-"], vec![("rest", vec!["x=1", "-"])])]
+    fn equals_space_matrix(#[case] tokens: Vec<&str>, #[case] expected: Vec<(&str, Vec<&str>)>) {
+        // Setup
+        let options = HashSet::from([
+            OptionConfig::new("apple", Some('a'), Bound::Range(0, 0)),
+            OptionConfig::new("banana", Some('b'), Bound::Range(0, 0)),
+            OptionConfig::new("key", Some('k'), Bound::Range(1, 1)),
+            OptionConfig::new("greedy", Some('g'), Bound::Lower(0)),
+        ]);
+        let arguments = VecDeque::from([ArgumentConfig::new("rest", Bound::Lower(0))]);
+        let mut tp = TokenMatcher::new(options, arguments).unwrap();
+
+        // Execute
+        for token in &tokens {
+            tp.feed(token).unwrap();
+        }
+        let matches = tp.close().unwrap();
+
+        // Verify
+        // The greedy argument always closes, so only include it when it captured values.
+        let actual: Vec<(&str, Vec<&str>)> = matches
+            .values
+            .iter()
+            .filter(|mt| !(mt.name == "rest" && mt.values.is_empty()))
+            .map(|mt| {
+                (
+                    mt.name.as_str(),
+                    mt.values.iter().map(|(_, v)| v.as_str()).collect(),
+                )
+            })
+            .collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case(vec!["--apple=x"], MatchError::Overcomplete("APPLE".to_string()))]
+    #[case(vec!["-a=x"], MatchError::Overcomplete("APPLE".to_string()))]
+    #[case(vec!["-ka=val"], MatchError::Undercomplete("KEY".to_string()))]
+    #[case(vec!["-kb"], MatchError::Undercomplete("KEY".to_string()))]
+    #[case(vec!["--key=val", "x", "--key"], MatchError::InvalidOption("KEY".to_string()))]
+    #[case(vec!["-k=val", "-k"], MatchError::InvalidShortOption('k'))]
+    fn equals_space_matrix_invalid(#[case] tokens: Vec<&str>, #[case] expected: MatchError) {
+        // Setup
+        let options = HashSet::from([
+            OptionConfig::new("apple", Some('a'), Bound::Range(0, 0)),
+            OptionConfig::new("banana", Some('b'), Bound::Range(0, 0)),
+            OptionConfig::new("key", Some('k'), Bound::Range(1, 1)),
+            OptionConfig::new("greedy", Some('g'), Bound::Lower(0)),
+        ]);
+        let arguments = VecDeque::from([ArgumentConfig::new("rest", Bound::Lower(0))]);
+        let mut tp = TokenMatcher::new(options, arguments).unwrap();
+        let (last, head) = tokens.split_last().unwrap();
+
+        // Execute
+        for token in head {
+            tp.feed(token).unwrap();
+        }
+        let error = tp.feed(last).unwrap_err();
+
+        // Verify
+        assert_eq!(error, expected);
+    }
+
+    #[rstest]
+    #[case(vec!["-5"], vec![("rest", vec!["-5"])])]
+    #[case(vec!["-30m", "-2.5"], vec![("rest", vec!["-30m", "-2.5"])])]
+    #[case(vec!["--offset", "-30m"], vec![("offset", vec!["-30m"])])]
+    #[case(vec!["--offset", "-3", "-4"], vec![("offset", vec!["-3"]), ("rest", vec!["-4"])])]
+    #[case(vec!["-o", "-30m"], vec![("offset", vec!["-30m"])])]
+    #[case(vec!["-1"], vec![("one", vec![])])]
+    #[case(vec!["-1", "-2"], vec![("one", vec![]), ("rest", vec!["-2"])])]
+    fn negative_numbers(#[case] tokens: Vec<&str>, #[case] expected: Vec<(&str, Vec<&str>)>) {
+        // Setup
+        let options = HashSet::from([
+            OptionConfig::new("offset", Some('o'), Bound::Range(1, 1)),
+            OptionConfig::new("one", Some('1'), Bound::Range(0, 0)),
+        ]);
+        let arguments = VecDeque::from([ArgumentConfig::new("rest", Bound::Lower(0))]);
+        let mut tp = TokenMatcher::new(options, arguments)
+            .unwrap()
+            .with_negative_numbers();
+
+        // Execute
+        for token in &tokens {
+            tp.feed(token).unwrap();
+        }
+        let matches = tp.close().unwrap();
+
+        // Verify
+        let actual: Vec<(&str, Vec<&str>)> = matches
+            .values
+            .iter()
+            .filter(|mt| !(mt.name == "rest" && mt.values.is_empty()))
+            .map(|mt| {
+                (
+                    mt.name.as_str(),
+                    mt.values.iter().map(|(_, v)| v.as_str()).collect(),
+                )
+            })
+            .collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn negative_numbers_digit_short() {
+        // Setup
+        let options = HashSet::from([OptionConfig::new("one", Some('1'), Bound::Range(0, 0))]);
+        let arguments = VecDeque::from([ArgumentConfig::new("rest", Bound::Lower(0))]);
+        let mut tp = TokenMatcher::new(options, arguments)
+            .unwrap()
+            .with_negative_numbers();
+        tp.feed("-1").unwrap();
+
+        // Execute
+        let error = tp.feed("-12").unwrap_err();
+
+        // Verify
+        assert_eq!(error, MatchError::InvalidShortOption('1'));
+    }
+
+    #[test]
+    fn negative_numbers_disabled() {
+        // Setup
+        let arguments = VecDeque::from([ArgumentConfig::new("rest", Bound::Lower(0))]);
+        let mut tp = TokenMatcher::new(HashSet::default(), arguments).unwrap();
+
+        // Execute
+        let error = tp.feed("-5").unwrap_err();
+
+        // Verify
+        assert_eq!(error, MatchError::InvalidShortOption('5'));
+    }
+
+    #[rstest]
+    #[case(vec!["-v", "a"], vec![("verbose", vec![]), ("rest", vec!["a"])])]
+    #[case(vec!["a", "-v"], vec![("rest", vec!["a", "-v"])])]
+    #[case(vec!["a", "--verbose", "b"], vec![("rest", vec!["a", "--verbose", "b"])])]
+    #[case(vec!["-v", "a", "--moot"], vec![("verbose", vec![]), ("rest", vec!["a", "--moot"])])]
+    fn options_before_positionals(
+        #[case] tokens: Vec<&str>,
+        #[case] expected: Vec<(&str, Vec<&str>)>,
+    ) {
+        // Setup
+        let options = HashSet::from([OptionConfig::new("verbose", Some('v'), Bound::Range(0, 0))]);
+        let arguments = VecDeque::from([ArgumentConfig::new("rest", Bound::Lower(0))]);
+        let mut tp = TokenMatcher::new(options, arguments)
+            .unwrap()
+            .with_options_before_positionals();
+
+        // Execute
+        for token in &tokens {
+            tp.feed(token).unwrap();
+        }
+        let matches = tp.close().unwrap();
+
+        // Verify
+        let actual: Vec<(&str, Vec<&str>)> = matches
+            .values
+            .iter()
+            .map(|mt| {
+                (
+                    mt.name.as_str(),
+                    mt.values.iter().map(|(_, v)| v.as_str()).collect(),
+                )
+            })
+            .collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn options_before_positionals_exhausted() {
+        // Setup
+        let options = HashSet::from([OptionConfig::new("verbose", Some('v'), Bound::Range(0, 0))]);
+        let arguments = VecDeque::from([ArgumentConfig::new("item", Bound::Range(1, 1))]);
+        let mut tp = TokenMatcher::new(options, arguments)
+            .unwrap()
+            .with_options_before_positionals();
+        tp.feed("a").unwrap();
+
+        // Execute
+        let error = tp.feed("-v").unwrap_err();
+
+        // Verify
+        assert_eq!(error, MatchError::ArgumentsExhausted);
+    }
+
+    #[rstest]
+    #[case(vec![], vec![])]
+    #[case(vec!["x"], vec![])]
+    #[case(vec!["-v"], vec![("verbose", "-v")])]
+    #[case(vec!["-w"], vec![("verbose", "-w")])]
+    #[case(vec!["--verbose"], vec![("verbose", "--verbose")])]
+    #[case(vec!["-qv"], vec![("quiet", "-q"), ("verbose", "-v")])]
+    #[case(vec!["--quiet", "-w"], vec![("quiet", "--quiet"), ("verbose", "-w")])]
+    fn spellings(#[case] tokens: Vec<&str>, #[case] expected: Vec<(&str, &str)>) {
+        // Setup
+        let options = HashSet::from([
+            OptionConfig::new("verbose", Some('v'), Bound::Range(0, 0))
+                .with_short_aliases(vec!['w']),
+            OptionConfig::new("quiet", Some('q'), Bound::Range(0, 0)),
+        ]);
+        let arguments = VecDeque::from([ArgumentConfig::new("item", Bound::Lower(0))]);
+        let mut tp = TokenMatcher::new(options, arguments).unwrap();
+
+        // Execute
+        for token in tokens {
+            tp.feed(token).unwrap();
+        }
+
+        // Verify
+        let matches = tp.close().unwrap();
+        assert_eq!(
+            matches.spellings,
+            expected
+                .into_iter()
+                .map(|(name, spelling)| (name.to_string(), spelling.to_string()))
+                .collect::<HashMap<String, String>>()
+        );
+    }
+
+    #[rstest]
+    #[case(vec!["a", "b"], vec!["a", "b"])]
+    #[case(vec!["a", "b", "--", "c", "d"], vec!["a", "b", "--", "c", "d"])]
+    #[case(vec!["--", "-v", "--verbose"], vec!["--", "-v", "--verbose"])]
+    #[case(vec!["a", "--", "--"], vec!["a", "--", "--"])]
+    #[case(vec!["-v", "a", "--", "-v"], vec!["a", "--", "-v"])]
+    fn argument_terminator(#[case] tokens: Vec<&str>, #[case] expected: Vec<&str>) {
+        // Setup
+        let options = HashSet::from([OptionConfig::new("verbose", Some('v'), Bound::Range(0, 0))]);
+        let arguments =
+            VecDeque::from([ArgumentConfig::new("item", Bound::Lower(0)).with_terminator(true)]);
+        let mut tp = TokenMatcher::new(options, arguments).unwrap();
+
+        // Execute
+        for token in tokens {
+            tp.feed(token).unwrap();
+        }
+
+        // Verify
+        let matches = tp.close().unwrap();
+        let item = matches
+            .values
+            .into_iter()
+            .find(|match_tokens| match_tokens.name == "item")
+            .unwrap();
+        assert_eq!(
+            item.values
+                .into_iter()
+                .map(|(_, value)| value)
+                .collect::<Vec<String>>(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(vec!["--"], vec![])]
+    #[case(vec!["a", "--"], vec![(0, "a")])]
+    #[case(vec!["--", "-weird.txt"], vec![(2, "-weird.txt")])]
+    #[case(vec!["--", "--notanoption", "-v"], vec![(2, "--notanoption"), (15, "-v")])]
+    #[case(vec!["a", "--", "b"], vec![(0, "a"), (3, "b")])]
+    #[case(vec!["-v", "--", "--", "-v"], vec![(4, "--"), (6, "-v")])]
+    #[case(vec!["--values", "x", "--", "y"], vec![(11, "y")])]
+    #[case(vec!["--values", "--", "-x"], vec![(10, "-x")])]
+    fn end_of_options(#[case] tokens: Vec<&str>, #[case] expected: Vec<(usize, &str)>) {
+        // Setup
+        let options = HashSet::from([
+            OptionConfig::new("verbose", Some('v'), Bound::Range(0, 0)),
+            OptionConfig::new("values", None, Bound::Lower(0)),
+        ]);
+        let arguments = VecDeque::from([ArgumentConfig::new("item", Bound::Lower(0))]);
+        let mut tp = TokenMatcher::new(options, arguments).unwrap();
+
+        // Execute
+        for token in tokens {
+            tp.feed(token).unwrap();
+        }
+
+        // Verify
+        let matches = tp.close().unwrap();
+        let item = matches
+            .values
+            .into_iter()
+            .find(|match_tokens| match_tokens.name == "item")
+            .unwrap();
+        assert_eq!(
+            item.values,
+            expected
+                .into_iter()
+                .map(|(offset, value)| (offset, value.to_string()))
+                .collect::<Vec<(usize, String)>>()
+        );
+    }
+
+    #[test]
+    fn end_of_options_closes_option() {
+        // Setup
+        let options = HashSet::from([OptionConfig::new("values", None, Bound::Lower(1))]);
+        let arguments = VecDeque::from([ArgumentConfig::new("item", Bound::Range(1, 1))]);
+        let mut tp = TokenMatcher::new(options, arguments).unwrap();
+
+        // Execute
+        let result = ["--values", "--", "x"]
+            .into_iter()
+            .try_for_each(|token| tp.feed(token));
+
+        // Verify
+        assert_eq!(result, Err(MatchError::Undercomplete("VALUES".to_string())));
+    }
+
+    #[rstest]
+    #[case(vec!["--command", "ls"], vec![("command", vec!["ls"])])]
+    #[case(vec!["--command", "ls", "-la", "--all"], vec![("command", vec!["ls", "-la", "--all"])])]
+    #[case(vec!["-c", "ls", "-v", "--", "x"], vec![("command", vec!["ls", "-v", "--", "x"])])]
+    #[case(vec!["-vc", "ls", "-v"], vec![("verbose", vec![]), ("command", vec!["ls", "-v"])])]
+    #[case(vec!["a", "--command", "ls", "b"], vec![("item", vec!["a"]), ("command", vec!["ls", "b"])])]
+    fn option_rest(#[case] tokens: Vec<&str>, #[case] expected: Vec<(&str, Vec<&str>)>) {
+        // Setup
+        let options = HashSet::from([
+            OptionConfig::new("verbose", Some('v'), Bound::Range(0, 0)),
+            OptionConfig::new("command", Some('c'), Bound::Lower(1)).with_rest(true),
+        ]);
+        let arguments = VecDeque::from([ArgumentConfig::new("item", Bound::Lower(0))]);
+        let mut tp = TokenMatcher::new(options, arguments).unwrap();
+
+        // Execute
+        for token in tokens {
+            tp.feed(token).unwrap();
+        }
+
+        // Verify
+        let matches = tp.close().unwrap();
+        assert_eq!(
+            matches
+                .values
+                .into_iter()
+                .filter(|mt| !(mt.name == "item" && mt.values.is_empty()))
+                .map(|mt| (
+                    mt.name,
+                    mt.values
+                        .into_iter()
+                        .map(|(_, value)| value)
+                        .collect::<Vec<String>>()
+                ))
+                .collect::<Vec<(String, Vec<String>)>>(),
+            expected
+                .into_iter()
+                .map(|(name, values)| (
+                    name.to_string(),
+                    values.into_iter().map(|value| value.to_string()).collect()
+                ))
+                .collect::<Vec<(String, Vec<String>)>>()
+        );
+    }
+
+    #[rstest]
+    #[case(vec!["a", "b", ";", "c", "d"], vec!["a", "b"], vec!["c", "d"])]
+    #[case(vec!["a", ";", "c"], vec!["a"], vec!["c"])]
+    #[case(vec!["a", "b", ";"], vec!["a", "b"], vec![])]
+    #[case(vec!["a", "--", ";", "c"], vec!["a", "--", ";", "c"], vec![])]
+    fn argument_sentinel(
+        #[case] tokens: Vec<&str>,
+        #[case] expected_sources: Vec<&str>,
+        #[case] expected_targets: Vec<&str>,
+    ) {
+        // Setup
+        let arguments = VecDeque::from([
+            ArgumentConfig::new("source", Bound::Lower(1)).with_terminator(true),
+            ArgumentConfig::new("target", Bound::Lower(0)),
+        ]);
+        let mut tp = TokenMatcher::new(HashSet::default(), arguments)
+            .unwrap()
+            .with_sentinel(";");
+
+        // Execute
+        for token in tokens {
+            tp.feed(token).unwrap();
+        }
+
+        // Verify
+        let matches = tp.close().unwrap();
+        let values = |name: &str| {
+            matches
+                .values
+                .iter()
+                .find(|match_tokens| match_tokens.name == name)
+                .map(|match_tokens| {
+                    match_tokens
+                        .values
+                        .iter()
+                        .map(|(_, value)| value.clone())
+                        .collect::<Vec<String>>()
+                })
+                .unwrap_or_default()
+        };
+        assert_eq!(values("source"), expected_sources);
+        assert_eq!(values("target"), expected_targets);
+    }
+
+    #[rstest]
+    #[case(vec![";"], "SOURCE")]
+    #[case(vec!["a", ";", ";"], "TARGET")]
+    fn argument_sentinel_undercomplete(#[case] tokens: Vec<&str>, #[case] expected: &str) {
+        // Setup
+        let arguments = VecDeque::from([
+            ArgumentConfig::new("source", Bound::Lower(1)),
+            ArgumentConfig::new("target", Bound::Lower(1)),
+        ]);
+        let mut tp = TokenMatcher::new(HashSet::default(), arguments)
+            .unwrap()
+            .with_sentinel(";");
+
+        // Execute
+        for token in tokens {
+            tp.feed(token).unwrap();
+        }
+        let (_, error, _) = tp.close().unwrap_err();
+
+        // Verify
+        assert_eq!(error, MatchError::Undercomplete(expected.to_string()));
+    }
+
+    #[rstest]
+    #[case(vec![], false)]
+    #[case(vec!["-v"], false)]
+    #[case(vec!["--initial", "x"], false)]
+    #[case(vec!["-v", "x"], true)]
+    #[case(vec!["--initial", "x", "y"], true)]
+    fn argument_matched(#[case] tokens: Vec<&str>, #[case] expected: bool) {
+        // Setup
+        let options = HashSet::from([
+            OptionConfig::new("verbose", Some('v'), Bound::Range(0, 0)),
+            OptionConfig::new("initial", None, Bound::Range(1, 1)),
+        ]);
+        let arguments = VecDeque::from([ArgumentConfig::new("item", Bound::Range(1, 1))]);
+        let mut tp = TokenMatcher::new(options, arguments).unwrap();
+
+        // Execute
+        for token in tokens {
+            tp.feed(token).unwrap();
+        }
+
+        // Verify
+        assert_eq!(tp.argument_matched(), expected);
+    }
+
     #[rstest]
     #[case(vec!["--initial="], Some((10, "")))]
     #[case(vec!["--initial=a"], Some((10, "a")))]
@@ -707,6 +1539,12 @@ mod tests {
     #[case(Bound::Range(1, 1), 0, false)]
     #[case(Bound::Range(1, 1), 1, true)]
     #[case(Bound::Range(1, 1), 2, false)]
+    #[case(Bound::Range(2, 4), 0, false)]
+    #[case(Bound::Range(2, 4), 1, false)]
+    #[case(Bound::Range(2, 4), 2, true)]
+    #[case(Bound::Range(2, 4), 3, true)]
+    #[case(Bound::Range(2, 4), 4, true)]
+    #[case(Bound::Range(2, 4), 5, false)]
     fn argument_range_upper(#[case] bound: Bound, #[case] feed: u8, #[case] expected_ok: bool) {
         // Setup
         let arguments = VecDeque::from([ArgumentConfig::new("item", bound)]);
@@ -754,6 +1592,49 @@ mod tests {
         }
     }
 
+    #[rstest]
+    #[case(vec![])]
+    #[case(vec!["a"])]
+    fn argument_zero_or_one(#[case] tokens: Vec<&str>) {
+        // Setup
+        let arguments =
+            VecDeque::from([ArgumentConfig::new("item", Bound::from(Nargs::ZeroOrOne))]);
+        let mut tp = TokenMatcher::new(HashSet::default(), arguments).unwrap();
+
+        // Execute
+        for token in &tokens {
+            tp.feed(token).unwrap();
+        }
+
+        // Verify
+        assert_eq!(
+            tp.close().unwrap().values,
+            vec![MatchTokens {
+                name: "item".to_string(),
+                values: tokens
+                    .into_iter()
+                    .map(|t| t.to_string())
+                    .enumerate()
+                    .collect(),
+            }]
+        );
+    }
+
+    #[test]
+    fn argument_zero_or_one_exhausted() {
+        // Setup
+        let arguments =
+            VecDeque::from([ArgumentConfig::new("item", Bound::from(Nargs::ZeroOrOne))]);
+        let mut tp = TokenMatcher::new(HashSet::default(), arguments).unwrap();
+        tp.feed("a").unwrap();
+
+        // Execute
+        let error = tp.feed("b").unwrap_err();
+
+        // Verify
+        assert_eq!(error, MatchError::ArgumentsExhausted);
+    }
+
     #[rstest]
     #[case(0, false)]
     #[case(1, true)]
@@ -998,6 +1879,81 @@ mod tests {
         assert_eq!(tp.close().unwrap().values, expected);
     }
 
+    #[rstest]
+    #[case(vec!["", "y", "z"], 0, 2, 3)]
+    #[case(vec!["x", "", "z"], 0, 1, 3)]
+    #[case(vec!["x", "y", ""], 0, 1, 2)]
+    fn arguments_empty_token(
+        #[case] tokens: Vec<&str>,
+        #[case] x_offset: usize,
+        #[case] y_offset: usize,
+        #[case] z_offset: usize,
+    ) {
+        let arguments = VecDeque::from([ArgumentConfig::new("arg", Bound::Lower(1))]);
+        let mut tp = TokenMatcher::new(HashSet::default(), arguments).unwrap();
+
+        for token in &tokens {
+            tp.feed(token).unwrap();
+        }
+
+        assert_eq!(
+            tp.close().unwrap().values,
+            vec![MatchTokens {
+                name: "arg".to_string(),
+                values: vec![
+                    (x_offset, tokens[0].to_string()),
+                    (y_offset, tokens[1].to_string()),
+                    (z_offset, tokens[2].to_string()),
+                ],
+            }]
+        );
+    }
+
+    #[rstest]
+    #[case(vec!["--moot", "x"], vec!["--moot"])]
+    #[case(vec!["x", "--moot=1", "-m"], vec!["--moot=1", "-m"])]
+    #[case(vec!["-vm", "x"], vec!["-vm"])]
+    #[case(vec!["-v", "x"], vec![])]
+    fn skip_unknown(#[case] tokens: Vec<&str>, #[case] expected: Vec<&str>) {
+        // Setup
+        let options = HashSet::from([OptionConfig::new("verbose", Some('v'), Bound::Range(0, 0))]);
+        let arguments = VecDeque::from([ArgumentConfig::new("arg", Bound::Range(1, 1))]);
+        let mut tp = TokenMatcher::new(options, arguments)
+            .unwrap()
+            .with_skip_unknown();
+
+        // Execute
+        for token in &tokens {
+            tp.feed(token).unwrap();
+        }
+
+        // Verify
+        assert_eq!(tp.take_unknown(), expected);
+        assert!(tp.close().unwrap().contains("arg"));
+    }
+
+    #[test]
+    fn skip_unknown_repeated() {
+        // Setup
+        let options = HashSet::from([OptionConfig::new("verbose", Some('v'), Bound::Range(0, 0))]);
+        let mut tp = TokenMatcher::new(options, VecDeque::default())
+            .unwrap()
+            .with_skip_unknown();
+        tp.feed("--verbose").unwrap();
+
+        // Execute
+        let long_error = tp.feed("--verbose").unwrap_err();
+        let short_error = tp.feed("-v").unwrap_err();
+
+        // Verify
+        // A repeated option is known, so it is still rejected.
+        assert_eq!(long_error, MatchError::InvalidOption("VERBOSE".to_string()));
+        assert_eq!(
+            short_error,
+            MatchError::InvalidOption("VERBOSE".to_string())
+        );
+    }
+
     #[test]
     fn arguments_option_breaker() {
         let options = HashSet::from([OptionConfig::new("verbose", None, Bound::Range(0, 0))]);
@@ -29,6 +29,7 @@ pub(super) enum CloseError {
 pub(super) struct MatchBuffer {
     name: String,
     bound: Bound,
+    group: u8,
     values: Vec<OffsetValue>,
 }
 
@@ -37,10 +38,27 @@ impl MatchBuffer {
         Self {
             name: name.into(),
             bound,
+            group: 1,
             values: Vec::default(),
         }
     }
 
+    /// Require the values to form complete groups (a multiple of `group` values).
+    pub(super) fn with_group(mut self, group: u8) -> Self {
+        self.group = group;
+        self
+    }
+
+    /// The number of values missing from the final group.
+    fn group_remainder(&self) -> usize {
+        let group = self.group as usize;
+        (group - self.values.len() % group) % group
+    }
+
+    pub(super) fn name(&self) -> &str {
+        &self.name
+    }
+
     pub(super) fn push(&mut self, offset: usize, value: String) {
         self.values.push((offset, value));
     }
@@ -57,7 +75,7 @@ impl MatchBuffer {
             Bound::Range(n, _) => n,
             Bound::Lower(n) => n,
         };
-        self.values.len() >= n as usize
+        self.values.len() >= n as usize && self.group_remainder() == 0
     }
 
     pub(super) fn close(self) -> Result<MatchTokens, CloseError> {
@@ -88,6 +106,15 @@ impl MatchBuffer {
             }
         };
 
+        if self.group_remainder() > 0 {
+            return Err(CloseError::TooFewValues {
+                expected: u8::try_from(self.values.len() + self.group_remainder())
+                    .unwrap_or(u8::MAX),
+                name: self.name,
+                provided: self.values.len(),
+            });
+        }
+
         Ok(MatchTokens {
             name: self.name,
             values: self.values,
@@ -232,4 +259,48 @@ mod tests {
             );
         }
     }
+
+    #[rstest]
+    #[case(2, 0, None)]
+    #[case(2, 1, Some(2))]
+    #[case(2, 2, None)]
+    #[case(2, 3, Some(4))]
+    #[case(2, 4, None)]
+    #[case(3, 4, Some(6))]
+    fn match_buffer_group(#[case] group: u8, #[case] feed: u8, #[case] expected: Option<u8>) {
+        let name = "name";
+        let mut pb = MatchBuffer::new(name, Bound::Lower(0)).with_group(group);
+        let tokens: Vec<(usize, String)> = (0..feed)
+            .map(|i| (thread_rng().gen(), i.to_string()))
+            .collect();
+
+        for (offset, token) in &tokens {
+            pb.push(*offset, token.clone());
+        }
+
+        assert!(pb.is_open());
+        assert_eq!(pb.can_close(), expected.is_none());
+
+        match expected {
+            None => {
+                assert_eq!(
+                    pb.close().unwrap(),
+                    MatchTokens {
+                        name: name.to_string(),
+                        values: tokens,
+                    }
+                );
+            }
+            Some(expected) => {
+                assert_eq!(
+                    pb.close().unwrap_err(),
+                    CloseError::TooFewValues {
+                        name: name.to_string(),
+                        provided: feed as usize,
+                        expected,
+                    }
+                );
+            }
+        }
+    }
 }
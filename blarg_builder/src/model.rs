@@ -2,6 +2,7 @@
 ///
 /// Inspired by argparse: <https://docs.python.org/3/library/argparse.html#nargs>
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum Nargs {
     /// `N`: Precisely `N` values.
     Precisely(u8),
@@ -9,6 +10,27 @@ pub enum Nargs {
     Any,
     /// `+`: At least one value must be specified.
     AtLeastOne,
+    /// `?`: Either zero or one value (ex: an optional argument, which is left at its initial value when absent).
+    ZeroOrOne,
+    /// `{N,M}`: At least `N` and at most `M` values (inclusive).
+    /// Building the parser fails when `N > M`, or when `M` exceeds `255`.
+    Range(usize, usize),
+}
+
+impl Nargs {
+    /// Get the reason this cardinality cannot be matched (ex: an inverted `Range(4, 2)`), if any.
+    pub(crate) fn misconfiguration(&self) -> Option<String> {
+        match self {
+            Nargs::Range(min, max) if min > max => Some(format!(
+                "cannot match any number of values: its minimum {min} exceeds its maximum {max}."
+            )),
+            Nargs::Range(_, max) if *max > u8::MAX as usize => Some(format!(
+                "cannot match {max} values: it may match at most {} values.",
+                u8::MAX
+            )),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for Nargs {
@@ -16,3 +38,182 @@ impl std::fmt::Display for Nargs {
         write!(f, "{:?}", self)
     }
 }
+
+/// The case convention with which to present (and accept) choice keys.
+///
+/// Words are split on `-`, `_`, and whitespace, as well as on case boundaries (ex: `HttpServer` splits into `Http` and `Server`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Case {
+    /// `lowercase`: All characters lowercase, with word boundaries preserved as-is.
+    Lower,
+    /// `UPPERCASE`: All characters uppercase, with word boundaries preserved as-is.
+    Upper,
+    /// `kebab-case`: Lowercase words joined by `-`.
+    Kebab,
+    /// `snake_case`: Lowercase words joined by `_`.
+    Snake,
+}
+
+impl Case {
+    pub(crate) fn apply(&self, value: &str) -> String {
+        match self {
+            Case::Lower => value.to_lowercase(),
+            Case::Upper => value.to_uppercase(),
+            Case::Kebab => words(value).join("-"),
+            Case::Snake => words(value).join("_"),
+        }
+    }
+}
+
+fn words(value: &str) -> Vec<String> {
+    let characters: Vec<char> = value.chars().collect();
+    let mut words: Vec<String> = Vec::default();
+    let mut current = String::default();
+
+    for (i, c) in characters.iter().enumerate() {
+        if *c == '-' || *c == '_' || c.is_whitespace() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+
+        if c.is_uppercase() && !current.is_empty() {
+            let previous = characters[i - 1];
+            let next_lower = characters
+                .get(i + 1)
+                .map(|n| n.is_lowercase())
+                .unwrap_or(false);
+
+            if previous.is_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_uppercase() && next_lower)
+            {
+                words.push(std::mem::take(&mut current));
+            }
+        }
+
+        current.extend(c.to_lowercase());
+    }
+
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    words
+}
+
+/// A section of the detailed help message, which follows the usage summary and about message.
+/// Used with [`CommandLineParser::section_order`](./struct.CommandLineParser.html#method.section_order).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Section {
+    /// The positional arguments (omitted when there are none).
+    Positionals,
+    /// The options, including the built-in flags (ex: `-h`).
+    Options,
+    /// The example invocations (omitted when there are none).
+    Examples,
+}
+
+/// The literal messages used to structure the help message.
+/// Used with [`CommandLineParser::messages`](./struct.CommandLineParser.html#method.messages).
+///
+/// ### Example
+/// ```
+/// # use blarg_builder as blarg;
+/// use blarg::Messages;
+///
+/// Messages::default()
+///     .usage("Verwendung:")
+///     .positional_arguments("Argumente:")
+///     .options("Optionen:");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Messages {
+    pub(crate) usage: String,
+    pub(crate) positional_arguments: String,
+    pub(crate) options: String,
+    pub(crate) other_commands: String,
+    pub(crate) examples: String,
+    pub(crate) help_flag: String,
+}
+
+impl Default for Messages {
+    fn default() -> Self {
+        Self {
+            usage: "usage:".to_string(),
+            positional_arguments: "positional arguments:".to_string(),
+            options: "options:".to_string(),
+            other_commands: "other commands:".to_string(),
+            examples: "examples:".to_string(),
+            help_flag: "{short}, {long}".to_string(),
+        }
+    }
+}
+
+impl Messages {
+    /// Set the prefix of the usage line (default: `usage:`).
+    pub fn usage(mut self, usage: impl Into<String>) -> Self {
+        self.usage = usage.into();
+        self
+    }
+
+    /// Set the header of the positional arguments section (default: `positional arguments:`).
+    pub fn positional_arguments(mut self, positional_arguments: impl Into<String>) -> Self {
+        self.positional_arguments = positional_arguments.into();
+        self
+    }
+
+    /// Set the header of the options section (default: `options:`).
+    pub fn options(mut self, options: impl Into<String>) -> Self {
+        self.options = options.into();
+        self
+    }
+
+    /// Set the heading of the sub-commands which do not belong to any command group (default: `other commands:`).
+    pub fn other_commands(mut self, other_commands: impl Into<String>) -> Self {
+        self.other_commands = other_commands.into();
+        self
+    }
+
+    /// Set the header of the examples section (default: `examples:`).
+    pub fn examples(mut self, examples: impl Into<String>) -> Self {
+        self.examples = examples.into();
+        self
+    }
+
+    /// Set the format of the help flag in the options section (default: `{short}, {long}`).
+    /// The placeholders `{short}` and `{long}` are replaced by `-h` and `--help`, respectively (ex: `{short}|{long}`).
+    pub fn help_flag(mut self, help_flag: impl Into<String>) -> Self {
+        self.help_flag = help_flag.into();
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(Case::Kebab, "FooBar", "foo-bar")]
+    #[case(Case::Kebab, "FOO_BAR", "foo-bar")]
+    #[case(Case::Kebab, "HTTPServer", "http-server")]
+    #[case(Case::Kebab, "fooBar2Baz", "foo-bar2-baz")]
+    #[case(Case::Kebab, "foo bar", "foo-bar")]
+    #[case(Case::Kebab, "foo", "foo")]
+    #[case(Case::Kebab, "", "")]
+    #[case(Case::Snake, "FooBar", "foo_bar")]
+    #[case(Case::Snake, "foo-bar", "foo_bar")]
+    #[case(Case::Lower, "FooBar", "foobar")]
+    #[case(Case::Lower, "FOO_BAR", "foo_bar")]
+    #[case(Case::Upper, "FooBar", "FOOBAR")]
+    #[case(Case::Upper, "foo-bar", "FOO-BAR")]
+    fn case_apply(#[case] case: Case, #[case] value: &str, #[case] expected: &str) {
+        // Execute
+        let result = case.apply(value);
+
+        // Verify
+        assert_eq!(result, expected);
+    }
+}
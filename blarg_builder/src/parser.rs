@@ -4,6 +4,8 @@ mod middleware;
 mod printer;
 
 pub(crate) use self::base::*;
+pub use self::base::{ConfigWarning, ParseError};
 pub(crate) use self::interface::*;
 pub use self::middleware::*;
+pub use self::printer::ErrorContext;
 pub(crate) use self::printer::*;
@@ -1,8 +1,12 @@
+use std::cell::Cell;
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::rc::Rc;
 use thiserror::Error;
 
+use crate::api::{UnknownOptionPolicy, ValidationContext, Validator};
 use crate::constant::*;
 use crate::matcher::*;
+use crate::parser::interface::UserInterface;
 use crate::InvalidCapture;
 
 #[cfg(feature = "tracing_debug")]
@@ -28,14 +32,111 @@ impl From<TokenMatcherError> for ConfigError {
     }
 }
 
-#[derive(Debug, Error)]
-pub(crate) enum ParseError {
+/// A potential issue with the configuration of a command line parser, as found by [`GeneralParser::lint`](./struct.GeneralParser.html#method.lint).
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum ConfigWarning {
+    /// The named parameter has a range whose minimum exceeds its maximum (ex: `Nargs::Range(2, 1)`), so it can never be satisfied.
+    #[error("parameter '{0}' cannot match any number of values: its minimum {1} exceeds its maximum {2}.")]
+    EmptyRange(String, u8, u8),
+    /// The second named argument follows the first unbounded argument, so its values must be separated from the latter's by an option.
+    #[error("argument '{1}' follows the unbounded argument '{0}': its values are only reachable after an option.")]
+    UnreachableArgument(String, String),
+    /// The named option has a digit short name while negative numbers are accepted, so the negative numbers beginning with that digit are matched as the option instead.
+    #[error("option '{0}' has the digit short name '-{1}': it shadows the negative numbers beginning with '-{1}'.")]
+    DigitShort(String, char),
+}
+
+impl ConfigWarning {
+    /// Whether the issue makes part of the command line parser unusable, rather than merely error-prone.
+    pub fn is_fatal(&self) -> bool {
+        match self {
+            ConfigWarning::EmptyRange(..) => true,
+            ConfigWarning::UnreachableArgument(..) => false,
+            ConfigWarning::DigitShort(..) => false,
+        }
+    }
+}
+
+/// An error encountered while parsing the input tokens.
+///
+/// See [`GeneralParser::parse_tokens`](./struct.GeneralParser.html#method.parse_tokens) for a description of the parsing phases.
+#[derive(Debug, Error, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// The tokens could not be aligned to the arguments and options.
     #[error("Parse error during matching: {0}")]
     MatchPhase(MatchError),
-    #[error("Parse error during capture: {0}")]
-    CapturePhase(InvalidCapture),
+    /// The named parameter could not capture one of its tokens.
+    #[error("Parse error during capture: {1}")]
+    CapturePhase(String, InvalidCapture),
+    /// The sub-command could not be branched into.
     #[error("Parse error during branching: {0}")]
     BranchingPhase(String),
+    /// The input line could not be split into tokens.
+    #[error("Parse error during tokenizing: {0}")]
+    TokenizePhase(String),
+    /// The sub-command `variant` does not exist.
+    /// Includes the `remaining` tokens following the `variant` (ex: to dispatch to an external program instead).
+    #[error("Parse error during branching: unknown sub-command '{variant}'.{}", did_you_mean(.suggestion))]
+    UnknownSubCommand {
+        /// The unknown sub-command, as provided.
+        variant: String,
+        /// The nearest sub-command to the `variant`, if any is close enough to be a plausible typo.
+        suggestion: Option<String>,
+        /// The tokens following the `variant`, un-parsed.
+        remaining: Vec<String>,
+    },
+    /// The `option` was provided alongside the `preset` flag, which also sets it.
+    #[error(
+        "Parse error during matching: option '--{option}' conflicts with the preset '--{preset}'."
+    )]
+    PresetConflict {
+        /// The preset flag, by name.
+        preset: String,
+        /// The conflicting option, by name.
+        option: String,
+    },
+    /// The named parameter could not capture the `value` of its environment `variable` (ex: `PREFIX_OUTPUT`).
+    #[error("Parse error during capture from the environment variable '{variable}': {error}")]
+    Environment {
+        /// The parameter, by name.
+        name: String,
+        /// The environment variable.
+        variable: String,
+        /// The value of the environment variable.
+        value: String,
+        /// The reason the value could not be captured.
+        error: Box<InvalidCapture>,
+    },
+}
+
+fn did_you_mean(suggestion: &Option<String>) -> String {
+    match suggestion {
+        Some(candidate) => format!(" Did you mean '{candidate}'?"),
+        None => "".to_string(),
+    }
+}
+
+impl ParseError {
+    /// Whether this error is attributable to the parameter `name`.
+    pub(crate) fn concerns(&self, name: &str) -> bool {
+        match self {
+            ParseError::MatchPhase(MatchError::Undercomplete(parameter))
+            | ParseError::MatchPhase(MatchError::Overcomplete(parameter)) => {
+                parameter.eq_ignore_ascii_case(name)
+            }
+            ParseError::MatchPhase(MatchError::RequiredGroup(parameters)) => {
+                parameters.iter().any(|parameter| parameter == name)
+            }
+            ParseError::MatchPhase(MatchError::RequiredOption(parameter)) => parameter == name,
+            ParseError::CapturePhase(parameter, _)
+            | ParseError::Environment {
+                name: parameter, ..
+            } => parameter == name,
+            ParseError::PresetConflict { preset, option } => preset == name || option == name,
+            _ => false,
+        }
+    }
 }
 
 /// Behaviour to capture an implicit generic type T from an input `&str`.
@@ -45,6 +146,11 @@ pub(crate) trait AnonymousCapturable {
     /// Declare that the parameter has been matched.
     fn matched(&mut self);
 
+    /// Declare the flag spelling with which the option has been matched (ex: `-v` or `--verbose`).
+    fn spelled(&mut self, _spelling: &str) {
+        // Do nothing.
+    }
+
     /// Capture a value anonymously for this parameter.
     fn capture(&mut self, value: &str) -> Result<(), InvalidCapture>;
 }
@@ -74,10 +180,62 @@ pub mod test {
     }
 }
 
+/// Captures the `--quiet` flag, so that the user interface may drop the warnings.
+struct QuietCapture {
+    quiet: Rc<Cell<bool>>,
+}
+
+impl AnonymousCapturable for QuietCapture {
+    fn matched(&mut self) {
+        self.quiet.set(true);
+    }
+
+    fn capture(&mut self, _value: &str) -> Result<(), InvalidCapture> {
+        unreachable!("internal error - must not capture on the quiet flag");
+    }
+}
+
+/// Build the term of a parameter for the grammar, by repeating its name according to the `bound`.
+/// Ex: `Bound::Lower(1)` -> "TARGET+"
+fn grammar_term(name: &str, bound: Bound, value_names: Option<&Vec<String>>) -> String {
+    if let Some(value_names) = value_names {
+        return value_names.join(" ");
+    }
+
+    let name_example = name.to_ascii_uppercase().replace("-", "_");
+
+    match bound {
+        Bound::Range(0, 0) => "".to_string(),
+        Bound::Range(1, 1) => name_example,
+        Bound::Range(0, 1) => format!("{name_example}?"),
+        Bound::Range(min, max) if min == max => format!("{name_example}{{{min}}}"),
+        Bound::Range(min, max) => format!("{name_example}{{{min},{max}}}"),
+        Bound::Lower(0) => format!("{name_example}*"),
+        Bound::Lower(1) => format!("{name_example}+"),
+        Bound::Lower(min) => format!("{name_example}{{{min},}}"),
+    }
+}
+
 pub(crate) struct Parser<'a> {
     token_matcher: TokenMatcher,
     captures: HashMap<String, Box<(dyn AnonymousCapturable + 'a)>>,
     discriminator: Option<String>,
+    required_groups: Vec<Vec<String>>,
+    required_options: Vec<String>,
+    validators: Vec<(String, Option<Validator<'a>>)>,
+    option_names: HashSet<String>,
+    initials: HashMap<String, String>,
+    deprecations: HashMap<String, String>,
+    value_names: HashMap<String, Vec<String>>,
+    prompts: HashSet<String>,
+    glob_warnings: HashSet<String>,
+    environment: Vec<(String, String)>,
+    presets: Vec<(String, Vec<(String, String)>)>,
+    remainder: Option<&'a mut Vec<String>>,
+    trailing: bool,
+    unknown_option_policy: UnknownOptionPolicy<'a>,
+    usage_flag: bool,
+    version_flag: bool,
 }
 
 impl<'a> std::fmt::Debug for Parser<'a> {
@@ -101,6 +259,7 @@ impl<'a> Parser<'a> {
         let mut option_configs = HashSet::from([help_config]);
         let mut argument_configs = VecDeque::default();
         let mut captures: HashMap<String, Box<(dyn AnonymousCapturable + 'a)>> = HashMap::default();
+        let mut option_names = HashSet::default();
 
         for (oc, f) in options.into_iter() {
             if captures.insert(oc.name().to_string(), f).is_some() {
@@ -110,6 +269,7 @@ impl<'a> Parser<'a> {
                 )));
             }
 
+            option_names.insert(oc.name().to_string());
             option_configs.insert(oc);
         }
 
@@ -130,14 +290,349 @@ impl<'a> Parser<'a> {
             token_matcher,
             captures,
             discriminator,
+            required_groups: Vec::default(),
+            required_options: Vec::default(),
+            validators: Vec::default(),
+            option_names,
+            initials: HashMap::default(),
+            deprecations: HashMap::default(),
+            value_names: HashMap::default(),
+            prompts: HashSet::default(),
+            glob_warnings: HashSet::default(),
+            environment: Vec::default(),
+            presets: Vec::default(),
+            remainder: None,
+            trailing: false,
+            unknown_option_policy: UnknownOptionPolicy::Error,
+            usage_flag: false,
+            version_flag: false,
         })
     }
 
-    pub(crate) fn consume(self, tokens: &[&str]) -> Result<Action, (usize, ParseError)> {
+    /// Find the potential issues with the configuration, without parsing.
+    pub(crate) fn lint(&self) -> Vec<ConfigWarning> {
+        let mut warnings = Vec::default();
+        let mut options: Vec<(&String, &Bound)> =
+            self.token_matcher.option_bounds().iter().collect();
+        options.sort_by_key(|(name, _)| *name);
+        let arguments = self
+            .token_matcher
+            .arguments()
+            .iter()
+            .map(|argument_config| (argument_config.name(), argument_config.bound()));
+
+        for (name, bound) in options
+            .into_iter()
+            .map(|(name, bound)| (name.as_str(), *bound))
+            .chain(arguments)
+        {
+            if let Bound::Range(min, max) = bound {
+                if min > max {
+                    warnings.push(ConfigWarning::EmptyRange(name.to_string(), min, max));
+                }
+            }
+        }
+
+        // A sentinel (or the `--` of a split collection) separates the values of an unbounded argument from those which follow.
+        if !self.token_matcher.has_sentinel() {
+            let mut unbounded: Option<&str> = None;
+
+            for argument_config in self.token_matcher.arguments() {
+                if let Some(previous) = unbounded {
+                    warnings.push(ConfigWarning::UnreachableArgument(
+                        previous.to_string(),
+                        argument_config.name().to_string(),
+                    ));
+                } else if matches!(argument_config.bound(), Bound::Lower(_))
+                    && !argument_config.terminator()
+                {
+                    unbounded.replace(argument_config.name());
+                }
+            }
+        }
+
+        if self.token_matcher.has_negative_numbers() {
+            let mut digit_shorts: Vec<(&char, &String)> = self
+                .token_matcher
+                .short_options()
+                .iter()
+                .filter(|(short, _)| short.is_ascii_digit())
+                .collect();
+            digit_shorts.sort();
+
+            for (short, name) in digit_shorts {
+                warnings.push(ConfigWarning::DigitShort(name.to_string(), *short));
+            }
+        }
+
+        warnings
+    }
+
+    /// The grammar of the command, as EBNF-like production rules named after the `rule`.
+    /// Ex: `program ::= program-option* SOURCE TARGET+`
+    pub(crate) fn grammar(&self, rule: &str) -> Vec<String> {
+        let option_rule = format!("{rule}-option");
+        let production: Vec<String> = std::iter::once(format!("{option_rule}*"))
+            .chain(
+                self.token_matcher
+                    .arguments()
+                    .iter()
+                    .map(|argument_config| {
+                        grammar_term(
+                            argument_config.name(),
+                            argument_config.bound(),
+                            self.value_names.get(argument_config.name()),
+                        )
+                    }),
+            )
+            .collect();
+
+        let mut options: Vec<(&String, &Bound)> =
+            self.token_matcher.option_bounds().iter().collect();
+        options.sort_by_key(|(name, _)| *name);
+        let alternatives: Vec<String> = options
+            .into_iter()
+            .map(|(name, bound)| {
+                let mut shorts: Vec<&char> = self
+                    .token_matcher
+                    .short_options()
+                    .iter()
+                    .filter(|(_, option_name)| *option_name == name)
+                    .map(|(short, _)| short)
+                    .collect();
+                shorts.sort();
+                let flags: Vec<String> = shorts
+                    .into_iter()
+                    .map(|short| format!("\"-{short}\""))
+                    .chain(std::iter::once(format!("\"--{name}\"")))
+                    .collect();
+                let flags = if flags.len() == 1 {
+                    flags.join("")
+                } else {
+                    format!("({})", flags.join(" | "))
+                };
+
+                match grammar_term(name, *bound, self.value_names.get(name)) {
+                    term if term.is_empty() => flags,
+                    term => format!("{flags} {term}"),
+                }
+            })
+            .collect();
+        let indent = " ".repeat(option_rule.len() + 1);
+
+        vec![
+            format!("{rule} ::= {}", production.join(" ")),
+            format!(
+                "{option_rule} ::= {}",
+                alternatives.join(&format!("\n{indent}| "))
+            ),
+        ]
+    }
+
+    /// The name of the sub-command discriminator argument, if branching.
+    pub(crate) fn discriminator(&self) -> Option<&String> {
+        self.discriminator.as_ref()
+    }
+
+    /// Match the `--usage` flag, which requests the usage message.
+    pub(crate) fn with_usage_flag(mut self) -> Result<Self, ConfigError> {
+        if self.captures.contains_key(USAGE_NAME) {
+            return Err(ConfigError(format!(
+                "cannot duplicate the parameter '{USAGE_NAME}'."
+            )));
+        }
+
+        self.token_matcher = self.token_matcher.with_option(OptionConfig::new(
+            USAGE_NAME,
+            None,
+            Bound::Range(0, 0),
+        ))?;
+        self.usage_flag = true;
+        Ok(self)
+    }
+
+    /// Match the `--version` flag, which requests the version message.
+    pub(crate) fn with_version_flag(mut self) -> Result<Self, ConfigError> {
+        if self.captures.contains_key(VERSION_NAME) {
+            return Err(ConfigError(format!(
+                "cannot duplicate the parameter '{VERSION_NAME}'."
+            )));
+        }
+
+        self.token_matcher = self.token_matcher.with_option(OptionConfig::new(
+            VERSION_NAME,
+            None,
+            Bound::Range(0, 0),
+        ))?;
+        self.version_flag = true;
+        Ok(self)
+    }
+
+    /// Match the `--quiet` flag, which sets `quiet` to request that warnings are suppressed.
+    pub(crate) fn with_quiet_flag(mut self, quiet: Rc<Cell<bool>>) -> Result<Self, ConfigError> {
+        if self.captures.contains_key(QUIET_NAME) {
+            return Err(ConfigError(format!(
+                "cannot duplicate the parameter '{QUIET_NAME}'."
+            )));
+        }
+
+        self.token_matcher = self.token_matcher.with_option(OptionConfig::new(
+            QUIET_NAME,
+            None,
+            Bound::Range(0, 0),
+        ))?;
+        self.captures
+            .insert(QUIET_NAME.to_string(), Box::new(QuietCapture { quiet }));
+        Ok(self)
+    }
+
+    /// Match each of the preset flags, which stand in for their option values (by flag name, with the option name and value).
+    pub(crate) fn with_presets(
+        mut self,
+        presets: Vec<(String, Vec<(String, String)>)>,
+    ) -> Result<Self, ConfigError> {
+        for (name, _) in &presets {
+            if self.token_matcher.option_bounds().contains_key(name) {
+                return Err(ConfigError(format!(
+                    "cannot duplicate the parameter '{name}'."
+                )));
+            }
+
+            self.token_matcher = self.token_matcher.with_option(OptionConfig::new(
+                name,
+                None,
+                Bound::Range(0, 0),
+            ))?;
+        }
+
+        self.presets = presets;
+        Ok(self)
+    }
+
+    /// Match tokens such as `-5` as values rather than short options.
+    pub(crate) fn with_negative_numbers(mut self) -> Self {
+        self.token_matcher = self.token_matcher.with_negative_numbers();
+        self
+    }
+
+    /// Match tokens after the first positional as positionals, even when they resemble options (ex: `--flag`).
+    pub(crate) fn with_options_before_positionals(mut self) -> Self {
+        self.token_matcher = self.token_matcher.with_options_before_positionals();
+        self
+    }
+
+    /// Match the `sentinel` token as the end of the current argument's values, rather than as a value.
+    pub(crate) fn with_argument_sentinel(mut self, sentinel: char) -> Self {
+        self.token_matcher = self.token_matcher.with_sentinel(sentinel);
+        self
+    }
+
+    /// Require that at least one option from each group is matched.
+    pub(crate) fn with_required_groups(mut self, required_groups: Vec<Vec<String>>) -> Self {
+        self.required_groups = required_groups;
+        self
+    }
+
+    /// Require each of the named options to be provided, in declaration order.
+    pub(crate) fn with_required_options(mut self, required_options: Vec<String>) -> Self {
+        self.required_options = required_options;
+        self
+    }
+
+    /// Validate the parameters, in declaration order (all parameters must be listed).
+    pub(crate) fn with_validators(
+        mut self,
+        validators: Vec<(String, Option<Validator<'a>>)>,
+    ) -> Self {
+        self.validators = validators;
+        self
+    }
+
+    /// Resolve the un-matched parameters to their initial value (as a token).
+    pub(crate) fn with_initials(mut self, initials: HashMap<String, String>) -> Self {
+        self.initials = initials;
+        self
+    }
+
+    /// Warn when any of the deprecated parameters are matched (by name, with the deprecation message).
+    pub(crate) fn with_deprecations(mut self, deprecations: HashMap<String, String>) -> Self {
+        self.deprecations = deprecations;
+        self
+    }
+
+    /// Label the values which fail to capture by their position's name (by parameter name, with the value names).
+    pub(crate) fn with_value_names(mut self, value_names: HashMap<String, Vec<String>>) -> Self {
+        self.value_names = value_names;
+        self
+    }
+
+    /// Prompt for the value of each of these options, when matched without one.
+    pub(crate) fn with_prompts(mut self, prompts: HashSet<String>) -> Self {
+        self.prompts = prompts;
+        self
+    }
+
+    /// Warn when any of the values of these parameters resemble an unexpanded glob (ex: `*.txt`).
+    pub(crate) fn with_glob_warnings(mut self, glob_warnings: HashSet<String>) -> Self {
+        self.glob_warnings = glob_warnings;
+        self
+    }
+
+    /// Capture the un-matched options from their environment variable, when set (by option name, with the variable name).
+    pub(crate) fn with_environment(mut self, environment: Vec<(String, String)>) -> Self {
+        self.environment = environment;
+        self
+    }
+
+    /// Stop matching at the first argument, and collect all the subsequent tokens into the `remainder` (as is).
+    pub(crate) fn with_remainder(mut self, remainder: &'a mut Vec<String>) -> Self {
+        self.remainder.replace(remainder);
+        self
+    }
+
+    /// Stop matching at the first token beyond the arguments, leaving it and all the subsequent tokens as the remaining (as is).
+    pub(crate) fn with_trailing(mut self) -> Self {
+        self.trailing = true;
+        self
+    }
+
+    /// Handle the unknown options according to the `policy`.
+    pub(crate) fn with_unknown_option_policy(mut self, policy: UnknownOptionPolicy<'a>) -> Self {
+        match policy {
+            UnknownOptionPolicy::Error => {}
+            UnknownOptionPolicy::Collect(_) | UnknownOptionPolicy::Ignore => {
+                self.token_matcher = self.token_matcher.with_skip_unknown();
+            }
+        };
+        self.unknown_option_policy = policy;
+        self
+    }
+
+    pub(crate) fn consume(
+        self,
+        tokens: &[&str],
+        user_interface: &(impl UserInterface + ?Sized),
+    ) -> Result<Action, (usize, ParseError)> {
         let Parser {
             mut token_matcher,
             mut captures,
             discriminator,
+            required_groups,
+            required_options,
+            validators,
+            option_names,
+            initials,
+            deprecations,
+            value_names,
+            prompts,
+            glob_warnings,
+            environment,
+            presets,
+            remainder,
+            trailing,
+            unknown_option_policy,
+            usage_flag,
+            version_flag,
         } = self;
 
         #[cfg(feature = "tracing_debug")]
@@ -151,54 +646,180 @@ impl<'a> Parser<'a> {
         let minimal_consume = discriminator.is_some();
         // 1. Feed the raw token strings to the matcher.
         let mut fed = 0;
+        let mut trailing_token: Option<&str> = None;
 
         loop {
             match token_iter.next() {
                 Some(token) => {
-                    let token_length = token.len();
-                    token_matcher
-                        .feed(token)
-                        .map_err(|e| (fed, ParseError::MatchPhase(e)))?;
+                    let token_length = token_width(token);
+                    match token_matcher.feed(token) {
+                        // Leave the token beyond the arguments (and its successors) for a downstream parser.
+                        Err(MatchError::ArgumentsExhausted) if trailing => {
+                            trailing_token.replace(token);
+                            break;
+                        }
+                        result => result.map_err(|e| (fed, ParseError::MatchPhase(e)))?,
+                    };
                     fed += token_length;
 
                     if minimal_consume && token_matcher.can_close() {
                         break;
                     }
+
+                    if remainder.is_some() && token_matcher.argument_matched() {
+                        break;
+                    }
                 }
                 None => break,
             }
         }
 
-        let matches = match token_matcher.close() {
+        if let UnknownOptionPolicy::Collect(unknown) = unknown_option_policy {
+            unknown.append(&mut token_matcher.take_unknown());
+        }
+
+        let mut matches = match token_matcher.close() {
             Ok(matches) | Err((_, _, matches)) if matches.contains(HELP_NAME) => {
                 return Ok(Action::PrintHelp);
             }
+            Ok(matches) | Err((_, _, matches)) if usage_flag && matches.contains(USAGE_NAME) => {
+                return Ok(Action::PrintUsage);
+            }
+            Ok(matches) | Err((_, _, matches))
+                if version_flag && matches.contains(VERSION_NAME) =>
+            {
+                return Ok(Action::PrintVersion);
+            }
             Ok(matches) => Ok(matches),
             Err((offset, e, _)) => Err((offset, ParseError::MatchPhase(e))),
         }?;
 
+        // Expand each of the provided preset flags into its option values (ex: `--fast` -> `--level 3`).
+        for (name, options) in presets {
+            if !matches.contains(&name) {
+                continue;
+            }
+
+            matches
+                .values
+                .retain(|match_tokens| match_tokens.name != name);
+
+            for (option, value) in options {
+                if matches.contains(&option) {
+                    return Err((
+                        fed,
+                        ParseError::PresetConflict {
+                            preset: name,
+                            option,
+                        },
+                    ));
+                }
+
+                matches.values.push(MatchTokens {
+                    name: option,
+                    values: vec![(fed, value)],
+                });
+            }
+        }
+
+        for group in required_groups {
+            if !group.iter().any(|name| matches.contains(name)) {
+                return Err((
+                    fed,
+                    ParseError::MatchPhase(MatchError::RequiredGroup(group)),
+                ));
+            }
+        }
+
+        for name in required_options {
+            // An option read from the environment is also considered provided.
+            let provided = matches.contains(&name)
+                || environment
+                    .iter()
+                    .any(|(option, variable)| option == &name && std::env::var(variable).is_ok());
+
+            if !provided {
+                return Err((
+                    fed,
+                    ParseError::MatchPhase(MatchError::RequiredOption(name)),
+                ));
+            }
+        }
+
         #[cfg(feature = "tracing_debug")]
         {
             debug!("Running parser capture phase: {matches:?}.");
         }
 
         let mut discriminee: Option<OffsetValue> = None;
+        let mut parsed: HashMap<String, Vec<OffsetValue>> = HashMap::default();
+        let mut consumed: HashMap<String, Vec<OffsetValue>> = HashMap::default();
+        // A repeatable option is matched upon each of its occurrences, so its capture is kept for the next.
+        let mut matched_captures: HashMap<String, Box<dyn AnonymousCapturable + 'a>> =
+            HashMap::default();
 
         // 2. Get the matching between tokens-parameter/options, still as raw strings.
         for match_tokens in matches.values {
             // 3. Find the corresponding capture.
             let mut box_capture = captures
                 .remove(&match_tokens.name)
+                .or_else(|| matched_captures.remove(&match_tokens.name))
                 .expect("internal error - mismatch between matches and captures");
             // 4. Let the capture know it has been matched.
             // Some captures may do something based off the fact they were simply matched.
             box_capture.matched();
 
+            if let Some(spelling) = matches.spellings.get(&match_tokens.name) {
+                box_capture.spelled(spelling);
+            }
+
             // 5. Convert each of the raw value strings into the capture type.
-            for (offset, value) in &match_tokens.values {
+            for (i, (offset, value)) in match_tokens.values.iter().enumerate() {
+                let result = box_capture.capture(value);
+
+                // The variant which doesn't convert (ex: for an enum `Condition`) is an unknown sub-command, reported upon dispatch.
+                if result.is_err() && discriminator.as_ref() == Some(&match_tokens.name) {
+                    continue;
+                }
+
+                result.map_err(|error| {
+                    // Label the value by its position, when the values are named (ex: `MIN MAX`).
+                    let error = match value_names.get(&match_tokens.name) {
+                        Some(names) => InvalidCapture::InvalidPosition {
+                            label: names[i % names.len()].clone(),
+                            error: Box::new(error),
+                        },
+                        None => error,
+                    };
+                    (
+                        *offset,
+                        ParseError::CapturePhase(match_tokens.name.clone(), error),
+                    )
+                })?;
+            }
+
+            // When the option prompts for its omitted value (ex: a bare `--password`), capture the response instead.
+            if match_tokens.values.is_empty() && prompts.contains(&match_tokens.name) {
+                let name = &match_tokens.name;
+                let response = user_interface
+                    .prompt_hidden(format!("{name}: "))
+                    .ok_or_else(|| {
+                        (
+                            fed,
+                            ParseError::CapturePhase(
+                                name.clone(),
+                                InvalidCapture::InvalidValue {
+                                    token: String::default(),
+                                    message: format!(
+                                        "cannot prompt for '--{name}' without a terminal that hides the response."
+                                    ),
+                                },
+                            ),
+                        )
+                    })?;
                 box_capture
-                    .capture(value)
-                    .map_err(|error| (*offset, ParseError::CapturePhase(error)))?;
+                    .capture(&response)
+                    .map_err(|error| (fed, ParseError::CapturePhase(name.clone(), error)))?;
             }
 
             if let Some(ref target) = &discriminator {
@@ -219,11 +840,144 @@ impl<'a> Parser<'a> {
                     }
                 }
             }
+
+            consumed
+                .entry(match_tokens.name.clone())
+                .or_default()
+                .extend(match_tokens.values.clone());
+            parsed
+                .entry(match_tokens.name.clone())
+                .or_default()
+                .extend(match_tokens.values);
+            matched_captures.insert(match_tokens.name, box_capture);
+        }
+
+        // Fall back to the environment for the un-matched options (ex: `--output` from `PREFIX_OUTPUT`), in declaration order.
+        let mut sourced: HashMap<&String, &String> = HashMap::default();
+
+        for (name, variable) in &environment {
+            if let Some(mut box_capture) = captures.remove(name) {
+                if let Ok(value) = std::env::var(variable) {
+                    box_capture.matched();
+
+                    if let Err(error) = box_capture.capture(&value) {
+                        return Err((
+                            fed,
+                            ParseError::Environment {
+                                name: name.clone(),
+                                variable: variable.clone(),
+                                value,
+                                error: Box::new(error),
+                            },
+                        ));
+                    }
+
+                    sourced.insert(name, variable);
+                    parsed.insert(name.clone(), vec![(fed, value)]);
+                }
+            }
+        }
+
+        // 6. Validate each parameter against the context of its predecessors, in declaration order.
+        let mut context = ValidationContext::default();
+        // 7. Resolve the effective command: options first, then arguments, each in declaration order.
+        let mut resolved_options = Vec::default();
+        let mut resolved_arguments = Vec::default();
+        let mut warnings = Vec::default();
+
+        for (name, validator) in &validators {
+            let option = option_names.contains(name);
+            let tokens = match parsed.remove(name) {
+                Some(values) => {
+                    // An argument matched without values (ex: `Nargs::Any`) is not considered used.
+                    if let Some(message) = deprecations
+                        .get(name)
+                        .filter(|_| option || !values.is_empty())
+                    {
+                        let flag = if option {
+                            format!("--{name}")
+                        } else {
+                            name.clone()
+                        };
+                        warnings.push(format!("warning: '{flag}' is deprecated: {message}"));
+                    }
+
+                    // The shell passes a glob which matches nothing as is (ex: `*.txt`).
+                    if glob_warnings.contains(name) {
+                        let flag = if option {
+                            format!("--{name}")
+                        } else {
+                            name.clone()
+                        };
+                        for (_, value) in &values {
+                            if value.contains(['*', '?']) {
+                                warnings.push(format!("warning: '{value}' for '{flag}' looks like an unexpanded glob (the shell may not have matched any files)."));
+                            }
+                        }
+                    }
+
+                    if let Some(validator) = validator {
+                        for (offset, value) in &values {
+                            validator(value, &context).map_err(|message| {
+                                let error = InvalidCapture::InvalidValue {
+                                    token: value.clone(),
+                                    message,
+                                };
+                                let error = match sourced.get(name) {
+                                    Some(variable) => ParseError::Environment {
+                                        name: name.clone(),
+                                        variable: variable.to_string(),
+                                        value: value.clone(),
+                                        error: Box::new(error),
+                                    },
+                                    None => ParseError::CapturePhase(name.clone(), error),
+                                };
+                                (*offset, error)
+                            })?;
+                        }
+                    }
+
+                    let values: Vec<String> = values.into_iter().map(|(_, value)| value).collect();
+                    context.insert(name.clone(), values.clone());
+                    Some(values)
+                }
+                None => None,
+            };
+            // An argument matched without values (ex: `Nargs::Any`) is resolved as if un-matched.
+            let tokens = match tokens {
+                Some(tokens) if option || !tokens.is_empty() => Some(tokens),
+                _ => initials.get(name).map(|initial| vec![initial.clone()]),
+            };
+
+            if let Some(tokens) = tokens {
+                if option {
+                    resolved_options.push(format!("--{name}"));
+                    resolved_options.extend(tokens);
+                } else {
+                    resolved_arguments.extend(tokens);
+                }
+            }
+        }
+
+        resolved_options.extend(resolved_arguments);
+        let mut remaining: Vec<String> = trailing_token
+            .into_iter()
+            .chain(token_iter.copied())
+            .map(|s| s.to_string())
+            .collect();
+
+        // 8. Hand the unmatched tokens over to the remainder, rather than the sub-command.
+        if let Some(remainder) = remainder {
+            resolved_options.extend(remaining.iter().cloned());
+            remainder.append(&mut remaining);
         }
 
         Ok(Action::Continue {
             discriminee,
-            remaining: token_iter.map(|s| s.to_string()).collect(),
+            remaining,
+            resolved: resolved_options,
+            consumed,
+            warnings,
         })
     }
 }
@@ -233,8 +987,13 @@ pub(crate) enum Action {
     Continue {
         discriminee: Option<OffsetValue>,
         remaining: Vec<String>,
+        resolved: Vec<String>,
+        consumed: HashMap<String, Vec<OffsetValue>>,
+        warnings: Vec<String>,
     },
     PrintHelp,
+    PrintUsage,
+    PrintVersion,
 }
 
 #[cfg(test)]
@@ -243,6 +1002,7 @@ mod tests {
     use crate::api::{AnonymousCapture, Collection, GenericCapturable, Scalar};
     use crate::model::Nargs;
     use crate::parser::base::test::BlackHole;
+    use crate::parser::util::InMemoryInterface;
     use rand::{thread_rng, Rng};
     use rstest::rstest;
 
@@ -252,7 +1012,9 @@ mod tests {
         let parser = Parser::empty();
 
         // Execute
-        let result = parser.consume(empty::slice()).unwrap();
+        let result = parser
+            .consume(empty::slice(), &InMemoryInterface::default())
+            .unwrap();
 
         // Verify
         assert_eq!(
@@ -260,18 +1022,21 @@ mod tests {
             Action::Continue {
                 discriminee: None,
                 remaining: vec![],
+                resolved: vec![],
+                consumed: HashMap::default(),
+                warnings: vec![],
             }
         );
     }
 
     #[rstest]
-    #[case(vec!["--variable", "1"])]
-    #[case(vec!["--variable", "01"])]
-    #[case(vec!["-v", "1"])]
-    #[case(vec!["-v", "01"])]
-    #[case(vec!["-v=1"])]
-    #[case(vec!["-v=01"])]
-    fn parser_option(#[case] tokens: Vec<&str>) {
+    #[case(vec!["--variable", "1"], (10, "1"))]
+    #[case(vec!["--variable", "01"], (10, "01"))]
+    #[case(vec!["-v", "1"], (2, "1"))]
+    #[case(vec!["-v", "01"], (2, "01"))]
+    #[case(vec!["-v=1"], (3, "1"))]
+    #[case(vec!["-v=01"], (3, "01"))]
+    fn parser_option(#[case] tokens: Vec<&str>, #[case] expected_consumed: (usize, &str)) {
         // Setup
         let mut variable: u32 = 0;
         let generic_capture = Scalar::new(&mut variable);
@@ -280,7 +1045,9 @@ mod tests {
         let parser = Parser::new(vec![(config, Box::new(capture))], Vec::default(), None).unwrap();
 
         // Execute
-        let result = parser.consume(tokens.as_slice()).unwrap();
+        let result = parser
+            .consume(tokens.as_slice(), &InMemoryInterface::default())
+            .unwrap();
 
         // Verify
         assert_eq!(
@@ -288,6 +1055,12 @@ mod tests {
             Action::Continue {
                 discriminee: None,
                 remaining: vec![],
+                resolved: vec![],
+                consumed: HashMap::from([(
+                    "variable".to_string(),
+                    vec![(expected_consumed.0, expected_consumed.1.to_string())]
+                )]),
+                warnings: vec![],
             }
         );
         assert_eq!(variable, 1);
@@ -307,7 +1080,9 @@ mod tests {
         let parser = Parser::new(Vec::default(), vec![(config, Box::new(capture))], None).unwrap();
 
         // Execute
-        let result = parser.consume(tokens.as_slice()).unwrap();
+        let result = parser
+            .consume(tokens.as_slice(), &InMemoryInterface::default())
+            .unwrap();
 
         // Verify
         assert_eq!(
@@ -315,11 +1090,196 @@ mod tests {
             Action::Continue {
                 discriminee: None,
                 remaining: vec![],
+                resolved: vec![],
+                consumed: HashMap::from([(
+                    "variable".to_string(),
+                    tokens
+                        .iter()
+                        .scan(0, |offset, token| {
+                            let value = (*offset, token.to_string());
+                            *offset += token.len();
+                            Some(value)
+                        })
+                        .collect()
+                )]),
+                warnings: vec![],
             }
         );
         assert_eq!(variable, expected);
     }
 
+    #[rstest]
+    #[case(vec!["--range", "x", "2"], 7, "x", "MIN")]
+    #[case(vec!["--range", "1", "x"], 8, "x", "MAX")]
+    fn parser_value_names(
+        #[case] tokens: Vec<&str>,
+        #[case] offset: usize,
+        #[case] token: &str,
+        #[case] label: &str,
+    ) {
+        // Setup
+        let mut variable: Vec<u32> = Vec::default();
+        let generic_capture = Collection::new(&mut variable, Nargs::Precisely(2));
+        let config = OptionConfig::new("range", None, generic_capture.nargs().into());
+        let capture = AnonymousCapture::bind(generic_capture);
+        let parser = Parser::new(vec![(config, Box::new(capture))], Vec::default(), None)
+            .unwrap()
+            .with_value_names(HashMap::from([(
+                "range".to_string(),
+                vec!["MIN".to_string(), "MAX".to_string()],
+            )]));
+
+        // Execute
+        let result = parser.consume(tokens.as_slice(), &InMemoryInterface::default());
+
+        // Verify
+        let (error_offset, error) = result.unwrap_err();
+        assert_eq!(error_offset, offset);
+        assert_eq!(
+            error,
+            ParseError::CapturePhase(
+                "range".to_string(),
+                InvalidCapture::InvalidPosition {
+                    label: label.to_string(),
+                    error: Box::new(InvalidCapture::InvalidConversion {
+                        token: token.to_string(),
+                        type_name: "u32",
+                    }),
+                }
+            )
+        );
+        assert_eq!(
+            error.to_string(),
+            format!("Parse error during capture: cannot convert '{token}' to u32 ({label}).")
+        );
+    }
+
+    #[rstest]
+    #[case(vec![], 5, vec![])]
+    #[case(vec!["--value", "1"], 1, vec![("value", vec![(7, "1")])])]
+    fn parser_environment(
+        #[case] tokens: Vec<&str>,
+        #[case] expected: u32,
+        #[case] expected_consumed: Vec<(&str, Vec<(usize, &str)>)>,
+    ) {
+        // Setup
+        std::env::set_var("BLARG_PARSER_ENVIRONMENT_VALUE", "5");
+        let mut variable: u32 = 0;
+        let generic_capture = Scalar::new(&mut variable);
+        let config = OptionConfig::new("value", None, generic_capture.nargs().into());
+        let capture = AnonymousCapture::bind(generic_capture);
+        let parser = Parser::new(vec![(config, Box::new(capture))], Vec::default(), None)
+            .unwrap()
+            .with_validators(vec![("value".to_string(), None)])
+            .with_environment(vec![(
+                "value".to_string(),
+                "BLARG_PARSER_ENVIRONMENT_VALUE".to_string(),
+            )]);
+
+        // Execute
+        let result = parser
+            .consume(tokens.as_slice(), &InMemoryInterface::default())
+            .unwrap();
+
+        // Verify
+        assert_eq!(
+            result,
+            Action::Continue {
+                discriminee: None,
+                remaining: vec![],
+                resolved: vec!["--value".to_string(), expected.to_string()],
+                consumed: expected_consumed
+                    .into_iter()
+                    .map(|(name, values)| (
+                        name.to_string(),
+                        values
+                            .into_iter()
+                            .map(|(offset, value)| (offset, value.to_string()))
+                            .collect()
+                    ))
+                    .collect(),
+                warnings: vec![],
+            }
+        );
+        assert_eq!(variable, expected);
+    }
+
+    #[rstest]
+    #[case(vec!["value", "other"], "value", "x")]
+    #[case(vec!["other", "value"], "other", "y")]
+    fn parser_environment_invalid(
+        #[case] declarations: Vec<&str>,
+        #[case] expected_name: &str,
+        #[case] expected_token: &str,
+    ) {
+        // Setup
+        std::env::set_var("BLARG_PARSER_ENVIRONMENT_INVALID_VALUE", "x");
+        std::env::set_var("BLARG_PARSER_ENVIRONMENT_INVALID_OTHER", "y");
+        let mut value: u32 = 0;
+        let mut other: u32 = 0;
+        let value_capture = Scalar::new(&mut value);
+        let other_capture = Scalar::new(&mut other);
+        let value_config = OptionConfig::new("value", None, value_capture.nargs().into());
+        let other_config = OptionConfig::new("other", None, other_capture.nargs().into());
+        let parser = Parser::new(
+            vec![
+                (
+                    value_config,
+                    Box::new(AnonymousCapture::bind(value_capture)),
+                ),
+                (
+                    other_config,
+                    Box::new(AnonymousCapture::bind(other_capture)),
+                ),
+            ],
+            Vec::default(),
+            None,
+        )
+        .unwrap()
+        .with_environment(
+            declarations
+                .into_iter()
+                .map(|name| {
+                    (
+                        name.to_string(),
+                        format!(
+                            "BLARG_PARSER_ENVIRONMENT_INVALID_{}",
+                            name.to_ascii_uppercase()
+                        ),
+                    )
+                })
+                .collect(),
+        );
+
+        // Execute
+        let result = parser.consume(empty::slice(), &InMemoryInterface::default());
+
+        // Verify
+        let (_, error) = result.unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "Parse error during capture from the environment variable 'BLARG_PARSER_ENVIRONMENT_INVALID_{}': cannot convert '{expected_token}' to u32.",
+                expected_name.to_ascii_uppercase()
+            )
+        );
+        assert_eq!(
+            error,
+            ParseError::Environment {
+                name: expected_name.to_string(),
+                variable: format!(
+                    "BLARG_PARSER_ENVIRONMENT_INVALID_{}",
+                    expected_name.to_ascii_uppercase()
+                ),
+                value: expected_token.to_string(),
+                error: Box::new(InvalidCapture::InvalidConversion {
+                    token: expected_token.to_string(),
+                    type_name: "u32",
+                })
+            }
+        );
+    }
+
     #[rstest]
     #[case(vec!["--help"])]
     #[case(vec!["-h"])]
@@ -336,7 +1296,9 @@ mod tests {
         let parser = Parser::new(Vec::default(), vec![(config, Box::new(capture))], None).unwrap();
 
         // Execute
-        let result = parser.consume(tokens.as_slice()).unwrap();
+        let result = parser
+            .consume(tokens.as_slice(), &InMemoryInterface::default())
+            .unwrap();
 
         // Verify
         assert_eq!(result, Action::PrintHelp);
@@ -344,16 +1306,487 @@ mod tests {
     }
 
     #[rstest]
-    #[case(vec!["1"], 0, "1", vec![])]
-    #[case(vec!["01"], 0, "01", vec![])]
-    #[case(vec!["1", "abc"], 0, "1", vec!["abc"])]
-    #[case(vec!["1", "abc", "2"], 0, "1", vec!["abc", "2"])]
-    #[case(vec!["--flag", "1"], 6, "1", vec![])]
+    #[case(vec!["--usage"])]
+    #[case(vec!["--usage", "1"])]
+    #[case(vec!["--usage", "not-a-u32"])]
+    fn parser_usage(#[case] tokens: Vec<&str>) {
+        // Setup
+        let mut variable: u32 = 0;
+        let generic_capture = Scalar::new(&mut variable);
+        let config = ArgumentConfig::new("variable", generic_capture.nargs().into());
+        let capture = AnonymousCapture::bind(generic_capture);
+        let parser = Parser::new(Vec::default(), vec![(config, Box::new(capture))], None)
+            .unwrap()
+            .with_usage_flag()
+            .unwrap();
+
+        // Execute
+        let result = parser
+            .consume(tokens.as_slice(), &InMemoryInterface::default())
+            .unwrap();
+
+        // Verify
+        assert_eq!(result, Action::PrintUsage);
+        assert_eq!(variable, 0);
+    }
+
+    #[test]
+    fn parser_usage_duplicate() {
+        let result = Parser::new(
+            vec![(
+                OptionConfig::new("usage", None, thread_rng().gen()),
+                Box::new(BlackHole::default()),
+            )],
+            Vec::default(),
+            None,
+        )
+        .unwrap()
+        .with_usage_flag();
+        assert_matches!(result, Err(ConfigError(_)));
+    }
+
+    #[rstest]
+    #[case(vec!["--version"])]
+    #[case(vec!["--version", "1"])]
+    #[case(vec!["--version", "not-a-u32"])]
+    fn parser_version(#[case] tokens: Vec<&str>) {
+        // Setup
+        let mut variable: u32 = 0;
+        let generic_capture = Scalar::new(&mut variable);
+        let config = ArgumentConfig::new("variable", generic_capture.nargs().into());
+        let capture = AnonymousCapture::bind(generic_capture);
+        let parser = Parser::new(Vec::default(), vec![(config, Box::new(capture))], None)
+            .unwrap()
+            .with_version_flag()
+            .unwrap();
+
+        // Execute
+        let result = parser
+            .consume(tokens.as_slice(), &InMemoryInterface::default())
+            .unwrap();
+
+        // Verify
+        assert_eq!(result, Action::PrintVersion);
+        assert_eq!(variable, 0);
+    }
+
+    #[rstest]
+    #[case(vec!["1"], false)]
+    #[case(vec!["--quiet", "1"], true)]
+    #[case(vec!["1", "--quiet"], true)]
+    fn parser_quiet(#[case] tokens: Vec<&str>, #[case] expected: bool) {
+        // Setup
+        let quiet = Rc::new(Cell::new(false));
+        let mut variable: u32 = 0;
+        let generic_capture = Scalar::new(&mut variable);
+        let config = ArgumentConfig::new("variable", generic_capture.nargs().into());
+        let capture = AnonymousCapture::bind(generic_capture);
+        let parser = Parser::new(Vec::default(), vec![(config, Box::new(capture))], None)
+            .unwrap()
+            .with_quiet_flag(quiet.clone())
+            .unwrap();
+
+        // Execute
+        let result = parser
+            .consume(tokens.as_slice(), &InMemoryInterface::default())
+            .unwrap();
+
+        // Verify
+        assert_matches!(result, Action::Continue { .. });
+        assert_eq!(quiet.get(), expected);
+        assert_eq!(variable, 1);
+    }
+
+    #[test]
+    fn parser_quiet_duplicate() {
+        let result = Parser::new(
+            vec![(
+                OptionConfig::new("quiet", None, thread_rng().gen()),
+                Box::new(BlackHole::default()),
+            )],
+            Vec::default(),
+            None,
+        )
+        .unwrap()
+        .with_quiet_flag(Rc::new(Cell::new(false)));
+        assert_matches!(result, Err(ConfigError(_)));
+    }
+
+    #[rstest]
+    #[case(vec![], vec![])]
+    #[case(vec!["--a", "1"], vec!["warning: '--a' is deprecated: Use b."])]
+    #[case(vec!["1"], vec!["warning: 'c' is deprecated: Use nothing."])]
+    #[case(vec!["1", "--a", "1"], vec!["warning: '--a' is deprecated: Use b.", "warning: 'c' is deprecated: Use nothing."])]
+    fn parser_deprecations(#[case] tokens: Vec<&str>, #[case] expected: Vec<&str>) {
+        // Setup
+        let parser = Parser::new(
+            vec![(
+                OptionConfig::new("a", None, Bound::Range(1, 1)),
+                Box::new(BlackHole::default()),
+            )],
+            vec![(
+                ArgumentConfig::new("c", Bound::Range(0, 1)),
+                Box::new(BlackHole::default()),
+            )],
+            None,
+        )
+        .unwrap()
+        .with_validators(vec![("a".to_string(), None), ("c".to_string(), None)])
+        .with_deprecations(HashMap::from([
+            ("a".to_string(), "Use b.".to_string()),
+            ("c".to_string(), "Use nothing.".to_string()),
+        ]));
+
+        // Execute
+        let result = parser
+            .consume(tokens.as_slice(), &InMemoryInterface::default())
+            .unwrap();
+
+        // Verify
+        assert_matches!(result, Action::Continue { warnings, .. } => {
+            assert_eq!(warnings, expected);
+        });
+    }
+
+    #[rstest]
+    #[case(vec![], vec![])]
+    #[case(vec!["file.txt"], vec![])]
+    #[case(vec!["*.txt"], vec!["warning: '*.txt' for 'c' looks like an unexpanded glob (the shell may not have matched any files)."])]
+    #[case(vec!["--a", "file?.txt", "*.txt"], vec!["warning: 'file?.txt' for '--a' looks like an unexpanded glob (the shell may not have matched any files).", "warning: '*.txt' for 'c' looks like an unexpanded glob (the shell may not have matched any files)."])]
+    fn parser_glob_warnings(#[case] tokens: Vec<&str>, #[case] expected: Vec<&str>) {
+        // Setup
+        let parser = Parser::new(
+            vec![(
+                OptionConfig::new("a", None, Bound::Range(1, 1)),
+                Box::new(BlackHole::default()),
+            )],
+            vec![(
+                ArgumentConfig::new("c", Bound::Range(0, 1)),
+                Box::new(BlackHole::default()),
+            )],
+            None,
+        )
+        .unwrap()
+        .with_validators(vec![("a".to_string(), None), ("c".to_string(), None)])
+        .with_glob_warnings(HashSet::from(["a".to_string(), "c".to_string()]));
+
+        // Execute
+        let result = parser
+            .consume(tokens.as_slice(), &InMemoryInterface::default())
+            .unwrap();
+
+        // Verify
+        assert_matches!(result, Action::Continue { warnings, .. } => {
+            assert_eq!(warnings, expected);
+        });
+    }
+
+    #[rstest]
+    #[case(vec![], vec![])]
+    #[case(vec![Bound::Range(1, 1), Bound::Lower(0)], vec![])]
+    #[case(vec![Bound::Lower(1), Bound::Range(0, 1)], vec![ConfigWarning::UnreachableArgument("a0".to_string(), "a1".to_string())])]
+    #[case(vec![Bound::Range(2, 1), Bound::Lower(0)], vec![ConfigWarning::EmptyRange("a0".to_string(), 2, 1)])]
+    fn parser_lint(#[case] bounds: Vec<Bound>, #[case] expected: Vec<ConfigWarning>) {
+        // Setup
+        let arguments: Vec<ArgumentCapture> = bounds
+            .into_iter()
+            .enumerate()
+            .map(|(i, bound)| {
+                let capture: Box<dyn AnonymousCapturable> = Box::new(BlackHole::default());
+                (ArgumentConfig::new(format!("a{i}"), bound), capture)
+            })
+            .collect();
+        let parser = Parser::new(Vec::default(), arguments, None).unwrap();
+
+        // Execute
+        let warnings = parser.lint();
+
+        // Verify
+        assert_eq!(warnings, expected);
+    }
+
+    #[test]
+    fn parser_lint_sentinel() {
+        // Setup
+        let parser = Parser::new(
+            vec![(
+                OptionConfig::new("o", None, Bound::Range(3, 2)),
+                Box::new(BlackHole::default()),
+            )],
+            vec![
+                (
+                    ArgumentConfig::new("a", Bound::Lower(0)),
+                    Box::new(BlackHole::default()),
+                ),
+                (
+                    ArgumentConfig::new("b", Bound::Lower(0)),
+                    Box::new(BlackHole::default()),
+                ),
+            ],
+            None,
+        )
+        .unwrap()
+        .with_argument_sentinel(';');
+
+        // Execute
+        let warnings = parser.lint();
+
+        // Verify
+        assert_eq!(
+            warnings,
+            vec![ConfigWarning::EmptyRange("o".to_string(), 3, 2)]
+        );
+    }
+
+    #[rstest]
+    #[case(false, vec![])]
+    #[case(true, vec![ConfigWarning::DigitShort("one".to_string(), '1')])]
+    fn parser_lint_digit_short(
+        #[case] negative_numbers: bool,
+        #[case] expected: Vec<ConfigWarning>,
+    ) {
+        // Setup
+        let mut parser = Parser::new(
+            vec![
+                (
+                    OptionConfig::new("one", Some('1'), Bound::Range(0, 0)),
+                    Box::new(BlackHole::default()),
+                ),
+                (
+                    OptionConfig::new("verbose", Some('v'), Bound::Range(0, 0)),
+                    Box::new(BlackHole::default()),
+                ),
+            ],
+            vec![],
+            None,
+        )
+        .unwrap();
+
+        if negative_numbers {
+            parser = parser.with_negative_numbers();
+        }
+
+        // Execute
+        let warnings = parser.lint();
+
+        // Verify
+        assert_eq!(warnings, expected);
+    }
+
+    #[rstest]
+    #[case(vec!["--b", "2", "--a", "1"], true, None)]
+    #[case(vec!["--a", "1", "--b", "2"], true, None)]
+    #[case(vec!["--a", "1"], true, Some((3, "missing b")))]
+    #[case(vec!["--b", "2"], false, None)]
+    #[case(vec!["--a", "1", "--b", "2"], false, Some((3, "missing b")))]
+    fn parser_validators(
+        #[case] tokens: Vec<&str>,
+        #[case] b_first: bool,
+        #[case] expected: Option<(usize, &str)>,
+    ) {
+        // Setup
+        let validator: Validator = Box::new(|value, context| {
+            assert_eq!(value, "1");
+            match context.get("b") {
+                Some("2") => Ok(()),
+                Some(_) => unreachable!(),
+                None => Err("missing b".to_string()),
+            }
+        });
+        let validators = if b_first {
+            vec![("b".to_string(), None), ("a".to_string(), Some(validator))]
+        } else {
+            vec![("a".to_string(), Some(validator)), ("b".to_string(), None)]
+        };
+        let parser = Parser::new(
+            vec![
+                (
+                    OptionConfig::new("a", None, Bound::Range(1, 1)),
+                    Box::new(BlackHole::default()),
+                ),
+                (
+                    OptionConfig::new("b", None, Bound::Range(1, 1)),
+                    Box::new(BlackHole::default()),
+                ),
+            ],
+            Vec::default(),
+            None,
+        )
+        .unwrap()
+        .with_validators(validators);
+
+        // Execute
+        let result = parser.consume(tokens.as_slice(), &InMemoryInterface::default());
+
+        // Verify
+        match expected {
+            None => {
+                assert_matches!(result, Ok(Action::Continue { .. }));
+            }
+            Some((expected_offset, expected_message)) => {
+                let (offset, error) = result.unwrap_err();
+                assert_eq!(offset, expected_offset);
+                assert_eq!(
+                    error,
+                    ParseError::CapturePhase(
+                        "a".to_string(),
+                        InvalidCapture::InvalidValue {
+                            token: "1".to_string(),
+                            message: expected_message.to_string(),
+                        }
+                    )
+                );
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(vec![], vec!["--a", "7", "x"], vec![("c", vec![])])]
+    #[case(vec!["--flag", "y"], vec!["--a", "7", "--flag", "y"], vec![("flag", vec![]), ("c", vec![(6, "y")])])]
+    #[case(vec!["y", "--a", "3"], vec!["--a", "3", "y"], vec![("a", vec![(4, "3")]), ("c", vec![(0, "y")])])]
+    #[case(vec!["--flag", "--a", "3", "y"], vec!["--a", "3", "--flag", "y"], vec![("a", vec![(9, "3")]), ("flag", vec![]), ("c", vec![(10, "y")])])]
+    fn parser_resolved(
+        #[case] tokens: Vec<&str>,
+        #[case] expected: Vec<&str>,
+        #[case] expected_consumed: Vec<(&str, Vec<(usize, &str)>)>,
+    ) {
+        // Setup
+        let parser = Parser::new(
+            vec![
+                (
+                    OptionConfig::new("a", None, Bound::Range(1, 1)),
+                    Box::new(BlackHole::default()),
+                ),
+                (
+                    OptionConfig::new("flag", None, Bound::Range(0, 0)),
+                    Box::new(BlackHole::default()),
+                ),
+            ],
+            vec![(
+                ArgumentConfig::new("c", Bound::Range(0, 1)),
+                Box::new(BlackHole::default()),
+            )],
+            None,
+        )
+        .unwrap()
+        .with_validators(vec![
+            ("a".to_string(), None),
+            ("flag".to_string(), None),
+            ("c".to_string(), None),
+        ])
+        .with_initials(HashMap::from([
+            ("a".to_string(), "7".to_string()),
+            ("c".to_string(), "x".to_string()),
+        ]));
+
+        // Execute
+        let result = parser
+            .consume(tokens.as_slice(), &InMemoryInterface::default())
+            .unwrap();
+
+        // Verify
+        assert_eq!(
+            result,
+            Action::Continue {
+                discriminee: None,
+                remaining: vec![],
+                resolved: expected.into_iter().map(|s| s.to_string()).collect(),
+                consumed: expected_consumed
+                    .into_iter()
+                    .map(|(name, values)| (
+                        name.to_string(),
+                        values
+                            .into_iter()
+                            .map(|(offset, value)| (offset, value.to_string()))
+                            .collect()
+                    ))
+                    .collect(),
+                warnings: vec![],
+            }
+        );
+    }
+
+    #[rstest]
+    #[case(vec![], vec![])]
+    #[case(vec!["-v"], vec![])]
+    #[case(vec!["run"], vec![])]
+    #[case(vec!["-v", "run", "--flag", "x"], vec!["--flag", "x"])]
+    #[case(vec!["run", "-v", "run"], vec!["-v", "run"])]
+    #[case(vec!["run", "--", "--help"], vec!["--", "--help"])]
+    fn parser_remainder(#[case] tokens: Vec<&str>, #[case] expected: Vec<&str>) {
+        // Setup
+        let mut remainder: Vec<String> = Vec::default();
+        let parser = Parser::new(
+            vec![(
+                OptionConfig::new("verbose", Some('v'), Bound::Range(0, 0)),
+                Box::new(BlackHole::default()),
+            )],
+            vec![(
+                ArgumentConfig::new("command", Bound::Range(0, 1)),
+                Box::new(BlackHole::default()),
+            )],
+            None,
+        )
+        .unwrap()
+        .with_remainder(&mut remainder);
+
+        // Execute
+        let result = parser
+            .consume(tokens.as_slice(), &InMemoryInterface::default())
+            .unwrap();
+
+        // Verify
+        assert_matches!(result, Action::Continue { discriminee, remaining, .. } => {
+            assert_eq!(discriminee, None);
+            assert_eq!(remaining, Vec::<String>::default());
+        });
+        assert_eq!(remainder, expected);
+    }
+
+    #[rstest]
+    #[case(vec!["a"], vec![])]
+    #[case(vec!["a", "b"], vec!["b"])]
+    #[case(vec!["-v", "a", "b", "-v"], vec!["b", "-v"])]
+    fn parser_trailing(#[case] tokens: Vec<&str>, #[case] expected: Vec<&str>) {
+        // Setup
+        let parser = Parser::new(
+            vec![(
+                OptionConfig::new("verbose", Some('v'), Bound::Range(0, 0)),
+                Box::new(BlackHole::default()),
+            )],
+            vec![(
+                ArgumentConfig::new("source", Bound::Range(1, 1)),
+                Box::new(BlackHole::default()),
+            )],
+            None,
+        )
+        .unwrap()
+        .with_trailing();
+
+        // Execute
+        let result = parser
+            .consume(tokens.as_slice(), &InMemoryInterface::default())
+            .unwrap();
+
+        // Verify
+        assert_matches!(result, Action::Continue { discriminee, remaining, .. } => {
+            assert_eq!(discriminee, None);
+            assert_eq!(remaining, expected);
+        });
+    }
+
+    #[rstest]
+    #[case(vec!["1"], 0, "1", vec![], vec![("variable", vec![(0, "1")])])]
+    #[case(vec!["01"], 0, "01", vec![], vec![("variable", vec![(0, "01")])])]
+    #[case(vec!["1", "abc"], 0, "1", vec!["abc"], vec![("variable", vec![(0, "1")])])]
+    #[case(vec!["1", "abc", "2"], 0, "1", vec!["abc", "2"], vec![("variable", vec![(0, "1")])])]
+    #[case(vec!["--flag", "1"], 6, "1", vec![], vec![("flag", vec![]), ("variable", vec![(6, "1")])])]
     fn parser_discriminator(
         #[case] tokens: Vec<&str>,
         #[case] discriminee_offset: usize,
         #[case] discriminee_value: &str,
         #[case] expected: Vec<&str>,
+        #[case] expected_consumed: Vec<(&str, Vec<(usize, &str)>)>,
     ) {
         // Setup
         let mut variable: u32 = 0;
@@ -372,7 +1805,9 @@ mod tests {
         .unwrap();
 
         // Execute
-        let result = parser.consume(tokens.as_slice()).unwrap();
+        let result = parser
+            .consume(tokens.as_slice(), &InMemoryInterface::default())
+            .unwrap();
 
         // Verify
         assert_eq!(
@@ -380,6 +1815,18 @@ mod tests {
             Action::Continue {
                 discriminee: Some((discriminee_offset, discriminee_value.to_string())),
                 remaining: expected.into_iter().map(|s| s.to_string()).collect(),
+                resolved: vec![],
+                consumed: expected_consumed
+                    .into_iter()
+                    .map(|(name, values)| (
+                        name.to_string(),
+                        values
+                            .into_iter()
+                            .map(|(offset, value)| (offset, value.to_string()))
+                            .collect()
+                    ))
+                    .collect(),
+                warnings: vec![],
             }
         );
     }
@@ -1,3 +1,7 @@
+use std::cell::{Cell, RefCell};
+use std::io::IsTerminal;
+use std::rc::Rc;
+
 use crate::parser::base::ParseError;
 use crate::parser::ErrorContext;
 
@@ -82,6 +86,9 @@ const TARGET_TOTAL_FACTOR: f64 = 0.95;
 // Then 17 is a good minimum, because it allows precisely 3 words with a space between them.
 pub(crate) const MINIMUM_MIDDLE_WIDTH: usize = 17;
 
+// A sentence end is close enough to the edge to break on when it uses at least 75% of the width.
+const SENTENCE_BREAK_FACTOR: f64 = 0.75;
+
 impl ColumnRenderer {
     /// Produce a renderer based off the provided widths.
     /// This renderer will use a heuristic to chose the middle width.
@@ -186,7 +193,7 @@ impl ColumnRenderer {
         let left_column_width = &self.left.0;
         assert!(&left.len() <= left_column_width);
         let middle_column_width = &self.middle.0 - indent;
-        let middle_parts = chunk(middle, middle_column_width);
+        let middle_parts = chunk_sentences(middle, middle_column_width);
         let mut out = Vec::default();
 
         for (i, part) in middle_parts.iter().enumerate() {
@@ -214,7 +221,8 @@ impl ColumnRenderer {
         if out.is_empty() {
             assert!(middle_parts.is_empty());
             if right.is_empty() {
-                out.push(format!("{:indent$}{:left_column_width$}", "", left));
+                // Don't pad the left column when there is nothing to its right.
+                out.push(format!("{:indent$}{left}", ""));
             } else {
                 out.push(format!(
                     "{:indent$}{:left_column_width$}{padding}{:middle_column_width$}{right}",
@@ -255,6 +263,62 @@ fn chunk(paragraph: &str, width: usize) -> Vec<String> {
     lines
 }
 
+/// Chunk like [`chunk`], except prefer to break a line after a sentence end when it is close to the width.
+fn chunk_sentences(paragraph: &str, width: usize) -> Vec<String> {
+    let minimum = (width as f64 * SENTENCE_BREAK_FACTOR).ceil() as usize;
+    let mut lines = Vec::default();
+    let mut remaining = paragraph;
+
+    loop {
+        let chunked = chunk(remaining, width);
+
+        if chunked.len() <= 1 {
+            lines.extend(chunked);
+            break;
+        }
+
+        // Only the tail of the first line is moved, which never contains a hyphenated word.
+        let first = &chunked[0];
+        let sentence_end = first
+            .match_indices(". ")
+            .chain(first.match_indices("! "))
+            .chain(first.match_indices("? "))
+            .map(|(index, _)| index + 1)
+            .filter(|index| *index >= minimum)
+            .max();
+
+        match sentence_end {
+            Some(index) => {
+                let head = &first[..index];
+                // Skip past the words of the head, which are separated by (possibly multiple) spaces in the paragraph.
+                let mut words = head.split(' ').count();
+                let mut offset = 0;
+
+                for part in remaining.split(' ') {
+                    if words == 0 {
+                        break;
+                    }
+
+                    if !part.is_empty() {
+                        words -= 1;
+                    }
+
+                    offset += part.len() + 1;
+                }
+
+                lines.push(head.to_string());
+                remaining = &remaining[offset..];
+            }
+            None => {
+                lines.extend(chunked);
+                break;
+            }
+        }
+    }
+
+    lines
+}
+
 fn hyphenate(width: usize, lines: &mut Vec<String>, current: &mut String, word: &str) {
     let increment = width - 1;
     let mut left = 0;
@@ -273,22 +337,270 @@ pub(crate) trait UserInterface {
     fn print(&self, message: String);
     fn print_error(&self, error: ParseError);
     fn print_error_context(&self, error_context: ErrorContext);
+    fn print_hint(&self, hint: String);
+    fn print_warning(&self, warning: String);
+    /// Prompt for a value without echoing the response (ex: a password).
+    /// Returns `None` when the value cannot be prompted for (ex: without a terminal, or when the echo cannot be disabled).
+    fn prompt_hidden(&self, message: String) -> Option<String>;
 }
 
 #[derive(Default)]
-pub(crate) struct ConsoleInterface {}
+pub(crate) struct ConsoleInterface {
+    plain: bool,
+}
+
+impl ConsoleInterface {
+    /// Create a console interface, which strips the control characters from all output when `plain` (otherwise, only from output which isn't to a terminal).
+    pub(crate) fn new(plain: bool) -> Self {
+        Self { plain }
+    }
+
+    /// Present the `text` for a stream, stripping its control characters unless the stream is a `terminal` (and plain output isn't forced).
+    fn present(&self, text: String, terminal: bool) -> String {
+        if self.plain || !terminal {
+            strip_control(&text)
+        } else {
+            text
+        }
+    }
+
+    fn print_stderr(&self, text: String) {
+        eprintln!("{}", self.present(text, std::io::stderr().is_terminal()));
+    }
+}
 
 impl UserInterface for ConsoleInterface {
     fn print(&self, message: String) {
-        println!("{message}");
+        println!("{}", self.present(message, std::io::stdout().is_terminal()));
     }
 
     fn print_error(&self, error: ParseError) {
-        eprintln!("{error}");
+        self.print_stderr(error.to_string());
     }
 
     fn print_error_context(&self, error_context: ErrorContext) {
-        eprintln!("{error_context}");
+        self.print_stderr(error_context.to_string());
+    }
+
+    fn print_hint(&self, hint: String) {
+        self.print_stderr(hint);
+    }
+
+    fn print_warning(&self, warning: String) {
+        self.print_stderr(warning);
+    }
+
+    #[cfg(unix)]
+    fn prompt_hidden(&self, message: String) -> Option<String> {
+        let stdin = std::io::stdin();
+
+        // Without a terminal the prompt would go unanswered, so refuse rather than hang.
+        if !stdin.is_terminal() {
+            return None;
+        }
+
+        // Fail closed: never prompt when the response would be echoed.
+        if !set_echo(false) {
+            return None;
+        }
+
+        eprint!("{message}");
+        let mut response = String::default();
+        let result = stdin.read_line(&mut response);
+        set_echo(true);
+        eprintln!();
+        result
+            .ok()
+            .map(|_| response.trim_end_matches(['\r', '\n']).to_string())
+    }
+
+    #[cfg(not(unix))]
+    fn prompt_hidden(&self, _message: String) -> Option<String> {
+        // The echo cannot be disabled, so refuse rather than reveal the response.
+        None
+    }
+}
+
+/// Strip the control characters from the `text`, including whole ANSI escape sequences (ex: `\x1b[1m`).
+/// Only the line breaks and tabs are kept.
+pub(crate) fn strip_control(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\n' | '\t' => stripped.push(c),
+            '\x1b' => match chars.peek() {
+                // CSI (ex: `\x1b[1;31m`): parameters, up to and including the final byte in `@` to `~`.
+                Some('[') => {
+                    chars.next();
+                    for c in chars.by_ref() {
+                        if ('@'..='~').contains(&c) {
+                            break;
+                        }
+                    }
+                }
+                // OSC (ex: `\x1b]8;;url\x07`): up to and including the terminating BEL or ST (`\x1b\\`).
+                Some(']') => {
+                    chars.next();
+                    while let Some(c) = chars.next() {
+                        if c == '\x07' || (c == '\x1b' && chars.next_if_eq(&'\\').is_some()) {
+                            break;
+                        }
+                    }
+                }
+                // Any other escape is a single character (ex: `\x1bc`).
+                _ => {
+                    chars.next();
+                }
+            },
+            _ if c.is_control() => {}
+            _ => stripped.push(c),
+        }
+    }
+
+    stripped
+}
+
+/// Toggle whether the terminal echoes the input (via `stty`).
+/// Returns whether the setting was applied.
+#[cfg(unix)]
+fn set_echo(echo: bool) -> bool {
+    let setting = if echo { "echo" } else { "-echo" };
+    std::process::Command::new("stty")
+        .arg(setting)
+        .stdin(std::process::Stdio::inherit())
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
+
+/// Drops the warnings once the `--quiet` flag has been matched, while passing through everything else.
+pub(crate) struct QuietInterface {
+    inner: Box<dyn UserInterface>,
+    quiet: Rc<Cell<bool>>,
+}
+
+impl QuietInterface {
+    pub(crate) fn new(inner: Box<dyn UserInterface>, quiet: Rc<Cell<bool>>) -> Self {
+        Self { inner, quiet }
+    }
+}
+
+impl UserInterface for QuietInterface {
+    fn print(&self, message: String) {
+        self.inner.print(message);
+    }
+
+    fn print_error(&self, error: ParseError) {
+        self.inner.print_error(error);
+    }
+
+    fn print_error_context(&self, error_context: ErrorContext) {
+        self.inner.print_error_context(error_context);
+    }
+
+    fn print_hint(&self, hint: String) {
+        self.inner.print_hint(hint);
+    }
+
+    fn print_warning(&self, warning: String) {
+        if !self.quiet.get() {
+            self.inner.print_warning(warning);
+        }
+    }
+
+    fn prompt_hidden(&self, message: String) -> Option<String> {
+        self.inner.prompt_hidden(message)
+    }
+}
+
+/// Renders the printed messages into a single string, rather than displaying them.
+#[derive(Default)]
+pub(crate) struct RenderInterface {
+    messages: RefCell<Vec<String>>,
+}
+
+impl RenderInterface {
+    pub(crate) fn rendered(self) -> String {
+        self.messages.into_inner().join("\n")
+    }
+}
+
+impl UserInterface for RenderInterface {
+    fn print(&self, message: String) {
+        self.messages.borrow_mut().push(message);
+    }
+
+    fn print_error(&self, _error: ParseError) {
+        unreachable!("internal error - must only render messages");
+    }
+
+    fn print_error_context(&self, _error_context: ErrorContext) {
+        unreachable!("internal error - must only render messages");
+    }
+
+    fn print_hint(&self, _hint: String) {
+        unreachable!("internal error - must only render messages");
+    }
+
+    fn print_warning(&self, _warning: String) {
+        unreachable!("internal error - must only render messages");
+    }
+
+    fn prompt_hidden(&self, _message: String) -> Option<String> {
+        unreachable!("internal error - must only render messages");
+    }
+}
+
+/// Writes the printed messages (each on its own line) to a writer, rather than displaying them.
+/// Like for any output which isn't to a terminal, the control characters are stripped.
+pub(crate) struct WriteInterface<'w> {
+    writer: RefCell<&'w mut dyn std::io::Write>,
+    result: RefCell<std::io::Result<()>>,
+}
+
+impl<'w> WriteInterface<'w> {
+    pub(crate) fn new(writer: &'w mut dyn std::io::Write) -> Self {
+        Self {
+            writer: RefCell::new(writer),
+            result: RefCell::new(Ok(())),
+        }
+    }
+
+    /// The result of writing the messages, which stops at the first error.
+    pub(crate) fn result(self) -> std::io::Result<()> {
+        self.result.into_inner()
+    }
+}
+
+impl<'w> UserInterface for WriteInterface<'w> {
+    fn print(&self, message: String) {
+        let mut result = self.result.borrow_mut();
+
+        if result.is_ok() {
+            *result = writeln!(self.writer.borrow_mut(), "{}", strip_control(&message));
+        }
+    }
+
+    fn print_error(&self, _error: ParseError) {
+        unreachable!("internal error - must only write messages");
+    }
+
+    fn print_error_context(&self, _error_context: ErrorContext) {
+        unreachable!("internal error - must only write messages");
+    }
+
+    fn print_hint(&self, _hint: String) {
+        unreachable!("internal error - must only write messages");
+    }
+
+    fn print_warning(&self, _warning: String) {
+        unreachable!("internal error - must only write messages");
+    }
+
+    fn prompt_hidden(&self, _message: String) -> Option<String> {
+        unreachable!("internal error - must only write messages");
     }
 }
 
@@ -296,12 +608,15 @@ impl UserInterface for ConsoleInterface {
 pub(crate) mod util {
     use crate::parser::{ErrorContext, ParseError, UserInterface};
     use std::cell::RefCell;
+    use std::collections::VecDeque;
     use std::sync::mpsc;
 
     pub(crate) struct InMemoryInterface {
         message: RefCell<Option<Vec<String>>>,
         error: RefCell<Option<String>>,
         error_context: RefCell<Option<ErrorContext>>,
+        hints: RefCell<Vec<String>>,
+        warnings: RefCell<Vec<String>>,
     }
 
     impl Default for InMemoryInterface {
@@ -310,6 +625,8 @@ pub(crate) mod util {
                 message: RefCell::new(None),
                 error: RefCell::new(None),
                 error_context: RefCell::new(None),
+                hints: RefCell::new(Vec::default()),
+                warnings: RefCell::new(Vec::default()),
             }
         }
     }
@@ -335,6 +652,18 @@ pub(crate) mod util {
             // Assumes print_error_context() is only ever called once.
             self.error_context.borrow_mut().replace(error_context);
         }
+
+        fn print_hint(&self, hint: String) {
+            self.hints.borrow_mut().push(hint);
+        }
+
+        fn print_warning(&self, warning: String) {
+            self.warnings.borrow_mut().push(warning);
+        }
+
+        fn prompt_hidden(&self, _message: String) -> Option<String> {
+            None
+        }
     }
 
     impl InMemoryInterface {
@@ -343,6 +672,7 @@ pub(crate) mod util {
                 message,
                 error,
                 error_context,
+                ..
             } = self;
 
             (
@@ -364,15 +694,22 @@ pub(crate) mod util {
         let (message_tx, message_rx) = mpsc::channel();
         let (error_tx, error_rx) = mpsc::channel();
         let (error_context_tx, error_context_rx) = mpsc::channel();
+        let (hint_tx, hint_rx) = mpsc::channel();
+        let (warning_tx, warning_rx) = mpsc::channel();
         let sender = SenderInterface {
             message_tx,
             error_tx,
             error_context_tx,
+            hint_tx,
+            warning_tx,
+            responses: RefCell::new(VecDeque::default()),
         };
         let receiver = ReceiverInterface {
             message_rx,
             error_rx,
             error_context_rx,
+            hint_rx,
+            warning_rx,
         };
         (sender, receiver)
     }
@@ -381,6 +718,18 @@ pub(crate) mod util {
         message_tx: mpsc::Sender<Option<String>>,
         error_tx: mpsc::Sender<Option<String>>,
         error_context_tx: mpsc::Sender<Option<ErrorContext>>,
+        hint_tx: mpsc::Sender<Option<String>>,
+        warning_tx: mpsc::Sender<Option<String>>,
+        responses: RefCell<VecDeque<String>>,
+    }
+
+    impl SenderInterface {
+        /// Script the responses to the prompts, in order (without any, prompting behaves as if there's no terminal).
+        pub(crate) fn with_responses(self, responses: Vec<&str>) -> Self {
+            self.responses
+                .replace(responses.into_iter().map(|r| r.to_string()).collect());
+            self
+        }
     }
 
     impl Drop for SenderInterface {
@@ -388,6 +737,8 @@ pub(crate) mod util {
             self.message_tx.send(None).unwrap();
             self.error_tx.send(None).unwrap();
             self.error_context_tx.send(None).unwrap();
+            self.hint_tx.send(None).unwrap();
+            self.warning_tx.send(None).unwrap();
         }
     }
 
@@ -406,25 +757,50 @@ pub(crate) mod util {
             // Assumes print_error_context() is only ever called once, with the receiver only taking the first.
             self.error_context_tx.send(Some(error_context)).unwrap();
         }
+
+        fn print_hint(&self, hint: String) {
+            // Allows for print_hint() to be called many times, with the receiver concatenating the hints.
+            self.hint_tx.send(Some(hint)).unwrap();
+        }
+
+        fn print_warning(&self, warning: String) {
+            // Allows for print_warning() to be called many times, with the receiver concatenating the warnings.
+            self.warning_tx.send(Some(warning)).unwrap();
+        }
+
+        fn prompt_hidden(&self, _message: String) -> Option<String> {
+            self.responses.borrow_mut().pop_front()
+        }
     }
 
     pub(crate) struct ReceiverInterface {
         message_rx: mpsc::Receiver<Option<String>>,
         error_rx: mpsc::Receiver<Option<String>>,
         error_context_rx: mpsc::Receiver<Option<ErrorContext>>,
+        hint_rx: mpsc::Receiver<Option<String>>,
+        warning_rx: mpsc::Receiver<Option<String>>,
     }
 
     impl ReceiverInterface {
+        pub(crate) fn consume_hints(&self) -> Option<String> {
+            drain(&self.hint_rx)
+        }
+
+        pub(crate) fn consume_warnings(&self) -> Option<String> {
+            drain(&self.warning_rx)
+        }
+
         pub(crate) fn consume(self) -> (Option<String>, Option<String>, Option<ErrorContext>) {
             let ReceiverInterface {
                 message_rx,
                 error_rx,
                 error_context_rx,
+                ..
             } = self;
 
             (
-                drain(message_rx),
-                drain(error_rx),
+                drain(&message_rx),
+                drain(&error_rx),
                 // Assumes print_error_context() is only ever called once
                 // (we take the first if multiple were sent on the channel).
                 error_context_rx.recv().unwrap(),
@@ -439,7 +815,7 @@ pub(crate) mod util {
         }
     }
 
-    fn drain(receiver: mpsc::Receiver<Option<String>>) -> Option<String> {
+    fn drain(receiver: &mpsc::Receiver<Option<String>>) -> Option<String> {
         let mut values = Vec::default();
 
         loop {
@@ -460,6 +836,99 @@ pub(crate) mod util {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parser::util::channel_interface;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(false, Some("warning: abc".to_string()))]
+    #[case(true, None)]
+    fn quiet_interface(#[case] quiet: bool, #[case] expected_warnings: Option<String>) {
+        // Setup
+        let (sender, receiver) = channel_interface();
+        let interface = QuietInterface::new(Box::new(sender), Rc::new(Cell::new(quiet)));
+
+        // Execute
+        interface.print("message".to_string());
+        interface.print_warning("warning: abc".to_string());
+        interface.print_error(ParseError::BranchingPhase("error".to_string()));
+        interface.print_error_context(ErrorContext::new(0, &["abc"]));
+        interface.print_hint("hint".to_string());
+        drop(interface);
+
+        // Verify
+        assert_eq!(receiver.consume_warnings(), expected_warnings);
+        assert_eq!(receiver.consume_hints(), Some("hint".to_string()));
+        let (message, error, error_context) = receiver.consume();
+        assert_eq!(message, Some("message".to_string()));
+        assert_eq!(
+            error,
+            Some("Parse error during branching: error".to_string())
+        );
+        assert_eq!(error_context, Some(ErrorContext::new(0, &["abc"])));
+    }
+
+    #[rstest]
+    #[case("", "")]
+    #[case("plain text", "plain text")]
+    #[case("a\nb\tc", "a\nb\tc")]
+    #[case("\x1b[1mbold\x1b[0m", "bold")]
+    #[case("\x1b[1;31mred\x1b[m text", "red text")]
+    #[case("\x1b]8;;https://a.b\x07link\x1b]8;;\x1b\\", "link")]
+    #[case("\x1bcreset", "reset")]
+    #[case("bell\x07 cr\r nul\0 del\x7f", "bell cr nul del")]
+    #[case("unterminated \x1b[1", "unterminated ")]
+    #[case("ünïcödé", "ünïcödé")]
+    fn strip_control_characters(#[case] text: &str, #[case] expected: &str) {
+        // Execute
+        let stripped = strip_control(text);
+
+        // Verify
+        assert_eq!(stripped, expected);
+    }
+
+    #[rstest]
+    #[case(false, true, "\x1b[1mbold\x1b[0m")]
+    #[case(false, false, "bold")]
+    #[case(true, true, "bold")]
+    #[case(true, false, "bold")]
+    fn console_interface_present(
+        #[case] plain: bool,
+        #[case] terminal: bool,
+        #[case] expected: &str,
+    ) {
+        // Setup
+        let interface = ConsoleInterface::new(plain);
+
+        // Execute
+        let presented = interface.present("\x1b[1mbold\x1b[0m".to_string(), terminal);
+
+        // Verify
+        assert_eq!(presented, expected);
+    }
+
+    #[test]
+    fn console_interface_non_terminal() {
+        // Setup
+        let interface = ConsoleInterface::default();
+        let error = ParseError::BranchingPhase("unknown '\x1b[31mx\x1b[0m'".to_string());
+        let error_context = ErrorContext::new(0, &["\x1b[31mx\x1b[0m", "\x07"]);
+        let help = "usage: program [-h]\n\n\x1b[1moptions:\x1b[0m\n -h, --help  Show this help message and exit.";
+
+        // Execute
+        let output = [
+            interface.present(help.to_string(), false),
+            interface.present(error.to_string(), false),
+            interface.present(error_context.to_string(), false),
+        ];
+
+        // Verify
+        for text in output {
+            assert!(
+                !text.chars().any(|c| c.is_control() && c != '\n'),
+                "{text:?}"
+            );
+        }
+    }
 
     #[test]
     fn column_renderer_combined() {
@@ -694,6 +1163,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn column_renderer_middle_sentences() {
+        let cr = ColumnRenderer::new(
+            PaddingWidth::new(4).unwrap(),
+            LeftWidth::new(5).unwrap(),
+            MiddleWidth::new(23).unwrap(),
+            vec![],
+        );
+
+        // The sentence end is close to the width.
+        assert_eq!(
+            cr.render(0, "abc", "Open the front door. It is now open.", &vec![]),
+            vec![
+                "abc      Open the front door.".to_string(),
+                "         It is now open.".to_string(),
+            ]
+        );
+        assert_eq!(
+            cr.render(0, "abc", "Open the  front door.  It is now open!", &vec![]),
+            vec![
+                "abc      Open the front door.".to_string(),
+                "         It is now open!".to_string(),
+            ]
+        );
+        assert_eq!(
+            cr.render(
+                0,
+                "abc",
+                "Open the front door? It is wide open now. Go right in.",
+                &vec![]
+            ),
+            vec![
+                "abc      Open the front door?".to_string(),
+                "         It is wide open now.".to_string(),
+                "         Go right in.".to_string(),
+            ]
+        );
+
+        // The sentence end is far from the width.
+        assert_eq!(
+            cr.render(0, "abc", "Go now. Open the front door please.", &vec![]),
+            vec![
+                "abc      Go now. Open the front".to_string(),
+                "         door please.".to_string(),
+            ]
+        );
+    }
+
     #[test]
     fn column_renderer_middle_empty() {
         let cr = ColumnRenderer::new(
@@ -707,8 +1224,7 @@ mod tests {
             ],
         );
 
-        // TODO: Fix the trailing whitespace on this one.
-        assert_eq!(cr.render(0, "abc", "", &vec![]), vec!["abc  ".to_string()]);
+        assert_eq!(cr.render(0, "abc", "", &vec![]), vec!["abc".to_string()]);
         assert_eq!(
             cr.render(
                 0,
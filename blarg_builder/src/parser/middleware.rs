@@ -1,17 +1,29 @@
 use std::collections::HashMap;
 use std::env;
+use std::rc::Rc;
 
+use crate::matcher::{token_width, OffsetValue};
 use crate::parser::base::*;
-use crate::parser::interface::UserInterface;
+use crate::parser::interface::{RenderInterface, UserInterface, WriteInterface};
 use crate::parser::printer::Printer;
 use crate::parser::ErrorContext;
 
+/// The rewriting of the input tokens, before they are matched.
+pub(crate) type Preprocessor<'a> = Box<dyn Fn(Vec<String>) -> Vec<String> + 'a>;
+
+/// The dispatch to a sub-command, after it is successfully parsed.
+pub(crate) type Handler<'a> = Box<dyn FnOnce() + 'a>;
+
 /// The configured command line parser.
 /// Built via [`CommandLineParser::build`](./struct.CommandLineParser.html#method.build) or [`SubCommandParser::build`](./struct.SubCommandParser.html#method.build).
 pub struct GeneralParser<'a> {
     command: ParseUnit<'a>,
     sub_commands: HashMap<String, ParseUnit<'a>>,
-    user_interface: Box<dyn UserInterface>,
+    aliases: HashMap<String, String>,
+    handlers: HashMap<String, Handler<'a>>,
+    preprocessor: Option<Preprocessor<'a>>,
+    help_on_unknown: bool,
+    user_interface: Rc<dyn UserInterface>,
 }
 
 impl<'a> std::fmt::Debug for GeneralParser<'a> {
@@ -25,7 +37,11 @@ impl<'a> GeneralParser<'a> {
         Self {
             command,
             sub_commands: HashMap::default(),
-            user_interface,
+            aliases: HashMap::default(),
+            handlers: HashMap::default(),
+            preprocessor: None,
+            help_on_unknown: false,
+            user_interface: Rc::from(user_interface),
         }
     }
 
@@ -37,9 +53,37 @@ impl<'a> GeneralParser<'a> {
         Self {
             command,
             sub_commands,
-            user_interface,
+            aliases: HashMap::default(),
+            handlers: HashMap::default(),
+            preprocessor: None,
+            help_on_unknown: false,
+            user_interface: Rc::from(user_interface),
         }
     }
+
+    /// Invoke the sub-command of the canonical variant for each alias.
+    pub(crate) fn with_aliases(mut self, aliases: HashMap<String, String>) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
+    /// Dispatch to the handler of the sub-command (when present), once it is successfully parsed.
+    pub(crate) fn with_handlers(mut self, handlers: HashMap<String, Handler<'a>>) -> Self {
+        self.handlers = handlers;
+        self
+    }
+
+    /// Rewrite the input tokens via the `preprocessor` (when present), before they are matched.
+    pub(crate) fn with_preprocessor(mut self, preprocessor: Option<Preprocessor<'a>>) -> Self {
+        self.preprocessor = preprocessor;
+        self
+    }
+
+    /// Print the help message of the root command along with the error for an unknown sub-command.
+    pub(crate) fn with_help_on_unknown(mut self, help_on_unknown: bool) -> Self {
+        self.help_on_unknown = help_on_unknown;
+        self
+    }
 }
 
 pub(crate) struct ParseUnit<'a> {
@@ -64,26 +108,71 @@ impl<'a> ParseUnit<'a> {
     ) -> ParseResult {
         let ParseUnit { parser, printer } = self;
 
-        match parser.consume(tokens) {
+        match parser.consume(tokens, user_interface) {
             Ok(Action::Continue {
                 discriminee,
                 remaining,
-            }) => match discriminee {
-                Some((offset, variant)) => ParseResult::Incomplete {
-                    variant_offset: offset,
-                    variant,
-                    remaining,
-                },
-                None => ParseResult::Complete,
-            },
+                resolved,
+                consumed,
+                warnings,
+            }) => {
+                for warning in warnings {
+                    user_interface.print_warning(warning);
+                }
+
+                match discriminee {
+                    Some((offset, variant)) => ParseResult::Incomplete {
+                        variant_offset: offset,
+                        variant,
+                        remaining,
+                        resolved,
+                        consumed,
+                    },
+                    None => ParseResult::Complete {
+                        resolved,
+                        consumed,
+                        remaining,
+                    },
+                }
+            }
             Ok(Action::PrintHelp) => {
-                printer.print_help(user_interface);
-                ParseResult::Exit(0)
+                let render_interface = RenderInterface::default();
+                printer.print_help(&render_interface);
+                ParseResult::Requested(ParseOutcome::HelpRequested {
+                    text: render_interface.rendered(),
+                })
+            }
+            Ok(Action::PrintUsage) => {
+                let render_interface = RenderInterface::default();
+                printer.print_usage(&render_interface);
+                ParseResult::Requested(ParseOutcome::UsageRequested {
+                    text: render_interface.rendered(),
+                })
+            }
+            Ok(Action::PrintVersion) => {
+                let render_interface = RenderInterface::default();
+                printer.print_version(&render_interface);
+                ParseResult::Requested(ParseOutcome::VersionRequested {
+                    text: render_interface.rendered(),
+                })
             }
             Err((offset, parse_error)) => {
-                user_interface.print_error(parse_error);
-                user_interface.print_error_context(ErrorContext::new(offset, tokens));
-                ParseResult::Exit(1)
+                let error_context = match &parse_error {
+                    // The value didn't come from the input tokens, so point at its assignment instead.
+                    ParseError::Environment {
+                        variable, value, ..
+                    } => ErrorContext::new(
+                        token_width(variable) + 1,
+                        &[&format!("{variable}={value}")],
+                    ),
+                    _ => ErrorContext::new(offset, tokens),
+                };
+                ParseResult::Error(ParseFailure {
+                    hints: printer.contextual_hints(&parse_error),
+                    help: None,
+                    error: Box::new(parse_error),
+                    error_context,
+                })
             }
         }
     }
@@ -91,13 +180,151 @@ impl<'a> ParseUnit<'a> {
 
 #[derive(Debug, PartialEq, Eq)]
 enum ParseResult {
-    Complete,
+    Complete {
+        resolved: Vec<String>,
+        consumed: HashMap<String, Vec<OffsetValue>>,
+        remaining: Vec<String>,
+    },
     Incomplete {
         variant_offset: usize,
         variant: String,
         remaining: Vec<String>,
+        resolved: Vec<String>,
+        consumed: HashMap<String, Vec<OffsetValue>>,
     },
-    Exit(i32),
+    Requested(ParseOutcome),
+    Error(ParseFailure),
+}
+
+/// The outcome of running the command line parser, via [`GeneralParser::parse_tokens_outcome`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseOutcome {
+    /// The input tokens were parsed, and captured into the program variables.
+    Parsed {
+        /// The number of values matched by each parameter (by name), as consumed from the input tokens.
+        /// Options which aren't provided are absent, while a switch (ex: `--verbose`) matches 0 values.
+        counts: HashMap<String, usize>,
+        /// The values matched by each parameter (by name), along with the offset of each value within the input tokens.
+        /// The offset counts the characters of the input before the value (excluding the spaces between tokens), as when pointing out an error.
+        /// Options which aren't provided are absent, while a switch (ex: `--verbose`) matches no values.
+        consumed: HashMap<String, Vec<(usize, String)>>,
+    },
+    /// The help switch (`-h` or `--help`) was encountered.
+    HelpRequested {
+        /// The rendered help message.
+        text: String,
+    },
+    /// The `--usage` flag was encountered.
+    UsageRequested {
+        /// The rendered usage summary.
+        text: String,
+    },
+    /// The `--version` flag was encountered.
+    VersionRequested {
+        /// The rendered version message (ex: "program 1.2.3").
+        text: String,
+    },
+}
+
+/// Print the text of the requested message (ex: for `--help`), returning the error code `0`.
+fn print_request(outcome: ParseOutcome, user_interface: &dyn UserInterface) -> i32 {
+    match outcome {
+        ParseOutcome::Parsed { .. } => unreachable!("internal error - must be a request."),
+        ParseOutcome::HelpRequested { text }
+        | ParseOutcome::UsageRequested { text }
+        | ParseOutcome::VersionRequested { text } => user_interface.print(text),
+    }
+
+    0
+}
+
+/// The result of a successful parse, as gathered across the command and its sub-command.
+#[derive(Debug, PartialEq, Eq)]
+struct Parsed {
+    resolved: Vec<String>,
+    consumed: HashMap<String, Vec<OffsetValue>>,
+    remaining: Vec<String>,
+}
+
+impl From<Parsed> for ParseOutcome {
+    fn from(parsed: Parsed) -> Self {
+        ParseOutcome::Parsed {
+            counts: parsed
+                .consumed
+                .iter()
+                .map(|(name, values)| (name.clone(), values.len()))
+                .collect(),
+            consumed: parsed.consumed,
+        }
+    }
+}
+
+/// A parse error, along with everything needed to report it to the user.
+///
+/// Displays as the error, followed by its [`ErrorContext`] on the following lines.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseFailure {
+    // Boxed, so that the failure stays small to return.
+    error: Box<ParseError>,
+    error_context: ErrorContext,
+    hints: Vec<String>,
+    help: Option<String>,
+}
+
+impl ParseFailure {
+    /// The parse error.
+    pub fn error(&self) -> &ParseError {
+        &self.error
+    }
+
+    /// The input tokens at which the parse error occurred.
+    pub fn error_context(&self) -> &ErrorContext {
+        &self.error_context
+    }
+
+    /// The hints which accompany the parse error (ex: `hint: see also '--level-curve'.`).
+    pub fn hints(&self) -> &[String] {
+        &self.hints
+    }
+
+    /// Split into the parse error and its context.
+    pub fn into_parts(self) -> (ParseError, ErrorContext) {
+        (*self.error, self.error_context)
+    }
+
+    fn report(self, user_interface: &(impl UserInterface + ?Sized)) -> i32 {
+        let ParseFailure {
+            error,
+            error_context,
+            hints,
+            help,
+        } = self;
+
+        if let Some(help) = help {
+            user_interface.print(help);
+        }
+
+        user_interface.print_error(*error);
+        user_interface.print_error_context(error_context);
+
+        for hint in hints {
+            user_interface.print_hint(hint);
+        }
+
+        1
+    }
+}
+
+impl std::fmt::Display for ParseFailure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\n{}", self.error, self.error_context)
+    }
+}
+
+impl std::error::Error for ParseFailure {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
 }
 
 impl<'a> GeneralParser<'a> {
@@ -138,51 +365,382 @@ impl<'a> GeneralParser<'a> {
     /// After branching, the token matching and token capturing phases are repeated for the sub-command.
     /// In effect, the input tokens are partitioned based off the branching `Condition`.
     pub fn parse_tokens(self, tokens: &[&str]) -> Result<(), i32> {
+        // The parse consumes the parser, so hold onto its user interface to report the outcome.
+        let user_interface = self.user_interface.clone();
+
+        match self.try_parse_tokens(tokens) {
+            Ok(ParseOutcome::Parsed { .. }) => Ok(()),
+            Ok(outcome) => Err(print_request(outcome, &*user_interface)),
+            Err(failure) => Err(failure.report(&*user_interface)),
+        }
+    }
+
+    /// Run the command line parser against the input tokens, returning the resolved command.
+    /// Help messages are printed on `stdout`, while error messages are printed on `stderr`.
+    ///
+    /// Behaves like [`GeneralParser::parse_tokens`], except that a successful parse returns the canonical command that was effectively invoked.
+    /// The resolved command lists the program, followed by the options and then the arguments, each in declaration order.
+    /// Options use their long name, while parameters which aren't matched appear only if they declare a [`Parameter::initial`](./struct.Parameter.html#method.initial) value.
+    /// For sub-command based parsers, the sub-command's parameters follow those of the root command.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar, Switch};
+    ///
+    /// let mut verbose: bool = false;
+    /// let mut output: String = "out.txt".to_string();
+    /// let mut input: String = String::default();
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(Switch::new(&mut verbose, true), "verbose", Some('v')))
+    ///     .add(Parameter::option(Scalar::new(&mut output), "output", None).initial("out.txt"))
+    ///     .add(Parameter::argument(Scalar::new(&mut input), "input"))
+    ///     .build();
+    ///
+    /// let resolved = parser
+    ///     .parse_tokens_resolved(vec!["INPUT", "-v"].as_slice())
+    ///     .unwrap();
+    /// assert_eq!(resolved, "program --verbose --output out.txt INPUT");
+    /// ```
+    pub fn parse_tokens_resolved(self, tokens: &[&str]) -> Result<String, i32> {
+        let program = self.command.printer.program.clone();
+        self.run(
+            tokens,
+            |failure, user_interface| failure.report(user_interface),
+            print_request,
+        )
+        .map(|parsed| {
+            std::iter::once(program)
+                .chain(parsed.resolved)
+                .collect::<Vec<String>>()
+                .join(" ")
+        })
+    }
+
+    /// Run the command line parser against the input tokens, handing any parse error to `handler`.
+    /// Help messages are printed on `stdout`, while parse errors are *not* printed.
+    ///
+    /// Behaves like [`GeneralParser::parse_tokens`], except that a parse error is passed to the `handler` instead of being printed.
+    /// The `handler` formats/reports the error as it sees fit, returning the error code with which to return `Err(..)`.
+    /// The handler may also recover from the error; for example, [`ParseError::UnknownSubCommand`] includes the tokens following the unknown sub-command (ex: to dispatch to an external `program-foo`).
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, ParseError, Scalar};
+    ///
+    /// let mut value: u32 = 0;
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::argument(Scalar::new(&mut value), "value"))
+    ///     .build();
+    ///
+    /// let error_code = parser
+    ///     .parse_tokens_with(vec!["not-a-u32"].as_slice(), |error: ParseError| {
+    ///         println!("{{\"error\": \"{error}\"}}");
+    ///         2
+    ///     })
+    ///     .unwrap_err();
+    /// assert_eq!(error_code, 2);
+    /// ```
+    pub fn parse_tokens_with(
+        self,
+        tokens: &[&str],
+        handler: impl FnOnce(ParseError) -> i32,
+    ) -> Result<(), i32> {
+        self.run(tokens, |failure, _| handler(*failure.error), print_request)
+            .map(|_| ())
+    }
+
+    /// Run the command line parser against the input tokens, returning the outcome rather than printing any requested message.
+    /// Error messages are printed on `stderr`.
+    ///
+    /// Behaves like [`GeneralParser::parse_tokens`], except that the help, usage, and version messages are *not* printed.
+    /// Instead, their rendered text is returned via the [`ParseOutcome`], leaving the caller to decide what to do (ex: page the help message).
+    /// As with `parse_tokens`, a requested message skips the phase #2 capturing.
+    /// A successful parse reports the number of values matched by each parameter (ex: for diagnostics on a greedy argument).
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, ParseOutcome};
+    ///
+    /// let parser = CommandLineParser::new("program")
+    ///     .version("1.2.3")
+    ///     .build();
+    ///
+    /// let outcome = parser
+    ///     .parse_tokens_outcome(vec!["--version"].as_slice())
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     outcome,
+    ///     ParseOutcome::VersionRequested {
+    ///         text: "program 1.2.3".to_string()
+    ///     }
+    /// );
+    /// ```
+    pub fn parse_tokens_outcome(self, tokens: &[&str]) -> Result<ParseOutcome, i32> {
+        let mut requested: Option<ParseOutcome> = None;
+        let result = self.run(
+            tokens,
+            |failure, user_interface| failure.report(user_interface),
+            |outcome, _| {
+                requested.replace(outcome);
+                0
+            },
+        );
+
+        match (result, requested) {
+            (Ok(parsed), _) => Ok(parsed.into()),
+            (Err(_), Some(outcome)) => Ok(outcome),
+            (Err(error_code), None) => Err(error_code),
+        }
+    }
+
+    /// Run the command line parser against the input tokens, returning the outcome or parse error rather than printing either.
+    ///
+    /// Behaves like [`GeneralParser::parse_tokens_outcome`], except that a parse error is returned as a [`ParseFailure`] (along with its [`ErrorContext`]) instead of being printed.
+    /// This leaves the caller to decide whether to print, log, or recover from the error (ex: when embedding the command line parser into a larger program).
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, ParseError, Scalar, Switch};
+    ///
+    /// let mut verbose: bool = false;
+    /// let mut value: u32 = 0;
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(Switch::new(&mut verbose, true), "verbose", Some('v')))
+    ///     .add(Parameter::argument(Scalar::new(&mut value), "value"))
+    ///     .build();
+    ///
+    /// let failure = parser
+    ///     .try_parse_tokens(vec!["-v", "not-a-u32"].as_slice())
+    ///     .unwrap_err();
+    /// assert!(matches!(failure.error(), ParseError::CapturePhase(..)));
+    /// assert_eq!(failure.error_context().offset(), 2);
+    /// assert_eq!(failure.error_context().tokens(), vec!["-v", "not-a-u32"]);
+    /// ```
+    pub fn try_parse_tokens(self, tokens: &[&str]) -> Result<ParseOutcome, ParseFailure> {
+        let mut failed: Option<ParseFailure> = None;
+        let mut requested: Option<ParseOutcome> = None;
+        let result = self.run(
+            tokens,
+            |failure, _| {
+                failed.replace(failure);
+                1
+            },
+            |outcome, _| {
+                requested.replace(outcome);
+                0
+            },
+        );
+
+        match (result, requested, failed) {
+            (Ok(parsed), _, _) => Ok(parsed.into()),
+            (Err(_), Some(outcome), _) => Ok(outcome),
+            (Err(_), None, Some(failure)) => Err(failure),
+            (Err(_), None, None) => {
+                unreachable!("internal error - parse must either request or fail.")
+            }
+        }
+    }
+
+    /// Run the command line parser against the input tokens, returning the tokens which it leaves unconsumed.
+    /// Help messages are printed on `stdout`, while error messages are printed on `stderr`.
+    ///
+    /// Behaves like [`GeneralParser::parse_tokens`], except that a successful parse returns the remaining tokens (as is), for a downstream parser.
+    /// Only a parser configured with [`CommandLineParser::allow_trailing`](./struct.CommandLineParser.html#method.allow_trailing) leaves any tokens unconsumed.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar, Switch};
+    ///
+    /// let mut verbose: bool = false;
+    /// let mut source: String = "".to_string();
+    /// let parser = CommandLineParser::new("program")
+    ///     .allow_trailing()
+    ///     .add(Parameter::option(Switch::new(&mut verbose, true), "verbose", Some('v')))
+    ///     .add(Parameter::argument(Scalar::new(&mut source), "source"))
+    ///     .build();
+    ///
+    /// let remaining = parser
+    ///     .parse_partial(vec!["-v", "a", "b", "-v"].as_slice())
+    ///     .unwrap();
+    ///
+    /// assert!(verbose);
+    /// assert_eq!(&source, "a");
+    /// assert_eq!(remaining, vec!["b", "-v"]);
+    /// ```
+    pub fn parse_partial(self, tokens: &[&str]) -> Result<Vec<String>, i32> {
+        self.run(
+            tokens,
+            |failure, user_interface| failure.report(user_interface),
+            print_request,
+        )
+        .map(|parsed| parsed.remaining)
+    }
+
+    /// Run the command line parser against the input line, after splitting it into tokens.
+    /// Help messages are printed on `stdout`, while error messages are printed on `stderr`.
+    ///
+    /// Behaves like [`GeneralParser::parse_tokens`], except that the tokens are first split from the `line` (ex: for a REPL).
+    /// Tokens are separated by whitespace, following simple shell-like quoting:
+    /// * Single quotes preserve their contents as is.
+    /// * Double quotes preserve their contents, except that `\"` and `\\` are escaped.
+    /// * Outside of quotes, a backslash escapes the following character.
+    ///
+    /// If the line cannot be split (ex: an un-terminated quote), the parser will return with `Err(1)`.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar, Switch};
+    ///
+    /// let mut verbose: bool = false;
+    /// let mut input: String = String::default();
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::option(Switch::new(&mut verbose, true), "verbose", Some('v')))
+    ///     .add(Parameter::argument(Scalar::new(&mut input), "input"))
+    ///     .build();
+    ///
+    /// parser.parse_line("--verbose 'my input.txt'").unwrap();
+    ///
+    /// assert!(verbose);
+    /// assert_eq!(&input, "my input.txt");
+    /// ```
+    pub fn parse_line(self, line: &str) -> Result<(), i32> {
+        match split_line(line) {
+            Ok(tokens) => {
+                let tokens: Vec<&str> = tokens.iter().map(AsRef::as_ref).collect();
+                self.parse_tokens(tokens.as_slice())
+            }
+            Err((offset, message)) => {
+                let failure = ParseFailure {
+                    error: Box::new(ParseError::TokenizePhase(message)),
+                    error_context: ErrorContext::new(offset, &[line]),
+                    hints: Vec::default(),
+                    help: None,
+                };
+                Err(failure.report(&*self.user_interface))
+            }
+        }
+    }
+
+    fn run(
+        self,
+        tokens: &[&str],
+        on_failure: impl FnOnce(ParseFailure, &dyn UserInterface) -> i32,
+        on_request: impl FnOnce(ParseOutcome, &dyn UserInterface) -> i32,
+    ) -> Result<Parsed, i32> {
         let GeneralParser {
             command,
             mut sub_commands,
+            aliases,
+            mut handlers,
+            preprocessor,
+            help_on_unknown,
             user_interface,
         } = self;
+        // Rewrite the input tokens, so that the remainder of the parse only sees the rewritten tokens.
+        let preprocessed: Option<Vec<String>> = preprocessor
+            .map(|preprocessor| preprocessor(tokens.iter().map(|t| t.to_string()).collect()));
+        let preprocessed: Option<Vec<&str>> = preprocessed
+            .as_ref()
+            .map(|preprocessed| preprocessed.iter().map(AsRef::as_ref).collect());
+        let tokens = preprocessed.as_deref().unwrap_or(tokens);
+        // The root command is consumed by its parse, so render its help up front.
+        let root_help = help_on_unknown.then(|| render_help(&command));
         let command_result = command.invoke(tokens, &*user_interface);
+        let mut handler: Option<Handler<'a>> = None;
 
-        match command_result {
-            ParseResult::Complete => Ok(()),
+        let result = match command_result {
             ParseResult::Incomplete {
                 variant_offset,
                 variant,
                 remaining,
+                resolved,
+                mut consumed,
             } => {
-                match sub_commands.remove(&variant) {
-                    Some(sub_command) => {
-                        match sub_command.invoke(
-                            remaining
+                // An alias shares the sub-command of its canonical variant.
+                let canonical = aliases.get(&variant).unwrap_or(&variant);
+                handler = handlers.remove(canonical);
+
+                match sub_commands.remove(canonical) {
+                    Some(sub_command) => match sub_command.invoke(
+                        remaining
+                            .iter()
+                            .map(AsRef::as_ref)
+                            .collect::<Vec<&str>>()
+                            .as_slice(),
+                        &*user_interface,
+                    ) {
+                        ParseResult::Complete {
+                            resolved: sub_resolved,
+                            consumed: sub_consumed,
+                            remaining: sub_remaining,
+                        } => {
+                            // The sub-command's offsets are relative to its own tokens, which follow those of the command.
+                            let shift: usize = tokens[..tokens.len() - remaining.len()]
                                 .iter()
-                                .map(AsRef::as_ref)
-                                .collect::<Vec<&str>>()
-                                .as_slice(),
-                            &*user_interface,
-                        ) {
-                            ParseResult::Complete => Ok(()),
-                            ParseResult::Incomplete { .. } => {
-                                unreachable!(
-                                    "internal error - sub-command parse must complete/exit."
-                                )
+                                .map(|token| token_width(token))
+                                .sum();
+                            consumed.extend(sub_consumed.into_iter().map(|(name, values)| {
+                                let values = values
+                                    .into_iter()
+                                    .map(|(offset, value)| (offset + shift, value))
+                                    .collect();
+                                (name, values)
+                            }));
+                            ParseResult::Complete {
+                                resolved: resolved.into_iter().chain(sub_resolved).collect(),
+                                consumed,
+                                remaining: sub_remaining,
                             }
-                            ParseResult::Exit(code) => Err(code),
                         }
-                    }
+                        result => result,
+                    },
                     None => {
                         // The variant isn't amongst the sub-commands.
-                        user_interface.print_error(ParseError::BranchingPhase(format!(
-                            "unknown sub-command '{variant}'."
-                        )));
-                        user_interface
-                            .print_error_context(ErrorContext::new(variant_offset, tokens));
-                        Err(1)
+                        let suggestion =
+                            nearest(&variant, sub_commands.keys().chain(aliases.keys())).cloned();
+                        ParseResult::Error(ParseFailure {
+                            error: Box::new(ParseError::UnknownSubCommand {
+                                variant,
+                                suggestion,
+                                remaining,
+                            }),
+                            error_context: ErrorContext::new(variant_offset, tokens),
+                            hints: Vec::default(),
+                            help: root_help,
+                        })
                     }
                 }
             }
-            ParseResult::Exit(code) => Err(code),
+            result => result,
+        };
+
+        match result {
+            ParseResult::Complete {
+                resolved,
+                consumed,
+                remaining,
+            } => {
+                if let Some(handler) = handler {
+                    handler();
+                }
+
+                Ok(Parsed {
+                    resolved,
+                    consumed,
+                    remaining,
+                })
+            }
+            ParseResult::Incomplete { .. } => {
+                unreachable!("internal error - sub-command parse must complete/exit.")
+            }
+            ParseResult::Requested(outcome) => Err(on_request(outcome, &*user_interface)),
+            ParseResult::Error(failure) => Err(on_failure(failure, &*user_interface)),
         }
     }
 
@@ -219,6 +777,367 @@ impl<'a> GeneralParser<'a> {
             }
         };
     }
+
+    /// Run the command line parser against the Cli [`env::args`], handing any parse error to `handler`.
+    /// Help messages are printed on `stdout`, while parse errors are *not* printed.
+    ///
+    /// Behaves like [`GeneralParser::parse`], except that a parse error is passed to the `handler` instead of being printed.
+    /// The `handler` formats/reports the error as it sees fit, returning the error code with which to exit (via [`std::process::exit`]).
+    ///
+    /// See [`GeneralParser::parse_tokens_with`] for the non-exiting variant.
+    pub fn parse_or_exit_with(self, handler: impl FnOnce(ParseError) -> i32) {
+        let command_input: Vec<String> = env::args().skip(1).collect();
+        match self.parse_tokens_with(
+            command_input
+                .iter()
+                .map(AsRef::as_ref)
+                .collect::<Vec<&str>>()
+                .as_slice(),
+            handler,
+        ) {
+            Ok(()) => {}
+            Err(exit_code) => {
+                std::process::exit(exit_code);
+            }
+        };
+    }
+
+    /// Check the configuration of the command line parser (and its sub-commands), without parsing.
+    /// Each [`ConfigWarning`] reports whether it is fatal; a soundly configured parser produces none.
+    ///
+    /// Some issues (ex: duplicate parameter names) are already rejected when building the parser.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Collection, ConfigWarning, Nargs, Parameter};
+    ///
+    /// let mut files: Vec<String> = Vec::default();
+    /// let mut targets: Vec<String> = Vec::default();
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::argument(Collection::new(&mut files, Nargs::AtLeastOne), "files"))
+    ///     .add(Parameter::argument(Collection::new(&mut targets, Nargs::Any), "targets"))
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     parser.lint(),
+    ///     vec![ConfigWarning::UnreachableArgument("files".to_string(), "targets".to_string())]
+    /// );
+    /// ```
+    pub fn lint(&self) -> Vec<ConfigWarning> {
+        let mut variants: Vec<&String> = self.sub_commands.keys().collect();
+        variants.sort();
+
+        std::iter::once(&self.command)
+            .chain(
+                variants
+                    .into_iter()
+                    .map(|variant| &self.sub_commands[variant]),
+            )
+            .flat_map(|parse_unit| parse_unit.parser.lint())
+            .collect()
+    }
+
+    /// Render the grammar of the command line parser (and its sub-commands), as EBNF-like production rules.
+    ///
+    /// Each command is a production of its options followed by its arguments, in order.
+    /// The cardinality of a parameter is marked as a suffix: `?` (zero or one), `*` (any), `+` (at least one), or `{N,M}` (a range).
+    /// When branching, each sub-command is a production of its own (ex: `program-run`), selected by the variant of the sub-command argument.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Collection, Nargs, Parameter, Scalar};
+    ///
+    /// let mut source: String = "".to_string();
+    /// let mut targets: Vec<String> = Vec::default();
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::argument(Scalar::new(&mut source), "source"))
+    ///     .add(Parameter::argument(Collection::new(&mut targets, Nargs::AtLeastOne), "target"))
+    ///     .build();
+    ///
+    /// assert_eq!(
+    ///     parser.grammar(),
+    ///     r#"program ::= program-option* SOURCE TARGET+
+    /// program-option ::= ("-h" | "--help")"#
+    /// );
+    /// ```
+    pub fn grammar(&self) -> String {
+        let program = &self.command.printer.program;
+        let mut rules = self.command.parser.grammar(program);
+
+        if let Some(discriminator) = self.command.parser.discriminator() {
+            let mut variants: Vec<&String> = self.sub_commands.keys().collect();
+            variants.sort();
+            let selections: Vec<String> = variants
+                .iter()
+                .map(|variant| format!("\"{variant}\" {program}-{variant}"))
+                .collect();
+            rules.push(format!(
+                "{} ::= {}",
+                discriminator.to_ascii_uppercase().replace("-", "_"),
+                selections.join(" | ")
+            ));
+
+            for variant in variants {
+                rules.extend(
+                    self.sub_commands[variant]
+                        .parser
+                        .grammar(&format!("{program}-{variant}")),
+                );
+            }
+        }
+
+        rules.join("\n")
+    }
+
+    /// Render the help message, as printed for `--help` on an 80 column terminal.
+    ///
+    /// The rendering is independent of the environment (terminal size or `COLUMNS`), making it suitable for documentation and golden tests.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    ///
+    /// let mut value: u32 = 0;
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::argument(Scalar::new(&mut value), "value").help("The value."))
+    ///     .build();
+    ///
+    /// let help = parser.help_string_portable();
+    /// assert!(help.starts_with("usage: program [-h] VALUE\n"));
+    /// assert!(help.contains(" VALUE        The value.\n"));
+    /// ```
+    pub fn help_string_portable(&self) -> String {
+        let render_interface = RenderInterface::default();
+        self.command.printer.print_help_portable(&render_interface);
+        render_interface.rendered()
+    }
+
+    /// Write the help message to the `writer`, as printed for `--help`.
+    /// Control characters (ex: styling) are stripped, since the writer isn't a terminal.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    ///
+    /// let mut value: u32 = 0;
+    /// let parser = CommandLineParser::new("program")
+    ///     .add(Parameter::argument(Scalar::new(&mut value), "value").help("The value."))
+    ///     .build();
+    ///
+    /// let mut buffer: Vec<u8> = Vec::default();
+    /// parser.write_help(&mut buffer).unwrap();
+    /// assert!(String::from_utf8(buffer).unwrap().starts_with("usage: program [-h] VALUE\n"));
+    /// ```
+    pub fn write_help(&self, writer: &mut dyn std::io::Write) -> std::io::Result<()> {
+        let write_interface = WriteInterface::new(writer);
+        self.command.printer.print_help(&write_interface);
+        write_interface.result()
+    }
+
+    /// Render the help message of the sub-command `name` (or its alias), as printed for `NAME --help`.
+    /// Returns `None` when there is no such sub-command.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Condition, Parameter, Scalar};
+    ///
+    /// let mut sub: String = "".to_string();
+    /// let mut item: u32 = 0;
+    /// let parser = CommandLineParser::new("program")
+    ///     .branch(Condition::new(Scalar::new(&mut sub), "sub"))
+    ///     .command("checkout".to_string(), |sub| {
+    ///         sub.add(Parameter::argument(Scalar::new(&mut item), "item"))
+    ///     })
+    ///     .build();
+    ///
+    /// let help = parser.sub_command_help("checkout").unwrap();
+    /// assert!(help.starts_with("usage: program checkout [-h] ITEM\n"));
+    /// assert_eq!(parser.sub_command_help("status"), None);
+    /// ```
+    pub fn sub_command_help(&self, name: &str) -> Option<String> {
+        let canonical = self.aliases.get(name).map(String::as_str).unwrap_or(name);
+        self.sub_commands.get(canonical).map(render_help)
+    }
+
+    /// Decompose the command line parser into its [`ParserParts`], for orchestrating the parse directly.
+    ///
+    /// ### Example
+    /// ```
+    /// # use blarg_builder as blarg;
+    /// use blarg::{CommandLineParser, Parameter, Scalar};
+    ///
+    /// let mut value: u32 = 0;
+    /// let parts = CommandLineParser::new("program")
+    ///     .add(Parameter::argument(Scalar::new(&mut value), "value"))
+    ///     .build()
+    ///     .into_parts();
+    ///
+    /// assert_eq!(parts.usage(), "usage: program [-h] VALUE");
+    ///
+    /// let parser = parts.into_parser();
+    /// parser.parse_tokens(vec!["1"].as_slice()).unwrap();
+    /// assert_eq!(value, 1);
+    /// ```
+    pub fn into_parts(self) -> ParserParts<'a> {
+        ParserParts { inner: self }
+    }
+}
+
+/// The parts of a [`GeneralParser`].
+/// Built via [`GeneralParser::into_parts`].
+///
+/// Exposes the rendering of the help/usage messages, independent of parsing.
+/// Re-assemble the parser via [`ParserParts::into_parser`].
+pub struct ParserParts<'a> {
+    inner: GeneralParser<'a>,
+}
+
+impl<'a> std::fmt::Debug for ParserParts<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ParserParts{..}").finish()
+    }
+}
+
+impl<'a> ParserParts<'a> {
+    /// The program name.
+    pub fn program(&self) -> &str {
+        &self.inner.command.printer.program
+    }
+
+    /// The names of the sub-commands (excluding aliases), in sorted order.
+    pub fn sub_commands(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.inner.sub_commands.keys().map(String::as_str).collect();
+        names.sort();
+        names
+    }
+
+    /// Render the help message, as printed for `--help`.
+    pub fn help(&self) -> String {
+        render_help(&self.inner.command)
+    }
+
+    /// Render the single line usage summary.
+    pub fn usage(&self) -> String {
+        let render_interface = RenderInterface::default();
+        self.inner.command.printer.print_usage(&render_interface);
+        render_interface.rendered()
+    }
+
+    /// Render the help message of the sub-command `name` (or its alias), as printed for `NAME --help`.
+    pub fn sub_command_help(&self, name: &str) -> Option<String> {
+        self.inner.sub_command_help(name)
+    }
+
+    /// Re-assemble the command line parser.
+    pub fn into_parser(self) -> GeneralParser<'a> {
+        self.inner
+    }
+}
+
+fn render_help(parse_unit: &ParseUnit) -> String {
+    let render_interface = RenderInterface::default();
+    parse_unit.printer.print_help(&render_interface);
+    render_interface.rendered()
+}
+
+/// The number of single character edits (insertions, deletions, or substitutions) to turn `a` into `b`.
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+/// Find the `candidate` nearest to the `token`, if any is close enough to be a plausible typo.
+/// Ties are broken by the sorted order of the candidates.
+fn nearest<'b>(token: &str, candidates: impl Iterator<Item = &'b String>) -> Option<&'b String> {
+    let threshold = std::cmp::max(1, token.chars().count() / 3);
+    let mut candidates: Vec<&String> = candidates.collect();
+    candidates.sort();
+
+    candidates
+        .into_iter()
+        .map(|candidate| (levenshtein(token, candidate), candidate))
+        .filter(|(distance, _)| *distance <= threshold)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Split the `line` into tokens, following simple shell-like quoting.
+/// Fails with the offset and description of the first character which cannot be split.
+fn split_line(line: &str) -> Result<Vec<String>, (usize, String)> {
+    let mut tokens = Vec::default();
+    let mut current: Option<String> = None;
+    let mut characters = line.char_indices();
+
+    while let Some((offset, character)) = characters.next() {
+        match character {
+            '\'' => {
+                let token = current.get_or_insert_with(String::default);
+
+                loop {
+                    match characters.next() {
+                        Some((_, '\'')) => break,
+                        Some((_, quoted)) => token.push(quoted),
+                        None => return Err((offset, "un-terminated single quote.".to_string())),
+                    }
+                }
+            }
+            '"' => {
+                let token = current.get_or_insert_with(String::default);
+
+                loop {
+                    match characters.next() {
+                        Some((_, '"')) => break,
+                        Some((_, '\\')) => match characters.next() {
+                            Some((_, escaped @ ('"' | '\\'))) => token.push(escaped),
+                            Some((_, quoted)) => {
+                                token.push('\\');
+                                token.push(quoted);
+                            }
+                            None => {
+                                return Err((offset, "un-terminated double quote.".to_string()))
+                            }
+                        },
+                        Some((_, quoted)) => token.push(quoted),
+                        None => return Err((offset, "un-terminated double quote.".to_string())),
+                    }
+                }
+            }
+            '\\' => match characters.next() {
+                Some((_, escaped)) => current.get_or_insert_with(String::default).push(escaped),
+                None => return Err((offset, "nothing to escape.".to_string())),
+            },
+            _ if character.is_whitespace() => {
+                if let Some(token) = current.take() {
+                    tokens.push(token);
+                }
+            }
+            _ => current.get_or_insert_with(String::default).push(character),
+        }
+    }
+
+    if let Some(token) = current {
+        tokens.push(token);
+    }
+
+    Ok(tokens)
 }
 
 #[cfg(test)]
@@ -226,26 +1145,30 @@ mod tests {
     use super::*;
     use crate::api::{AnonymousCapture, GenericCapturable, Scalar};
     use crate::matcher::{ArgumentConfig, Bound, OptionConfig};
+    use crate::model::Nargs;
     use crate::parser::test::BlackHole;
     use crate::parser::util::{channel_interface, InMemoryInterface};
+    use crate::parser::OptionParameter;
     use crate::test::assert_contains;
+    use crate::InvalidCapture;
     use rstest::rstest;
 
     #[rstest]
-    #[case(vec!["1"], 0, "1", vec![])]
-    #[case(vec!["01"], 0, "01", vec![])]
-    #[case(vec!["--flag", "1"], 6, "1", vec![])]
-    #[case(vec!["1", "a"], 0, "1", vec!["a"])]
-    #[case(vec!["01", "a"], 0, "01", vec!["a"])]
-    #[case(vec!["--flag", "1", "a"], 6, "1", vec!["a"])]
-    #[case(vec!["1", "a", "--abc=123"], 0, "1", vec!["a", "--abc=123"])]
-    #[case(vec!["01", "a", "--abc=123"], 0, "01", vec!["a", "--abc=123"])]
-    #[case(vec!["--flag", "1", "a", "--abc=123"], 6, "1", vec!["a", "--abc=123"])]
+    #[case(vec!["1"], 0, "1", vec![], vec!["variable"])]
+    #[case(vec!["01"], 0, "01", vec![], vec!["variable"])]
+    #[case(vec!["--flag", "1"], 6, "1", vec![], vec!["flag", "variable"])]
+    #[case(vec!["1", "a"], 0, "1", vec!["a"], vec!["variable"])]
+    #[case(vec!["01", "a"], 0, "01", vec!["a"], vec!["variable"])]
+    #[case(vec!["--flag", "1", "a"], 6, "1", vec!["a"], vec!["flag", "variable"])]
+    #[case(vec!["1", "a", "--abc=123"], 0, "1", vec!["a", "--abc=123"], vec!["variable"])]
+    #[case(vec!["01", "a", "--abc=123"], 0, "01", vec!["a", "--abc=123"], vec!["variable"])]
+    #[case(vec!["--flag", "1", "a", "--abc=123"], 6, "1", vec!["a", "--abc=123"], vec!["flag", "variable"])]
     fn invoke_discriminator(
         #[case] tokens: Vec<&str>,
         #[case] offset: usize,
         #[case] discriminee: &str,
         #[case] remaining: Vec<&str>,
+        #[case] matched: Vec<&str>,
     ) {
         // Setup
         let config = ArgumentConfig::new("variable", Bound::Range(1, 1));
@@ -273,6 +1196,15 @@ mod tests {
                 variant_offset: offset,
                 variant: discriminee.to_string(),
                 remaining: remaining.into_iter().map(|s| s.to_string()).collect(),
+                resolved: vec![],
+                // The flag matches no values, while the discriminator matches the variant.
+                consumed: matched
+                    .into_iter()
+                    .map(|name| match name {
+                        "flag" => (name.to_string(), vec![]),
+                        _ => (name.to_string(), vec![(offset, discriminee.to_string())]),
+                    })
+                    .collect(),
             }
         );
 
@@ -282,6 +1214,59 @@ mod tests {
         assert_eq!(error_context, None);
     }
 
+    #[rstest]
+    #[case(vec!["--level", "x"], vec!["hint: see also '--level-curve LEVEL_CURVE': Expert tuning."])]
+    #[case(vec!["--level"], vec!["hint: see also '--level-curve LEVEL_CURVE': Expert tuning."])]
+    #[case(vec!["--level", "1", "2"], vec![])]
+    #[case(vec!["--other"], vec![])]
+    fn invoke_contextual_help(#[case] tokens: Vec<&str>, #[case] expected: Vec<&str>) {
+        // Setup
+        let mut level: u32 = 0;
+        let generic_capture = Scalar::new(&mut level);
+        let parse_unit = ParseUnit::new(
+            Parser::new(
+                vec![
+                    (
+                        OptionConfig::new("level", None, generic_capture.nargs().into()),
+                        Box::new(AnonymousCapture::bind(generic_capture)),
+                    ),
+                    (
+                        OptionConfig::new("level-curve", None, Bound::Range(1, 1)),
+                        Box::new(BlackHole::default()),
+                    ),
+                ],
+                vec![],
+                None,
+            )
+            .unwrap(),
+            Printer::empty().with_contextual(vec![(
+                "level".to_string(),
+                OptionParameter::new(
+                    "level-curve".to_string(),
+                    None,
+                    Nargs::Precisely(1),
+                    Some("Expert tuning.".to_string()),
+                    None,
+                    HashMap::default(),
+                ),
+            )]),
+        );
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        let result = parse_unit.invoke(tokens.as_slice(), &interface);
+
+        // Verify
+        assert_matches!(result, ParseResult::Error(ParseFailure { hints, .. }) => {
+            assert_eq!(hints, expected);
+        });
+
+        let (message, error, error_context) = interface.consume();
+        assert_eq!(message, None);
+        assert_eq!(error, None);
+        assert_eq!(error_context, None);
+    }
+
     #[test]
     fn parse_tokens_empty() {
         // Setup
@@ -391,6 +1376,147 @@ mod tests {
         assert_eq!(error_context, ErrorContext::new(offset, &tokens));
     }
 
+    #[rstest]
+    #[case(vec!["not-u32"], "variable", "not-u32")]
+    #[case(vec!["--flag", "not-u32"], "variable", "not-u32")]
+    fn parse_tokens_with(#[case] tokens: Vec<&str>, #[case] parameter: &str, #[case] token: &str) {
+        // Setup
+        let mut variable: u32 = 0;
+        let generic_capture = Scalar::new(&mut variable);
+        let parse_unit = ParseUnit::new(
+            Parser::new(
+                vec![(
+                    OptionConfig::new("flag", None, Bound::Range(0, 0)),
+                    Box::new(BlackHole::default()),
+                )],
+                vec![(
+                    ArgumentConfig::new("variable", generic_capture.nargs().into()),
+                    Box::new(AnonymousCapture::bind(generic_capture)),
+                )],
+                None,
+            )
+            .unwrap(),
+            Printer::empty(),
+        );
+        let (sender, receiver) = channel_interface();
+        let general_parser = GeneralParser::command(parse_unit, Box::new(sender));
+        let mut handled: Option<ParseError> = None;
+
+        // Execute
+        let error_code = general_parser
+            .parse_tokens_with(tokens.as_slice(), |error| {
+                handled.replace(error);
+                2
+            })
+            .unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 2);
+        assert_eq!(
+            handled,
+            Some(ParseError::CapturePhase(
+                parameter.to_string(),
+                InvalidCapture::InvalidConversion {
+                    token: token.to_string(),
+                    type_name: "u32",
+                }
+            ))
+        );
+
+        let (message, error, error_context) = receiver.consume();
+        assert_eq!(message, None);
+        assert_eq!(error, None);
+        assert_eq!(error_context, None);
+    }
+
+    #[test]
+    fn parse_tokens_with_help() {
+        // Setup
+        let (sender, receiver) = channel_interface();
+        let general_parser = GeneralParser::command(ParseUnit::empty(), Box::new(sender));
+
+        // Execute
+        let error_code = general_parser
+            .parse_tokens_with(&["--help"], |_| unreachable!("handler must not be invoked"))
+            .unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 0);
+
+        let message = receiver.consume_message();
+        assert_contains!(message, "usage: EMPTY [-h]");
+    }
+
+    #[rstest]
+    #[case(vec!["--help"])]
+    #[case(vec!["-h"])]
+    fn parse_tokens_outcome_help(#[case] tokens: Vec<&str>) {
+        // Setup
+        let (sender, receiver) = channel_interface();
+        let general_parser = GeneralParser::command(ParseUnit::empty(), Box::new(sender));
+
+        // Execute
+        let outcome = general_parser
+            .parse_tokens_outcome(tokens.as_slice())
+            .unwrap();
+
+        // Verify
+        let text = match outcome {
+            ParseOutcome::HelpRequested { text } => text,
+            outcome => panic!("unexpected outcome {outcome:?}"),
+        };
+        assert_contains!(text, "usage: EMPTY [-h]");
+        assert_contains!(text, "-h, --help");
+
+        let (message, error, error_context) = receiver.consume();
+        assert_eq!(message, None);
+        assert_eq!(error, None);
+        assert_eq!(error_context, None);
+    }
+
+    #[test]
+    fn parse_tokens_outcome_parsed() {
+        // Setup
+        let (sender, receiver) = channel_interface();
+        let general_parser = GeneralParser::command(ParseUnit::empty(), Box::new(sender));
+
+        // Execute
+        let outcome = general_parser.parse_tokens_outcome(&[]).unwrap();
+
+        // Verify
+        assert_eq!(
+            outcome,
+            ParseOutcome::Parsed {
+                counts: HashMap::default(),
+                consumed: HashMap::default(),
+            }
+        );
+
+        let (message, error, error_context) = receiver.consume();
+        assert_eq!(message, None);
+        assert_eq!(error, None);
+        assert_eq!(error_context, None);
+    }
+
+    #[test]
+    fn parse_tokens_outcome_error() {
+        // Setup
+        let (sender, receiver) = channel_interface();
+        let general_parser = GeneralParser::command(ParseUnit::empty(), Box::new(sender));
+
+        // Execute
+        let error_code = general_parser
+            .parse_tokens_outcome(&["unexpected"])
+            .unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 1);
+
+        let (message, error, _) = receiver.consume();
+        assert_eq!(message, None);
+        assert!(error.is_some());
+    }
+
     #[rstest]
     #[case(vec!["1"])]
     #[case(vec!["--flag", "1"])]
@@ -616,4 +1742,160 @@ mod tests {
         let error_context = error_context.unwrap();
         assert_eq!(error_context, ErrorContext::new(offset, &tokens));
     }
+
+    #[rstest]
+    #[case("", "abc", 3)]
+    #[case("abc", "", 3)]
+    #[case("abc", "abc", 0)]
+    #[case("statuss", "status", 1)]
+    #[case("stauts", "status", 2)]
+    #[case("kitten", "sitting", 3)]
+    fn levenshtein_distance(#[case] a: &str, #[case] b: &str, #[case] expected: usize) {
+        // Execute
+        let distance = levenshtein(a, b);
+
+        // Verify
+        assert_eq!(distance, expected);
+    }
+
+    #[rstest]
+    #[case("", vec![])]
+    #[case("  ", vec![])]
+    #[case("run", vec!["run"])]
+    #[case(" run  --verbose\tinput.txt ", vec!["run", "--verbose", "input.txt"])]
+    #[case("'a b'", vec!["a b"])]
+    #[case("\"a b\"", vec!["a b"])]
+    #[case("x'a b'y", vec!["xa by"])]
+    #[case("''", vec![""])]
+    #[case("a \"\" b", vec!["a", "", "b"])]
+    #[case("'a \\ \"b\"'", vec!["a \\ \"b\""])]
+    #[case("\"a \\\"b\\\" \\\\ \\n\"", vec!["a \"b\" \\ \\n"])]
+    #[case("a\\ b \\'c\\'", vec!["a b", "'c'"])]
+    fn split_line_tokens(#[case] line: &str, #[case] expected: Vec<&str>) {
+        // Execute
+        let tokens = split_line(line).unwrap();
+
+        // Verify
+        assert_eq!(tokens, expected);
+    }
+
+    #[rstest]
+    #[case("'abc", 0, "un-terminated single quote")]
+    #[case("a \"bc", 2, "un-terminated double quote")]
+    #[case("a \"bc\\", 2, "un-terminated double quote")]
+    #[case("abc\\", 3, "nothing to escape")]
+    fn split_line_invalid(#[case] line: &str, #[case] offset: usize, #[case] expected: &str) {
+        // Execute
+        let (error_offset, message) = split_line(line).unwrap_err();
+
+        // Verify
+        assert_eq!(error_offset, offset);
+        assert_contains!(message, expected);
+    }
+
+    #[rstest]
+    #[case(vec!["statuss"], Some("status"))]
+    #[case(vec!["--flag", "comit"], Some("commit"))]
+    #[case(vec!["ci"], Some("co"))]
+    #[case(vec!["xyz"], None)]
+    #[case(vec!["remove-everything"], None)]
+    fn sub_command_not_found_suggestion(#[case] tokens: Vec<&str>, #[case] expected: Option<&str>) {
+        // Setup
+        let parse_unit = ParseUnit::new(
+            Parser::new(
+                vec![(
+                    OptionConfig::new("flag", None, Bound::Range(0, 0)),
+                    Box::new(BlackHole::default()),
+                )],
+                vec![(
+                    ArgumentConfig::new("variable", Bound::Range(1, 1)),
+                    Box::new(BlackHole::default()),
+                )],
+                Some("variable".to_string()),
+            )
+            .unwrap(),
+            Printer::empty(),
+        );
+        let sub_commands = HashMap::from([
+            ("status".to_string(), ParseUnit::empty()),
+            ("commit".to_string(), ParseUnit::empty()),
+        ]);
+        let (sender, receiver) = channel_interface();
+        let general_parser = GeneralParser::sub_command(parse_unit, sub_commands, Box::new(sender))
+            .with_aliases(HashMap::from([("co".to_string(), "commit".to_string())]));
+
+        // Execute
+        let error_code = general_parser.parse_tokens(tokens.as_slice()).unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 1);
+
+        let (_, error, _) = receiver.consume();
+        let error = error.unwrap();
+        let variant = tokens.last().unwrap();
+        match expected {
+            Some(candidate) => {
+                assert_contains!(
+                    error,
+                    &format!("unknown sub-command '{variant}'. Did you mean '{candidate}'?")
+                );
+            }
+            None => {
+                assert_contains!(error, &format!("unknown sub-command '{variant}'."));
+                assert!(!error.contains("Did you mean"));
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(vec!["foo"], "foo", vec![])]
+    #[case(vec!["foo", "--x", "y"], "foo", vec!["--x", "y"])]
+    #[case(vec!["--flag", "foo", "1", "--help"], "foo", vec!["1", "--help"])]
+    fn sub_command_not_found_remaining(
+        #[case] tokens: Vec<&str>,
+        #[case] expected_variant: &str,
+        #[case] expected_remaining: Vec<&str>,
+    ) {
+        // Setup
+        let parse_unit = ParseUnit::new(
+            Parser::new(
+                vec![(
+                    OptionConfig::new("flag", None, Bound::Range(0, 0)),
+                    Box::new(BlackHole::default()),
+                )],
+                vec![(
+                    ArgumentConfig::new("variable", Bound::Range(1, 1)),
+                    Box::new(BlackHole::default()),
+                )],
+                Some("variable".to_string()),
+            )
+            .unwrap(),
+            Printer::empty(),
+        );
+        let sub_commands = HashMap::from([("1".to_string(), ParseUnit::empty())]);
+        let (sender, receiver) = channel_interface();
+        let general_parser = GeneralParser::sub_command(parse_unit, sub_commands, Box::new(sender));
+        let mut handled: Option<ParseError> = None;
+
+        // Execute
+        let error_code = general_parser
+            .parse_tokens_with(tokens.as_slice(), |error| {
+                handled.replace(error);
+                2
+            })
+            .unwrap_err();
+
+        // Verify
+        assert_eq!(error_code, 2);
+        assert_matches!(handled.unwrap(), ParseError::UnknownSubCommand { variant, suggestion, remaining } => {
+            assert_eq!(variant, expected_variant.to_string());
+            assert_eq!(suggestion, None);
+            assert_eq!(remaining, expected_remaining);
+        });
+
+        let (message, error, error_context) = receiver.consume();
+        assert_eq!(message, None);
+        assert_eq!(error, None);
+        assert_eq!(error_context, None);
+    }
 }
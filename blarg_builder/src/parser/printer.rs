@@ -2,8 +2,10 @@ use std::collections::HashMap;
 use terminal_size::{terminal_size, Width};
 
 use crate::constant::*;
-use crate::model::Nargs;
+use crate::matcher::token_width;
+use crate::model::{Messages, Nargs, Section};
 use crate::parser::interface::UserInterface;
+use crate::parser::ParseError;
 use crate::parser::{
     ColumnRenderer, LeftWidth, MiddleWidth, PaddingWidth, RightWidth, TotalWidth,
     MINIMUM_MIDDLE_WIDTH,
@@ -14,10 +16,16 @@ use tracing::debug;
 pub(crate) struct OptionParameter {
     name: String,
     short: Option<char>,
+    short_aliases: Vec<char>,
     nargs: Nargs,
     help: Option<String>,
     meta: Option<Vec<String>>,
     choices: HashMap<String, String>,
+    value_names: Vec<String>,
+    placeholder: Option<String>,
+    order: i32,
+    since: Option<String>,
+    required: bool,
 }
 
 impl OptionParameter {
@@ -32,10 +40,16 @@ impl OptionParameter {
         Self {
             name,
             short,
+            short_aliases: Vec::default(),
             nargs,
             help,
             meta,
             choices: HashMap::default(),
+            value_names: Vec::default(),
+            placeholder: None,
+            order: 0,
+            since: None,
+            required: false,
         }
     }
 
@@ -50,12 +64,72 @@ impl OptionParameter {
         Self {
             name,
             short,
+            short_aliases: Vec::default(),
             nargs,
             help,
             meta,
             choices,
+            value_names: Vec::default(),
+            placeholder: None,
+            order: 0,
+            since: None,
+            required: false,
         }
     }
+
+    pub(crate) fn with_short_aliases(mut self, short_aliases: Vec<char>) -> Self {
+        self.short_aliases = short_aliases;
+        self
+    }
+
+    /// Name each of the values in the grammar (ex: `--range MIN MAX`), in place of the repeated option name.
+    pub(crate) fn with_value_names(mut self, value_names: Vec<String>) -> Self {
+        self.value_names = value_names;
+        self
+    }
+
+    /// Annotate a switch (ex: `--verbose (on)`) in the detailed section, in place of its empty grammar.
+    pub(crate) fn with_placeholder(mut self, placeholder: Option<String>) -> Self {
+        self.placeholder = placeholder;
+        self
+    }
+
+    /// Weight the position of the option in the help message (lower first), ahead of its name.
+    pub(crate) fn with_order(mut self, order: i32) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Annotate the help message with the version the option was introduced in (ex: `(since 2.1)`).
+    pub(crate) fn with_since(mut self, since: Option<String>) -> Self {
+        self.since = since;
+        self
+    }
+
+    /// Show the option without its surrounding brackets in the usage summary, since it must be provided.
+    pub(crate) fn with_required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// The help message, followed by the since version annotation.
+    fn annotated_help(&self) -> Option<String> {
+        match (&self.help, &self.since) {
+            (Some(message), Some(version)) if !message.is_empty() => {
+                Some(format!("{message} (since {version})"))
+            }
+            (_, Some(version)) => Some(format!("(since {version})")),
+            (help, None) => help.clone(),
+        }
+    }
+
+    fn shorts(&self) -> Vec<char> {
+        self.short
+            .iter()
+            .chain(self.short_aliases.iter())
+            .cloned()
+            .collect()
+    }
 }
 
 pub(crate) struct ArgumentParameter {
@@ -64,6 +138,7 @@ pub(crate) struct ArgumentParameter {
     help: Option<String>,
     meta: Option<Vec<String>>,
     choices: HashMap<String, String>,
+    value_names: Vec<String>,
 }
 
 impl ArgumentParameter {
@@ -75,6 +150,7 @@ impl ArgumentParameter {
             help,
             meta,
             choices: HashMap::default(),
+            value_names: Vec::default(),
         }
     }
 
@@ -91,8 +167,15 @@ impl ArgumentParameter {
             help,
             meta,
             choices,
+            value_names: Vec::default(),
         }
     }
+
+    /// Name each of the values in the grammar (ex: `MIN MAX`), in place of the repeated argument name.
+    pub(crate) fn with_value_names(mut self, value_names: Vec<String>) -> Self {
+        self.value_names = value_names;
+        self
+    }
 }
 
 pub(crate) struct Printer {
@@ -101,11 +184,66 @@ pub(crate) struct Printer {
     options: Vec<OptionParameter>,
     arguments: Vec<ArgumentParameter>,
     terminal_width: Option<usize>,
+    columns_width: Option<usize>,
+    default_width: Option<usize>,
+    usage_flag: bool,
+    version: Option<String>,
+    quiet_flag: bool,
+    contextual: Vec<(String, OptionParameter)>,
+    choice_groups: HashMap<String, Vec<(String, Vec<String>)>>,
+    examples: Vec<(String, String)>,
+    summary_maximum: Option<usize>,
+    no_meta: bool,
+    messages: Messages,
+    sections: Vec<Section>,
 }
 
 const PADDING_WIDTH: usize = 3;
 const MAIN_INDENT: usize = 1;
 const CHOICE_INDENT: usize = 2;
+const COLUMNS_VARIABLE: &str = "COLUMNS";
+const PORTABLE_WIDTH: usize = 80;
+const INITIAL_PREFIX: &str = "initial: ";
+const ENV_PREFIX: &str = "env: ";
+const OPTIONS_SUMMARY: &str = "[OPTIONS]";
+const DEFAULT_SECTIONS: &[Section] = &[Section::Positionals, Section::Options, Section::Examples];
+
+/// Move the `initial: X` meta message into the help message, as `(default: X)`.
+/// Any trailing empty meta messages are dropped, since they only served to align the initial column.
+fn inline_initial(help: &mut Option<String>, meta: &mut Option<Vec<String>>) {
+    let messages = match meta {
+        Some(messages) => messages,
+        None => return,
+    };
+    let index = match messages.iter().position(|m| m.starts_with(INITIAL_PREFIX)) {
+        Some(index) => index,
+        None => return,
+    };
+
+    let initial = messages.remove(index);
+    let default = format!("(default: {})", &initial[INITIAL_PREFIX.len()..]);
+    *help = match help.take() {
+        Some(message) if !message.is_empty() => Some(format!("{message} {default}")),
+        _ => Some(default),
+    };
+
+    while messages.last().map(|m| m.is_empty()).unwrap_or(false) {
+        messages.pop();
+    }
+
+    if messages.is_empty() {
+        meta.take();
+    }
+}
+
+/// Parse the total width from the `COLUMNS` environment variable, which must be a positive integer.
+fn parse_columns(columns: &str) -> Option<usize> {
+    columns
+        .trim()
+        .parse::<usize>()
+        .ok()
+        .filter(|width| *width > 0)
+}
 
 impl Printer {
     #[cfg(test)]
@@ -131,7 +269,12 @@ impl Printer {
             None
         };
 
+        let columns_width = std::env::var(COLUMNS_VARIABLE)
+            .ok()
+            .and_then(|columns| parse_columns(&columns));
+
         Self::new(program, about, options, arguments, terminal_width)
+            .with_columns_width(columns_width)
     }
 
     pub(crate) fn new(
@@ -141,72 +284,308 @@ impl Printer {
         arguments: Vec<ArgumentParameter>,
         terminal_width: Option<usize>,
     ) -> Self {
-        options.sort_by(|a, b| a.name.cmp(&b.name));
+        options.sort_by(|a, b| (a.order, &a.name).cmp(&(b.order, &b.name)));
         Self {
             program: program.into(),
             about,
             options,
             arguments,
             terminal_width,
+            columns_width: None,
+            default_width: None,
+            usage_flag: false,
+            version: None,
+            quiet_flag: false,
+            contextual: Vec::default(),
+            choice_groups: HashMap::default(),
+            examples: Vec::default(),
+            summary_maximum: None,
+            no_meta: false,
+            messages: Messages::default(),
+            sections: DEFAULT_SECTIONS.to_vec(),
         }
     }
 
-    pub(crate) fn print_help(&self, user_interface: &(impl UserInterface + ?Sized)) {
-        let help_flags = format!("-{HELP_SHORT}, --{HELP_NAME}");
+    /// Use the `messages` to structure the help message.
+    pub(crate) fn with_messages(mut self, messages: Messages) -> Self {
+        self.messages = messages;
+        self
+    }
+
+    /// Use the `columns_width` (from the `COLUMNS` environment variable) as the total width when the terminal width is unavailable.
+    /// Takes precedence over the default width.
+    pub(crate) fn with_columns_width(mut self, columns_width: Option<usize>) -> Self {
+        self.columns_width = columns_width;
+        self
+    }
+
+    /// Use the `default_width` as the total width when the terminal width is unavailable (ex: piped output).
+    pub(crate) fn with_default_width(mut self, default_width: Option<usize>) -> Self {
+        self.default_width = default_width;
+        self
+    }
+
+    /// Document the `--quiet` flag in the help message.
+    pub(crate) fn with_quiet_flag(mut self, quiet_flag: bool) -> Self {
+        self.quiet_flag = quiet_flag;
+        self
+    }
+
+    /// Document the environment variable each option falls back to (by option name, with the variable name), as an `env: VAR` meta message.
+    pub(crate) fn with_environment(mut self, environment: &[(String, String)]) -> Self {
+        for (name, variable) in environment {
+            if let Some(option) = self.options.iter_mut().find(|option| &option.name == name) {
+                option
+                    .meta
+                    .get_or_insert_with(Vec::default)
+                    .push(format!("{ENV_PREFIX}{variable}"));
+            }
+        }
+
+        self
+    }
+
+    /// Render the initial value (the `initial: X` meta message) inline with the help message, as `(default: X)`.
+    pub(crate) fn with_inline_defaults(mut self, inline_defaults: bool) -> Self {
+        if inline_defaults {
+            for option in self.options.iter_mut() {
+                inline_initial(&mut option.help, &mut option.meta);
+            }
+
+            for argument in self.arguments.iter_mut() {
+                inline_initial(&mut argument.help, &mut argument.meta);
+            }
+        }
+
+        self
+    }
+
+    /// Omit the meta messages from the help message, leaving only the flags and help columns.
+    pub(crate) fn with_no_meta(mut self, no_meta: bool) -> Self {
+        self.no_meta = no_meta;
+        self
+    }
+
+    /// Document the `--usage` flag in the help message.
+    pub(crate) fn with_usage_flag(mut self, usage_flag: bool) -> Self {
+        self.usage_flag = usage_flag;
+        self
+    }
+
+    /// Document the `--version` flag in the help message, which prints the `version`.
+    pub(crate) fn with_version(mut self, version: Option<String>) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Include options which are hidden from the help message, shown only when an error concerns their trigger parameter.
+    pub(crate) fn with_contextual(mut self, contextual: Vec<(String, OptionParameter)>) -> Self {
+        self.contextual = contextual;
+        self
+    }
+
+    /// List the example invocations (command, description) after the options.
+    pub(crate) fn with_examples(mut self, examples: Vec<(String, String)>) -> Self {
+        self.examples = examples;
+        self
+    }
+
+    /// Render the sections in the `order`, followed by those which it omits (in their default order).
+    pub(crate) fn with_section_order(mut self, order: Option<Vec<Section>>) -> Self {
+        if let Some(order) = order {
+            let mut sections: Vec<Section> = Vec::default();
+
+            for section in order.into_iter().chain(DEFAULT_SECTIONS.iter().copied()) {
+                if !sections.contains(&section) {
+                    sections.push(section);
+                }
+            }
+
+            self.sections = sections;
+        }
+
+        self
+    }
+
+    /// Summarize the options as `[OPTIONS]` in the synopsis when there are more than `summary_maximum` of them.
+    pub(crate) fn with_summary_maximum(mut self, summary_maximum: Option<usize>) -> Self {
+        self.summary_maximum = summary_maximum;
+        self
+    }
+
+    /// List the choices of the `argument` under the headings of the `groups`.
+    /// Choices which do not belong to any group are listed under a default heading.
+    pub(crate) fn with_choice_groups(
+        mut self,
+        argument: String,
+        groups: Vec<(String, Vec<String>)>,
+    ) -> Self {
+        if !groups.is_empty() {
+            self.choice_groups.insert(argument, groups);
+        }
+        self
+    }
+
+    pub(crate) fn contextual_hints(&self, error: &ParseError) -> Vec<String> {
+        self.contextual
+            .iter()
+            .filter(|(trigger, _)| error.concerns(trigger))
+            .map(|(_, option)| {
+                let option_flags = match grammar(&option.name, &option.nargs, &option.value_names) {
+                    g if g.is_empty() => format!("--{}", option.name),
+                    g => format!("--{} {g}", option.name),
+                };
+
+                match &option.help {
+                    Some(message) => format!("hint: see also '{option_flags}': {message}"),
+                    None => format!("hint: see also '{option_flags}'."),
+                }
+            })
+            .collect()
+    }
+
+    /// The single line synopsis of the program.
+    /// Ex: "usage: program [-h] [-f FLAG] ITEM"
+    fn synopsis(&self) -> String {
         let mut summary = vec![format!("[-{HELP_SHORT}]")];
+
+        if self.usage_flag {
+            summary.push(format!("[--{USAGE_NAME}]"));
+        }
+
+        if self.version.is_some() {
+            summary.push(format!("[--{VERSION_NAME}]"));
+        }
+
+        if self.quiet_flag {
+            summary.push(format!("[--{QUIET_NAME}]"));
+        }
+
+        match self.summary_maximum {
+            Some(maximum) if self.options.len() > maximum => {
+                summary.push(OPTIONS_SUMMARY.to_string());
+            }
+            _ => {
+                for option in &self.options {
+                    let grammar = match grammar(&option.name, &option.nargs, &option.value_names) {
+                        g if g.is_empty() => g,
+                        g => format!(" {g}"),
+                    };
+
+                    let flag = match option.shorts().first() {
+                        Some(s) => format!("-{s}{grammar}"),
+                        None => format!("--{name}{grammar}", name = option.name),
+                    };
+
+                    if option.required {
+                        summary.push(flag);
+                    } else {
+                        summary.push(format!("[{flag}]"));
+                    }
+                }
+            }
+        }
+
+        for argument in &self.arguments {
+            summary.push(grammar(
+                &argument.name,
+                &argument.nargs,
+                &argument.value_names,
+            ));
+        }
+
+        format!(
+            "{u} {p} {s}",
+            u = self.messages.usage,
+            p = self.program,
+            s = summary.join(" ")
+        )
+    }
+
+    pub(crate) fn print_usage(&self, user_interface: &(impl UserInterface + ?Sized)) {
+        user_interface.print(self.synopsis());
+    }
+
+    pub(crate) fn print_version(&self, user_interface: &(impl UserInterface + ?Sized)) {
+        let version = self
+            .version
+            .as_ref()
+            .expect("internal error - must have been set");
+        user_interface.print(format!("{p} {version}", p = self.program));
+    }
+
+    pub(crate) fn print_help(&self, user_interface: &(impl UserInterface + ?Sized)) {
+        let total_width = self
+            .terminal_width
+            .or(self.columns_width)
+            .or(self.default_width);
+        self.render_help(user_interface, total_width);
+    }
+
+    /// Print the help message as if on a terminal of `PORTABLE_WIDTH`, regardless of the environment.
+    pub(crate) fn print_help_portable(&self, user_interface: &(impl UserInterface + ?Sized)) {
+        self.render_help(user_interface, Some(PORTABLE_WIDTH));
+    }
+
+    fn render_help(
+        &self,
+        user_interface: &(impl UserInterface + ?Sized),
+        total_width: Option<usize>,
+    ) {
+        let help_flags = self
+            .messages
+            .help_flag
+            .replace("{short}", &format!("-{HELP_SHORT}"))
+            .replace("{long}", &format!("--{HELP_NAME}"));
+        let usage_flags = format!("--{USAGE_NAME}");
+        let version_flags = format!("--{VERSION_NAME}");
+        let quiet_flags = format!("--{QUIET_NAME}");
         let mut left_column_width = help_flags.len();
         let mut middle_column_width = HELP_MESSAGE.len() + MAIN_INDENT;
+
+        if self.usage_flag {
+            left_column_width = std::cmp::max(left_column_width, usage_flags.len());
+            middle_column_width =
+                std::cmp::max(middle_column_width, USAGE_MESSAGE.len() + MAIN_INDENT);
+        }
+
+        if self.version.is_some() {
+            left_column_width = std::cmp::max(left_column_width, version_flags.len());
+            middle_column_width =
+                std::cmp::max(middle_column_width, VERSION_MESSAGE.len() + MAIN_INDENT);
+        }
+
+        if self.quiet_flag {
+            left_column_width = std::cmp::max(left_column_width, quiet_flags.len());
+            middle_column_width =
+                std::cmp::max(middle_column_width, QUIET_MESSAGE.len() + MAIN_INDENT);
+        }
         let mut right_columns_widths = Vec::default();
         let mut grammars: HashMap<String, String> = HashMap::default();
 
-        for OptionParameter {
-            name,
-            short,
-            nargs,
-            choices,
-            help,
-            meta,
-        } in &self.options
-        {
-            let name_example = name.to_ascii_uppercase().replace("-", "_");
-            let grammar = match nargs {
-                Nargs::Precisely(0) => "".to_string(),
-                Nargs::Precisely(n) => format!(
-                    " {}",
-                    (0..*n)
-                        .map(|_| name_example.clone())
-                        .collect::<Vec<String>>()
-                        .join(" ")
-                ),
-                Nargs::Any => format!(" [{} ...]", name_example),
-                Nargs::AtLeastOne => {
-                    format!(" {} [...]", name_example)
-                }
+        for option in &self.options {
+            let OptionParameter {
+                name,
+                nargs,
+                choices,
+                meta,
+                value_names,
+                placeholder,
+                ..
+            } = option;
+            let help = option.annotated_help();
+            let grammar = match (grammar(name, nargs, value_names), placeholder) {
+                (g, Some(placeholder)) if g.is_empty() => format!(" {placeholder}"),
+                (g, _) if g.is_empty() => g,
+                (g, _) => format!(" {g}"),
             };
             grammars.insert(name.clone(), grammar.clone());
 
-            match short {
-                Some(s) => {
-                    // The 6 accounts for "-S , --".
-                    // Ex: "-f FLAG, --flag FLAG"
-                    //      ^^     ^^^^
-                    if left_column_width < name.len() + (grammar.len() * 2) + 6 {
-                        left_column_width = name.len() + (grammar.len() * 2) + 6;
-                    }
-
-                    summary.push(format!("[-{s}{grammar}]"));
-                }
-                None => {
-                    // The 2 accounts for "--".
-                    // Ex: "--flag FLAG"
-                    //      ^^
-                    if left_column_width < name.len() + grammar.len() + 2 {
-                        left_column_width = name.len() + grammar.len() + 2;
-                    }
-
-                    summary.push(format!("[--{name}{grammar}]"));
-                }
-            };
+            // Ex: "-f FLAG, --flag FLAG"
+            let flags = option_flags(name, &option.shorts(), &grammar);
+            if left_column_width < flags.len() {
+                left_column_width = flags.len();
+            }
 
             for (choice, description) in choices.iter() {
                 if left_column_width < choice.len() + CHOICE_INDENT {
@@ -230,7 +609,7 @@ impl Printer {
                 }
             }
 
-            if let Some(meta) = meta {
+            if let Some(meta) = meta.as_ref().filter(|_| !self.no_meta) {
                 for (i, m) in meta.iter().enumerate() {
                     if i >= right_columns_widths.len() {
                         right_columns_widths
@@ -250,33 +629,26 @@ impl Printer {
             choices,
             help,
             meta,
+            value_names,
         } in &self.arguments
         {
-            let name_example = name.to_ascii_uppercase().replace("-", "_");
-            let grammar = match nargs {
-                Nargs::Precisely(n) => format!(
-                    "{}",
-                    (0..*n)
-                        .map(|_| name_example.clone())
-                        .collect::<Vec<String>>()
-                        .join(" ")
-                ),
-                Nargs::Any => format!("[{} ...]", name_example),
-                Nargs::AtLeastOne => {
-                    format!("{} [...]", name_example)
-                }
-            };
+            let grammar = grammar(name, nargs, value_names);
             grammars.insert(name.clone(), grammar.clone());
 
             if left_column_width < grammar.len() {
                 left_column_width = grammar.len();
             }
 
-            summary.push(format!("{grammar}"));
+            // Grouped choices are indented once more, beneath their heading.
+            let choice_indent = if self.choice_groups.contains_key(name) {
+                CHOICE_INDENT * 2
+            } else {
+                CHOICE_INDENT
+            };
 
             for (choice, description) in choices.iter() {
-                if left_column_width < choice.len() + CHOICE_INDENT {
-                    left_column_width = choice.len() + CHOICE_INDENT;
+                if left_column_width < choice.len() + choice_indent {
+                    left_column_width = choice.len() + choice_indent;
                 }
 
                 if middle_column_width < description.len() + MAIN_INDENT {
@@ -296,7 +668,7 @@ impl Printer {
                 }
             }
 
-            if let Some(meta) = meta {
+            if let Some(meta) = meta.as_ref().filter(|_| !self.no_meta) {
                 for (i, m) in meta.iter().enumerate() {
                     if i >= right_columns_widths.len() {
                         right_columns_widths
@@ -310,11 +682,11 @@ impl Printer {
             }
         }
 
-        let column_renderer = match &self.terminal_width {
+        let column_renderer = match total_width {
             Some(tw) => {
                 #[cfg(feature = "tracing_debug")]
                 {
-                    debug!("Found the terminal width: {tw}.");
+                    debug!("Found the total width: {tw}.");
                 }
 
                 ColumnRenderer::guided(
@@ -322,7 +694,7 @@ impl Printer {
                     LeftWidth::new(left_column_width.clone()).unwrap(),
                     MiddleWidth::new(middle_column_width.clone()).unwrap(),
                     right_columns_widths.clone(),
-                    TotalWidth(tw.clone()),
+                    TotalWidth(tw),
                 )
             }
             None => {
@@ -342,11 +714,7 @@ impl Printer {
             }
         };
 
-        user_interface.print(format!(
-            "usage: {p} {s}",
-            p = self.program,
-            s = summary.join(" ")
-        ));
+        user_interface.print(self.synopsis());
 
         if let Some(about) = &self.about {
             for line in column_renderer.combined_render(MAIN_INDENT, &about) {
@@ -354,128 +722,286 @@ impl Printer {
             }
         }
 
-        if !self.arguments.is_empty() {
-            user_interface.print("".to_string());
-            user_interface.print("positional arguments:".to_string());
-
-            for ArgumentParameter {
-                name,
-                help,
-                choices,
-                meta,
-                ..
-            } in &self.arguments
-            {
-                let grammar = grammars
-                    .remove(name)
-                    .expect("internal error - must have been set");
-                let argument_help = match help {
-                    Some(message) => format!("{message}"),
-                    None => "".to_string(),
-                };
-                let (argument_choices, choices_ordered) = if choices.is_empty() {
-                    ("".to_string(), None)
-                } else {
-                    let mut choices_ordered: Vec<String> = choices.keys().cloned().collect();
-                    choices_ordered.sort();
-                    (
-                        format!("{{{}}} ", choices_ordered.join(", ")),
-                        Some(choices_ordered),
-                    )
-                };
-                for line in column_renderer.render(
-                    MAIN_INDENT,
-                    &grammar,
-                    format!("{argument_choices}{argument_help}").as_str(),
-                    meta.as_ref().unwrap_or(&Vec::default()),
-                ) {
-                    user_interface.print(line);
+        for section in &self.sections {
+            match section {
+                Section::Positionals => {
+                    if !self.arguments.is_empty() {
+                        user_interface.print("".to_string());
+                        user_interface.print(self.messages.positional_arguments.clone());
+
+                        for ArgumentParameter {
+                            name,
+                            help,
+                            choices,
+                            meta,
+                            ..
+                        } in &self.arguments
+                        {
+                            let grammar = grammars
+                                .remove(name)
+                                .expect("internal error - must have been set");
+                            let argument_help = match help {
+                                Some(message) => format!("{message}"),
+                                None => "".to_string(),
+                            };
+                            let (argument_choices, choices_ordered) = if choices.is_empty() {
+                                ("".to_string(), None)
+                            } else {
+                                let mut choices_ordered: Vec<String> =
+                                    choices.keys().cloned().collect();
+                                choices_ordered.sort();
+                                (
+                                    format!("{{{}}} ", choices_ordered.join(", ")),
+                                    Some(choices_ordered),
+                                )
+                            };
+                            for line in column_renderer.render(
+                                MAIN_INDENT,
+                                &grammar,
+                                format!("{argument_choices}{argument_help}").as_str(),
+                                meta.as_ref()
+                                    .filter(|_| !self.no_meta)
+                                    .unwrap_or(&Vec::default()),
+                            ) {
+                                user_interface.print(line);
+                            }
+
+                            if let Some(choice_keys) = choices_ordered {
+                                let (choice_indent, sections) = match self.choice_groups.get(name) {
+                                    Some(groups) => {
+                                        let mut sections: Vec<(Option<String>, Vec<String>)> =
+                                            groups
+                                                .iter()
+                                                .map(|(heading, members)| {
+                                                    let members = choice_keys
+                                                        .iter()
+                                                        .filter(|choice| members.contains(choice))
+                                                        .cloned()
+                                                        .collect();
+                                                    (Some(format!("{heading}:")), members)
+                                                })
+                                                .collect();
+                                        let others = choice_keys
+                                            .iter()
+                                            .filter(|choice| {
+                                                !groups
+                                                    .iter()
+                                                    .any(|(_, members)| members.contains(choice))
+                                            })
+                                            .cloned()
+                                            .collect();
+                                        sections.push((
+                                            Some(self.messages.other_commands.clone()),
+                                            others,
+                                        ));
+                                        (CHOICE_INDENT * 2, sections)
+                                    }
+                                    None => (CHOICE_INDENT, vec![(None, choice_keys)]),
+                                };
+
+                                for (heading, members) in sections {
+                                    if members.is_empty() {
+                                        continue;
+                                    }
+
+                                    if let Some(heading) = heading {
+                                        user_interface.print(format!(
+                                            "{:indent$}{heading}",
+                                            "",
+                                            indent = MAIN_INDENT + CHOICE_INDENT,
+                                        ));
+                                    }
+
+                                    for choice in members {
+                                        let description = choices
+                                            .get(&choice)
+                                            .expect("internal error - choice must exist");
+                                        // Indent within the left column, so that the descriptions align with those of the parameters.
+                                        let indented_choice =
+                                            format!("{:choice_indent$}{choice}", "");
+                                        for line in column_renderer.render(
+                                            MAIN_INDENT,
+                                            &indented_choice,
+                                            description,
+                                            &vec![],
+                                        ) {
+                                            user_interface.print(line);
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
                 }
+                Section::Options => {
+                    user_interface.print("".to_string());
+                    user_interface.print(self.messages.options.clone());
+                    for line in
+                        column_renderer.render(MAIN_INDENT, &help_flags, HELP_MESSAGE, &vec![])
+                    {
+                        user_interface.print(line);
+                    }
+
+                    if self.usage_flag {
+                        for line in column_renderer.render(
+                            MAIN_INDENT,
+                            &usage_flags,
+                            USAGE_MESSAGE,
+                            &vec![],
+                        ) {
+                            user_interface.print(line);
+                        }
+                    }
+
+                    if self.version.is_some() {
+                        for line in column_renderer.render(
+                            MAIN_INDENT,
+                            &version_flags,
+                            VERSION_MESSAGE,
+                            &vec![],
+                        ) {
+                            user_interface.print(line);
+                        }
+                    }
 
-                if let Some(choice_keys) = choices_ordered {
-                    for choice in choice_keys {
-                        let description = choices
-                            .get(&choice)
-                            .expect("internal error - choice must exist");
+                    if self.quiet_flag {
                         for line in column_renderer.render(
-                            MAIN_INDENT + CHOICE_INDENT,
-                            &choice,
-                            description,
+                            MAIN_INDENT,
+                            &quiet_flags,
+                            QUIET_MESSAGE,
                             &vec![],
                         ) {
                             user_interface.print(line);
                         }
                     }
+
+                    for option in &self.options {
+                        let OptionParameter {
+                            name,
+                            choices,
+                            meta,
+                            ..
+                        } = option;
+                        let grammar = grammars
+                            .remove(name)
+                            .expect("internal error - must have been set");
+                        let option_flags = option_flags(name, &option.shorts(), &grammar);
+                        let option_help = match option.annotated_help() {
+                            Some(message) => message,
+                            None => "".to_string(),
+                        };
+                        let (option_choices, choices_ordered) = if choices.is_empty() {
+                            ("".to_string(), None)
+                        } else {
+                            let mut choices_ordered: Vec<String> =
+                                choices.keys().cloned().collect();
+                            choices_ordered.sort();
+                            (
+                                format!("{{{}}} ", choices_ordered.join(", ")),
+                                Some(choices_ordered),
+                            )
+                        };
+                        for line in column_renderer.render(
+                            MAIN_INDENT,
+                            &option_flags,
+                            format!("{option_choices}{option_help}").as_str(),
+                            meta.as_ref()
+                                .filter(|_| !self.no_meta)
+                                .unwrap_or(&Vec::default()),
+                        ) {
+                            user_interface.print(line);
+                        }
+
+                        if let Some(choice_keys) = choices_ordered {
+                            for choice in choice_keys {
+                                let description = choices
+                                    .get(&choice)
+                                    .expect("internal error - choice must exist");
+                                let indented_choice = format!("{:CHOICE_INDENT$}{choice}", "");
+                                for line in column_renderer.render(
+                                    MAIN_INDENT,
+                                    &indented_choice,
+                                    description,
+                                    &vec![],
+                                ) {
+                                    user_interface.print(line);
+                                }
+                            }
+                        }
+                    }
+                }
+                Section::Examples => {
+                    if !self.examples.is_empty() {
+                        user_interface.print("".to_string());
+                        user_interface.print(self.messages.examples.clone());
+
+                        for (command, description) in &self.examples {
+                            user_interface.print(format!("{:MAIN_INDENT$}$ {command}", ""));
+
+                            for line in column_renderer
+                                .combined_render(MAIN_INDENT + CHOICE_INDENT, description)
+                            {
+                                user_interface.print(line);
+                            }
+                        }
+                    }
                 }
             }
         }
+    }
+}
 
-        user_interface.print("".to_string());
-        user_interface.print("options:".to_string());
-        for line in column_renderer.render(MAIN_INDENT, &help_flags, HELP_MESSAGE, &vec![]) {
-            user_interface.print(line);
-        }
+/// Build the flags for an option, for the detailed section.
+/// Ex: "-q, -s, --quiet"
+fn option_flags(name: &str, shorts: &[char], grammar: &str) -> String {
+    shorts
+        .iter()
+        .map(|s| format!("-{s}{grammar}"))
+        .chain(std::iter::once(format!("--{name}{grammar}")))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
 
-        for OptionParameter {
-            name,
-            short,
-            help,
-            choices,
-            meta,
-            ..
-        } in &self.options
-        {
-            let grammar = grammars
-                .remove(name)
-                .expect("internal error - must have been set");
-            let option_flags = match short {
-                Some(s) => format!("-{s}{grammar}, --{name}{grammar}"),
-                None => format!("--{name}{grammar}"),
-            };
-            let option_help = match help {
-                Some(message) => format!("{message}"),
-                None => "".to_string(),
-            };
-            let (option_choices, choices_ordered) = if choices.is_empty() {
-                ("".to_string(), None)
-            } else {
-                let mut choices_ordered: Vec<String> = choices.keys().cloned().collect();
-                choices_ordered.sort();
-                (
-                    format!("{{{}}} ", choices_ordered.join(", ")),
-                    Some(choices_ordered),
-                )
-            };
-            for line in column_renderer.render(
-                MAIN_INDENT,
-                &option_flags,
-                format!("{option_choices}{option_help}").as_str(),
-                meta.as_ref().unwrap_or(&Vec::default()),
-            ) {
-                user_interface.print(line);
-            }
+/// Build the grammar for a parameter, for both the usage summary and the detailed section.
+/// Ex: `Nargs::Range(2, 4)` -> "TAGS TAGS [TAGS TAGS]"
+fn grammar(name: &str, nargs: &Nargs, value_names: &[String]) -> String {
+    if !value_names.is_empty() {
+        return value_names.join(" ");
+    }
 
-            if let Some(choice_keys) = choices_ordered {
-                for choice in choice_keys {
-                    let description = choices
-                        .get(&choice)
-                        .expect("internal error - choice must exist");
-                    for line in column_renderer.render(
-                        MAIN_INDENT + CHOICE_INDENT,
-                        &choice,
-                        description,
-                        &vec![],
-                    ) {
-                        user_interface.print(line);
-                    }
-                }
+    let name_example = name.to_ascii_uppercase().replace("-", "_");
+    let repeat = |n: usize| {
+        (0..n)
+            .map(|_| name_example.clone())
+            .collect::<Vec<String>>()
+            .join(" ")
+    };
+
+    match nargs {
+        Nargs::Precisely(n) => repeat(usize::from(*n)),
+        Nargs::Any => format!("[{} ...]", name_example),
+        Nargs::AtLeastOne => {
+            format!("{} [...]", name_example)
+        }
+        Nargs::ZeroOrOne => format!("[{name_example}]"),
+        Nargs::Range(min, max) => {
+            let required = repeat(*min);
+            let optional = repeat(max.saturating_sub(*min));
+
+            match (required.is_empty(), optional.is_empty()) {
+                (_, true) => required,
+                (true, false) => format!("[{optional}]"),
+                (false, false) => format!("{required} [{optional}]"),
             }
         }
     }
 }
 
+/// The input tokens at which a parse error occurred.
+///
+/// Displays as the tokens, with a caret pointing out the `offset` on the following line.
 #[derive(Debug, PartialEq, Eq)]
-pub(crate) struct ErrorContext {
+pub struct ErrorContext {
     offset: usize,
     tokens: Vec<String>,
 }
@@ -487,6 +1013,17 @@ impl ErrorContext {
             tokens: tokens.iter().map(|s| s.to_string()).collect(),
         }
     }
+
+    /// The offset of the error within the input tokens.
+    /// The offset counts the characters of the input before the error (excluding the spaces between tokens).
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The input tokens.
+    pub fn tokens(&self) -> &[String] {
+        &self.tokens
+    }
 }
 
 impl std::fmt::Display for ErrorContext {
@@ -496,8 +1033,13 @@ impl std::fmt::Display for ErrorContext {
         let mut projection_offset = 0;
 
         for (i, token) in self.tokens.iter().enumerate() {
-            tokens_length += token.len();
-            projection.push_str(token);
+            tokens_length += token_width(token);
+
+            if token.is_empty() {
+                projection.push_str(EMPTY_TOKEN);
+            } else {
+                projection.push_str(token);
+            }
 
             if i + 1 < self.tokens.len() {
                 projection.push_str(" ");
@@ -521,6 +1063,7 @@ impl std::fmt::Display for ErrorContext {
 mod tests {
     use super::*;
     use crate::parser::util::InMemoryInterface;
+    use rstest::rstest;
 
     #[test]
     fn print_help_empty() {
@@ -545,11 +1088,11 @@ options:
     }
 
     #[test]
-    fn print_help_option() {
+    fn print_usage() {
         // Setup
         let printer = Printer::new(
             "program",
-            None,
+            Some("about".to_string()),
             vec![OptionParameter::basic(
                 "flag".to_string(),
                 Some('f'),
@@ -557,6 +1100,1249 @@ options:
                 Some("message".to_string()),
                 None,
             )],
+            vec![ArgumentParameter::basic(
+                "item".to_string(),
+                Nargs::Precisely(1),
+                Some("message".to_string()),
+                None,
+            )],
+            Some(120),
+        )
+        .with_usage_flag(true);
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_usage(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(message, "usage: program [-h] [--usage] [-f FLAG] ITEM");
+    }
+
+    #[test]
+    fn print_usage_required() {
+        // Setup
+        let printer = Printer::new(
+            "program",
+            None,
+            vec![
+                OptionParameter::basic("config".to_string(), None, Nargs::Precisely(1), None, None)
+                    .with_required(true),
+                OptionParameter::basic("flag".to_string(), Some('f'), Nargs::Any, None, None)
+                    .with_required(true),
+                OptionParameter::basic(
+                    "verbose".to_string(),
+                    Some('v'),
+                    Nargs::Precisely(0),
+                    None,
+                    None,
+                ),
+            ],
+            Vec::default(),
+            Some(120),
+        );
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_usage(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(
+            message,
+            "usage: program [-h] --config CONFIG -f [FLAG ...] [-v]"
+        );
+    }
+
+    #[rstest]
+    #[case(vec![0, 0, 0], vec!["apple", "banana", "cherry"])]
+    #[case(vec![0, 0, -1], vec!["cherry", "apple", "banana"])]
+    #[case(vec![1, 0, 0], vec!["banana", "cherry", "apple"])]
+    #[case(vec![2, -5, 1], vec!["banana", "cherry", "apple"])]
+    fn print_help_option_order(#[case] orders: Vec<i32>, #[case] expected: Vec<&str>) {
+        // Setup
+        let printer = Printer::new(
+            "program",
+            None,
+            ["apple", "banana", "cherry"]
+                .into_iter()
+                .zip(orders)
+                .map(|(name, order)| {
+                    OptionParameter::basic(name.to_string(), None, Nargs::Precisely(0), None, None)
+                        .with_order(order)
+                })
+                .collect(),
+            Vec::default(),
+            Some(120),
+        );
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_help(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        let synopsis: Vec<String> = expected.iter().map(|name| format!("[--{name}]")).collect();
+        let details: Vec<String> = expected.iter().map(|name| format!(" --{name}")).collect();
+        assert_eq!(
+            message.lines().next().unwrap(),
+            format!("usage: program [-h] {}", synopsis.join(" "))
+        );
+        assert_eq!(message.lines().skip(4).collect::<Vec<&str>>(), details);
+    }
+
+    #[test]
+    fn print_help_option_order_snapshot() {
+        // Setup
+        let printer = Printer::new(
+            "program",
+            None,
+            vec![
+                OptionParameter::basic(
+                    "apple".to_string(),
+                    None,
+                    Nargs::Precisely(0),
+                    Some("message".to_string()),
+                    None,
+                ),
+                OptionParameter::basic(
+                    "banana".to_string(),
+                    Some('b'),
+                    Nargs::Precisely(1),
+                    Some("message".to_string()),
+                    None,
+                )
+                .with_order(-1),
+            ],
+            Vec::default(),
+            Some(120),
+        );
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_help(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(
+            message,
+            r#"usage: program [-h] [-b BANANA] [--apple]
+
+options:
+ -h, --help                   Show this help message and exit.
+ -b BANANA, --banana BANANA   message
+ --apple                      message"#
+        );
+    }
+
+    #[test]
+    fn print_help_value_names() {
+        // Setup
+        let printer = Printer::new(
+            "program",
+            None,
+            vec![OptionParameter::basic(
+                "range".to_string(),
+                Some('r'),
+                Nargs::Precisely(2),
+                Some("message".to_string()),
+                None,
+            )
+            .with_value_names(vec!["MIN".to_string(), "MAX".to_string()])],
+            vec![ArgumentParameter::basic(
+                "point".to_string(),
+                Nargs::Precisely(2),
+                Some("message".to_string()),
+                None,
+            )
+            .with_value_names(vec!["X".to_string(), "Y".to_string()])],
+            Some(120),
+        );
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_help(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(
+            message,
+            r#"usage: program [-h] [-r MIN MAX] X Y
+
+positional arguments:
+ X Y                           message
+
+options:
+ -h, --help                    Show this help message and exit.
+ -r MIN MAX, --range MIN MAX   message"#
+        );
+    }
+
+    #[test]
+    fn print_help_usage_flag() {
+        // Setup
+        let printer = Printer::new(
+            "program",
+            None,
+            vec![OptionParameter::basic(
+                "flag".to_string(),
+                Some('f'),
+                Nargs::Precisely(1),
+                Some("message".to_string()),
+                None,
+            )],
+            Vec::default(),
+            Some(120),
+        )
+        .with_usage_flag(true);
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_help(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(
+            message,
+            r#"usage: program [-h] [--usage] [-f FLAG]
+
+options:
+ -h, --help             Show this help message and exit.
+ --usage                Show the usage message and exit.
+ -f FLAG, --flag FLAG   message"#
+        );
+    }
+
+    #[test]
+    fn print_help_version() {
+        // Setup
+        let printer = Printer::new(
+            "program",
+            None,
+            vec![OptionParameter::basic(
+                "flag".to_string(),
+                Some('f'),
+                Nargs::Precisely(1),
+                Some("message".to_string()),
+                None,
+            )],
+            Vec::default(),
+            Some(120),
+        )
+        .with_version(Some("1.2.3".to_string()));
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_help(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(
+            message,
+            r#"usage: program [-h] [--version] [-f FLAG]
+
+options:
+ -h, --help             Show this help message and exit.
+ --version              Show the version and exit.
+ -f FLAG, --flag FLAG   message"#
+        );
+    }
+
+    #[test]
+    fn print_version() {
+        // Setup
+        let printer = Printer::new("program", None, Vec::default(), Vec::default(), Some(120))
+            .with_version(Some("1.2.3".to_string()));
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_version(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(message, "program 1.2.3");
+    }
+
+    #[test]
+    fn print_help_quiet_flag() {
+        // Setup
+        let printer = Printer::new(
+            "program",
+            None,
+            vec![OptionParameter::basic(
+                "flag".to_string(),
+                Some('f'),
+                Nargs::Precisely(1),
+                Some("message".to_string()),
+                None,
+            )],
+            Vec::default(),
+            Some(120),
+        )
+        .with_usage_flag(true)
+        .with_quiet_flag(true);
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_help(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(
+            message,
+            r#"usage: program [-h] [--usage] [--quiet] [-f FLAG]
+
+options:
+ -h, --help             Show this help message and exit.
+ --usage                Show the usage message and exit.
+ --quiet                Suppress warnings and notices.
+ -f FLAG, --flag FLAG   message"#
+        );
+    }
+
+    #[test]
+    fn print_help_summarize_options() {
+        // Setup
+        let options = ["alpha", "bravo", "charlie", "delta"]
+            .iter()
+            .map(|name| {
+                OptionParameter::basic(
+                    name.to_string(),
+                    None,
+                    Nargs::Precisely(1),
+                    Some(format!("The {name}.")),
+                    None,
+                )
+            })
+            .collect();
+        let printer = Printer::new(
+            "program",
+            None,
+            options,
+            vec![ArgumentParameter::basic(
+                "item".to_string(),
+                Nargs::Precisely(1),
+                Some("The item.".to_string()),
+                None,
+            )],
+            Some(120),
+        )
+        .with_summary_maximum(Some(3));
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_help(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(
+            message,
+            r#"usage: program [-h] [OPTIONS] ITEM
+
+positional arguments:
+ ITEM                The item.
+
+options:
+ -h, --help          Show this help message and exit.
+ --alpha ALPHA       The alpha.
+ --bravo BRAVO       The bravo.
+ --charlie CHARLIE   The charlie.
+ --delta DELTA       The delta."#
+        );
+    }
+
+    #[rstest]
+    #[case(None, "usage: program [-h] [--alpha ALPHA] [--bravo BRAVO]")]
+    #[case(Some(0), "usage: program [-h] [OPTIONS]")]
+    #[case(Some(2), "usage: program [-h] [--alpha ALPHA] [--bravo BRAVO]")]
+    fn print_usage_summarize_options(
+        #[case] summary_maximum: Option<usize>,
+        #[case] expected: &str,
+    ) {
+        // Setup
+        let options = ["alpha", "bravo"]
+            .iter()
+            .map(|name| {
+                OptionParameter::basic(name.to_string(), None, Nargs::Precisely(1), None, None)
+            })
+            .collect();
+        let printer = Printer::new("program", None, options, Vec::default(), Some(120))
+            .with_summary_maximum(summary_maximum);
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_usage(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn print_help_examples() {
+        // Setup
+        let printer = Printer::new(
+            "program",
+            None,
+            vec![OptionParameter::basic(
+                "flag".to_string(),
+                Some('f'),
+                Nargs::Precisely(1),
+                Some("message".to_string()),
+                None,
+            )],
+            Vec::default(),
+            Some(60),
+        )
+        .with_examples(vec![
+            (
+                "program -f x".to_string(),
+                "Run with the flag x.".to_string(),
+            ),
+            (
+                "program".to_string(),
+                "Run without any flag, which is a perfectly reasonable thing to do.".to_string(),
+            ),
+        ]);
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_help(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(
+            message,
+            r#"usage: program [-h] [-f FLAG]
+
+options:
+ -h, --help             Show this help message and exit.
+ -f FLAG, --flag FLAG   message
+
+examples:
+ $ program -f x
+   Run with the flag x.
+ $ program
+   Run without any flag, which is a perfectly reasonable
+   thing to do."#
+        );
+    }
+
+    #[rstest]
+    #[case(vec![Section::Options, Section::Positionals], r#"usage: program [-h] [-f FLAG] FILE
+
+options:
+ -h, --help             Show this help message and exit.
+ -f FLAG, --flag FLAG   message
+
+positional arguments:
+ FILE                   file message
+
+examples:
+ $ program a.txt
+   Run on a file."#)]
+    #[case(vec![Section::Examples], r#"usage: program [-h] [-f FLAG] FILE
+
+examples:
+ $ program a.txt
+   Run on a file.
+
+positional arguments:
+ FILE                   file message
+
+options:
+ -h, --help             Show this help message and exit.
+ -f FLAG, --flag FLAG   message"#)]
+    #[case(vec![Section::Options, Section::Options], r#"usage: program [-h] [-f FLAG] FILE
+
+options:
+ -h, --help             Show this help message and exit.
+ -f FLAG, --flag FLAG   message
+
+positional arguments:
+ FILE                   file message
+
+examples:
+ $ program a.txt
+   Run on a file."#)]
+    fn print_help_section_order(#[case] order: Vec<Section>, #[case] expected: &str) {
+        // Setup
+        let printer = Printer::new(
+            "program",
+            None,
+            vec![OptionParameter::basic(
+                "flag".to_string(),
+                Some('f'),
+                Nargs::Precisely(1),
+                Some("message".to_string()),
+                None,
+            )],
+            vec![ArgumentParameter::basic(
+                "file".to_string(),
+                Nargs::Precisely(1),
+                Some("file message".to_string()),
+                None,
+            )],
+            Some(60),
+        )
+        .with_examples(vec![(
+            "program a.txt".to_string(),
+            "Run on a file.".to_string(),
+        )])
+        .with_section_order(Some(order));
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_help(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(message, expected);
+    }
+
+    #[test]
+    fn print_help_messages() {
+        // Setup
+        let printer = Printer::new(
+            "program",
+            None,
+            vec![OptionParameter::basic(
+                "flag".to_string(),
+                Some('f'),
+                Nargs::Precisely(1),
+                Some("message".to_string()),
+                None,
+            )],
+            vec![ArgumentParameter::basic(
+                "item".to_string(),
+                Nargs::Precisely(1),
+                Some("message".to_string()),
+                None,
+            )],
+            Some(120),
+        )
+        .with_messages(
+            Messages::default()
+                .usage("Verwendung:")
+                .positional_arguments("Argumente:")
+                .options("Optionen:"),
+        );
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_help(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(
+            message,
+            r#"Verwendung: program [-h] [-f FLAG] ITEM
+
+Argumente:
+ ITEM                   message
+
+Optionen:
+ -h, --help             Show this help message and exit.
+ -f FLAG, --flag FLAG   message"#
+        );
+    }
+
+    #[rstest]
+    #[case(
+        "{short}|{long}",
+        " -h|--help              Show this help message and exit."
+    )]
+    #[case(
+        "{long}, {short}",
+        " --help, -h             Show this help message and exit."
+    )]
+    #[case("{long}", " --help                 Show this help message and exit.")]
+    fn print_help_messages_help_flag(#[case] help_flag: &str, #[case] expected: &str) {
+        // Setup
+        let printer = Printer::new(
+            "program",
+            None,
+            vec![OptionParameter::basic(
+                "flag".to_string(),
+                Some('f'),
+                Nargs::Precisely(1),
+                Some("message".to_string()),
+                None,
+            )],
+            Vec::default(),
+            Some(120),
+        )
+        .with_messages(Messages::default().help_flag(help_flag));
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_help(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(
+            message,
+            format!(
+                r#"usage: program [-h] [-f FLAG]
+
+options:
+{expected}
+ -f FLAG, --flag FLAG   message"#
+            )
+        );
+    }
+
+    #[test]
+    fn print_help_option() {
+        // Setup
+        let printer = Printer::new(
+            "program",
+            None,
+            vec![OptionParameter::basic(
+                "flag".to_string(),
+                Some('f'),
+                Nargs::Precisely(1),
+                Some("message".to_string()),
+                None,
+            )],
+            Vec::default(),
+            Some(120),
+        );
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_help(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(
+            message,
+            r#"usage: program [-h] [-f FLAG]
+
+options:
+ -h, --help             Show this help message and exit.
+ -f FLAG, --flag FLAG   message"#
+        );
+    }
+
+    #[test]
+    fn print_help_option_short_aliases() {
+        // Setup
+        let printer = Printer::new(
+            "program",
+            None,
+            vec![OptionParameter::basic(
+                "quiet".to_string(),
+                None,
+                Nargs::Precisely(0),
+                Some("message".to_string()),
+                None,
+            )
+            .with_short_aliases(vec!['q', 's'])],
+            Vec::default(),
+            Some(120),
+        );
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_help(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(
+            message,
+            r#"usage: program [-h] [-q]
+
+options:
+ -h, --help        Show this help message and exit.
+ -q, -s, --quiet   message"#
+        );
+    }
+
+    #[test]
+    fn print_help_option_choices() {
+        // Setup
+        let printer = Printer::new(
+            "program",
+            None,
+            vec![OptionParameter::new(
+                "flag".to_string(),
+                Some('f'),
+                Nargs::Precisely(1),
+                None,
+                None,
+                HashMap::from([
+                    ("xyz".to_string(), "do the xyz".to_string()),
+                    ("abc".to_string(), "do the abc".to_string()),
+                    ("123".to_string(), "do the 123".to_string()),
+                ]),
+            )],
+            Vec::default(),
+            Some(120),
+        );
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_help(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(
+            message,
+            r#"usage: program [-h] [-f FLAG]
+
+options:
+ -h, --help             Show this help message and exit.
+ -f FLAG, --flag FLAG   {123, abc, xyz}
+   123                  do the 123
+   abc                  do the abc
+   xyz                  do the xyz"#
+        );
+    }
+
+    #[test]
+    fn print_help_option_meta() {
+        // Setup
+        let printer = Printer::new(
+            "program",
+            None,
+            vec![OptionParameter::basic(
+                "flag".to_string(),
+                Some('f'),
+                Nargs::Precisely(1),
+                Some("message in a bottle, by the police.".to_string()),
+                Some(vec!["the swift".to_string(), "brown fox".to_string()]),
+            )],
+            Vec::default(),
+            Some(72),
+        );
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_help(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(
+            message,
+            r#"usage: program [-h] [-f FLAG]
+
+options:
+ -h, --help             Show this help message
+                        and exit.
+ -f FLAG, --flag FLAG   message in a bottle, by    the swift   brown fox
+                        the police."#
+        );
+    }
+
+    #[rstest]
+    #[case(
+        false,
+        r#"usage: program [-h] [--flag FLAG] ITEM
+
+positional arguments:
+ ITEM          the item                                       initial: x
+
+options:
+ -h, --help    Show this help message and exit.
+ --flag FLAG   the flag                           type: u32   initial: 0"#
+    )]
+    #[case(
+        true,
+        r#"usage: program [-h] [--flag FLAG] ITEM
+
+positional arguments:
+ ITEM          the item (default: x)
+
+options:
+ -h, --help    Show this help message and exit.
+ --flag FLAG   the flag (default: 0)              type: u32"#
+    )]
+    fn print_help_inline_defaults(#[case] inline_defaults: bool, #[case] expected: &str) {
+        // Setup
+        let printer = Printer::new(
+            "program",
+            None,
+            vec![OptionParameter::basic(
+                "flag".to_string(),
+                None,
+                Nargs::Precisely(1),
+                Some("the flag".to_string()),
+                Some(vec!["type: u32".to_string(), "initial: 0".to_string()]),
+            )],
+            vec![ArgumentParameter::basic(
+                "item".to_string(),
+                Nargs::Precisely(1),
+                Some("the item".to_string()),
+                Some(vec!["".to_string(), "initial: x".to_string()]),
+            )],
+            Some(120),
+        )
+        .with_inline_defaults(inline_defaults);
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_help(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(message, expected);
+    }
+
+    #[rstest]
+    #[case(
+        false,
+        r#"usage: program [-h] [--flag FLAG] ITEM
+
+positional arguments:
+ ITEM          the item
+
+options:
+ -h, --help    Show this help message and exit.
+ --flag FLAG   the flag"#
+    )]
+    #[case(
+        true,
+        r#"usage: program [-h] [--flag FLAG] ITEM
+
+positional arguments:
+ ITEM          the item (default: x)
+
+options:
+ -h, --help    Show this help message and exit.
+ --flag FLAG   the flag (default: 0)"#
+    )]
+    fn print_help_no_meta(#[case] inline_defaults: bool, #[case] expected: &str) {
+        // Setup
+        let printer = Printer::new(
+            "program",
+            None,
+            vec![OptionParameter::basic(
+                "flag".to_string(),
+                None,
+                Nargs::Precisely(1),
+                Some("the flag".to_string()),
+                Some(vec!["type: u32".to_string(), "initial: 0".to_string()]),
+            )],
+            vec![ArgumentParameter::basic(
+                "item".to_string(),
+                Nargs::Precisely(1),
+                Some("the item".to_string()),
+                Some(vec!["type: String".to_string(), "initial: x".to_string()]),
+            )],
+            Some(120),
+        )
+        .with_inline_defaults(inline_defaults)
+        .with_no_meta(true);
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_help(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(message, expected);
+    }
+
+    #[rstest]
+    #[case(None, None, None, None)]
+    #[case(Some("abc"), None, Some("abc"), None)]
+    #[case(Some("abc"), Some(vec!["type: u32"]), Some("abc"), Some(vec!["type: u32"]))]
+    #[case(None, Some(vec!["initial: 1"]), Some("(default: 1)"), None)]
+    #[case(Some(""), Some(vec!["", "initial: 1"]), Some("(default: 1)"), None)]
+    #[case(Some("abc"), Some(vec!["initial: []", "extra"]), Some("abc (default: [])"), Some(vec!["extra"]))]
+    fn inline_initial_meta(
+        #[case] help: Option<&str>,
+        #[case] meta: Option<Vec<&str>>,
+        #[case] expected_help: Option<&str>,
+        #[case] expected_meta: Option<Vec<&str>>,
+    ) {
+        // Setup
+        let mut help = help.map(|h| h.to_string());
+        let mut meta = meta.map(|m| m.into_iter().map(|s| s.to_string()).collect::<Vec<_>>());
+
+        // Execute
+        inline_initial(&mut help, &mut meta);
+
+        // Verify
+        assert_eq!(help, expected_help.map(|h| h.to_string()));
+        assert_eq!(
+            meta,
+            expected_meta.map(|m| m.into_iter().map(|s| s.to_string()).collect::<Vec<_>>())
+        );
+    }
+
+    #[test]
+    fn print_help_default_width() {
+        // Setup
+        let printer = Printer::new(
+            "program",
+            None,
+            vec![OptionParameter::basic(
+                "flag".to_string(),
+                Some('f'),
+                Nargs::Precisely(1),
+                Some("message in a bottle, by the police.".to_string()),
+                Some(vec!["the swift".to_string(), "brown fox".to_string()]),
+            )],
+            Vec::default(),
+            None,
+        )
+        .with_default_width(Some(72));
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_help(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(
+            message,
+            r#"usage: program [-h] [-f FLAG]
+
+options:
+ -h, --help             Show this help message
+                        and exit.
+ -f FLAG, --flag FLAG   message in a bottle, by    the swift   brown fox
+                        the police."#
+        );
+    }
+
+    #[rstest]
+    #[case(None)]
+    #[case(Some(40))]
+    #[case(Some(120))]
+    fn print_help_portable(#[case] terminal_width: Option<usize>) {
+        // Setup
+        let printer = Printer::new(
+            "program",
+            Some(
+                "A program with a rather long description, which must wrap at the portable width."
+                    .to_string(),
+            ),
+            vec![OptionParameter::basic(
+                "flag".to_string(),
+                Some('f'),
+                Nargs::Precisely(1),
+                Some("message in a bottle, by the police.".to_string()),
+                Some(vec!["the swift".to_string(), "brown fox".to_string()]),
+            )],
+            Vec::default(),
+            terminal_width,
+        )
+        .with_columns_width(Some(50))
+        .with_default_width(Some(72));
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_help_portable(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(
+            message,
+            r#"usage: program [-h] [-f FLAG]
+ A program with a rather long description, which must
+ wrap at the portable width.
+
+options:
+ -h, --help             Show this help message and exit.
+ -f FLAG, --flag FLAG   message in a bottle, by the        the swift   brown fox
+                        police."#
+        );
+    }
+
+    #[test]
+    fn print_help_default_width_ignored() {
+        // Setup
+        let printer = Printer::new(
+            "program",
+            None,
+            vec![OptionParameter::basic(
+                "flag".to_string(),
+                Some('f'),
+                Nargs::Precisely(1),
+                Some("message in a bottle, by the police.".to_string()),
+                Some(vec!["the swift".to_string(), "brown fox".to_string()]),
+            )],
+            Vec::default(),
+            Some(120),
+        )
+        .with_default_width(Some(72));
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_help(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(
+            message,
+            r#"usage: program [-h] [-f FLAG]
+
+options:
+ -h, --help             Show this help message and exit.
+ -f FLAG, --flag FLAG   message in a bottle, by the police.      the swift   brown fox"#
+        );
+    }
+
+    #[rstest]
+    #[case(None, Some(72), Some(120))]
+    #[case(None, Some(72), None)]
+    #[case(Some(72), Some(120), None)]
+    #[case(Some(72), Some(120), Some(120))]
+    fn print_help_columns_width(
+        #[case] terminal_width: Option<usize>,
+        #[case] columns_width: Option<usize>,
+        #[case] default_width: Option<usize>,
+    ) {
+        // Setup
+        let printer = Printer::new(
+            "program",
+            None,
+            vec![OptionParameter::basic(
+                "flag".to_string(),
+                Some('f'),
+                Nargs::Precisely(1),
+                Some("message in a bottle, by the police.".to_string()),
+                Some(vec!["the swift".to_string(), "brown fox".to_string()]),
+            )],
+            Vec::default(),
+            terminal_width,
+        )
+        .with_columns_width(columns_width)
+        .with_default_width(default_width);
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_help(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(
+            message,
+            r#"usage: program [-h] [-f FLAG]
+
+options:
+ -h, --help             Show this help message
+                        and exit.
+ -f FLAG, --flag FLAG   message in a bottle, by    the swift   brown fox
+                        the police."#
+        );
+    }
+
+    #[rstest]
+    #[case("80", Some(80))]
+    #[case(" 80\n", Some(80))]
+    #[case("0", None)]
+    #[case("-80", None)]
+    #[case("abc", None)]
+    #[case("", None)]
+    fn parse_columns_width(#[case] columns: &str, #[case] expected: Option<usize>) {
+        assert_eq!(parse_columns(columns), expected);
+    }
+
+    #[test]
+    fn print_help_option_meta_with_empty() {
+        // Setup
+        let printer = Printer::new(
+            "program",
+            None,
+            vec![
+                OptionParameter::basic(
+                    "flag".to_string(),
+                    Some('f'),
+                    Nargs::Precisely(1),
+                    Some("message in a bottle, by the police.".to_string()),
+                    Some(vec!["".to_string(), "brown fox".to_string()]),
+                ),
+                OptionParameter::basic(
+                    "other".to_string(),
+                    None,
+                    Nargs::Precisely(1),
+                    Some("".to_string()),
+                    Some(vec!["x".to_string(), "brown fox".to_string()]),
+                ),
+            ],
+            Vec::default(),
+            Some(72),
+        );
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_help(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(
+            message,
+            r#"usage: program [-h] [-f FLAG] [--other OTHER]
+
+options:
+ -h, --help             Show this help message and exit.
+ -f FLAG, --flag FLAG   message in a bottle, by the            brown fox
+                        police.
+ --other OTHER                                             x   brown fox"#
+        );
+    }
+
+    #[test]
+    fn print_help_option_meta_without_help() {
+        // Setup
+        let printer = Printer::new(
+            "program",
+            None,
+            vec![OptionParameter::basic(
+                "flag".to_string(),
+                Some('f'),
+                Nargs::Precisely(1),
+                None,
+                Some(vec!["the swift".to_string(), "brown fox".to_string()]),
+            )],
+            Vec::default(),
+            Some(72),
+        );
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_help(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(
+            message,
+            r#"usage: program [-h] [-f FLAG]
+
+options:
+ -h, --help             Show this help message
+                        and exit.
+ -f FLAG, --flag FLAG                              the swift   brown fox"#
+        );
+    }
+
+    #[test]
+    fn print_help_option_precisely0() {
+        // Setup
+        let printer = Printer::new(
+            "program",
+            None,
+            vec![OptionParameter::basic(
+                "flag".to_string(),
+                None,
+                Nargs::Precisely(0),
+                None,
+                None,
+            )],
+            Vec::default(),
+            Some(120),
+        );
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_help(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(
+            message,
+            r#"usage: program [-h] [--flag]
+
+options:
+ -h, --help   Show this help message and exit.
+ --flag"#
+        );
+    }
+
+    #[test]
+    fn print_help_option_precisely0_no_trailing_whitespace() {
+        // Setup
+        let printer = Printer::new(
+            "program",
+            None,
+            vec![
+                OptionParameter::basic(
+                    "flag".to_string(),
+                    Some('f'),
+                    Nargs::Precisely(0),
+                    None,
+                    None,
+                ),
+                OptionParameter::basic(
+                    "verbose".to_string(),
+                    None,
+                    Nargs::Precisely(0),
+                    None,
+                    None,
+                )
+                .with_placeholder(Some("(on)".to_string())),
+            ],
+            Vec::default(),
+            Some(120),
+        );
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_help(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        for line in message.lines() {
+            assert_eq!(line, line.trim_end());
+        }
+    }
+
+    #[test]
+    fn print_help_option_placeholder() {
+        // Setup
+        let printer = Printer::new(
+            "program",
+            None,
+            vec![OptionParameter::basic(
+                "flag".to_string(),
+                Some('f'),
+                Nargs::Precisely(0),
+                Some("message".to_string()),
+                None,
+            )
+            .with_placeholder(Some("(on)".to_string()))],
+            Vec::default(),
+            Some(120),
+        );
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_help(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(
+            message,
+            r#"usage: program [-h] [-f]
+
+options:
+ -h, --help             Show this help message and exit.
+ -f (on), --flag (on)   message"#
+        );
+    }
+
+    #[test]
+    fn print_help_option_since() {
+        // Setup
+        let printer = Printer::new(
+            "program",
+            None,
+            vec![
+                OptionParameter::basic(
+                    "flag".to_string(),
+                    Some('f'),
+                    Nargs::Precisely(1),
+                    Some("message".to_string()),
+                    None,
+                )
+                .with_since(Some("2.1".to_string())),
+                OptionParameter::basic("other".to_string(), None, Nargs::Precisely(0), None, None)
+                    .with_since(Some("1.0".to_string())),
+            ],
             Vec::default(),
             Some(120),
         );
@@ -569,34 +2355,31 @@ options:
         let message = interface.consume_message();
         assert_eq!(
             message,
-            r#"usage: program [-h] [-f FLAG]
+            r#"usage: program [-h] [-f FLAG] [--other]
 
 options:
  -h, --help             Show this help message and exit.
- -f FLAG, --flag FLAG   message"#
+ -f FLAG, --flag FLAG   message (since 2.1)
+ --other                (since 1.0)"#
         );
     }
 
     #[test]
-    fn print_help_option_choices() {
+    fn print_help_option_since_wrap() {
         // Setup
         let printer = Printer::new(
             "program",
             None,
-            vec![OptionParameter::new(
+            vec![OptionParameter::basic(
                 "flag".to_string(),
-                Some('f'),
-                Nargs::Precisely(1),
-                None,
                 None,
-                HashMap::from([
-                    ("xyz".to_string(), "do the xyz".to_string()),
-                    ("abc".to_string(), "do the abc".to_string()),
-                    ("123".to_string(), "do the 123".to_string()),
-                ]),
-            )],
+                Nargs::Precisely(0),
+                Some("message in a bottle, by the police.".to_string()),
+                Some(vec!["the swift".to_string()]),
+            )
+            .with_since(Some("2.1".to_string()))],
             Vec::default(),
-            Some(120),
+            Some(60),
         );
         let interface = InMemoryInterface::default();
 
@@ -607,32 +2390,30 @@ options:
         let message = interface.consume_message();
         assert_eq!(
             message,
-            r#"usage: program [-h] [-f FLAG]
+            r#"usage: program [-h] [--flag]
 
 options:
- -h, --help             Show this help message and exit.
- -f FLAG, --flag FLAG   {123, abc, xyz}
-   123                    do the 123
-   abc                    do the abc
-   xyz                    do the xyz"#
+ -h, --help   Show this help message and exit.
+ --flag       message in a bottle, by the          the swift
+              police. (since 2.1)"#
         );
     }
 
     #[test]
-    fn print_help_option_meta() {
+    fn print_help_option_precisely2() {
         // Setup
         let printer = Printer::new(
             "program",
             None,
             vec![OptionParameter::basic(
                 "flag".to_string(),
-                Some('f'),
-                Nargs::Precisely(1),
-                Some("message in a bottle, by the police.".to_string()),
-                Some(vec!["the swift".to_string(), "brown fox".to_string()]),
+                None,
+                Nargs::Precisely(2),
+                None,
+                None,
             )],
             Vec::default(),
-            Some(72),
+            Some(120),
         );
         let interface = InMemoryInterface::default();
 
@@ -643,40 +2424,29 @@ options:
         let message = interface.consume_message();
         assert_eq!(
             message,
-            r#"usage: program [-h] [-f FLAG]
+            r#"usage: program [-h] [--flag FLAG FLAG]
 
 options:
- -h, --help             Show this help message
-                        and exit.
- -f FLAG, --flag FLAG   message in a bottle, by    the swift   brown fox
-                        the police."#
+ -h, --help         Show this help message and exit.
+ --flag FLAG FLAG"#
         );
     }
 
     #[test]
-    fn print_help_option_meta_with_empty() {
+    fn print_help_option_atleastone() {
         // Setup
         let printer = Printer::new(
             "program",
             None,
-            vec![
-                OptionParameter::basic(
-                    "flag".to_string(),
-                    Some('f'),
-                    Nargs::Precisely(1),
-                    Some("message in a bottle, by the police.".to_string()),
-                    Some(vec!["".to_string(), "brown fox".to_string()]),
-                ),
-                OptionParameter::basic(
-                    "other".to_string(),
-                    None,
-                    Nargs::Precisely(1),
-                    Some("".to_string()),
-                    Some(vec!["x".to_string(), "brown fox".to_string()]),
-                ),
-            ],
+            vec![OptionParameter::basic(
+                "flag".to_string(),
+                None,
+                Nargs::AtLeastOne,
+                None,
+                None,
+            )],
             Vec::default(),
-            Some(72),
+            Some(120),
         );
         let interface = InMemoryInterface::default();
 
@@ -687,31 +2457,29 @@ options:
         let message = interface.consume_message();
         assert_eq!(
             message,
-            r#"usage: program [-h] [-f FLAG] [--other OTHER]
+            r#"usage: program [-h] [--flag FLAG [...]]
 
 options:
- -h, --help             Show this help message and exit.
- -f FLAG, --flag FLAG   message in a bottle, by the            brown fox
-                        police.
- --other OTHER                                             x   brown fox"#
+ -h, --help          Show this help message and exit.
+ --flag FLAG [...]"#
         );
     }
 
     #[test]
-    fn print_help_option_meta_without_help() {
+    fn print_help_option_any() {
         // Setup
         let printer = Printer::new(
             "program",
             None,
             vec![OptionParameter::basic(
                 "flag".to_string(),
-                Some('f'),
-                Nargs::Precisely(1),
                 None,
-                Some(vec!["the swift".to_string(), "brown fox".to_string()]),
+                Nargs::Any,
+                None,
+                None,
             )],
             Vec::default(),
-            Some(72),
+            Some(120),
         );
         let interface = InMemoryInterface::default();
 
@@ -722,25 +2490,24 @@ options:
         let message = interface.consume_message();
         assert_eq!(
             message,
-            r#"usage: program [-h] [-f FLAG]
+            r#"usage: program [-h] [--flag [FLAG ...]]
 
 options:
- -h, --help             Show this help message
-                        and exit.
- -f FLAG, --flag FLAG                              the swift   brown fox"#
+ -h, --help          Show this help message and exit.
+ --flag [FLAG ...]"#
         );
     }
 
     #[test]
-    fn print_help_option_precisely0() {
+    fn print_help_option_range() {
         // Setup
         let printer = Printer::new(
             "program",
             None,
             vec![OptionParameter::basic(
-                "flag".to_string(),
+                "tags".to_string(),
                 None,
-                Nargs::Precisely(0),
+                Nargs::Range(2, 4),
                 None,
                 None,
             )],
@@ -756,24 +2523,24 @@ options:
         let message = interface.consume_message();
         assert_eq!(
             message,
-            r#"usage: program [-h] [--flag]
+            r#"usage: program [-h] [--tags TAGS TAGS [TAGS TAGS]]
 
 options:
- -h, --help   Show this help message and exit.
- --flag    "#
+ -h, --help                     Show this help message and exit.
+ --tags TAGS TAGS [TAGS TAGS]"#
         );
     }
 
     #[test]
-    fn print_help_option_precisely2() {
+    fn print_help_option_range_optional() {
         // Setup
         let printer = Printer::new(
             "program",
             None,
             vec![OptionParameter::basic(
-                "flag".to_string(),
-                None,
-                Nargs::Precisely(2),
+                "tags".to_string(),
+                Some('t'),
+                Nargs::Range(0, 2),
                 None,
                 None,
             )],
@@ -789,28 +2556,27 @@ options:
         let message = interface.consume_message();
         assert_eq!(
             message,
-            r#"usage: program [-h] [--flag FLAG FLAG]
+            r#"usage: program [-h] [-t [TAGS TAGS]]
 
 options:
- -h, --help         Show this help message and exit.
- --flag FLAG FLAG"#
+ -h, --help                           Show this help message and exit.
+ -t [TAGS TAGS], --tags [TAGS TAGS]"#
         );
     }
 
     #[test]
-    fn print_help_option_atleastone() {
+    fn print_help_argument() {
         // Setup
         let printer = Printer::new(
             "program",
             None,
-            vec![OptionParameter::basic(
-                "flag".to_string(),
-                None,
-                Nargs::AtLeastOne,
-                None,
+            Vec::default(),
+            vec![ArgumentParameter::basic(
+                "name".to_string(),
+                Nargs::Precisely(1),
+                Some("message".to_string()),
                 None,
             )],
-            Vec::default(),
             Some(120),
         );
         let interface = InMemoryInterface::default();
@@ -822,28 +2588,34 @@ options:
         let message = interface.consume_message();
         assert_eq!(
             message,
-            r#"usage: program [-h] [--flag FLAG [...]]
+            r#"usage: program [-h] NAME
+
+positional arguments:
+ NAME         message
 
 options:
- -h, --help          Show this help message and exit.
- --flag FLAG [...]"#
+ -h, --help   Show this help message and exit."#
         );
     }
 
     #[test]
-    fn print_help_option_any() {
+    fn print_help_argument_choices() {
         // Setup
         let printer = Printer::new(
             "program",
             None,
-            vec![OptionParameter::basic(
-                "flag".to_string(),
-                None,
-                Nargs::Any,
+            Vec::default(),
+            vec![ArgumentParameter::new(
+                "name".to_string(),
+                Nargs::Precisely(1),
                 None,
                 None,
+                HashMap::from([
+                    ("xyz".to_string(), "do the xyz".to_string()),
+                    ("abc".to_string(), "do the abc".to_string()),
+                    ("123".to_string(), "do the 123".to_string()),
+                ]),
             )],
-            Vec::default(),
             Some(120),
         );
         let interface = InMemoryInterface::default();
@@ -855,28 +2627,50 @@ options:
         let message = interface.consume_message();
         assert_eq!(
             message,
-            r#"usage: program [-h] [--flag [FLAG ...]]
+            r#"usage: program [-h] NAME
+
+positional arguments:
+ NAME         {123, abc, xyz}
+   123        do the 123
+   abc        do the abc
+   xyz        do the xyz
 
 options:
- -h, --help          Show this help message and exit.
- --flag [FLAG ...]"#
+ -h, --help   Show this help message and exit."#
         );
     }
 
     #[test]
-    fn print_help_argument() {
+    fn print_help_argument_choice_groups() {
         // Setup
         let printer = Printer::new(
             "program",
             None,
             Vec::default(),
-            vec![ArgumentParameter::basic(
+            vec![ArgumentParameter::new(
                 "name".to_string(),
                 Nargs::Precisely(1),
-                Some("message".to_string()),
                 None,
+                None,
+                HashMap::from([
+                    ("xyz".to_string(), "do the xyz".to_string()),
+                    ("abc".to_string(), "do the abc".to_string()),
+                    ("123".to_string(), "do the 123".to_string()),
+                    ("def".to_string(), "do the def".to_string()),
+                ]),
             )],
             Some(120),
+        )
+        .with_choice_groups(
+            "name".to_string(),
+            vec![
+                (
+                    "letters".to_string(),
+                    vec!["xyz".to_string(), "abc".to_string()],
+                ),
+                ("empty".to_string(), vec!["hidden".to_string()]),
+                ("more letters".to_string(), vec!["def".to_string()]),
+            ],
         );
         let interface = InMemoryInterface::default();
 
@@ -890,7 +2684,14 @@ options:
             r#"usage: program [-h] NAME
 
 positional arguments:
- NAME         message
+ NAME         {123, abc, def, xyz}
+   letters:
+     abc      do the abc
+     xyz      do the xyz
+   more letters:
+     def      do the def
+   other commands:
+     123      do the 123
 
 options:
  -h, --help   Show this help message and exit."#
@@ -898,21 +2699,30 @@ options:
     }
 
     #[test]
-    fn print_help_argument_choices() {
+    fn print_help_argument_choices_aligned() {
         // Setup
         let printer = Printer::new(
             "program",
             None,
-            Vec::default(),
-            vec![ArgumentParameter::new(
-                "name".to_string(),
+            vec![OptionParameter::basic(
+                "flag".to_string(),
+                Some('f'),
                 Nargs::Precisely(1),
+                Some("message".to_string()),
                 None,
+            )],
+            vec![ArgumentParameter::new(
+                "sub".to_string(),
+                Nargs::Precisely(1),
+                Some("The sub-command.".to_string()),
                 None,
                 HashMap::from([
-                    ("xyz".to_string(), "do the xyz".to_string()),
-                    ("abc".to_string(), "do the abc".to_string()),
-                    ("123".to_string(), "do the 123".to_string()),
+                    ("a".to_string(), "do the a".to_string()),
+                    ("status".to_string(), "do the status".to_string()),
+                    (
+                        "synchronize-everything".to_string(),
+                        "do the sync".to_string(),
+                    ),
                 ]),
             )],
             Some(120),
@@ -926,16 +2736,17 @@ options:
         let message = interface.consume_message();
         assert_eq!(
             message,
-            r#"usage: program [-h] NAME
+            r#"usage: program [-h] [-f FLAG] SUB
 
 positional arguments:
- NAME         {123, abc, xyz}
-   123          do the 123
-   abc          do the abc
-   xyz          do the xyz
+ SUB                        {a, status, synchronize-everything} The sub-command.
+   a                        do the a
+   status                   do the status
+   synchronize-everything   do the sync
 
 options:
- -h, --help   Show this help message and exit."#
+ -h, --help                 Show this help message and exit.
+ -f FLAG, --flag FLAG       message"#
         );
     }
 
@@ -1081,7 +2892,7 @@ options:
             r#"usage: program [-h] NAME NAME
 
 positional arguments:
- NAME NAME 
+ NAME NAME
 
 options:
  -h, --help   Show this help message and exit."#
@@ -1151,6 +2962,74 @@ options:
 positional arguments:
  [NAME ...]
 
+options:
+ -h, --help   Show this help message and exit."#
+        );
+    }
+
+    #[test]
+    fn print_help_argument_range() {
+        // Setup
+        let printer = Printer::new(
+            "program",
+            None,
+            Vec::default(),
+            vec![ArgumentParameter::basic(
+                "tags".to_string(),
+                Nargs::Range(2, 4),
+                None,
+                None,
+            )],
+            Some(120),
+        );
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_help(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(
+            message,
+            r#"usage: program [-h] TAGS TAGS [TAGS TAGS]
+
+positional arguments:
+ TAGS TAGS [TAGS TAGS]
+
+options:
+ -h, --help              Show this help message and exit."#
+        );
+    }
+
+    #[test]
+    fn print_help_argument_zero_or_one() {
+        // Setup
+        let printer = Printer::new(
+            "program",
+            None,
+            Vec::default(),
+            vec![ArgumentParameter::basic(
+                "target".to_string(),
+                Nargs::ZeroOrOne,
+                Some("message".to_string()),
+                None,
+            )],
+            Some(120),
+        );
+        let interface = InMemoryInterface::default();
+
+        // Execute
+        printer.print_help(&interface);
+
+        // Verify
+        let message = interface.consume_message();
+        assert_eq!(
+            message,
+            r#"usage: program [-h] [TARGET]
+
+positional arguments:
+ [TARGET]     message
+
 options:
  -h, --help   Show this help message and exit."#
         );
@@ -1284,7 +3163,7 @@ positional arguments:
 options:
  -h, --help                     Show this help message and exit.
  -z APPLE, --apple APPLE        {abcdefghijklmnopqrstuvwxyz} extra
-   abcdefghijklmnopqrstuvwxyz     abcdefghijklmnopqrstuvwxyz
+   abcdefghijklmnopqrstuvwxyz   abcdefghijklmnopqrstuvwxyz
  -y, --blue                     blue message"#
         );
     }
@@ -1335,7 +3214,7 @@ options:
 
 positional arguments:
  NAME                           {abcdefghijklmnopqrstuvwxyz} extra
-   abcdefghijklmnopqrstuvwxyz     abcdefghijklmnopqrstuvwxyz
+   abcdefghijklmnopqrstuvwxyz   abcdefghijklmnopqrstuvwxyz
  [ITEMS ...]                    items message
 
 options:
@@ -1430,4 +3309,23 @@ options:
       ^"#
         );
     }
+
+    #[test]
+    fn error_context_tokens_empty() {
+        assert_eq!(
+            ErrorContext::new(1, &["a", "", "b"]).to_string(),
+            r#"a "" b
+  ^"#
+        );
+        assert_eq!(
+            ErrorContext::new(3, &["a", "", "b"]).to_string(),
+            r#"a "" b
+     ^"#
+        );
+        assert_eq!(
+            ErrorContext::new(0, &["", "b"]).to_string(),
+            r#""" b
+^"#
+        );
+    }
 }
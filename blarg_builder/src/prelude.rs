@@ -7,6 +7,11 @@ pub trait Collectable<T> {
     /// Add a value to this `Collectable`.
     /// Return `Ok` on success, and `Err(message)` on failure.
     fn add(&mut self, item: T) -> Result<(), String>;
+
+    /// Declare that this `Collectable` has been matched, regardless of whether any values are added.
+    fn matched(&mut self) {
+        // Do nothing.
+    }
 }
 
 /// Behaviour for documenting choices on a [`Parameter`](../struct.Parameter.html) or [`Condition`](../struct.Condition.html).
@@ -10,8 +10,17 @@ impl DeriveParameter {
             parameter_type,
             choices,
             help,
+            type_name,
+            required,
         } = self;
         let field_name_str = format!("{field_name}");
+        let type_name = match type_name {
+            Some(type_name) => type_name.tokens,
+            None => {
+                let type_name = friendly_type_name(&from_str_type);
+                quote! { #type_name }
+            }
+        };
 
         let (before_lines, parameter, after_lines) = match &parameter_type {
             ParameterType::CollectionArgument { nargs } => {
@@ -31,6 +40,13 @@ impl DeriveParameter {
                 },
                 None,
             ),
+            ParameterType::GroupArgument => (
+                None,
+                quote! {
+                    Parameter::argument(Collection::repeatable_group(&mut #parent.#field_name), #field_name_str)
+                },
+                None,
+            ),
 
             ParameterType::CollectionOption { nargs, short } => {
                 let field_name_str = field_name_str.replace("_", "-");
@@ -66,6 +82,18 @@ impl DeriveParameter {
                     None,
                 )
             }
+            ParameterType::OptionalCollectionOption { nargs, short } => {
+                let field_name_str = field_name_str.replace("_", "-");
+                let nargs = &nargs.tokens;
+                let short = flatten(short.as_ref());
+                (
+                    None,
+                    quote! {
+                        Parameter::option(Collection::new(&mut #parent.#field_name, #nargs), #field_name_str, #short)
+                    },
+                    None,
+                )
+            }
 
             ParameterType::Switch { short } => {
                 let field_name_str = field_name_str.replace("_", "-");
@@ -110,8 +138,16 @@ impl DeriveParameter {
             }
         };
 
+        let parameter = if required {
+            quote! { #parameter.required() }
+        } else {
+            parameter
+        };
+
         let default = match &parameter_type {
-            ParameterType::CollectionArgument { .. } | ParameterType::CollectionOption { .. } => {
+            ParameterType::CollectionArgument { .. }
+            | ParameterType::GroupArgument
+            | ParameterType::CollectionOption { .. } => {
                 let field_default = format_ident!("{field_name}_default");
                 Some(quote! { let #field_default = format!("{:?}", #parent.#field_name); })
             }
@@ -150,7 +186,7 @@ impl DeriveParameter {
                                 #before_lines
                                 clp = clp.add(#parameter
                                     .help(#help)
-                                    .meta(vec![format!("type: {}", #from_str_type)]));
+                                    .meta(vec![format!("type: {}", #type_name)]));
                                 #after_lines
                             }
                         }
@@ -169,7 +205,7 @@ impl DeriveParameter {
                         quote! {
                             #before_lines
                             clp = clp.add(#parameter
-                                .meta(vec![format!("type: {}", #from_str_type)]));
+                                .meta(vec![format!("type: {}", #type_name)]));
                             #after_lines
                         }
                     }
@@ -183,6 +219,7 @@ impl DeriveParameter {
                 },
             },
             ParameterType::CollectionArgument { .. }
+            | ParameterType::GroupArgument
             | ParameterType::CollectionOption { .. }
             | ParameterType::ScalarOption { .. } => {
                 let field_default = format_ident!("{field_name}_default");
@@ -242,7 +279,7 @@ impl DeriveParameter {
                                     #default
                                     clp = clp.add(#parameter
                                         .help(#help)
-                                        .meta(vec![format!("type: {}", #from_str_type), format!("initial: {}", #field_default)]));
+                                        .meta(vec![format!("type: {}", #type_name), format!("initial: {}", #field_default)]));
                                     #after_lines
                                 }
                             }
@@ -262,7 +299,7 @@ impl DeriveParameter {
                                 #before_lines
                                 #default
                                 clp = clp.add(#parameter
-                                    .meta(vec![format!("type: {}", #from_str_type), format!("initial: {}", #field_default)]));
+                                    .meta(vec![format!("type: {}", #type_name), format!("initial: {}", #field_default)]));
                                 #after_lines
                             }
                         }
@@ -276,8 +313,15 @@ impl DeriveParameter {
                     },
                 }
             }
-            ParameterType::OptionalOption { .. } => {
+            ParameterType::OptionalOption { .. }
+            | ParameterType::OptionalCollectionOption { .. } => {
                 let field_default = format_ident!("{field_name}_default");
+                let initial = match &parameter_type {
+                    ParameterType::OptionalCollectionOption { .. } => {
+                        quote! { format!("{inner:?}") }
+                    }
+                    _ => quote! { format!("{inner}") },
+                };
 
                 match (choices, help) {
                     (Some(choices), Some(help)) => {
@@ -288,7 +332,7 @@ impl DeriveParameter {
                                 quote! {
                                     #before_lines
                                     if let Some(inner) = #parent.#field_name.as_ref() {
-                                        let #field_default = format!("{inner}");
+                                        let #field_default = #initial;
                                         clp = clp.add(#choices(#parameter
                                             .help(#help)
                                             .meta(vec!["".to_string(), format!("initial: {}", #field_default)])));
@@ -316,7 +360,7 @@ impl DeriveParameter {
                                 quote! {
                                     #before_lines
                                     if let Some(inner) = #parent.#field_name.as_ref() {
-                                        let #field_default = format!("{inner}");
+                                        let #field_default = #initial;
                                         clp = clp.add(#choices(#parameter
                                             .meta(vec!["".to_string(), format!("initial: {}", #field_default)])));
                                     } else {
@@ -341,14 +385,14 @@ impl DeriveParameter {
                                 quote! {
                                     #before_lines
                                     if let Some(inner) = #parent.#field_name.as_ref() {
-                                        let #field_default = format!("{inner}");
+                                        let #field_default = #initial;
                                         clp = clp.add(#parameter
                                             .help(#help)
-                                            .meta(vec![format!("type: {}", #from_str_type), format!("initial: {}", #field_default)]));
+                                            .meta(vec![format!("type: {}", #type_name), format!("initial: {}", #field_default)]));
                                     } else {
                                         clp = clp.add(#parameter
                                             .help(#help)
-                                            .meta(vec![format!("type: {}", #from_str_type)]));
+                                            .meta(vec![format!("type: {}", #type_name)]));
                                     }
                                     #after_lines
                                 }
@@ -368,12 +412,12 @@ impl DeriveParameter {
                             quote! {
                                 #before_lines
                                 if let Some(inner) = #parent.#field_name.as_ref() {
-                                    let #field_default = format!("{inner}");
+                                    let #field_default = #initial;
                                     clp = clp.add(#parameter
-                                        .meta(vec![format!("type: {}", #from_str_type), format!("initial: {}", #field_default)]));
+                                        .meta(vec![format!("type: {}", #type_name), format!("initial: {}", #field_default)]));
                                 } else {
                                     clp = clp.add(#parameter
-                                        .meta(vec![format!("type: {}", #from_str_type)]));
+                                        .meta(vec![format!("type: {}", #type_name)]));
                                 }
                                 #after_lines
                             }
@@ -415,7 +459,7 @@ impl DeriveParameter {
                                 #before_lines
                                 let mut clp = clp.branch(#parameter
                                     .help(#help)
-                                    .meta(vec![format!("type: {}", #from_str_type)]));
+                                    .meta(vec![format!("type: {}", #type_name)]));
                                 #after_lines
                             }
                         }
@@ -434,7 +478,7 @@ impl DeriveParameter {
                         quote! {
                             #before_lines
                             let mut clp = clp.branch(#parameter
-                                .meta(vec![format!("type: {}", #from_str_type)]));
+                                .meta(vec![format!("type: {}", #type_name)]));
                             #after_lines
                         }
                     }
@@ -487,6 +531,35 @@ impl DeriveParameter {
     }
 }
 
+/// The user facing names of the common std types, for the `type: ..` hint.
+const FRIENDLY_TYPE_NAMES: &[(&str, &str)] = &[
+    ("i8", "number"),
+    ("i16", "number"),
+    ("i32", "number"),
+    ("i64", "number"),
+    ("i128", "number"),
+    ("isize", "number"),
+    ("u8", "number"),
+    ("u16", "number"),
+    ("u32", "number"),
+    ("u64", "number"),
+    ("u128", "number"),
+    ("usize", "number"),
+    ("f32", "number"),
+    ("f64", "number"),
+    ("String", "string"),
+    ("PathBuf", "path"),
+];
+
+/// The user facing name of the type, falling back to the Rust type name itself (ex: `MyEnum`).
+fn friendly_type_name(from_str_type: &str) -> &str {
+    FRIENDLY_TYPE_NAMES
+        .iter()
+        .find(|(rust_type, _)| *rust_type == from_str_type)
+        .map(|(_, friendly)| *friendly)
+        .unwrap_or(from_str_type)
+}
+
 fn flatten(value: Option<&DeriveValue>) -> TokenStream2 {
     value.map_or_else(
         || quote! { None },
@@ -519,6 +592,8 @@ mod tests {
             },
             choices: None,
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -532,7 +607,7 @@ mod tests {
 " , target . my_field) ;
  clp = clp . add (Parameter :: argument (Collection :: new (& mut target . my_field , Nargs :: AtLeastOne) , "my_field") . meta (vec ! [format ! ("type: {
 }
-" , "usize") , format ! ("initial: {
+" , "number") , format ! ("initial: {
 }
 " , my_field_default)])) ;
 "#
@@ -554,6 +629,8 @@ mod tests {
                 tokens: quote! { my_func },
             }),
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -589,6 +666,8 @@ mod tests {
             help: Some(DeriveValue {
                 tokens: Literal::string("abc 123").to_token_stream(),
             }),
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -622,6 +701,8 @@ mod tests {
             help: Some(DeriveValue {
                 tokens: Literal::string("abc 123").to_token_stream(),
             }),
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -635,7 +716,71 @@ mod tests {
 " , target . my_field) ;
  clp = clp . add (Parameter :: argument (Collection :: new (& mut target . my_field , Nargs :: AtLeastOne) , "my_field") . help ("abc 123") . meta (vec ! [format ! ("type: {
 }
-" , "usize") , format ! ("initial: {
+" , "number") , format ! ("initial: {
+}
+" , my_field_default)])) ;
+"#
+        );
+    }
+
+    #[test]
+    fn render_group_argument() {
+        // Setup
+        let parameter = DeriveParameter {
+            field_name: ident("my_field"),
+            from_str_type: "Pair".to_string(),
+            parameter_type: ParameterType::GroupArgument,
+            choices: None,
+            help: None,
+            type_name: None,
+            required: false,
+        };
+
+        // Execute
+        let token_stream = parameter.generate(&ident("target"), &Hints::On);
+
+        // Verify
+        assert_eq!(
+            simple_format(token_stream.to_string()),
+            r#"let my_field_default = format ! ("{
+:?}
+" , target . my_field) ;
+ clp = clp . add (Parameter :: argument (Collection :: repeatable_group (& mut target . my_field) , "my_field") . meta (vec ! [format ! ("type: {
+}
+" , "Pair") , format ! ("initial: {
+}
+" , my_field_default)])) ;
+"#
+        );
+    }
+
+    #[test]
+    fn render_group_argument_help() {
+        // Setup
+        let parameter = DeriveParameter {
+            field_name: ident("my_field"),
+            from_str_type: "Pair".to_string(),
+            parameter_type: ParameterType::GroupArgument,
+            choices: None,
+            help: Some(DeriveValue {
+                tokens: Literal::string("abc 123").to_token_stream(),
+            }),
+            type_name: None,
+            required: false,
+        };
+
+        // Execute
+        let token_stream = parameter.generate(&ident("target"), &Hints::On);
+
+        // Verify
+        assert_eq!(
+            simple_format(token_stream.to_string()),
+            r#"let my_field_default = format ! ("{
+:?}
+" , target . my_field) ;
+ clp = clp . add (Parameter :: argument (Collection :: repeatable_group (& mut target . my_field) , "my_field") . help ("abc 123") . meta (vec ! [format ! ("type: {
+}
+" , "Pair") , format ! ("initial: {
 }
 " , my_field_default)])) ;
 "#
@@ -651,6 +796,34 @@ mod tests {
             parameter_type: ParameterType::ScalarArgument,
             choices: None,
             help: None,
+            type_name: None,
+            required: false,
+        };
+
+        // Execute
+        let token_stream = parameter.generate(&ident("target"), &Hints::On);
+
+        // Verify
+        assert_eq!(
+            simple_format(token_stream.to_string()),
+            r#"clp = clp . add (Parameter :: argument (Scalar :: new (& mut target . my_field) , "my_field") . meta (vec ! [format ! ("type: {
+}
+" , "number")])) ;
+"#
+        );
+    }
+
+    #[test]
+    fn render_scalar_argument_path() {
+        // Setup
+        let parameter = DeriveParameter {
+            field_name: ident("my_field"),
+            from_str_type: "PathBuf".to_string(),
+            parameter_type: ParameterType::ScalarArgument,
+            choices: None,
+            help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -661,7 +834,61 @@ mod tests {
             simple_format(token_stream.to_string()),
             r#"clp = clp . add (Parameter :: argument (Scalar :: new (& mut target . my_field) , "my_field") . meta (vec ! [format ! ("type: {
 }
-" , "usize")])) ;
+" , "path")])) ;
+"#
+        );
+    }
+
+    #[test]
+    fn render_scalar_argument_type_name() {
+        // Setup
+        let parameter = DeriveParameter {
+            field_name: ident("my_field"),
+            from_str_type: "PathBuf".to_string(),
+            parameter_type: ParameterType::ScalarArgument,
+            choices: None,
+            help: None,
+            type_name: Some(DeriveValue {
+                tokens: Literal::string("directory").to_token_stream(),
+            }),
+            required: false,
+        };
+
+        // Execute
+        let token_stream = parameter.generate(&ident("target"), &Hints::On);
+
+        // Verify
+        assert_eq!(
+            simple_format(token_stream.to_string()),
+            r#"clp = clp . add (Parameter :: argument (Scalar :: new (& mut target . my_field) , "my_field") . meta (vec ! [format ! ("type: {
+}
+" , "directory")])) ;
+"#
+        );
+    }
+
+    #[test]
+    fn render_scalar_argument_unfriendly() {
+        // Setup
+        let parameter = DeriveParameter {
+            field_name: ident("my_field"),
+            from_str_type: "MyEnum".to_string(),
+            parameter_type: ParameterType::ScalarArgument,
+            choices: None,
+            help: None,
+            type_name: None,
+            required: false,
+        };
+
+        // Execute
+        let token_stream = parameter.generate(&ident("target"), &Hints::On);
+
+        // Verify
+        assert_eq!(
+            simple_format(token_stream.to_string()),
+            r#"clp = clp . add (Parameter :: argument (Scalar :: new (& mut target . my_field) , "my_field") . meta (vec ! [format ! ("type: {
+}
+" , "MyEnum")])) ;
 "#
         );
     }
@@ -677,6 +904,8 @@ mod tests {
                 tokens: quote! { my_func },
             }),
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -703,6 +932,8 @@ mod tests {
             help: Some(DeriveValue {
                 tokens: Literal::string("abc 123").to_token_stream(),
             }),
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -727,6 +958,8 @@ mod tests {
             help: Some(DeriveValue {
                 tokens: Literal::string("abc 123").to_token_stream(),
             }),
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -737,7 +970,7 @@ mod tests {
             simple_format(token_stream.to_string()),
             r#"clp = clp . add (Parameter :: argument (Scalar :: new (& mut target . my_field) , "my_field") . help ("abc 123") . meta (vec ! [format ! ("type: {
 }
-" , "usize")])) ;
+" , "number")])) ;
 "#
         );
     }
@@ -756,6 +989,8 @@ mod tests {
             },
             choices: None,
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -769,7 +1004,7 @@ mod tests {
 " , target . my_field) ;
  clp = clp . add (Parameter :: option (Collection :: new (& mut target . my_field , Nargs :: AtLeastOne) , "my-field" , None) . meta (vec ! [format ! ("type: {
 }
-" , "usize") , format ! ("initial: {
+" , "number") , format ! ("initial: {
 }
 " , my_field_default)])) ;
 "#
@@ -792,6 +1027,8 @@ mod tests {
                 tokens: quote! { my_func },
             }),
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -828,6 +1065,8 @@ mod tests {
             help: Some(DeriveValue {
                 tokens: Literal::string("abc 123").to_token_stream(),
             }),
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -862,6 +1101,8 @@ mod tests {
             help: Some(DeriveValue {
                 tokens: Literal::string("abc 123").to_token_stream(),
             }),
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -875,7 +1116,7 @@ mod tests {
 " , target . my_field) ;
  clp = clp . add (Parameter :: option (Collection :: new (& mut target . my_field , Nargs :: AtLeastOne) , "my-field" , None) . help ("abc 123") . meta (vec ! [format ! ("type: {
 }
-" , "usize") , format ! ("initial: {
+" , "number") , format ! ("initial: {
 }
 " , my_field_default)])) ;
 "#
@@ -898,6 +1139,8 @@ mod tests {
             },
             choices: None,
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -911,7 +1154,7 @@ mod tests {
 " , target . my_field) ;
  clp = clp . add (Parameter :: option (Collection :: new (& mut target . my_field , Nargs :: AtLeastOne) , "my-field" , Some ('m')) . meta (vec ! [format ! ("type: {
 }
-" , "usize") , format ! ("initial: {
+" , "number") , format ! ("initial: {
 }
 " , my_field_default)])) ;
 "#
@@ -927,6 +1170,8 @@ mod tests {
             parameter_type: ParameterType::OptionalOption { short: None },
             choices: None,
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -941,14 +1186,57 @@ inner}
 ") ;
  clp = clp . add (Parameter :: option (Optional :: new (& mut target . my_field) , "my-field" , None) . meta (vec ! [format ! ("type: {
 }
-" , "usize") , format ! ("initial: {
+" , "number") , format ! ("initial: {
 }
 " , my_field_default)])) ;
  }
  else {
  clp = clp . add (Parameter :: option (Optional :: new (& mut target . my_field) , "my-field" , None) . meta (vec ! [format ! ("type: {
 }
-" , "usize")])) ;
+" , "number")])) ;
+ }
+"#
+        );
+    }
+
+    #[test]
+    fn render_optional_collection_option() {
+        // Setup
+        let parameter = DeriveParameter {
+            field_name: ident("my_field"),
+            from_str_type: "usize".to_string(),
+            parameter_type: ParameterType::OptionalCollectionOption {
+                nargs: DeriveValue {
+                    tokens: quote! { Nargs::Any },
+                },
+                short: None,
+            },
+            choices: None,
+            help: None,
+            type_name: None,
+            required: false,
+        };
+
+        // Execute
+        let token_stream = parameter.generate(&ident("target"), &Hints::On);
+
+        // Verify
+        assert_eq!(
+            simple_format(token_stream.to_string()),
+            r#"if let Some (inner) = target . my_field . as_ref () {
+ let my_field_default = format ! ("{
+inner:?}
+") ;
+ clp = clp . add (Parameter :: option (Collection :: new (& mut target . my_field , Nargs :: Any) , "my-field" , None) . meta (vec ! [format ! ("type: {
+}
+" , "number") , format ! ("initial: {
+}
+" , my_field_default)])) ;
+ }
+ else {
+ clp = clp . add (Parameter :: option (Collection :: new (& mut target . my_field , Nargs :: Any) , "my-field" , None) . meta (vec ! [format ! ("type: {
+}
+" , "number")])) ;
  }
 "#
         );
@@ -965,6 +1253,8 @@ inner}
                 tokens: quote! { my_func },
             }),
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1000,6 +1290,8 @@ inner}
             help: Some(DeriveValue {
                 tokens: Literal::string("abc 123").to_token_stream(),
             }),
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1034,6 +1326,8 @@ inner}
             help: Some(DeriveValue {
                 tokens: Literal::string("abc 123").to_token_stream(),
             }),
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1048,14 +1342,14 @@ inner}
 ") ;
  clp = clp . add (Parameter :: option (Optional :: new (& mut target . my_field) , "my-field" , None) . help ("abc 123") . meta (vec ! [format ! ("type: {
 }
-" , "usize") , format ! ("initial: {
+" , "number") , format ! ("initial: {
 }
 " , my_field_default)])) ;
  }
  else {
  clp = clp . add (Parameter :: option (Optional :: new (& mut target . my_field) , "my-field" , None) . help ("abc 123") . meta (vec ! [format ! ("type: {
 }
-" , "usize")])) ;
+" , "number")])) ;
  }
 "#
         );
@@ -1074,6 +1368,8 @@ inner}
             },
             choices: None,
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1088,14 +1384,14 @@ inner}
 ") ;
  clp = clp . add (Parameter :: option (Optional :: new (& mut target . my_field) , "my-field" , Some ('m')) . meta (vec ! [format ! ("type: {
 }
-" , "usize") , format ! ("initial: {
+" , "number") , format ! ("initial: {
 }
 " , my_field_default)])) ;
  }
  else {
  clp = clp . add (Parameter :: option (Optional :: new (& mut target . my_field) , "my-field" , Some ('m')) . meta (vec ! [format ! ("type: {
 }
-" , "usize")])) ;
+" , "number")])) ;
  }
 "#
         );
@@ -1110,6 +1406,8 @@ inner}
             parameter_type: ParameterType::ScalarOption { short: None },
             choices: None,
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1121,7 +1419,36 @@ inner}
             r#"let my_field_default = target . my_field . to_string () ;
  clp = clp . add (Parameter :: option (Scalar :: new (& mut target . my_field) , "my-field" , None) . meta (vec ! [format ! ("type: {
 }
-" , "usize") , format ! ("initial: {
+" , "number") , format ! ("initial: {
+}
+" , my_field_default)])) ;
+"#
+        );
+    }
+
+    #[test]
+    fn render_scalar_option_required() {
+        // Setup
+        let parameter = DeriveParameter {
+            field_name: ident("my_field"),
+            from_str_type: "usize".to_string(),
+            parameter_type: ParameterType::ScalarOption { short: None },
+            choices: None,
+            help: None,
+            type_name: None,
+            required: true,
+        };
+
+        // Execute
+        let token_stream = parameter.generate(&ident("target"), &Hints::On);
+
+        // Verify
+        assert_eq!(
+            simple_format(token_stream.to_string()),
+            r#"let my_field_default = target . my_field . to_string () ;
+ clp = clp . add (Parameter :: option (Scalar :: new (& mut target . my_field) , "my-field" , None) . required () . meta (vec ! [format ! ("type: {
+}
+" , "number") , format ! ("initial: {
 }
 " , my_field_default)])) ;
 "#
@@ -1139,6 +1466,8 @@ inner}
                 tokens: quote! { my_func },
             }),
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1168,6 +1497,8 @@ inner}
             help: Some(DeriveValue {
                 tokens: Literal::string("abc 123").to_token_stream(),
             }),
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1195,6 +1526,8 @@ inner}
             help: Some(DeriveValue {
                 tokens: Literal::string("abc 123").to_token_stream(),
             }),
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1206,7 +1539,7 @@ inner}
             r#"let my_field_default = target . my_field . to_string () ;
  clp = clp . add (Parameter :: option (Scalar :: new (& mut target . my_field) , "my-field" , None) . help ("abc 123") . meta (vec ! [format ! ("type: {
 }
-" , "usize") , format ! ("initial: {
+" , "number") , format ! ("initial: {
 }
 " , my_field_default)])) ;
 "#
@@ -1226,6 +1559,8 @@ inner}
             },
             choices: None,
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1237,7 +1572,7 @@ inner}
             r#"let my_field_default = target . my_field . to_string () ;
  clp = clp . add (Parameter :: option (Scalar :: new (& mut target . my_field) , "my-field" , Some ('m')) . meta (vec ! [format ! ("type: {
 }
-" , "usize") , format ! ("initial: {
+" , "number") , format ! ("initial: {
 }
 " , my_field_default)])) ;
 "#
@@ -1253,6 +1588,8 @@ inner}
             parameter_type: ParameterType::Switch { short: None },
             choices: None,
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1276,6 +1613,8 @@ inner}
                 tokens: quote! { my_func },
             }),
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1303,6 +1642,8 @@ inner}
             help: Some(DeriveValue {
                 tokens: Literal::string("abc 123").to_token_stream(),
             }),
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1328,6 +1669,8 @@ inner}
             help: Some(DeriveValue {
                 tokens: Literal::string("abc 123").to_token_stream(),
             }),
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1355,6 +1698,8 @@ inner}
             },
             choices: None,
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1395,6 +1740,8 @@ inner}
             },
             choices: None,
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1442,6 +1789,8 @@ inner}
                 tokens: quote! { my_func },
             }),
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1489,6 +1838,8 @@ inner}
             help: Some(DeriveValue {
                 tokens: Literal::string("abc 123").to_token_stream(),
             }),
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1534,6 +1885,8 @@ inner}
             help: Some(DeriveValue {
                 tokens: Literal::string("abc 123").to_token_stream(),
             }),
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1566,6 +1919,8 @@ inner}
             },
             choices: None,
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1594,6 +1949,8 @@ inner}
                 tokens: quote! { my_func },
             }),
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1624,6 +1981,8 @@ inner}
             help: Some(DeriveValue {
                 tokens: Literal::string("abc 123").to_token_stream(),
             }),
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1652,6 +2011,8 @@ inner}
             help: Some(DeriveValue {
                 tokens: Literal::string("abc 123").to_token_stream(),
             }),
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1665,6 +2026,30 @@ inner}
         );
     }
 
+    #[test]
+    fn render_group_argument_hintsoff() {
+        // Setup
+        let parameter = DeriveParameter {
+            field_name: ident("my_field"),
+            from_str_type: "Pair".to_string(),
+            parameter_type: ParameterType::GroupArgument,
+            choices: None,
+            help: None,
+            type_name: None,
+            required: false,
+        };
+
+        // Execute
+        let token_stream = parameter.generate(&ident("target"), &Hints::Off);
+
+        // Verify
+        assert_eq!(
+            simple_format(token_stream.to_string()),
+            r#"clp = clp . add (Parameter :: argument (Collection :: repeatable_group (& mut target . my_field) , "my_field")) ;
+"#
+        );
+    }
+
     #[test]
     fn render_scalar_argument_hintsoff() {
         // Setup
@@ -1674,6 +2059,8 @@ inner}
             parameter_type: ParameterType::ScalarArgument,
             choices: None,
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1698,6 +2085,8 @@ inner}
                 tokens: quote! { my_func },
             }),
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1724,6 +2113,8 @@ inner}
             help: Some(DeriveValue {
                 tokens: Literal::string("abc 123").to_token_stream(),
             }),
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1748,6 +2139,8 @@ inner}
             help: Some(DeriveValue {
                 tokens: Literal::string("abc 123").to_token_stream(),
             }),
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1775,6 +2168,8 @@ inner}
             },
             choices: None,
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1804,6 +2199,8 @@ inner}
                 tokens: quote! { my_func },
             }),
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1835,6 +2232,8 @@ inner}
             help: Some(DeriveValue {
                 tokens: Literal::string("abc 123").to_token_stream(),
             }),
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1864,6 +2263,8 @@ inner}
             help: Some(DeriveValue {
                 tokens: Literal::string("abc 123").to_token_stream(),
             }),
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1893,6 +2294,8 @@ inner}
             },
             choices: None,
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1915,6 +2318,8 @@ inner}
             parameter_type: ParameterType::OptionalOption { short: None },
             choices: None,
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1939,6 +2344,8 @@ inner}
                 tokens: quote! { my_func },
             }),
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1964,6 +2371,8 @@ inner}
             help: Some(DeriveValue {
                 tokens: Literal::string("abc 123").to_token_stream(),
             }),
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -1988,6 +2397,8 @@ inner}
             help: Some(DeriveValue {
                 tokens: Literal::string("abc 123").to_token_stream(),
             }),
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -2014,6 +2425,8 @@ inner}
             },
             choices: None,
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -2036,6 +2449,8 @@ inner}
             parameter_type: ParameterType::ScalarOption { short: None },
             choices: None,
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -2060,6 +2475,8 @@ inner}
                 tokens: quote! { my_func },
             }),
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -2086,6 +2503,8 @@ inner}
             help: Some(DeriveValue {
                 tokens: Literal::string("abc 123").to_token_stream(),
             }),
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -2110,6 +2529,8 @@ inner}
             help: Some(DeriveValue {
                 tokens: Literal::string("abc 123").to_token_stream(),
             }),
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -2136,6 +2557,8 @@ inner}
             },
             choices: None,
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -2158,6 +2581,8 @@ inner}
             parameter_type: ParameterType::Switch { short: None },
             choices: None,
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -2181,6 +2606,8 @@ inner}
                 tokens: quote! { my_func },
             }),
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -2208,6 +2635,8 @@ inner}
             help: Some(DeriveValue {
                 tokens: Literal::string("abc 123").to_token_stream(),
             }),
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -2233,6 +2662,8 @@ inner}
             help: Some(DeriveValue {
                 tokens: Literal::string("abc 123").to_token_stream(),
             }),
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -2260,6 +2691,8 @@ inner}
             },
             choices: None,
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -2300,6 +2733,8 @@ inner}
             },
             choices: None,
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -2345,6 +2780,8 @@ inner}
                 tokens: quote! { my_func },
             }),
             help: None,
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -2392,6 +2829,8 @@ inner}
             help: Some(DeriveValue {
                 tokens: Literal::string("abc 123").to_token_stream(),
             }),
+            type_name: None,
+            required: false,
         };
 
         // Execute
@@ -2437,6 +2876,8 @@ inner}
             help: Some(DeriveValue {
                 tokens: Literal::string("abc 123").to_token_stream(),
             }),
+            type_name: None,
+            required: false,
         };
 
         // Execute
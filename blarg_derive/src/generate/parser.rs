@@ -280,6 +280,8 @@ mod tests {
                 parameter_type: ParameterType::ScalarArgument,
                 choices: None,
                 help: None,
+                type_name: None,
+                required: false,
             }],
             hints: Hints::Off,
         };
@@ -303,6 +305,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn render_derive_parser_skip() {
+        // Setup
+        let input: syn::DeriveInput = syn::parse_str(
+            r#"
+                #[derive(Default, BlargParser)]
+                #[blarg(program = "abc", hints_off)]
+                struct my_struct {
+                    my_field: usize,
+                    #[blarg(skip)]
+                    my_state: Vec<Box<dyn Fn()>>,
+                }
+            "#,
+        )
+        .unwrap();
+        let parser = DeriveParser::try_from(input).unwrap();
+
+        // Execute
+        let token_stream = TokenStream2::try_from(parser).unwrap();
+
+        // Verify
+        assert_eq!(
+            simple_format(token_stream.to_string()),
+            r#"impl my_struct {
+ # [doc = r" Generated by BlargParser"] pub fn blarg_parse () -> my_struct {
+ let mut my_struct_target = < my_struct > :: default () ;
+ let mut clp = CommandLineParser :: new ("abc") ;
+ clp = clp . add (Parameter :: argument (Scalar :: new (& mut my_struct_target . my_field) , "my_field")) ;
+ let parser = clp . build () ;
+ parser . parse () ;
+ my_struct_target }
+ }
+"#,
+        );
+    }
+
     #[test]
     fn render_derive_parser_about() {
         // Setup
@@ -323,6 +361,8 @@ mod tests {
                 parameter_type: ParameterType::ScalarArgument,
                 choices: None,
                 help: None,
+                type_name: None,
+                required: false,
             }],
             hints: Hints::Off,
         };
@@ -383,6 +423,8 @@ mod tests {
                 },
                 choices: None,
                 help: None,
+                type_name: None,
+                required: false,
             }],
             hints: Hints::Off,
         };
@@ -472,6 +514,8 @@ mod tests {
                 parameter_type: ParameterType::ScalarArgument,
                 choices: None,
                 help: None,
+                type_name: None,
+                required: false,
             }],
             hints: Hints::Off,
         };
@@ -507,6 +551,8 @@ mod tests {
                 parameter_type: ParameterType::ScalarArgument,
                 choices: None,
                 help: None,
+                type_name: None,
+                required: false,
             }],
             hints: Hints::Off,
         };
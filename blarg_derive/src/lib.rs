@@ -31,14 +31,18 @@ pub(crate) const MACRO_BLARG_PARSER: &str = "BlargParser";
 /// Supports:
 /// * `#[blarg(argument)] or #[blarg(option)]`
 /// * `#[blarg(short = C)]`
+/// * `#[blarg(required)]`
 /// * `#[blarg(collection = N)]`
+/// * `#[blarg(group)]`
 /// * `#[blarg(command = (Vi, Si), .., command = (Vj, Sj))]`
+/// * `#[blarg(skip)]`
 ///
 /// Refer to [help messages](../derive/index.html#help-messages) to configure the help message for this struct.
 /// Supports:
 /// * `#[blarg(help = "..")]`
 /// * `#[blarg(choices)]`
 /// * `#[blarg(choices = F)]`
+/// * `#[blarg(type_name = "..")]`
 ///
 /// ### Example
 /// ```ignore
@@ -87,13 +91,17 @@ pub(crate) const MACRO_BLARG_SUB_PARSER: &str = "BlargSubParser";
 /// Supports:
 /// * `#[blarg(argument)] or #[blarg(option)]`
 /// * `#[blarg(short = C)]`
+/// * `#[blarg(required)]`
 /// * `#[blarg(collection = N)]`
+/// * `#[blarg(group)]`
+/// * `#[blarg(skip)]`
 ///
 /// Refer to [help messages](../derive/index.html#help-messages) to configure the help message for this struct.
 /// Supports:
 /// * `#[blarg(help = "..")]`
 /// * `#[blarg(choices)]`
 /// * `#[blarg(choices = F)]`
+/// * `#[blarg(type_name = "..")]`
 ///
 /// ### Example
 /// ```ignore
@@ -1,3 +1,5 @@
+use crate::model::IntermediateAttributes;
+
 mod attribute;
 mod choices;
 mod parameter;
@@ -18,3 +20,18 @@ pub(self) fn incompatible_error(
         ),
     )
 }
+
+/// Whether the field is excluded from parameter generation, via `#[blarg(skip)]`.
+fn skipped(field: &syn::Field) -> bool {
+    field
+        .attrs
+        .iter()
+        .rev()
+        .find(|attribute| attribute.path().is_ident("blarg"))
+        .map(|attribute| {
+            IntermediateAttributes::from(attribute)
+                .singletons
+                .contains("skip")
+        })
+        .unwrap_or(false)
+}
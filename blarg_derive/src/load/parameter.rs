@@ -17,6 +17,8 @@ impl TryFrom<&syn::Field> for DeriveParameter {
         let field_name = value.ident.clone().unwrap();
         let explicit_argument = attributes.singletons.contains("argument");
         let explicit_option = attributes.singletons.contains("option");
+        let explicit_group = attributes.singletons.contains("group");
+        let required = attributes.singletons.contains("required");
         let short = match attributes.pairs.get("short") {
             Some(values) => {
                 let tokens = values
@@ -76,6 +78,17 @@ impl TryFrom<&syn::Field> for DeriveParameter {
             }
             None => None,
         };
+        let type_name = match attributes.pairs.get("type_name") {
+            Some(values) => {
+                let tokens = values
+                    .first()
+                    .expect("attribute pair 'type_name' must contain non-empty values")
+                    .tokens
+                    .clone();
+                Some(DeriveValue { tokens })
+            }
+            None => None,
+        };
         let commands: Option<&Vec<DeriveValue>> = attributes.pairs.get("command");
         let explicit_command = commands.is_some();
 
@@ -106,18 +119,59 @@ impl TryFrom<&syn::Field> for DeriveParameter {
             ));
         }
 
+        if explicit_group && explicit_option {
+            return Err(incompatible_error(
+                "field",
+                &field_name,
+                "#[blarg(group)]",
+                "#[blarg(option)]",
+            ));
+        }
+
+        if explicit_group && explicit_collection {
+            return Err(incompatible_error(
+                "field",
+                &field_name,
+                "#[blarg(group)]",
+                "#[blarg(collection = ..)]",
+            ));
+        }
+
+        if explicit_group && explicit_command {
+            return Err(incompatible_error(
+                "field",
+                &field_name,
+                "#[blarg(group)]",
+                "#[blarg(command = ..)]",
+            ));
+        }
+
         let parameter_type = match &value.ty {
             syn::Type::Path(path) => match &path.path.segments.first() {
                 Some(segment) => {
                     let ident = segment.ident.to_string();
 
                     match ident.as_str() {
+                        "Option" if optional_collection(&value.ty) => {
+                            disallow(
+                                &field_name,
+                                "Option<Vec<..>>",
+                                &[
+                                    (&explicit_argument, "argument"),
+                                    (&explicit_group, "group"),
+                                    (&explicit_command, "#[blarg(command = ..)]"),
+                                ],
+                            )?;
+
+                            ParameterType::OptionalCollectionOption { nargs, short }
+                        }
                         "Option" => {
                             disallow(
                                 &field_name,
                                 "Option<..>",
                                 &[
                                     (&explicit_argument, "argument"),
+                                    (&explicit_group, "group"),
                                     (&explicit_collection, "#[blarg(collection = ..)]"),
                                     (&explicit_command, "#[blarg(command = ..)]"),
                                 ],
@@ -125,14 +179,16 @@ impl TryFrom<&syn::Field> for DeriveParameter {
 
                             ParameterType::OptionalOption { short }
                         }
-                        "Vec" | "HashSet" => {
+                        "Vec" | "VecDeque" | "HashSet" | "BTreeSet" => {
                             disallow(
                                 &field_name,
                                 format!("{}<..>", ident.as_str()),
                                 &[(&explicit_command, "#[blarg(command = ..)]")],
                             )?;
 
-                            if explicit_option {
+                            if explicit_group {
+                                ParameterType::GroupArgument
+                            } else if explicit_option {
                                 ParameterType::CollectionOption { nargs, short }
                             } else {
                                 ParameterType::CollectionArgument { nargs }
@@ -142,12 +198,17 @@ impl TryFrom<&syn::Field> for DeriveParameter {
                             disallow(
                                 &field_name,
                                 "bool",
-                                &[(&explicit_command, "#[blarg(command = ..)]")],
+                                &[
+                                    (&explicit_group, "group"),
+                                    (&explicit_command, "#[blarg(command = ..)]"),
+                                ],
                             )?;
 
                             ParameterType::Switch { short }
                         }
                         _ => {
+                            disallow(&field_name, ident.as_str(), &[(&explicit_group, "group")])?;
+
                             if let Some(cmds) = commands {
                                 let commands = cmds
                                     .iter()
@@ -169,22 +230,32 @@ impl TryFrom<&syn::Field> for DeriveParameter {
                     }
                 }
                 None => {
-                    let tts = &value.to_token_stream();
-                    let type_string = quote! {
-                        #tts
-                    };
-                    panic!("Empty field path: {type_string}");
+                    return Err(unsupported_type(&field_name, &value.ty));
                 }
             },
             _ => {
-                let tts = &value.ty.to_token_stream();
-                let field_string = quote! {
-                    #tts
-                };
-                panic!("Unparseable field: {field_string}");
+                return Err(unsupported_type(&field_name, &value.ty));
             }
         };
 
+        // Only options may be required, since the arguments are required by their `Nargs`.
+        let argument = match &parameter_type {
+            ParameterType::CollectionArgument { .. }
+            | ParameterType::ScalarArgument
+            | ParameterType::GroupArgument => Some("argument"),
+            ParameterType::Condition { .. } => Some("#[blarg(command = ..)]"),
+            _ => None,
+        };
+
+        if let (true, Some(argument)) = (required, argument) {
+            return Err(incompatible_error(
+                "field",
+                &field_name,
+                argument,
+                "#[blarg(required)]",
+            ));
+        }
+
         let from_str_type = match &value.ty {
             syn::Type::Path(path) => match &path.path.segments.first() {
                 Some(segment) => match &segment.arguments {
@@ -192,6 +263,32 @@ impl TryFrom<&syn::Field> for DeriveParameter {
                     syn::PathArguments::AngleBracketed(inner) => {
                         let first = inner.args.first().expect("must have first arg");
                         match first {
+                            // Unwrap the optional collection down to its item type (ex: `u32` from `Option<Vec<u32>>`).
+                            syn::GenericArgument::Type(ty)
+                                if matches!(
+                                    parameter_type,
+                                    ParameterType::OptionalCollectionOption { .. }
+                                ) =>
+                            {
+                                match first_generic(ty) {
+                                    Some(syn::Type::Path(ty)) => ty
+                                        .path
+                                        .segments
+                                        .first()
+                                        .expect("must have a segment")
+                                        .ident
+                                        .to_string(),
+                                    _ => {
+                                        return Err(syn::Error::new(
+                                            field_name.span(),
+                                            format!(
+                                                "Unsupported - field type {}<..>.",
+                                                segment.ident
+                                            ),
+                                        ));
+                                    }
+                                }
+                            }
                             syn::GenericArgument::Type(syn::Type::Path(ty)) => ty
                                 .path
                                 .segments
@@ -208,27 +305,15 @@ impl TryFrom<&syn::Field> for DeriveParameter {
                         }
                     }
                     syn::PathArguments::Parenthesized(_) => {
-                        let tts = &value.to_token_stream();
-                        let type_string = quote! {
-                            #tts
-                        };
-                        panic!("Parenthesized field: {type_string}");
+                        return Err(unsupported_type(&field_name, &value.ty));
                     }
                 },
                 None => {
-                    let tts = &value.to_token_stream();
-                    let type_string = quote! {
-                        #tts
-                    };
-                    panic!("Empty field path: {type_string}");
+                    return Err(unsupported_type(&field_name, &value.ty));
                 }
             },
             _ => {
-                let tts = &value.ty.to_token_stream();
-                let field_string = quote! {
-                    #tts
-                };
-                panic!("Unparseable field: {field_string}");
+                return Err(unsupported_type(&field_name, &value.ty));
             }
         };
 
@@ -238,6 +323,8 @@ impl TryFrom<&syn::Field> for DeriveParameter {
             parameter_type,
             choices,
             help,
+            type_name,
+            required,
         })
     }
 }
@@ -289,6 +376,42 @@ fn build_command(
     }
 }
 
+/// The error for a field type which cannot be interpreted as a parameter (ex: `&str`).
+fn unsupported_type(field_name: &syn::Ident, ty: &syn::Type) -> syn::Error {
+    syn::Error::new(
+        field_name.span(),
+        format!("Unsupported - field type `{}`.", ty.to_token_stream()),
+    )
+}
+
+/// Whether the type is an optional collection (ex: `Option<Vec<u32>>`).
+fn optional_collection(ty: &syn::Type) -> bool {
+    match first_generic(ty) {
+        Some(syn::Type::Path(inner)) => match inner.path.segments.first() {
+            Some(segment) => matches!(
+                segment.ident.to_string().as_str(),
+                "Vec" | "VecDeque" | "HashSet" | "BTreeSet"
+            ),
+            None => false,
+        },
+        _ => false,
+    }
+}
+
+/// The first generic type argument of the type (ex: `u32` from `Vec<u32>`).
+fn first_generic(ty: &syn::Type) -> Option<&syn::Type> {
+    match ty {
+        syn::Type::Path(path) => match &path.path.segments.first()?.arguments {
+            syn::PathArguments::AngleBracketed(inner) => match inner.args.first()? {
+                syn::GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 fn disallow(
     field_name: &syn::Ident,
     antecedent: impl Into<String>,
@@ -319,7 +442,6 @@ mod tests {
     use syn::{parse_quote, AngleBracketedGenericArguments, PathArguments, PathSegment};
 
     #[test]
-    #[should_panic]
     fn construct_derive_parameter_unknown_type() {
         // Setup
         let input: syn::Field = syn::Field {
@@ -331,12 +453,14 @@ mod tests {
             ty: syn::Type::Verbatim(Literal::string("moot").into_token_stream()),
         };
 
-        // Execute & verify
-        let _ = DeriveParameter::try_from(&input).unwrap();
+        // Execute
+        let error = DeriveParameter::try_from(&input).unwrap_err();
+
+        // Verify
+        assert_eq!(error.to_string(), "Unsupported - field type `\"moot\"`.");
     }
 
     #[test]
-    #[should_panic]
     fn construct_derive_parameter_empty() {
         // Setup
         let segments = syn::punctuated::Punctuated::new();
@@ -355,8 +479,63 @@ mod tests {
             }),
         };
 
-        // Execute & verify
-        let _ = DeriveParameter::try_from(&input).unwrap();
+        // Execute
+        let error = DeriveParameter::try_from(&input).unwrap_err();
+
+        // Verify
+        assert_eq!(error.to_string(), "Unsupported - field type ``.");
+    }
+
+    #[test]
+    fn construct_derive_parameter_reference() {
+        // Setup
+        let input: syn::Field = syn::Field {
+            attrs: vec![],
+            vis: syn::Visibility::Inherited,
+            mutability: syn::FieldMutability::None,
+            ident: Some(ident("my_field")),
+            colon_token: None,
+            ty: parse_quote! { &'static str },
+        };
+
+        // Execute
+        let error = DeriveParameter::try_from(&input).unwrap_err();
+
+        // Verify
+        assert_eq!(
+            error.to_string(),
+            "Unsupported - field type `& 'static str`."
+        );
+    }
+
+    #[test]
+    fn construct_derive_parameter_parenthesized() {
+        // Setup
+        let mut segments = syn::punctuated::Punctuated::new();
+        segments.push_value(PathSegment {
+            ident: ident("Fn"),
+            arguments: PathArguments::Parenthesized(parse_quote! { (u32) }),
+        });
+        let input: syn::Field = syn::Field {
+            attrs: vec![],
+            vis: syn::Visibility::Inherited,
+            mutability: syn::FieldMutability::None,
+            ident: Some(ident("my_field")),
+            colon_token: None,
+            ty: syn::Type::Path(syn::TypePath {
+                qself: None,
+                path: syn::Path {
+                    leading_colon: None,
+                    segments,
+                },
+            }),
+        };
+
+        // Execute
+        let error = DeriveParameter::try_from(&input).unwrap_err();
+
+        // Verify
+        assert_eq!(error.to_string(), "Unsupported - field type `Fn (u32)`.");
     }
 
     //# Implicit construction
@@ -396,6 +575,8 @@ mod tests {
                 parameter_type: ParameterType::ScalarArgument,
                 choices: None,
                 help: None,
+                type_name: None,
+                required: false,
             }
         );
     }
@@ -440,6 +621,8 @@ mod tests {
                 parameter_type: ParameterType::OptionalOption { short: None },
                 choices: None,
                 help: None,
+                type_name: None,
+                required: false,
             }
         );
     }
@@ -491,31 +674,22 @@ mod tests {
                 },
                 choices: None,
                 help: None,
+                type_name: None,
+                required: false,
             }
         );
     }
 
     #[test]
-    fn construct_switch() {
+    fn construct_optional_collection_option() {
         // Setup
-        let mut segments = syn::punctuated::Punctuated::new();
-        segments.push_value(PathSegment {
-            ident: ident("bool"),
-            arguments: PathArguments::None,
-        });
         let input: syn::Field = syn::Field {
             attrs: vec![],
             vis: syn::Visibility::Inherited,
             mutability: syn::FieldMutability::None,
             ident: Some(ident("my_field")),
             colon_token: None,
-            ty: syn::Type::Path(syn::TypePath {
-                qself: None,
-                path: syn::Path {
-                    leading_colon: None,
-                    segments,
-                },
-            }),
+            ty: parse_quote! { Option<Vec<u32>> },
         };
 
         // Execute
@@ -526,40 +700,34 @@ mod tests {
             derive_parameter,
             DeriveParameter {
                 field_name: ident("my_field"),
-                from_str_type: "bool".to_string(),
-                parameter_type: ParameterType::Switch { short: None },
+                from_str_type: "u32".to_string(),
+                parameter_type: ParameterType::OptionalCollectionOption {
+                    nargs: DeriveValue {
+                        tokens: quote! { Nargs::AtLeastOne }
+                    },
+                    short: None,
+                },
                 choices: None,
                 help: None,
+                type_name: None,
+                required: false,
             }
         );
     }
 
     #[test]
-    fn construct_collection_argument() {
+    fn construct_optional_collection_option_explicit() {
         // Setup
-        let mut segments = syn::punctuated::Punctuated::new();
-        segments.push_value(PathSegment {
-            ident: ident("Vec"),
-            arguments: PathArguments::AngleBracketed(AngleBracketedGenericArguments {
-                colon2_token: None,
-                lt_token: Default::default(),
-                args: generic("usize"),
-                gt_token: Default::default(),
-            }),
-        });
+        let attribute: syn::Attribute = parse_quote! {
+            #[blarg(option, collection = Nargs::Any, short = 'm')]
+        };
         let input: syn::Field = syn::Field {
-            attrs: vec![],
+            attrs: vec![attribute],
             vis: syn::Visibility::Inherited,
             mutability: syn::FieldMutability::None,
             ident: Some(ident("my_field")),
             colon_token: None,
-            ty: syn::Type::Path(syn::TypePath {
-                qself: None,
-                path: syn::Path {
-                    leading_colon: None,
-                    segments,
-                },
-            }),
+            ty: parse_quote! { Option<HashSet<u32>> },
         };
 
         // Execute
@@ -570,28 +738,28 @@ mod tests {
             derive_parameter,
             DeriveParameter {
                 field_name: ident("my_field"),
-                from_str_type: "usize".to_string(),
-                parameter_type: ParameterType::CollectionArgument {
+                from_str_type: "u32".to_string(),
+                parameter_type: ParameterType::OptionalCollectionOption {
                     nargs: DeriveValue {
-                        tokens: quote! { Nargs::AtLeastOne }
-                    }
+                        tokens: quote! { Nargs::Any }
+                    },
+                    short: Some(DeriveValue {
+                        tokens: Literal::character('m').into_token_stream(),
+                    }),
                 },
                 choices: None,
                 help: None,
+                type_name: None,
+                required: false,
             }
         );
     }
 
     #[test]
-    fn construct_with_choices() {
+    fn construct_optional_collection_argument() {
         // Setup
-        let mut segments = syn::punctuated::Punctuated::new();
-        segments.push_value(PathSegment {
-            ident: ident("usize"),
-            arguments: PathArguments::None,
-        });
         let attribute: syn::Attribute = parse_quote! {
-            #[blarg(choices)]
+            #[blarg(argument)]
         };
         let input: syn::Field = syn::Field {
             attrs: vec![attribute],
@@ -599,46 +767,28 @@ mod tests {
             mutability: syn::FieldMutability::None,
             ident: Some(ident("my_field")),
             colon_token: None,
-            ty: syn::Type::Path(syn::TypePath {
-                qself: None,
-                path: syn::Path {
-                    leading_colon: None,
-                    segments,
-                },
-            }),
+            ty: parse_quote! { Option<Vec<u32>> },
         };
 
         // Execute
-        let derive_parameter = DeriveParameter::try_from(&input).unwrap();
+        let error = DeriveParameter::try_from(&input).unwrap_err();
 
         // Verify
-        assert_eq!(
-            derive_parameter,
-            DeriveParameter {
-                field_name: ident("my_field"),
-                from_str_type: "usize".to_string(),
-                parameter_type: ParameterType::ScalarArgument,
-                choices: Some(DeriveValue {
-                    tokens: quote! { <usize>::blarg_choices },
-                }),
-                help: None,
-            }
-        );
+        assert_contains!(error.to_string(), "Invalid - field cannot be both");
+        assert_contains!(error.to_string(), "#[blarg(argument)]");
+        assert_contains!(error.to_string(), "Option<Vec<..>>");
     }
 
     #[test]
-    fn construct_with_choices_function() {
+    fn construct_switch() {
         // Setup
         let mut segments = syn::punctuated::Punctuated::new();
         segments.push_value(PathSegment {
-            ident: ident("usize"),
+            ident: ident("bool"),
             arguments: PathArguments::None,
         });
-        let attribute: syn::Attribute = parse_quote! {
-            #[blarg(choices = my_func)]
-        };
         let input: syn::Field = syn::Field {
-            attrs: vec![attribute],
+            attrs: vec![],
             vis: syn::Visibility::Inherited,
             mutability: syn::FieldMutability::None,
             ident: Some(ident("my_field")),
@@ -660,29 +810,31 @@ mod tests {
             derive_parameter,
             DeriveParameter {
                 field_name: ident("my_field"),
-                from_str_type: "usize".to_string(),
-                parameter_type: ParameterType::ScalarArgument,
-                choices: Some(DeriveValue {
-                    tokens: quote! { my_func },
-                }),
+                from_str_type: "bool".to_string(),
+                parameter_type: ParameterType::Switch { short: None },
+                choices: None,
                 help: None,
+                type_name: None,
+                required: false,
             }
         );
     }
 
     #[test]
-    fn construct_with_help() {
+    fn construct_collection_argument() {
         // Setup
         let mut segments = syn::punctuated::Punctuated::new();
         segments.push_value(PathSegment {
-            ident: ident("usize"),
-            arguments: PathArguments::None,
+            ident: ident("Vec"),
+            arguments: PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+                colon2_token: None,
+                lt_token: Default::default(),
+                args: generic("usize"),
+                gt_token: Default::default(),
+            }),
         });
-        let attribute: syn::Attribute = parse_quote! {
-            #[blarg(help = "abc 123")]
-        };
         let input: syn::Field = syn::Field {
-            attrs: vec![attribute],
+            attrs: vec![],
             vis: syn::Visibility::Inherited,
             mutability: syn::FieldMutability::None,
             ident: Some(ident("my_field")),
@@ -705,24 +857,474 @@ mod tests {
             DeriveParameter {
                 field_name: ident("my_field"),
                 from_str_type: "usize".to_string(),
-                parameter_type: ParameterType::ScalarArgument,
+                parameter_type: ParameterType::CollectionArgument {
+                    nargs: DeriveValue {
+                        tokens: quote! { Nargs::AtLeastOne }
+                    }
+                },
                 choices: None,
-                help: Some(DeriveValue {
-                    tokens: Literal::string("abc 123").to_token_stream(),
-                }),
+                help: None,
+                type_name: None,
+                required: false,
             }
         );
     }
 
-    //# Explicit construction
-
     #[test]
-    fn construct_scalar_option() {
+    fn construct_collection_argument_btreeset() {
         // Setup
-        let mut segments = syn::punctuated::Punctuated::new();
-        segments.push_value(PathSegment {
-            ident: ident("usize"),
-            arguments: PathArguments::None,
+        let input: syn::Field = syn::Field {
+            attrs: vec![],
+            vis: syn::Visibility::Inherited,
+            mutability: syn::FieldMutability::None,
+            ident: Some(ident("my_field")),
+            colon_token: None,
+            ty: parse_quote! { BTreeSet<u32> },
+        };
+
+        // Execute
+        let derive_parameter = DeriveParameter::try_from(&input).unwrap();
+
+        // Verify
+        assert_eq!(
+            derive_parameter,
+            DeriveParameter {
+                field_name: ident("my_field"),
+                from_str_type: "u32".to_string(),
+                parameter_type: ParameterType::CollectionArgument {
+                    nargs: DeriveValue {
+                        tokens: quote! { Nargs::AtLeastOne }
+                    }
+                },
+                choices: None,
+                help: None,
+                type_name: None,
+                required: false,
+            }
+        );
+    }
+
+    #[test]
+    fn construct_optional_collection_option_btreeset() {
+        // Setup
+        let input: syn::Field = syn::Field {
+            attrs: vec![],
+            vis: syn::Visibility::Inherited,
+            mutability: syn::FieldMutability::None,
+            ident: Some(ident("my_field")),
+            colon_token: None,
+            ty: parse_quote! { Option<BTreeSet<u32>> },
+        };
+
+        // Execute
+        let derive_parameter = DeriveParameter::try_from(&input).unwrap();
+
+        // Verify
+        assert_eq!(
+            derive_parameter,
+            DeriveParameter {
+                field_name: ident("my_field"),
+                from_str_type: "u32".to_string(),
+                parameter_type: ParameterType::OptionalCollectionOption {
+                    nargs: DeriveValue {
+                        tokens: quote! { Nargs::AtLeastOne }
+                    },
+                    short: None,
+                },
+                choices: None,
+                help: None,
+                type_name: None,
+                required: false,
+            }
+        );
+    }
+
+    #[test]
+    fn construct_collection_argument_vecdeque() {
+        // Setup
+        let input: syn::Field = syn::Field {
+            attrs: vec![],
+            vis: syn::Visibility::Inherited,
+            mutability: syn::FieldMutability::None,
+            ident: Some(ident("my_field")),
+            colon_token: None,
+            ty: parse_quote! { VecDeque<u32> },
+        };
+
+        // Execute
+        let derive_parameter = DeriveParameter::try_from(&input).unwrap();
+
+        // Verify
+        assert_eq!(
+            derive_parameter,
+            DeriveParameter {
+                field_name: ident("my_field"),
+                from_str_type: "u32".to_string(),
+                parameter_type: ParameterType::CollectionArgument {
+                    nargs: DeriveValue {
+                        tokens: quote! { Nargs::AtLeastOne }
+                    }
+                },
+                choices: None,
+                help: None,
+                type_name: None,
+                required: false,
+            }
+        );
+    }
+
+    #[test]
+    fn construct_optional_collection_option_vecdeque() {
+        // Setup
+        let input: syn::Field = syn::Field {
+            attrs: vec![],
+            vis: syn::Visibility::Inherited,
+            mutability: syn::FieldMutability::None,
+            ident: Some(ident("my_field")),
+            colon_token: None,
+            ty: parse_quote! { Option<VecDeque<u32>> },
+        };
+
+        // Execute
+        let derive_parameter = DeriveParameter::try_from(&input).unwrap();
+
+        // Verify
+        assert_eq!(
+            derive_parameter,
+            DeriveParameter {
+                field_name: ident("my_field"),
+                from_str_type: "u32".to_string(),
+                parameter_type: ParameterType::OptionalCollectionOption {
+                    nargs: DeriveValue {
+                        tokens: quote! { Nargs::AtLeastOne }
+                    },
+                    short: None,
+                },
+                choices: None,
+                help: None,
+                type_name: None,
+                required: false,
+            }
+        );
+    }
+
+    #[test]
+    fn construct_group_argument() {
+        // Setup
+        let mut segments = syn::punctuated::Punctuated::new();
+        segments.push_value(PathSegment {
+            ident: ident("Vec"),
+            arguments: PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+                colon2_token: None,
+                lt_token: Default::default(),
+                args: generic("Pair"),
+                gt_token: Default::default(),
+            }),
+        });
+        let attribute: syn::Attribute = parse_quote! {
+            #[blarg(group)]
+        };
+        let input: syn::Field = syn::Field {
+            attrs: vec![attribute],
+            vis: syn::Visibility::Inherited,
+            mutability: syn::FieldMutability::None,
+            ident: Some(ident("my_field")),
+            colon_token: None,
+            ty: syn::Type::Path(syn::TypePath {
+                qself: None,
+                path: syn::Path {
+                    leading_colon: None,
+                    segments,
+                },
+            }),
+        };
+
+        // Execute
+        let derive_parameter = DeriveParameter::try_from(&input).unwrap();
+
+        // Verify
+        assert_eq!(
+            derive_parameter,
+            DeriveParameter {
+                field_name: ident("my_field"),
+                from_str_type: "Pair".to_string(),
+                parameter_type: ParameterType::GroupArgument,
+                choices: None,
+                help: None,
+                type_name: None,
+                required: false,
+            }
+        );
+    }
+
+    #[test]
+    fn construct_group_option() {
+        // Setup
+        let mut segments = syn::punctuated::Punctuated::new();
+        segments.push_value(PathSegment {
+            ident: ident("Vec"),
+            arguments: PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+                colon2_token: None,
+                lt_token: Default::default(),
+                args: generic("Pair"),
+                gt_token: Default::default(),
+            }),
+        });
+        let attribute: syn::Attribute = parse_quote! {
+            #[blarg(group, option)]
+        };
+        let input: syn::Field = syn::Field {
+            attrs: vec![attribute],
+            vis: syn::Visibility::Inherited,
+            mutability: syn::FieldMutability::None,
+            ident: Some(ident("my_field")),
+            colon_token: None,
+            ty: syn::Type::Path(syn::TypePath {
+                qself: None,
+                path: syn::Path {
+                    leading_colon: None,
+                    segments,
+                },
+            }),
+        };
+
+        // Execute
+        let error = DeriveParameter::try_from(&input).unwrap_err();
+
+        // Verify
+        assert_contains!(error.to_string(), "Invalid - field cannot be both");
+        assert_contains!(error.to_string(), "#[blarg(group)]");
+        assert_contains!(error.to_string(), "#[blarg(option)]");
+    }
+
+    #[test]
+    fn construct_group_scalar() {
+        // Setup
+        let mut segments = syn::punctuated::Punctuated::new();
+        segments.push_value(PathSegment {
+            ident: ident("usize"),
+            arguments: PathArguments::None,
+        });
+        let attribute: syn::Attribute = parse_quote! {
+            #[blarg(group)]
+        };
+        let input: syn::Field = syn::Field {
+            attrs: vec![attribute],
+            vis: syn::Visibility::Inherited,
+            mutability: syn::FieldMutability::None,
+            ident: Some(ident("my_field")),
+            colon_token: None,
+            ty: syn::Type::Path(syn::TypePath {
+                qself: None,
+                path: syn::Path {
+                    leading_colon: None,
+                    segments,
+                },
+            }),
+        };
+
+        // Execute
+        let error = DeriveParameter::try_from(&input).unwrap_err();
+
+        // Verify
+        assert_contains!(error.to_string(), "Invalid - field cannot be both");
+        assert_contains!(error.to_string(), "usize");
+        assert_contains!(error.to_string(), "#[blarg(group)]");
+    }
+
+    #[test]
+    fn construct_with_choices() {
+        // Setup
+        let mut segments = syn::punctuated::Punctuated::new();
+        segments.push_value(PathSegment {
+            ident: ident("usize"),
+            arguments: PathArguments::None,
+        });
+        let attribute: syn::Attribute = parse_quote! {
+            #[blarg(choices)]
+        };
+        let input: syn::Field = syn::Field {
+            attrs: vec![attribute],
+            vis: syn::Visibility::Inherited,
+            mutability: syn::FieldMutability::None,
+            ident: Some(ident("my_field")),
+            colon_token: None,
+            ty: syn::Type::Path(syn::TypePath {
+                qself: None,
+                path: syn::Path {
+                    leading_colon: None,
+                    segments,
+                },
+            }),
+        };
+
+        // Execute
+        let derive_parameter = DeriveParameter::try_from(&input).unwrap();
+
+        // Verify
+        assert_eq!(
+            derive_parameter,
+            DeriveParameter {
+                field_name: ident("my_field"),
+                from_str_type: "usize".to_string(),
+                parameter_type: ParameterType::ScalarArgument,
+                choices: Some(DeriveValue {
+                    tokens: quote! { <usize>::blarg_choices },
+                }),
+                help: None,
+                type_name: None,
+                required: false,
+            }
+        );
+    }
+
+    #[test]
+    fn construct_with_choices_function() {
+        // Setup
+        let mut segments = syn::punctuated::Punctuated::new();
+        segments.push_value(PathSegment {
+            ident: ident("usize"),
+            arguments: PathArguments::None,
+        });
+        let attribute: syn::Attribute = parse_quote! {
+            #[blarg(choices = my_func)]
+        };
+        let input: syn::Field = syn::Field {
+            attrs: vec![attribute],
+            vis: syn::Visibility::Inherited,
+            mutability: syn::FieldMutability::None,
+            ident: Some(ident("my_field")),
+            colon_token: None,
+            ty: syn::Type::Path(syn::TypePath {
+                qself: None,
+                path: syn::Path {
+                    leading_colon: None,
+                    segments,
+                },
+            }),
+        };
+
+        // Execute
+        let derive_parameter = DeriveParameter::try_from(&input).unwrap();
+
+        // Verify
+        assert_eq!(
+            derive_parameter,
+            DeriveParameter {
+                field_name: ident("my_field"),
+                from_str_type: "usize".to_string(),
+                parameter_type: ParameterType::ScalarArgument,
+                choices: Some(DeriveValue {
+                    tokens: quote! { my_func },
+                }),
+                help: None,
+                type_name: None,
+                required: false,
+            }
+        );
+    }
+
+    #[test]
+    fn construct_with_help() {
+        // Setup
+        let mut segments = syn::punctuated::Punctuated::new();
+        segments.push_value(PathSegment {
+            ident: ident("usize"),
+            arguments: PathArguments::None,
+        });
+        let attribute: syn::Attribute = parse_quote! {
+            #[blarg(help = "abc 123")]
+        };
+        let input: syn::Field = syn::Field {
+            attrs: vec![attribute],
+            vis: syn::Visibility::Inherited,
+            mutability: syn::FieldMutability::None,
+            ident: Some(ident("my_field")),
+            colon_token: None,
+            ty: syn::Type::Path(syn::TypePath {
+                qself: None,
+                path: syn::Path {
+                    leading_colon: None,
+                    segments,
+                },
+            }),
+        };
+
+        // Execute
+        let derive_parameter = DeriveParameter::try_from(&input).unwrap();
+
+        // Verify
+        assert_eq!(
+            derive_parameter,
+            DeriveParameter {
+                field_name: ident("my_field"),
+                from_str_type: "usize".to_string(),
+                parameter_type: ParameterType::ScalarArgument,
+                choices: None,
+                help: Some(DeriveValue {
+                    tokens: Literal::string("abc 123").to_token_stream(),
+                }),
+                type_name: None,
+                required: false,
+            }
+        );
+    }
+
+    #[test]
+    fn construct_with_type_name() {
+        // Setup
+        let mut segments = syn::punctuated::Punctuated::new();
+        segments.push_value(PathSegment {
+            ident: ident("usize"),
+            arguments: PathArguments::None,
+        });
+        let attribute: syn::Attribute = parse_quote! {
+            #[blarg(type_name = "count")]
+        };
+        let input: syn::Field = syn::Field {
+            attrs: vec![attribute],
+            vis: syn::Visibility::Inherited,
+            mutability: syn::FieldMutability::None,
+            ident: Some(ident("my_field")),
+            colon_token: None,
+            ty: syn::Type::Path(syn::TypePath {
+                qself: None,
+                path: syn::Path {
+                    leading_colon: None,
+                    segments,
+                },
+            }),
+        };
+
+        // Execute
+        let derive_parameter = DeriveParameter::try_from(&input).unwrap();
+
+        // Verify
+        assert_eq!(
+            derive_parameter,
+            DeriveParameter {
+                field_name: ident("my_field"),
+                from_str_type: "usize".to_string(),
+                parameter_type: ParameterType::ScalarArgument,
+                choices: None,
+                help: None,
+                type_name: Some(DeriveValue {
+                    tokens: Literal::string("count").to_token_stream(),
+                }),
+                required: false,
+            }
+        );
+    }
+
+    //# Explicit construction
+
+    #[test]
+    fn construct_scalar_option() {
+        // Setup
+        let mut segments = syn::punctuated::Punctuated::new();
+        segments.push_value(PathSegment {
+            ident: ident("usize"),
+            arguments: PathArguments::None,
         });
         let attribute: syn::Attribute = parse_quote! {
             #[blarg(option)]
@@ -754,10 +1356,90 @@ mod tests {
                 parameter_type: ParameterType::ScalarOption { short: None },
                 choices: None,
                 help: None,
+                type_name: None,
+                required: false,
             }
         );
     }
 
+    #[test]
+    fn construct_scalar_option_required() {
+        // Setup
+        let mut segments = syn::punctuated::Punctuated::new();
+        segments.push_value(PathSegment {
+            ident: ident("usize"),
+            arguments: PathArguments::None,
+        });
+        let attribute: syn::Attribute = parse_quote! {
+            #[blarg(option, required)]
+        };
+        let input: syn::Field = syn::Field {
+            attrs: vec![attribute],
+            vis: syn::Visibility::Inherited,
+            mutability: syn::FieldMutability::None,
+            ident: Some(ident("my_field")),
+            colon_token: None,
+            ty: syn::Type::Path(syn::TypePath {
+                qself: None,
+                path: syn::Path {
+                    leading_colon: None,
+                    segments,
+                },
+            }),
+        };
+
+        // Execute
+        let derive_parameter = DeriveParameter::try_from(&input).unwrap();
+
+        // Verify
+        assert_eq!(
+            derive_parameter,
+            DeriveParameter {
+                field_name: ident("my_field"),
+                from_str_type: "usize".to_string(),
+                parameter_type: ParameterType::ScalarOption { short: None },
+                choices: None,
+                help: None,
+                type_name: None,
+                required: true,
+            }
+        );
+    }
+
+    #[test]
+    fn construct_scalar_argument_required() {
+        // Setup
+        let mut segments = syn::punctuated::Punctuated::new();
+        segments.push_value(PathSegment {
+            ident: ident("usize"),
+            arguments: PathArguments::None,
+        });
+        let attribute: syn::Attribute = parse_quote! {
+            #[blarg(argument, required)]
+        };
+        let input: syn::Field = syn::Field {
+            attrs: vec![attribute],
+            vis: syn::Visibility::Inherited,
+            mutability: syn::FieldMutability::None,
+            ident: Some(ident("my_field")),
+            colon_token: None,
+            ty: syn::Type::Path(syn::TypePath {
+                qself: None,
+                path: syn::Path {
+                    leading_colon: None,
+                    segments,
+                },
+            }),
+        };
+
+        // Execute
+        let error = DeriveParameter::try_from(&input).unwrap_err();
+
+        // Verify
+        assert_contains!(error.to_string(), "Invalid - field cannot be both");
+        assert_contains!(error.to_string(), "#[blarg(required)]");
+    }
+
     #[test]
     fn construct_scalar_option_short() {
         // Setup
@@ -800,6 +1482,8 @@ mod tests {
                 },
                 choices: None,
                 help: None,
+                type_name: None,
+                required: false,
             }
         );
     }
@@ -861,6 +1545,8 @@ mod tests {
                 },
                 choices: None,
                 help: None,
+                type_name: None,
+                required: false,
             }
         );
     }
@@ -924,6 +1610,8 @@ mod tests {
                 },
                 choices: None,
                 help: None,
+                type_name: None,
+                required: false,
             }
         );
     }
@@ -976,6 +1664,8 @@ mod tests {
                 },
                 choices: None,
                 help: None,
+                type_name: None,
+                required: false,
             }
         );
     }
@@ -1028,6 +1718,8 @@ mod tests {
                 },
                 choices: None,
                 help: None,
+                type_name: None,
+                required: false,
             }
         );
     }
@@ -1082,6 +1774,8 @@ mod tests {
                 },
                 choices: None,
                 help: None,
+                type_name: None,
+                required: false,
             },
         );
     }
@@ -1124,6 +1818,8 @@ mod tests {
                 parameter_type: ParameterType::ScalarArgument,
                 choices: None,
                 help: None,
+                type_name: None,
+                required: false,
             },
         );
     }
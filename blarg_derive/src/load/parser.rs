@@ -1,4 +1,4 @@
-use crate::load::incompatible_error;
+use crate::load::{incompatible_error, skipped};
 use crate::model::Hints;
 use crate::{
     model::{
@@ -78,6 +78,7 @@ impl TryFrom<syn::DeriveInput> for DeriveParser {
                     } => fields
                         .named
                         .iter()
+                        .filter(|field| !skipped(field))
                         .map(DeriveParameter::try_from)
                         .collect::<Result<Vec<_>, _>>()?,
                     syn::DataStruct { .. } => Vec::default(),
@@ -172,6 +173,7 @@ impl TryFrom<syn::DeriveInput> for DeriveSubParser {
                     } => fields
                         .named
                         .iter()
+                        .filter(|field| !skipped(field))
                         .map(DeriveParameter::try_from)
                         .collect::<Result<Vec<_>, _>>()?,
                     syn::DataStruct { .. } => Vec::default(),
@@ -287,12 +289,47 @@ mod tests {
                     parameter_type: ParameterType::ScalarArgument,
                     choices: None,
                     help: None,
+                    type_name: None,
+                    required: false,
                 }],
                 hints: Hints::On,
             }
         );
     }
 
+    #[test]
+    fn construct_derive_parser_skip() {
+        // Setup
+        let input: syn::DeriveInput = syn::parse_str(
+            r#"
+                #[derive(Default, BlargParser)]
+                struct Parameters {
+                    apple: usize,
+                    #[blarg(skip)]
+                    banana: Vec<Box<dyn Fn()>>,
+                }
+            "#,
+        )
+        .unwrap();
+
+        // Execute
+        let derive_parser = DeriveParser::try_from(input).unwrap();
+
+        // Verify
+        assert_eq!(
+            derive_parser.parameters,
+            vec![DeriveParameter {
+                field_name: ident("apple"),
+                from_str_type: "usize".to_string(),
+                parameter_type: ParameterType::ScalarArgument,
+                choices: None,
+                help: None,
+                type_name: None,
+                required: false,
+            }]
+        );
+    }
+
     #[test]
     fn construct_derive_parser_with_attributes() {
         // Setup
@@ -330,6 +367,8 @@ mod tests {
                     parameter_type: ParameterType::ScalarArgument,
                     choices: None,
                     help: None,
+                    type_name: None,
+                    required: false,
                 }],
                 hints: Hints::Off,
             }
@@ -461,12 +500,35 @@ mod tests {
                     parameter_type: ParameterType::ScalarArgument,
                     choices: None,
                     help: None,
+                    type_name: None,
+                    required: false,
                 }],
                 hints: Hints::On,
             }
         );
     }
 
+    #[test]
+    fn construct_derive_sub_parser_skip() {
+        // Setup
+        let input: syn::DeriveInput = syn::parse_str(
+            r#"
+                #[derive(Default, BlargSubParser)]
+                struct Parameters {
+                    #[blarg(skip)]
+                    apple: usize,
+                }
+            "#,
+        )
+        .unwrap();
+
+        // Execute
+        let derive_sub_parser = DeriveSubParser::try_from(input).unwrap();
+
+        // Verify
+        assert_eq!(derive_sub_parser.parameters, vec![]);
+    }
+
     #[test]
     fn construct_derive_sub_parser_with_attributes() {
         // Setup
@@ -498,6 +560,8 @@ mod tests {
                     parameter_type: ParameterType::ScalarArgument,
                     choices: None,
                     help: None,
+                    type_name: None,
+                    required: false,
                 }],
                 hints: Hints::Off,
             }
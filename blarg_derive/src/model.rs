@@ -38,6 +38,7 @@ pub enum ParameterType {
         nargs: DeriveValue,
     },
     ScalarArgument,
+    GroupArgument,
 
     CollectionOption {
         nargs: DeriveValue,
@@ -46,6 +47,10 @@ pub enum ParameterType {
     OptionalOption {
         short: Option<DeriveValue>,
     },
+    OptionalCollectionOption {
+        nargs: DeriveValue,
+        short: Option<DeriveValue>,
+    },
     ScalarOption {
         short: Option<DeriveValue>,
     },
@@ -66,6 +71,8 @@ pub struct DeriveParameter {
     pub parameter_type: ParameterType,
     pub choices: Option<DeriveValue>,
     pub help: Option<DeriveValue>,
+    pub type_name: Option<DeriveValue>,
+    pub required: bool,
 }
 
 #[derive(Debug, PartialEq, Eq)]
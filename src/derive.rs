@@ -17,12 +17,12 @@
 //! $ demo_derived -h
 //! usage: demo_derived [-h] [--banana] [--daikon-root DAIKON_ROOT] APPLE CARROTS [...]
 //! positional arguments:
-//!  APPLE                                                          type: usize
-//!  CARROTS [...]                                                  type: u32      initial: []
+//!  APPLE                                                          type: number
+//!  CARROTS [...]                                                  type: number   initial: []
 //! options:
 //!  -h, --help                  Show this help message and exit.
 //!  --banana
-//!  --daikon-root DAIKON_ROOT                                      type: String
+//!  --daikon-root DAIKON_ROOT                                      type: string
 //! ```
 //!
 //!
@@ -32,13 +32,16 @@
 //! ### Parameter Configuration
 //! The implicit Cli inference uses the following rules:
 //! ```console
-//! Type        | Parameter
+//! Type            | Parameter
 //! -----------------------------------
-//! Option<T>   | Parameter::option(Optional::new(..), ..)
-//! Vec<T>      | Parameter::argument(Collection::new(.., Nargs::AtLeastOne), ..)
-//! HashSet<T>  | Parameter::argument(Collection::new(.., Nargs::AtLeastOne), ..)
-//! bool        | Parameter::option(Switch::new(..), ..)
-//! T           | Parameter::argument(Scalar::new(..) , ..)
+//! Option<T>       | Parameter::option(Optional::new(..), ..)
+//! Option<Vec<T>>  | Parameter::option(Collection::new(.., Nargs::AtLeastOne), ..)
+//! Vec<T>          | Parameter::argument(Collection::new(.., Nargs::AtLeastOne), ..)
+//! VecDeque<T>     | Parameter::argument(Collection::new(.., Nargs::AtLeastOne), ..)
+//! HashSet<T>      | Parameter::argument(Collection::new(.., Nargs::AtLeastOne), ..)
+//! BTreeSet<T>     | Parameter::argument(Collection::new(.., Nargs::AtLeastOne), ..)
+//! bool            | Parameter::option(Switch::new(..), ..)
+//! T               | Parameter::argument(Scalar::new(..) , ..)
 //! ```
 //!
 //! Notice, these implicit rules do not capture all possible `blarg` configurations.
@@ -47,11 +50,18 @@
 //! Only one of these may be used on the same field.
 //! * `#[blarg(short = C]` to explicitly set the short name for an option parameter.
 //! `C` must be a char value (ex: `'c'`).
+//! * `#[blarg(required)]` to require an option parameter be provided, via [`Parameter::required`](../struct.Parameter.html#method.required).
+//! This may not be combined with an argument parameter.
 //! * `#[blarg(collection = N)]` to explicitly use `Collection::new(.., N)`, where `N` is the [Nargs](../enum.Nargs.html) variant.
-//! This is useful both for non-`Vec`/`HashSet` [Collectable](../prelude/trait.Collectable.html) types, as well as to control the `Nargs` variant.
+//! This is useful both for non-`Vec`/`VecDeque`/`HashSet`/`BTreeSet` [Collectable](../prelude/trait.Collectable.html) types, as well as to control the `Nargs` variant.
+//! * `#[blarg(group)]` to explicitly use `Collection::repeatable_group(..)`, where each consecutive group of values forms one element.
+//! The element type must implement [Record](../trait.Record.html) (ex: a tuple, or a struct of positional values).
+//! This may not be combined with `#[blarg(option)]` or `#[blarg(collection = N)]`.
 //! * `#[blarg(command = (Vi, Si), .., command = (Vj, Sj))]` to define sub-command [branches](../struct.CommandLineParser.html#method.branch) on the pairs `(Vi, Si), .., (Vj, Sj)`.
 //! Each pair must be the variant `V*` and sub-parameter struct `S*` to configure.
 //! `S*` must be instrumented with `#[blarg(BlargSubParser)]`, and follows the same configuration rules (both implicit and explicit) as a `BlargParser`.
+//! * `#[blarg(skip)]` to exclude the field from the Cli entirely (ex: internal program state).
+//! The field retains the value given by the struct's initializer.
 //!
 //! A partial example of these rules is provided as follows:
 //! ```ignore
@@ -72,18 +82,33 @@
 //!     // the above generates:
 //!     //  .add(Parameter::option(Scalar::new(&mut parameters.fox), "fox", Some('f')))
 //!
+//!     #[blarg(option, required)]
+//!     swiftly: usize,
+//!     // the above generates:
+//!     //  .add(Parameter::option(Scalar::new(&mut parameters.swiftly), "swiftly", None).required())
+//!
 //!     #[blarg(collection = Nargs::Precisely(2))]
 //!     jumps: Pair<usize>,
 //!     // the above generates:
 //!     //  .add(Parameter::argument(Collection::new(&mut parameters.jumps, Nargs::Precisely(2)), "jumps"))
 //!     // assumes: `impl<T> Collectable<T> for Pair<T>`
 //!
+//!     #[blarg(group)]
+//!     the: Vec<Dog>,
+//!     // the above generates:
+//!     //  .add(Parameter::argument(Collection::repeatable_group(&mut parameters.the), "the"))
+//!     // assumes: `impl Record for Dog`
+//!
 //!     #[blarg(command = (0, Sub0), command = (1, Sub1))]
 //!     over: usize,
 //!     // the above generates:
 //!     //  .branch(Condition::new(Scalar::new(&mut parameters.over), "over"))
 //!     //  .command(0, Sub0::setup_command)  // assuming `Sub0` is instrumented with `BlargSubParser`
 //!     //  .command(1, Sub1::setup_command)  // assuming `Sub1` is instrumented with `BlargSubParser`
+//!
+//!     #[blarg(skip)]
+//!     lazy: Vec<Dog>,
+//!     // the above generates nothing
 //! }
 //!
 //! #[derive(Default, BlargSubParser)]
@@ -106,6 +131,8 @@
 //! See defining choices on a [parameter](../struct.Parameter.html#method.choice) or [condition](../struct.Condition.html#method.choice) for how this affects the Cli help message.
 //! * `#[blarg(choices = F)]` instructs `blarg` to use the choice function `F`.
 //! This has the same meaning as the previous point.
+//! * `#[blarg(type_name = "..")]` overrides the name of the type in the `type: ..` hint.
+//! When unspecified, common std types are given a friendly name (ex: `number` for `usize`, `path` for `PathBuf`), while other types use their Rust type name.
 //!
 //! The noted two `choices` attributes leverage functions of the signature `fn my_func(value: Parameter<T>) -> Parameter<T>`, where:
 //! * `T` is the concrete type of the field under instrumentation.
@@ -133,6 +160,12 @@
 //!     period: usize,
 //!     // the above generates:
 //!     //  .add(setup_choices(Parameter::argument(Scalar::new(&mut parameters.period), "period")))
+//!
+//!     #[blarg(type_name = "seconds")]
+//!     timeout: u64,
+//!     // the above generates:
+//!     //  .add(Parameter::argument(Scalar::new(&mut parameters.timeout), "timeout")
+//!     //      .meta(vec!["type: seconds"]))
 //! }
 //!
 //! /// My custom setup_choices fn.
@@ -85,12 +85,20 @@
 //! ### Fields
 //! * [`Scalar`]: defines a single-value `Parameter` (applies to both `Parameter::argument` & `Parameter::option`).
 //! This is the most common field to use in your Cli.
+//! Use [`Scalar::lazy`] to defer an expensive conversion until the value is accessed (see [`Lazy`]).
 //! * [`Collection`]: defines a multi-value `Parameter` (applies to both `Parameter::argument` & `Parameter::option`).
 //! This field allows you to configure the cardinality (aka: `Nargs`) for any collection that implements [Collectable](./prelude/trait.Collectable.html).
-//! `blarg` provides this `Collectable` implementations for `Vec<T>` and `HashSet<T>`.
+//! `blarg` provides this `Collectable` implementations for `Vec<T>`, `VecDeque<T>`, `HashSet<T>`, `BTreeSet<T>` (collected in sorted order), and `HashMap<K, V>` (via [`Collection::pairs`](./struct.Collection.html#method.pairs)).
+//! Use [`Collection::shell_command`] to take the rest of the tokens as a shell-quoted command line (ex: `--command ls -la 'my dir'`).
+//! Wrap either in an `Option` (ex: `Option<Vec<T>>`) to distinguish an absent option (`None`) from one given without values (`Some(vec![])`).
+//! Use [`Collection::pairs`] to collect delimited key-value pairs (ex: `Vec<(K, V)>`).
+//! Use [`Collection::path_list`] to collect paths separated by the platform's path list separator (ex: `/a:/b:/c`).
+//! Use [`Collection::from_file`] to collect the lines of a file (ex: `--hosts-file hosts.txt`).
 //! * [`Switch`]: defines a no-value `Parameter::option` (not applicable to `Parameter::argument`).
 //! This is used when specifying Cli *flags* (ex: `--verbose`).
 //! Note that `Switch` may apply to any type `T` (not restricted to just `bool`).
+//! * [`Cycle`]: defines a no-value `Parameter::option` which may be repeated (not applicable to `Parameter::argument`).
+//! Each occurrence advances the variable through an ordered set of states, wrapping around after the final state (ex: `--mode --mode`).
 //! * [`Optional`]: defines a `Parameter::option` (not applicable to `Parameter::argument`).
 //! This field is used exclusively to specify an `Option<T>` type.
 //!
@@ -119,9 +127,9 @@
 //! usage: sub-command [-h] SUB
 //! positional arguments:
 //!  SUB         {1, 2, 3}
-//!    1           the one sub-command
-//!    2           the two sub-command
-//!    3           the three sub-command
+//!    1         the one sub-command
+//!    2         the two sub-command
+//!    3         the three sub-command
 //! <truncated>
 //!
 //! $ sub-command 0 -h
@@ -235,6 +243,10 @@
 //! For example, `-abc` is equivalent to `--apple --banana --carrot`.
 //! The `=` separator rule may be applied *only* to the final option in this syntax.
 //! For example, `-abc=123` is equivalent to `--apple --banana --carrot=123`.
+//! * A bare `-` is not an option, and so is matched as a value (ex: the conventional reference to stdin).
+//! * With `CommandLineParser::negative_numbers`, a `-` followed by a digit is matched as a value (ex: `-5`), unless the digit is itself a short option.
+//! * A bare `--` ends the options, so that all subsequent tokens are matched against the arguments (ex: `-- -weird.txt`).
+//! The `--` itself is not matched as a value, except by [`Collection::split`].
 //!
 //!
 //! ### Field-Narg Interaction
@@ -257,6 +269,7 @@
 //! Collection<C<T>>  | *    | [0, ∞)      | [--NAME [VALUE ...]]     | any amount; captured greedily
 //! Collection<C<T>>  | +    | [1, ∞)      | [--NAME VALUE [...]]     | at least 1; captured greedily
 //! Switch<T>         |      | [0]         | [--NAME]                 | precisely 0
+//! Cycle<T>          |      | [0]         | [--NAME ...]             | precisely 0; repeatable
 //! Optional<T>       |      | [1]         | [--NAME VALUE]           | precisely 1
 //! ```
 //!
@@ -1,4 +1,9 @@
-use blarg::{derive::*, CommandLineParser, Optional, Parameter, Scalar};
+#[allow(unused_imports)]
+use blarg::{
+    derive::*, prelude::*, Collection, CommandLineParser, InvalidCapture, Nargs, Optional,
+    Parameter, Record, Scalar, SubCommand,
+};
+use std::collections::VecDeque;
 
 #[test]
 fn builder_compiles() {
@@ -16,3 +21,134 @@ struct Boo {
 fn derive_compiles() {
     Boo::blarg_parse();
 }
+
+#[derive(Debug, Default, BlargSubParser)]
+struct Optionals {
+    #[blarg(collection = Nargs::Any)]
+    items: Option<Vec<usize>>,
+}
+
+#[test]
+#[cfg(feature = "unit_test")]
+fn derive_optional_collection_absent() {
+    let mut optionals = Optionals::default();
+    let parser = Optionals::setup_command(&mut optionals)(SubCommand::test_dummy())
+        .build_parser()
+        .unwrap();
+    parser.parse_tokens(&[]).unwrap();
+    assert_eq!(optionals.items, None);
+}
+
+#[test]
+#[cfg(feature = "unit_test")]
+fn derive_optional_collection_present() {
+    let mut optionals = Optionals::default();
+    let parser = Optionals::setup_command(&mut optionals)(SubCommand::test_dummy())
+        .build_parser()
+        .unwrap();
+    parser.parse_tokens(&["--items"]).unwrap();
+    assert_eq!(optionals.items, Some(vec![]));
+
+    let mut optionals = Optionals::default();
+    let parser = Optionals::setup_command(&mut optionals)(SubCommand::test_dummy())
+        .build_parser()
+        .unwrap();
+    parser.parse_tokens(&["--items", "1", "2"]).unwrap();
+    assert_eq!(optionals.items, Some(vec![1, 2]));
+}
+
+#[derive(Debug, Default)]
+struct State {
+    count: usize,
+}
+
+#[derive(Debug, Default, BlargSubParser)]
+struct Skipped {
+    value: usize,
+    #[blarg(skip)]
+    state: State,
+}
+
+#[test]
+#[cfg(feature = "unit_test")]
+fn derive_skip() {
+    let mut skipped = Skipped::default();
+    skipped.state.count = 3;
+    let parser = Skipped::setup_command(&mut skipped)(SubCommand::test_dummy())
+        .build_parser()
+        .unwrap();
+    parser.parse_tokens(&["1"]).unwrap();
+    assert_eq!(skipped.value, 1);
+    assert_eq!(skipped.state.count, 3);
+}
+
+#[derive(Debug, PartialEq)]
+struct User {
+    name: String,
+    age: u32,
+}
+
+impl Record for User {
+    const ARITY: u8 = 2;
+
+    fn convert(tokens: &[String]) -> Result<Self, InvalidCapture> {
+        let age = tokens[1]
+            .parse()
+            .map_err(|_| InvalidCapture::InvalidConversion {
+                token: tokens[1].clone(),
+                type_name: "u32",
+            })?;
+        Ok(User {
+            name: tokens[0].clone(),
+            age,
+        })
+    }
+}
+
+#[derive(Debug, Default, BlargSubParser)]
+struct Grouped {
+    #[blarg(group)]
+    users: Vec<User>,
+}
+
+#[test]
+#[cfg(feature = "unit_test")]
+fn derive_group() {
+    let mut grouped = Grouped::default();
+    let parser = Grouped::setup_command(&mut grouped)(SubCommand::test_dummy())
+        .build_parser()
+        .unwrap();
+    parser.parse_tokens(&["alice", "30", "bob", "40"]).unwrap();
+    assert_eq!(
+        grouped.users,
+        vec![
+            User {
+                name: "alice".to_string(),
+                age: 30,
+            },
+            User {
+                name: "bob".to_string(),
+                age: 40,
+            },
+        ]
+    );
+}
+
+#[derive(Debug, Default, BlargSubParser)]
+struct Queued {
+    items: VecDeque<String>,
+}
+
+#[test]
+#[cfg(feature = "unit_test")]
+fn derive_vec_deque() {
+    let mut queued = Queued::default();
+    let parser = Queued::setup_command(&mut queued)(SubCommand::test_dummy())
+        .build_parser()
+        .unwrap();
+    parser.parse_tokens(&["a", "b", "c"]).unwrap();
+    assert_eq!(
+        queued.items,
+        VecDeque::from(["a", "b", "c"].map(String::from))
+    );
+}